@@ -0,0 +1,1257 @@
+//! Ball arithmetic.
+//!
+//! A ball is a midpoint together with a radius that rigorously bounds the distance from the
+//! midpoint to the exact value. Every operation widens the radius by the rounding error of the
+//! midpoint and by the propagated radii of its operands, so that the exact result of a composition
+//! of operations is always contained in the resulting ball. If the whole ball rounds to the same
+//! floating-point number, that number is the correctly rounded result.
+//!
+//! Two implementations are provided: [`DdBall`], a fast ball in double-double arithmetic with a
+//! limited exponent range (used only by the correctly rounded evaluation), and [`MpBall`], a
+//! multiprecision ball at an arbitrary precision. The elementary functions are evaluated on either
+//! kind of ball through [`Function`].
+
+use crate::big_float::BigFloat;
+#[cfg(any(test, feature = "correctly-rounded"))]
+use crate::double_double::{DoubleDouble, PI, PI_ERROR, pow2, round, two_product};
+#[cfg(any(test, feature = "correctly-rounded"))]
+use crate::rem_pio2::{PIO2_1, PIO2_2, PIO2_3, PIO2_3T};
+#[cfg(any(test, feature = "correctly-rounded"))]
+use core::f64::consts::{FRAC_2_PI, FRAC_PI_4};
+
+/// Bound on the relative error of the double-double arithmetic operations.
+#[cfg(any(test, feature = "correctly-rounded"))]
+const EPS_OP: f64 = pow2(-100);
+
+/// Bound on the relative error of the double-double elementary functions.
+#[cfg(any(test, feature = "correctly-rounded"))]
+const EPS_FN: f64 = pow2(-90);
+
+/// Smallest nonzero magnitude of the midpoint of a [`DdBall`].
+#[cfg(any(test, feature = "correctly-rounded"))]
+const MIN_MAGNITUDE: f64 = pow2(-400);
+
+/// Largest magnitude of the midpoint and radius of a [`DdBall`].
+#[cfg(any(test, feature = "correctly-rounded"))]
+const MAX_MAGNITUDE: f64 = pow2(400);
+
+/// Smallest nonzero radius of a [`DdBall`].
+#[cfg(any(test, feature = "correctly-rounded"))]
+const MIN_RADIUS: f64 = pow2(-500);
+
+/// Interval arithmetic on balls.
+///
+/// Each operation returns [`None`] if the result cannot be enclosed (for example, a division by a
+/// ball containing zero, or a midpoint outside of the supported range).
+pub(crate) trait Ball: Sized + Clone {
+    /// Constructs an exact ball.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - Finite value.
+    /// * `precision` - Working precision (ignored for fixed-precision balls).
+    ///
+    /// # Returns
+    ///
+    /// Ball with midpoint `x` and radius $0$.
+    fn new(x: f64, precision: u64) -> Option<Self>;
+
+    /// Constructs an exact ball at the same precision as this ball.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - Finite value.
+    ///
+    /// # Returns
+    ///
+    /// Ball with midpoint `x` and radius $0$.
+    fn exact(&self, x: f64) -> Option<Self>;
+
+    /// Adds two balls.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - Other summand.
+    ///
+    /// # Returns
+    ///
+    /// Ball containing $x+y$.
+    fn add(&self, other: &Self) -> Option<Self>;
+
+    /// Subtracts two balls.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - Subtrahend.
+    ///
+    /// # Returns
+    ///
+    /// Ball containing $x-y$.
+    fn sub(&self, other: &Self) -> Option<Self> {
+        self.add(&other.neg())
+    }
+
+    /// Multiplies two balls.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - Other factor.
+    ///
+    /// # Returns
+    ///
+    /// Ball containing $xy$.
+    fn mul(&self, other: &Self) -> Option<Self>;
+
+    /// Divides two balls.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - Divisor.
+    ///
+    /// # Returns
+    ///
+    /// Ball containing $\frac{x}{y}$.
+    fn div(&self, other: &Self) -> Option<Self>;
+
+    /// Square root of a ball.
+    ///
+    /// # Returns
+    ///
+    /// Ball containing $\sqrt{x}$.
+    fn sqrt(&self) -> Option<Self>;
+
+    /// Negation of a ball.
+    ///
+    /// # Returns
+    ///
+    /// Ball containing $-x$.
+    fn neg(&self) -> Self;
+
+    /// Ball containing $\pi$.
+    ///
+    /// # Returns
+    ///
+    /// Ball containing $\pi$ at the same precision as this ball.
+    fn pi(&self) -> Option<Self>;
+
+    /// Exponential function minus one of a ball.
+    ///
+    /// # Returns
+    ///
+    /// Ball containing $e^{x}-1$.
+    fn exp_m1(&self) -> Option<Self>;
+
+    /// Natural logarithm of a ball.
+    ///
+    /// # Returns
+    ///
+    /// Ball containing $\ln(x)$.
+    fn ln(&self) -> Option<Self>;
+
+    /// Natural logarithm of one plus a ball.
+    ///
+    /// # Returns
+    ///
+    /// Ball containing $\ln(1+x)$.
+    fn ln_1p(&self) -> Option<Self>;
+
+    /// Sine and cosine of a ball.
+    ///
+    /// # Returns
+    ///
+    /// Balls containing $\sin(x)$ and $\cos(x)$.
+    fn sin_cos(&self) -> Option<(Self, Self)>;
+
+    /// Inverse tangent of a ball.
+    ///
+    /// # Returns
+    ///
+    /// Ball containing $\arctan(x)$.
+    fn atan(&self) -> Option<Self>;
+
+    /// Determines whether every point of this ball is positive.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the ball lies in $(0,\infty)$.
+    fn is_positive(&self) -> bool;
+
+    /// Determines whether every point of this ball is negative.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the ball lies in $(-\infty,0)$.
+    fn is_negative(&self) -> bool {
+        self.neg().is_positive()
+    }
+
+    /// Rounds this ball to nearest in a binary floating-point format.
+    ///
+    /// # Arguments
+    ///
+    /// * `precision` - Precision of the format ($24$ for [`f32`] or $53$ for [`f64`]).
+    ///
+    /// # Returns
+    ///
+    /// The value (as an [`f64`]) that every point of the ball rounds to, or [`None`] if the ball
+    /// straddles a rounding boundary.
+    #[cfg(any(test, feature = "correctly-rounded"))]
+    fn round(&self, precision: u32) -> Option<f64>;
+
+    /// Approximates the midpoint of this ball by an [`f64`].
+    ///
+    /// # Returns
+    ///
+    /// Approximate midpoint.
+    fn to_f64(&self) -> f64;
+}
+
+/// Rounds a radius bound up to absorb the rounding errors of its own computation.
+///
+/// # Arguments
+///
+/// * `x` - Radius computed with a handful of round-to-nearest operations on nonnegative values.
+///
+/// # Returns
+///
+/// Upper bound of the exact radius.
+#[cfg(any(test, feature = "correctly-rounded"))]
+fn up(x: f64) -> f64 {
+    x * (1.0 + pow2(-50))
+}
+
+/// Ball with a double-double midpoint and an [`f64`] radius.
+#[cfg(any(test, feature = "correctly-rounded"))]
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct DdBall {
+    /// Midpoint.
+    mid: DoubleDouble,
+
+    /// Radius.
+    rad: f64,
+}
+
+#[cfg(any(test, feature = "correctly-rounded"))]
+impl DdBall {
+    /// Constructs a ball, checking that it lies in the supported range.
+    ///
+    /// # Arguments
+    ///
+    /// * `mid` - Midpoint.
+    /// * `rad` - Radius.
+    ///
+    /// # Returns
+    ///
+    /// Ball, or [`None`] if the midpoint is outside of $\{0\}\cup\left[2^{-400},2^{400}\right]$ in
+    /// magnitude (where the double-double error bounds could be invalidated by underflow or
+    /// overflow) or the radius is too large.
+    fn checked(mid: DoubleDouble, rad: f64) -> Option<DdBall> {
+        let magnitude = mid.hi.abs();
+        let in_range = (magnitude == 0.0 && mid.lo == 0.0)
+            || (MIN_MAGNITUDE..=MAX_MAGNITUDE).contains(&magnitude);
+        if !in_range || rad.is_nan() || rad > MAX_MAGNITUDE {
+            return None;
+        }
+        let rad = if rad == 0.0 { 0.0 } else { rad.max(MIN_RADIUS) };
+        Some(DdBall { mid, rad })
+    }
+
+    /// Upper bound of the magnitude of the midpoint.
+    ///
+    /// # Returns
+    ///
+    /// Upper bound of $|m|$.
+    fn magnitude(&self) -> f64 {
+        up(self.mid.hi.abs())
+    }
+}
+
+#[cfg(any(test, feature = "correctly-rounded"))]
+impl Ball for DdBall {
+    fn new(x: f64, _precision: u64) -> Option<DdBall> {
+        DdBall::checked(DoubleDouble::from_f64(x), 0.0)
+    }
+
+    fn exact(&self, x: f64) -> Option<DdBall> {
+        DdBall::new(x, 0)
+    }
+
+    fn add(&self, other: &DdBall) -> Option<DdBall> {
+        let mid = self.mid.add(other.mid);
+        let rad = up(self.rad + other.rad + EPS_OP * mid.hi.abs());
+        DdBall::checked(mid, rad)
+    }
+
+    fn mul(&self, other: &DdBall) -> Option<DdBall> {
+        let mid = self.mid.mul(other.mid);
+        let rad = up(self.magnitude() * other.rad
+            + other.magnitude() * self.rad
+            + self.rad * other.rad
+            + EPS_OP * mid.hi.abs());
+        DdBall::checked(mid, rad)
+    }
+
+    fn div(&self, other: &DdBall) -> Option<DdBall> {
+        // |x'/y' - x/y| <= (r_x + |x/y| r_y) / (|y| - r_y), where |y| - r_y >= 3|y|/4.
+        if other.mid.hi.abs() <= 4.0 * other.rad {
+            return None;
+        }
+        let mid = self.mid.div(other.mid);
+        let rad = up(
+            (self.rad + up(mid.hi.abs()) * other.rad) / (0.75 * other.mid.hi.abs())
+                + EPS_OP * mid.hi.abs(),
+        );
+        DdBall::checked(mid, rad)
+    }
+
+    fn sqrt(&self) -> Option<DdBall> {
+        // |sqrt(x') - sqrt(x)| = |x' - x| / (sqrt(x') + sqrt(x)) <= r / sqrt(x) for x >= 4r.
+        if self.mid.hi < 0.0 || (self.rad > 0.0 && self.mid.hi <= 4.0 * self.rad) {
+            return None;
+        }
+        let mid = self.mid.sqrt();
+        if mid.hi == 0.0 {
+            return DdBall::checked(mid, 0.0);
+        }
+        let rad = up(self.rad / mid.hi + EPS_OP * mid.hi);
+        DdBall::checked(mid, rad)
+    }
+
+    fn neg(&self) -> DdBall {
+        DdBall {
+            mid: self.mid.neg(),
+            rad: self.rad,
+        }
+    }
+
+    fn pi(&self) -> Option<DdBall> {
+        DdBall::checked(PI, PI_ERROR)
+    }
+
+    fn exp_m1(&self) -> Option<DdBall> {
+        // |e^x' - e^x| <= r e^(x + r) <= r (1 + |e^x - 1|) (1 + r + r^2) for r <= 1/4.
+        if self.mid.hi.abs() > 270.0 || self.rad > 0.25 {
+            return None;
+        }
+        let mid = self.mid.exp_m1();
+        let r = self.rad;
+        let rad = up(r * (1.0 + up(mid.hi.abs())) * (1.0 + r + r * r) + EPS_FN * mid.hi.abs());
+        DdBall::checked(mid, rad)
+    }
+
+    fn ln(&self) -> Option<DdBall> {
+        // |ln(x') - ln(x)| <= r / (x - r) <= 2r / x for x >= 2r.
+        if self.mid.hi <= 4.0 * self.rad {
+            return None;
+        }
+        let mid = self.mid.ln();
+        let rad = up(2.0 * self.rad / self.mid.hi + EPS_FN * mid.hi.abs());
+        DdBall::checked(mid, rad)
+    }
+
+    fn ln_1p(&self) -> Option<DdBall> {
+        // |ln(1 + x') - ln(1 + x)| <= 2r / (1 + x) for 1 + x >= 2r.
+        let one_plus = self.mid.add_f64(1.0).hi;
+        if one_plus <= 4.0 * self.rad {
+            return None;
+        }
+        let mid = self.mid.ln_1p();
+        let rad = up(2.0 * self.rad / (one_plus * (1.0 - pow2(-50))) + EPS_FN * mid.hi.abs());
+        DdBall::checked(mid, rad)
+    }
+
+    fn sin_cos(&self) -> Option<(DdBall, DdBall)> {
+        // Cody–Waite reduction x = nπ/2 + r, where n π/2 is split into 33-bit parts whose products
+        // with n are exact for |n| < 2^20.
+        let (q, r, rad) = if self.mid.hi.abs() <= FRAC_PI_4 {
+            (0, self.mid, self.rad)
+        } else {
+            let n = round(self.mid.hi * FRAC_2_PI);
+            if n.unsigned_abs() >= 1 << 20 {
+                return None;
+            }
+            let k = n as f64;
+            let (p, e) = two_product(k, PIO2_3T);
+            let r = self
+                .mid
+                .add_f64(-k * PIO2_1)
+                .add_f64(-k * PIO2_2)
+                .add_f64(-k * PIO2_3)
+                .sub(DoubleDouble::new(p, e));
+            let rad =
+                up(self.rad + 4.0 * EPS_OP * (r.hi.abs() + k.abs() * 1e-10) + k.abs() * 2e-47);
+            (n & 3, r, rad)
+        };
+        let (s, c) = r.sin_cos();
+        let sin = DdBall::checked(s, up(rad + EPS_FN * s.hi.abs()))?;
+        let cos = DdBall::checked(c, up(rad + EPS_FN * c.hi.abs()))?;
+        Some(match q {
+            0 => (sin, cos),
+            1 => (cos, sin.neg()),
+            2 => (sin.neg(), cos.neg()),
+            _ => (cos.neg(), sin),
+        })
+    }
+
+    fn atan(&self) -> Option<DdBall> {
+        let mid = self.mid.atan();
+        let rad = up(self.rad + EPS_FN * mid.hi.abs());
+        DdBall::checked(mid, rad)
+    }
+
+    fn is_positive(&self) -> bool {
+        self.mid.hi * (1.0 - pow2(-50)) > self.rad
+    }
+
+    fn round(&self, precision: u32) -> Option<f64> {
+        let DoubleDouble { hi, lo } = self.mid;
+        if hi == 0.0 {
+            return (self.rad == 0.0).then_some(hi);
+        }
+
+        // Candidate c, distance d from the midpoint to c, and the gaps to the neighbors of c.
+        let (c, gap_up, gap_down) = if precision == 24 {
+            if !(pow2(-125)..=pow2(127)).contains(&hi.abs()) {
+                return None;
+            }
+            let c = hi as f32;
+            let v = c as f64;
+            (v, c.next_up() as f64 - v, v - c.next_down() as f64)
+        } else {
+            let c = hi + lo;
+            (c, c.next_up() - c, c - c.next_down())
+        };
+        let d = (hi - c) + lo;
+        let e = up(self.rad + d.abs() * pow2(-52));
+        (d + e < 0.5 * gap_up && d - e > -0.5 * gap_down).then_some(c)
+    }
+
+    fn to_f64(&self) -> f64 {
+        self.mid.hi
+    }
+}
+
+/// Upper bound of a nonnegative number, $m\cdot2^{e}$ with $2^{31}\leq m<2^{32}$ (or $m=0$).
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Mag {
+    /// Mantissa $m$.
+    mantissa: u64,
+
+    /// Exponent $e$.
+    exponent: i64,
+}
+
+impl Mag {
+    /// Zero.
+    const ZERO: Mag = Mag {
+        mantissa: 0,
+        exponent: 0,
+    };
+
+    /// Constructs a [`Mag`], rounding the mantissa up to $32$ bits.
+    ///
+    /// # Arguments
+    ///
+    /// * `mantissa` - Mantissa.
+    /// * `exponent` - Exponent.
+    ///
+    /// # Returns
+    ///
+    /// Upper bound of $m\cdot2^{e}$.
+    fn new(mut mantissa: u128, mut exponent: i64) -> Mag {
+        if mantissa == 0 {
+            return Mag::ZERO;
+        }
+        while mantissa >= 1 << 32 {
+            mantissa = (mantissa >> 1) + (mantissa & 1);
+            exponent += 1;
+        }
+        while mantissa < 1 << 31 {
+            mantissa <<= 1;
+            exponent -= 1;
+        }
+        Mag {
+            mantissa: mantissa as u64,
+            exponent,
+        }
+    }
+
+    /// Power of two.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - Exponent.
+    ///
+    /// # Returns
+    ///
+    /// $2^{n}$.
+    fn pow2(n: i64) -> Mag {
+        Mag::new(1, n)
+    }
+
+    /// Upper bound of the magnitude of a number.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - Number.
+    ///
+    /// # Returns
+    ///
+    /// Upper bound of $|x|$.
+    fn upper(x: &BigFloat) -> Mag {
+        let (mantissa, exponent, inexact) = x.leading_bits();
+        Mag::new(mantissa as u128 + inexact as u128, exponent)
+    }
+
+    /// Lower bound of the magnitude of a number.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - Number.
+    ///
+    /// # Returns
+    ///
+    /// Lower bound of $|x|$ (exactly representable as a [`Mag`]).
+    fn lower(x: &BigFloat) -> Mag {
+        let (mantissa, exponent, _) = x.leading_bits();
+        Mag::new(mantissa as u128, exponent)
+    }
+
+    /// Determines whether this bound is zero.
+    ///
+    /// # Returns
+    ///
+    /// `true` if this bound is $0$.
+    fn is_zero(self) -> bool {
+        self.mantissa == 0
+    }
+
+    /// Adds two bounds.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - Other bound.
+    ///
+    /// # Returns
+    ///
+    /// Upper bound of $a+b$.
+    fn add(self, other: Mag) -> Mag {
+        if self.is_zero() {
+            return other;
+        }
+        if other.is_zero() {
+            return self;
+        }
+        let (big, small) = if self.exponent >= other.exponent {
+            (self, other)
+        } else {
+            (other, self)
+        };
+        let shift = (big.exponent - small.exponent) as u64;
+
+        // Align the smaller bound to the exponent of the larger bound minus 32 bits, rounding up.
+        let (mantissa, exponent) = ((big.mantissa as u128) << 32, big.exponent - 32);
+        let small = if shift >= 96 {
+            1
+        } else {
+            let s = (small.mantissa as u128) << 32;
+            (s >> shift) + ((s & ((1 << shift) - 1)) != 0) as u128
+        };
+        Mag::new(mantissa + small, exponent)
+    }
+
+    /// Multiplies two bounds.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - Other bound.
+    ///
+    /// # Returns
+    ///
+    /// Upper bound of $ab$.
+    fn mul(self, other: Mag) -> Mag {
+        Mag::new(
+            self.mantissa as u128 * other.mantissa as u128,
+            self.exponent + other.exponent,
+        )
+    }
+
+    /// Divides this bound by a lower bound of a positive number.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - Nonzero lower bound of the divisor.
+    ///
+    /// # Returns
+    ///
+    /// Upper bound of $\frac{a}{b}$.
+    fn div(self, other: Mag) -> Mag {
+        if self.is_zero() {
+            return Mag::ZERO;
+        }
+        Mag::new(
+            ((self.mantissa as u128) << 64) / other.mantissa as u128 + 1,
+            self.exponent - other.exponent - 64,
+        )
+    }
+
+    /// Multiplies this bound by $2^{n}$.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - Exponent.
+    ///
+    /// # Returns
+    ///
+    /// $2^{n}a$.
+    fn mul_pow2(self, n: i64) -> Mag {
+        if self.is_zero() {
+            self
+        } else {
+            Mag::new(self.mantissa as u128, self.exponent + n)
+        }
+    }
+
+    /// Determines whether this bound is less than another bound.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - Other bound.
+    ///
+    /// # Returns
+    ///
+    /// `true` if $a<b$.
+    fn lt(self, other: Mag) -> bool {
+        match (self.is_zero(), other.is_zero()) {
+            (_, true) => false,
+            (true, false) => true,
+            _ => (self.exponent, self.mantissa) < (other.exponent, other.mantissa),
+        }
+    }
+
+    /// Converts this bound to a [`BigFloat`].
+    ///
+    /// # Returns
+    ///
+    /// This bound as a [`BigFloat`] (exact).
+    fn to_big(self) -> BigFloat {
+        BigFloat::from_parts(self.mantissa, self.exponent)
+    }
+}
+
+/// Ball with a multiprecision midpoint and a [`Mag`] radius.
+#[derive(Clone, Debug)]
+pub(crate) struct MpBall {
+    /// Midpoint.
+    mid: BigFloat,
+
+    /// Radius.
+    rad: Mag,
+
+    /// Working precision, in bits.
+    precision: u64,
+}
+
+impl MpBall {
+    /// Constructs a ball from an exactly computed midpoint, truncating it to the working precision.
+    ///
+    /// # Arguments
+    ///
+    /// * `mid` - Exact midpoint.
+    /// * `rad` - Radius of the exact midpoint.
+    ///
+    /// # Returns
+    ///
+    /// Ball at the working precision.
+    fn exact_mid(&self, mid: BigFloat, rad: Mag) -> MpBall {
+        let (mid, inexact) = mid.truncate(self.precision);
+        let rad = if inexact {
+            rad.add(Mag::pow2(mid.msb() + 1 - self.precision as i64))
+        } else {
+            rad
+        };
+        MpBall {
+            mid,
+            rad,
+            precision: self.precision,
+        }
+    }
+
+    /// Constructs a ball from a midpoint computed by an elementary function with a relative error
+    /// below $2^{-(p+8)}$, truncating it to the working precision $p$.
+    ///
+    /// # Arguments
+    ///
+    /// * `mid` - Approximate midpoint.
+    /// * `rad` - Propagated radius.
+    ///
+    /// # Returns
+    ///
+    /// Ball at the working precision.
+    fn approximate_mid(&self, mid: BigFloat, rad: Mag) -> MpBall {
+        let error = Mag::upper(&mid).mul_pow2(2 - self.precision as i64);
+        MpBall {
+            mid: mid.truncate(self.precision).0,
+            rad: rad.add(error),
+            precision: self.precision,
+        }
+    }
+
+    /// Determines whether the midpoint exceeds a multiple of the radius in magnitude.
+    ///
+    /// # Arguments
+    ///
+    /// * `mid` - Midpoint.
+    /// * `factor` - Exponent $k$ of the multiple $2^{k}$.
+    ///
+    /// # Returns
+    ///
+    /// `true` if $|m|>2^{k}r$.
+    fn dominates(&self, mid: &BigFloat, factor: i64) -> bool {
+        !mid.is_zero() && self.rad.mul_pow2(factor).lt(Mag::lower(mid))
+    }
+
+    /// Rounds the midpoint of this ball to nearest in a binary floating-point format.
+    ///
+    /// # Arguments
+    ///
+    /// * `precision` - Precision of the format ($24$ for [`f32`] or $53$ for [`f64`]).
+    ///
+    /// # Returns
+    ///
+    /// Midpoint rounded to nearest, as an [`f64`].
+    #[cfg(any(test, feature = "correctly-rounded"))]
+    pub(crate) fn round_midpoint(&self, precision: u32) -> f64 {
+        self.mid.round(precision)
+    }
+
+    /// Constructs an exact ball from a multiprecision value.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - Value.
+    /// * `precision` - Working precision, in bits.
+    ///
+    /// # Returns
+    ///
+    /// Ball with midpoint `x` and radius $0$.
+    #[cfg(feature = "arbitrary-precision")]
+    pub(crate) fn from_big_float(x: BigFloat, precision: u64) -> MpBall {
+        MpBall {
+            mid: x,
+            rad: Mag::ZERO,
+            precision,
+        }
+    }
+
+    /// Rounds this ball to nearest with a given number of significant bits.
+    ///
+    /// # Arguments
+    ///
+    /// * `precision` - Number of significant bits.
+    ///
+    /// # Returns
+    ///
+    /// The value that every point of the ball rounds to, or [`None`] if the ball straddles a
+    /// rounding boundary.
+    #[cfg(feature = "arbitrary-precision")]
+    pub(crate) fn round_to(&self, precision: u64) -> Option<BigFloat> {
+        let rad = self.rad.to_big();
+        let lower = self.mid.sub(&rad).round_nearest(precision);
+        let upper = self.mid.add(&rad).round_nearest(precision);
+        (lower == upper).then_some(lower)
+    }
+
+    /// Rounds the midpoint of this ball to nearest with a given number of significant bits.
+    ///
+    /// # Arguments
+    ///
+    /// * `precision` - Number of significant bits.
+    ///
+    /// # Returns
+    ///
+    /// Midpoint rounded to nearest.
+    #[cfg(feature = "arbitrary-precision")]
+    pub(crate) fn round_midpoint_to(&self, precision: u64) -> BigFloat {
+        self.mid.round_nearest(precision)
+    }
+}
+
+impl Ball for MpBall {
+    fn new(x: f64, precision: u64) -> Option<MpBall> {
+        Some(MpBall {
+            mid: BigFloat::from_f64(x),
+            rad: Mag::ZERO,
+            precision,
+        })
+    }
+
+    fn exact(&self, x: f64) -> Option<MpBall> {
+        MpBall::new(x, self.precision)
+    }
+
+    fn add(&self, other: &MpBall) -> Option<MpBall> {
+        Some(self.exact_mid(self.mid.add(&other.mid), self.rad.add(other.rad)))
+    }
+
+    fn mul(&self, other: &MpBall) -> Option<MpBall> {
+        let rad = Mag::upper(&self.mid)
+            .mul(other.rad)
+            .add(Mag::upper(&other.mid).mul(self.rad))
+            .add(self.rad.mul(other.rad));
+        Some(self.exact_mid(self.mid.mul(&other.mid), rad))
+    }
+
+    fn div(&self, other: &MpBall) -> Option<MpBall> {
+        // |x'/y' - x/y| <= (r_x + |x/y| r_y) / (|y| - r_y), where |y| - r_y >= |y|/2 and
+        // |x/y| <= 2|q| for the truncated quotient q.
+        if !other.dominates(&other.mid, 1) {
+            return None;
+        }
+        let (mid, inexact) = self.mid.div(&other.mid, self.precision);
+        let rad = self
+            .rad
+            .add(Mag::upper(&mid).mul(other.rad).mul_pow2(1))
+            .div(Mag::lower(&other.mid))
+            .mul_pow2(1);
+        let rad = if inexact {
+            rad.add(Mag::pow2(mid.msb() + 1 - self.precision as i64))
+        } else {
+            rad
+        };
+        Some(MpBall {
+            mid,
+            rad,
+            precision: self.precision,
+        })
+    }
+
+    fn sqrt(&self) -> Option<MpBall> {
+        // |sqrt(x') - sqrt(x)| <= r / sqrt(x) for x >= 2r, and sqrt(x) is at least the truncated
+        // root.
+        if self.mid.is_negative() || (!self.rad.is_zero() && !self.dominates(&self.mid, 1)) {
+            return None;
+        }
+        let (mid, inexact) = self.mid.sqrt(self.precision);
+        if mid.is_zero() {
+            return Some(MpBall {
+                mid,
+                rad: Mag::ZERO,
+                precision: self.precision,
+            });
+        }
+        let rad = self.rad.div(Mag::lower(&mid));
+        let rad = if inexact {
+            rad.add(Mag::pow2(mid.msb() + 1 - self.precision as i64))
+        } else {
+            rad
+        };
+        Some(MpBall {
+            mid,
+            rad,
+            precision: self.precision,
+        })
+    }
+
+    fn neg(&self) -> MpBall {
+        MpBall {
+            mid: self.mid.neg(),
+            rad: self.rad,
+            precision: self.precision,
+        }
+    }
+
+    fn pi(&self) -> Option<MpBall> {
+        Some(self.approximate_mid(BigFloat::pi(self.precision + 8), Mag::ZERO))
+    }
+
+    fn exp_m1(&self) -> Option<MpBall> {
+        // |e^x' - e^x| <= r e^(x + r) <= r (1 + |e^x - 1|) (1 + r + r^2) for r <= 1/4.
+        if self.mid.msb() >= 40 || Mag::pow2(-2).lt(self.rad) {
+            return None;
+        }
+        let mid = self.mid.exp_m1(self.precision + 8);
+        let r = self.rad;
+        let rad = r
+            .mul(Mag::upper(&mid).add(Mag::pow2(0)))
+            .mul(Mag::pow2(0).add(r).add(r.mul(r)));
+        Some(self.approximate_mid(mid, rad))
+    }
+
+    fn ln(&self) -> Option<MpBall> {
+        // |ln(x') - ln(x)| <= r / (x - r) <= 2r / x for x >= 2r.
+        if self.mid.is_negative() || !self.dominates(&self.mid, 1) {
+            return None;
+        }
+        let rad = self.rad.mul_pow2(1).div(Mag::lower(&self.mid));
+        Some(self.approximate_mid(self.mid.ln(self.precision + 8), rad))
+    }
+
+    fn ln_1p(&self) -> Option<MpBall> {
+        let one_plus = self.mid.add(&BigFloat::from_i64(1));
+        if one_plus.is_negative() || !self.dominates(&one_plus, 1) {
+            return None;
+        }
+        let rad = self.rad.mul_pow2(1).div(Mag::lower(&one_plus));
+        Some(self.approximate_mid(self.mid.ln_1p(self.precision + 8), rad))
+    }
+
+    fn sin_cos(&self) -> Option<(MpBall, MpBall)> {
+        // Reduction x = nπ/2 + r, with π/2 computed to enough bits that r keeps the working
+        // precision.
+        let (q, r) = if self.mid.msb() < 0 {
+            (0, self.clone())
+        } else {
+            let msb = self.mid.msb();
+            let w = self.precision + msb as u64 + 64;
+            let half_pi = BigFloat::pi(w).mul_pow2(-1);
+            let (n, q) = self.mid.div(&half_pi, msb as u64 + 64).0.round_integer();
+            let rad = self.rad.add(Mag::pow2(n.msb() + 2 - w as i64));
+            (q, self.exact_mid(self.mid.sub(&n.mul(&half_pi)), rad))
+        };
+        let (s, c) = r.mid.sin_cos(self.precision + 8);
+        let sin = self.approximate_mid(s, r.rad);
+        let cos = self.approximate_mid(c, r.rad);
+        Some(match q {
+            0 => (sin, cos),
+            1 => (cos, sin.neg()),
+            2 => (sin.neg(), cos.neg()),
+            _ => (cos.neg(), sin),
+        })
+    }
+
+    fn atan(&self) -> Option<MpBall> {
+        Some(self.approximate_mid(self.mid.atan(self.precision + 8), self.rad))
+    }
+
+    fn is_positive(&self) -> bool {
+        !self.mid.is_negative() && self.dominates(&self.mid, 0)
+    }
+
+    #[cfg(any(test, feature = "correctly-rounded"))]
+    fn round(&self, precision: u32) -> Option<f64> {
+        let rad = self.rad.to_big();
+        let lower = self.mid.sub(&rad).round(precision);
+        let upper = self.mid.add(&rad).round(precision);
+        (lower.to_bits() == upper.to_bits()).then_some(lower)
+    }
+
+    fn to_f64(&self) -> f64 {
+        self.mid.to_f64()
+    }
+}
+
+/// Angular unit.
+#[derive(Clone, Copy)]
+pub(crate) enum Unit {
+    /// Radians.
+    Radians,
+
+    /// Degrees.
+    Degrees,
+
+    /// Half-turns.
+    HalfTurns,
+}
+
+impl Unit {
+    /// A quarter turn in this unit (for units with an exact quarter turn).
+    ///
+    /// # Returns
+    ///
+    /// Quarter turn.
+    pub(crate) fn quarter_turn(self) -> f64 {
+        match self {
+            Unit::Degrees => 90.0,
+            _ => 0.5,
+        }
+    }
+
+    /// Converts a ball from this unit to radians.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - Angle in this unit.
+    ///
+    /// # Returns
+    ///
+    /// Angle in radians.
+    fn to_radians<B: Ball>(self, x: &B) -> Option<B> {
+        match self {
+            Unit::Radians => Some(x.clone()),
+            Unit::Degrees => x.mul(&x.pi()?.div(&x.exact(180.0)?)?),
+            Unit::HalfTurns => x.mul(&x.pi()?),
+        }
+    }
+
+    /// Converts a ball from radians to this unit.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - Angle in radians.
+    ///
+    /// # Returns
+    ///
+    /// Angle in this unit.
+    pub(crate) fn radians_to<B: Ball>(self, x: &B) -> Option<B> {
+        match self {
+            Unit::Radians => Some(x.clone()),
+            Unit::Degrees => x.mul(&x.exact(180.0)?)?.div(&x.pi()?),
+            Unit::HalfTurns => x.div(&x.pi()?),
+        }
+    }
+}
+
+/// Function evaluated in ball arithmetic.
+///
+/// The trigonometric functions take an angle in the given unit, and the inverse trigonometric
+/// functions return an angle in the given unit. The hyperbolic functions and their inverses (except
+/// for `Acosh` and `Asech`, whose domains are positive) take the absolute value of their argument.
+#[derive(Clone, Copy)]
+pub(crate) enum Function {
+    Sin(Unit),
+    Cos(Unit),
+    Tan(Unit),
+    Csc(Unit),
+    Sec(Unit),
+    Cot(Unit),
+    Asin(Unit),
+    Acos(Unit),
+    Atan(Unit),
+    Acsc(Unit),
+    Asec(Unit),
+    Acot(Unit),
+    ToRadians(Unit),
+    FromRadians(Unit),
+    Sinh,
+    Cosh,
+    Tanh,
+    Csch,
+    Sech,
+    Coth,
+    Asinh,
+    Acosh,
+    Atanh,
+    Acsch,
+    Asech,
+    Acoth,
+}
+
+impl Function {
+    /// Evaluates this function on an exact ball.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - Argument.
+    ///
+    /// # Returns
+    ///
+    /// Ball containing the function value.
+    pub(crate) fn eval<B: Ball>(self, x: &B) -> Option<B> {
+        let one = x.exact(1.0)?;
+        let two = x.exact(2.0)?;
+        let half = x.exact(0.5)?;
+        match self {
+            Function::Sin(unit) => Some(unit.to_radians(x)?.sin_cos()?.0),
+            Function::Cos(unit) => Some(unit.to_radians(x)?.sin_cos()?.1),
+            Function::Tan(unit) => {
+                let (s, c) = unit.to_radians(x)?.sin_cos()?;
+                s.div(&c)
+            }
+            Function::Csc(unit) => one.div(&unit.to_radians(x)?.sin_cos()?.0),
+            Function::Sec(unit) => one.div(&unit.to_radians(x)?.sin_cos()?.1),
+            Function::Cot(unit) => {
+                let (s, c) = unit.to_radians(x)?.sin_cos()?;
+                c.div(&s)
+            }
+            Function::Asin(unit) => {
+                let c = one.sub(x)?.mul(&one.add(x)?)?.sqrt()?;
+                unit.radians_to(&atan2_ball(x, &c)?)
+            }
+            Function::Acos(unit) => {
+                let s = one.sub(x)?.mul(&one.add(x)?)?.sqrt()?;
+                unit.radians_to(&atan2_ball(&s, x)?)
+            }
+            Function::Atan(unit) => unit.radians_to(&x.atan()?),
+            Function::Acsc(unit) => {
+                let sign = if x.is_negative() {
+                    one.neg()
+                } else {
+                    one.clone()
+                };
+                let c = x.sub(&one)?.mul(&x.add(&one)?)?.sqrt()?;
+                unit.radians_to(&atan2_ball(&sign, &c)?)
+            }
+            Function::Asec(unit) => {
+                let sign = if x.is_negative() {
+                    one.neg()
+                } else {
+                    one.clone()
+                };
+                let s = x.sub(&one)?.mul(&x.add(&one)?)?.sqrt()?;
+                unit.radians_to(&atan2_ball(&s, &sign)?)
+            }
+            Function::Acot(unit) => {
+                let (sign, abs) = if x.is_negative() {
+                    (one.neg(), x.neg())
+                } else {
+                    (one.clone(), x.clone())
+                };
+                unit.radians_to(&atan2_ball(&sign, &abs)?)
+            }
+            Function::ToRadians(unit) => unit.to_radians(x),
+            Function::FromRadians(unit) => unit.radians_to(x),
+            Function::Sinh => {
+                // sinh(x) = (E + E / (E + 1)) / 2 with E = e^x - 1.
+                let e = x.exp_m1()?;
+                e.add(&e.div(&e.add(&one)?)?)?.mul(&half)
+            }
+            Function::Cosh => {
+                let e = x.exp_m1()?.add(&one)?;
+                e.add(&one.div(&e)?)?.mul(&half)
+            }
+            Function::Tanh => {
+                // tanh(x) = E / (E + 2) with E = e^(2x) - 1.
+                let e = x.add(x)?.exp_m1()?;
+                e.div(&e.add(&two)?)
+            }
+            Function::Csch => one.div(&Function::Sinh.eval(x)?),
+            Function::Sech => one.div(&Function::Cosh.eval(x)?),
+            Function::Coth => {
+                let e = x.add(x)?.exp_m1()?;
+                e.add(&two)?.div(&e)
+            }
+            Function::Asinh => {
+                if less_than(x, 1.0)? {
+                    // asinh(x) = ln(1 + x + x² / (1 + sqrt(1 + x²))).
+                    let x2 = x.mul(x)?;
+                    x.add(&x2.div(&one.add(&one.add(&x2)?.sqrt()?)?)?)?.ln_1p()
+                } else {
+                    // asinh(x) = ln(x) + ln(1 + sqrt(1 + 1 / x²)).
+                    let root = one.add(&one.div(&x.mul(x)?)?)?.sqrt()?;
+                    x.ln()?.add(&root.ln_1p()?)
+                }
+            }
+            Function::Acosh => {
+                if less_than(x, 2.0)? {
+                    // acosh(x) = ln(1 + t + sqrt(t (2 + t))) with t = x - 1.
+                    let t = x.sub(&one)?;
+                    t.add(&t.mul(&two.add(&t)?)?.sqrt()?)?.ln_1p()
+                } else {
+                    // acosh(x) = ln(x) + ln(1 + sqrt(1 - 1 / x²)).
+                    let y = one.div(x)?;
+                    let root = one.sub(&y)?.mul(&one.add(&y)?)?.sqrt()?;
+                    x.ln()?.add(&root.ln_1p()?)
+                }
+            }
+            Function::Atanh => {
+                // atanh(x) = ln(1 + 2x / (1 - x)) / 2.
+                two.mul(x)?.div(&one.sub(x)?)?.ln_1p()?.mul(&half)
+            }
+            Function::Acsch => {
+                if less_than(x, 1.0)? {
+                    // acsch(x) = ln(1 + sqrt(1 + x²)) - ln(x).
+                    let root = one.add(&x.mul(x)?)?.sqrt()?;
+                    root.ln_1p()?.sub(&x.ln()?)
+                } else {
+                    // acsch(x) = asinh(y) = ln(1 + y + y² / (1 + sqrt(1 + y²))) with y = 1 / x.
+                    let y = one.div(x)?;
+                    let y2 = y.mul(&y)?;
+                    y.add(&y2.div(&one.add(&one.add(&y2)?.sqrt()?)?)?)?.ln_1p()
+                }
+            }
+            Function::Asech => {
+                if less_than(x, 0.5)? {
+                    // asech(x) = ln(1 + sqrt((1 - x)(1 + x))) - ln(x).
+                    let root = one.sub(x)?.mul(&one.add(x)?)?.sqrt()?;
+                    root.ln_1p()?.sub(&x.ln()?)
+                } else {
+                    // asech(x) = ln(1 + t + sqrt(t (2 + t))) with t = (1 - x) / x.
+                    let t = one.sub(x)?.div(x)?;
+                    t.add(&t.mul(&two.add(&t)?)?.sqrt()?)?.ln_1p()
+                }
+            }
+            Function::Acoth => {
+                // acoth(x) = ln(1 + 2 / (x - 1)) / 2.
+                two.div(&x.sub(&one)?)?.ln_1p()?.mul(&half)
+            }
+        }
+    }
+}
+
+/// Determines whether a ball lies below a constant.
+///
+/// # Arguments
+///
+/// * `x` - Exact ball.
+/// * `c` - Constant.
+///
+/// # Returns
+///
+/// `true` if $x<c$.
+fn less_than<B: Ball>(x: &B, c: f64) -> Option<bool> {
+    Some(x.sub(&x.exact(c)?)?.is_negative())
+}
+
+/// Two-argument inverse tangent of balls.
+///
+/// # Arguments
+///
+/// * `y` - Ordinate.
+/// * `x` - Abscissa.
+///
+/// # Returns
+///
+/// Ball containing $\text{atan2}(y,x)$, or [`None`] if the quadrant cannot be decided.
+pub(crate) fn atan2_ball<B: Ball>(y: &B, x: &B) -> Option<B> {
+    if x.to_f64().abs() >= y.to_f64().abs() {
+        let a = y.div(x)?.atan()?;
+        if x.is_positive() {
+            Some(a)
+        } else if y.is_positive() {
+            a.add(&a.pi()?)
+        } else if y.is_negative() {
+            a.sub(&a.pi()?)
+        } else {
+            None
+        }
+    } else {
+        // atan2(y, x) = sgn(y) π/2 - atan(x / y).
+        let a = x.div(y)?.atan()?;
+        let half_pi = a.pi()?.mul(&a.exact(0.5)?)?;
+        if y.is_positive() {
+            half_pi.sub(&a)
+        } else if y.is_negative() {
+            half_pi.neg().sub(&a)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_dd_ball_round() {
+        let one = DdBall::new(1.0, 0).unwrap();
+        assert_eq!(one.pi().unwrap().round(53), Some(std::f64::consts::PI));
+        assert_eq!(
+            one.exp_m1().unwrap().round(53),
+            Some(1.718_281_828_459_045_3)
+        );
+
+        // A ball straddling a rounding boundary cannot be rounded.
+        let half_ulp = one.exact(f64::EPSILON / 2.0).unwrap();
+        assert_eq!(one.add(&half_ulp).unwrap().round(53), None);
+    }
+
+    #[test]
+    fn test_mp_ball_round() {
+        let x = MpBall::new(1e22, 256).unwrap();
+        let (sin, _) = x.sin_cos().unwrap();
+        assert_eq!(sin.round(53), Some(-0.8522008497671888));
+        assert_eq!(x.pi().unwrap().round(24), Some(std::f32::consts::PI as f64));
+    }
+}
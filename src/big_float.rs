@@ -0,0 +1,1303 @@
+//! Arbitrary-precision binary floating-point arithmetic.
+//!
+//! A [`BigFloat`] is a sign, an arbitrary-size integer mantissa, and an unbounded binary exponent.
+//! Addition, subtraction, and multiplication are exact; division and square roots are truncated to
+//! a requested number of bits. The elementary functions are evaluated with fixed-point Taylor
+//! series at a working precision with enough guard bits that the final relative error is below
+//! $2^{-p}$ for a requested precision $p$.
+
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+/// Number of guard bits added to the working precision of the elementary functions.
+const GUARD_BITS: u64 = 48;
+
+/// Arbitrary-precision binary floating-point number $(-1)^{s}\cdot m\cdot2^{e}$.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct BigFloat {
+    /// Sign $s$.
+    negative: bool,
+
+    /// Exponent $e$.
+    exponent: i64,
+
+    /// Mantissa $m$ as little-endian 64-bit limbs (odd, or empty for zero).
+    mantissa: Vec<u64>,
+}
+
+impl BigFloat {
+    /// Constructs a [`BigFloat`] from its parts, normalizing the mantissa.
+    ///
+    /// # Arguments
+    ///
+    /// * `negative` - Sign.
+    /// * `exponent` - Exponent.
+    /// * `mantissa` - Mantissa as little-endian 64-bit limbs.
+    ///
+    /// # Returns
+    ///
+    /// $(-1)^{s}\cdot m\cdot2^{e}$.
+    fn new(negative: bool, exponent: i64, mut mantissa: Vec<u64>) -> BigFloat {
+        trim(&mut mantissa);
+        if mantissa.is_empty() {
+            return BigFloat::zero();
+        }
+        let zeros = trailing_zeros(&mantissa);
+        if zeros > 0 {
+            mantissa = shr(&mantissa, zeros).0;
+        }
+        BigFloat {
+            negative,
+            exponent: exponent + zeros as i64,
+            mantissa,
+        }
+    }
+
+    /// Zero.
+    ///
+    /// # Returns
+    ///
+    /// $0$.
+    pub(crate) fn zero() -> BigFloat {
+        BigFloat {
+            negative: false,
+            exponent: 0,
+            mantissa: Vec::new(),
+        }
+    }
+
+    /// Converts a finite [`f64`] to a [`BigFloat`].
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - Finite value.
+    ///
+    /// # Returns
+    ///
+    /// `x` as a [`BigFloat`] (exact, with a zero of either sign mapped to $0$).
+    pub(crate) fn from_f64(x: f64) -> BigFloat {
+        let bits = x.to_bits();
+        let biased = ((bits >> 52) & 0x7ff) as i64;
+        let fraction = bits & 0x000f_ffff_ffff_ffff;
+        let (m, e) = if biased == 0 {
+            (fraction, -1074)
+        } else {
+            (fraction | 0x0010_0000_0000_0000, biased - 1075)
+        };
+        BigFloat::new(x.is_sign_negative(), e, vec![m])
+    }
+
+    /// Converts an integer to a [`BigFloat`].
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - Integer.
+    ///
+    /// # Returns
+    ///
+    /// `n` as a [`BigFloat`] (exact).
+    pub(crate) fn from_i64(n: i64) -> BigFloat {
+        BigFloat::new(n < 0, 0, vec![n.unsigned_abs()])
+    }
+
+    /// Constructs a nonnegative [`BigFloat`] from a single-limb mantissa.
+    ///
+    /// # Arguments
+    ///
+    /// * `mantissa` - Mantissa $m$.
+    /// * `exponent` - Exponent $e$.
+    ///
+    /// # Returns
+    ///
+    /// $m\cdot2^{e}$.
+    pub(crate) fn from_parts(mantissa: u64, exponent: i64) -> BigFloat {
+        BigFloat::new(false, exponent, vec![mantissa])
+    }
+
+    /// Leading 32 bits of the magnitude of this number.
+    ///
+    /// # Returns
+    ///
+    /// Mantissa $m<2^{32}$ and exponent $e$ such that $m\cdot2^{e}$ is $|x|$ truncated to $32$ bits,
+    /// and whether that truncation was inexact.
+    pub(crate) fn leading_bits(&self) -> (u64, i64, bool) {
+        if self.is_zero() {
+            return (0, 0, false);
+        }
+        let (x, inexact) = self.truncate(32);
+        (x.mantissa[0], x.exponent, inexact)
+    }
+
+    /// Determines whether this number is zero.
+    ///
+    /// # Returns
+    ///
+    /// `true` if this number is $0$.
+    pub(crate) fn is_zero(&self) -> bool {
+        self.mantissa.is_empty()
+    }
+
+    /// Determines whether this number is negative.
+    ///
+    /// # Returns
+    ///
+    /// `true` if this number is less than $0$.
+    pub(crate) fn is_negative(&self) -> bool {
+        self.negative && !self.is_zero()
+    }
+
+    /// Exponent of the leading bit of this number.
+    ///
+    /// # Returns
+    ///
+    /// $\lfloor\log_{2}|x|\rfloor$ for $x\neq0$ (and [`i64::MIN`] for $x=0$).
+    pub(crate) fn msb(&self) -> i64 {
+        if self.is_zero() {
+            i64::MIN
+        } else {
+            self.exponent + bit_length(&self.mantissa) as i64 - 1
+        }
+    }
+
+    /// Negates this number.
+    ///
+    /// # Returns
+    ///
+    /// $-x$ (exact).
+    pub(crate) fn neg(&self) -> BigFloat {
+        BigFloat::new(!self.negative, self.exponent, self.mantissa.clone())
+    }
+
+    /// Absolute value of this number.
+    ///
+    /// # Returns
+    ///
+    /// $|x|$ (exact).
+    pub(crate) fn abs(&self) -> BigFloat {
+        BigFloat::new(false, self.exponent, self.mantissa.clone())
+    }
+
+    /// Multiplies this number by $2^{n}$.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - Exponent.
+    ///
+    /// # Returns
+    ///
+    /// $2^{n}x$ (exact).
+    pub(crate) fn mul_pow2(&self, n: i64) -> BigFloat {
+        BigFloat::new(self.negative, self.exponent + n, self.mantissa.clone())
+    }
+
+    /// Truncates this number to a given number of significant bits.
+    ///
+    /// # Arguments
+    ///
+    /// * `precision` - Number of significant bits.
+    ///
+    /// # Returns
+    ///
+    /// `x` rounded toward zero to `precision` bits, and whether that rounding was inexact. The
+    /// rounding error is below $2^{\text{msb}(x)+1-p}$.
+    pub(crate) fn truncate(&self, precision: u64) -> (BigFloat, bool) {
+        let length = bit_length(&self.mantissa);
+        if length <= precision {
+            return (self.clone(), false);
+        }
+        let shift = length - precision;
+        let (mantissa, inexact) = shr(&self.mantissa, shift);
+        (
+            BigFloat::new(self.negative, self.exponent + shift as i64, mantissa),
+            inexact,
+        )
+    }
+
+    /// Rounds this number to nearest with a given number of significant bits.
+    ///
+    /// # Arguments
+    ///
+    /// * `precision` - Number of significant bits.
+    ///
+    /// # Returns
+    ///
+    /// `x` rounded to nearest (ties to even) to `precision` bits.
+    #[cfg(feature = "arbitrary-precision")]
+    pub(crate) fn round_nearest(&self, precision: u64) -> BigFloat {
+        let (truncated, inexact) = self.truncate(precision);
+        if !inexact {
+            return truncated;
+        }
+        let quantum = self.msb() + 1 - precision as i64;
+        let half = BigFloat::new(false, quantum - 1, vec![1]);
+        let odd = truncated.exponent == quantum;
+        match self.sub(&truncated).cmp_abs(&half) {
+            Ordering::Less => truncated,
+            Ordering::Equal if !odd => truncated,
+            _ => truncated.add(&BigFloat::new(self.negative, quantum, vec![1])),
+        }
+    }
+
+    /// Adds another number to this number.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - Other summand.
+    ///
+    /// # Returns
+    ///
+    /// $x+y$ (exact).
+    pub(crate) fn add(&self, other: &BigFloat) -> BigFloat {
+        if self.is_zero() {
+            return other.clone();
+        }
+        if other.is_zero() {
+            return self.clone();
+        }
+        let exponent = self.exponent.min(other.exponent);
+        let a = shl(&self.mantissa, (self.exponent - exponent) as u64);
+        let b = shl(&other.mantissa, (other.exponent - exponent) as u64);
+        if self.negative == other.negative {
+            BigFloat::new(self.negative, exponent, add(&a, &b))
+        } else {
+            match cmp(&a, &b) {
+                Ordering::Less => BigFloat::new(other.negative, exponent, sub(&b, &a)),
+                _ => BigFloat::new(self.negative, exponent, sub(&a, &b)),
+            }
+        }
+    }
+
+    /// Subtracts another number from this number.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - Subtrahend.
+    ///
+    /// # Returns
+    ///
+    /// $x-y$ (exact).
+    pub(crate) fn sub(&self, other: &BigFloat) -> BigFloat {
+        self.add(&other.neg())
+    }
+
+    /// Multiplies this number by another number.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - Other factor.
+    ///
+    /// # Returns
+    ///
+    /// $xy$ (exact).
+    pub(crate) fn mul(&self, other: &BigFloat) -> BigFloat {
+        BigFloat::new(
+            self.negative != other.negative,
+            self.exponent + other.exponent,
+            mul(&self.mantissa, &other.mantissa),
+        )
+    }
+
+    /// Divides this number by another number.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - Nonzero divisor.
+    /// * `precision` - Number of significant bits of the quotient.
+    ///
+    /// # Returns
+    ///
+    /// $\frac{x}{y}$ rounded toward zero to `precision` bits, and whether that rounding was
+    /// inexact.
+    pub(crate) fn div(&self, other: &BigFloat, precision: u64) -> (BigFloat, bool) {
+        if self.is_zero() {
+            return (BigFloat::zero(), false);
+        }
+        let shift = (precision + bit_length(&other.mantissa) + 1)
+            .saturating_sub(bit_length(&self.mantissa));
+        let (q, r) = div_rem(&shl(&self.mantissa, shift), &other.mantissa);
+        let quotient = BigFloat::new(
+            self.negative != other.negative,
+            self.exponent - other.exponent - shift as i64,
+            q,
+        );
+        let (quotient, inexact) = quotient.truncate(precision);
+        (quotient, inexact || !r.is_empty())
+    }
+
+    /// Computes the square root of this number.
+    ///
+    /// # Arguments
+    ///
+    /// * `precision` - Number of significant bits of the result.
+    ///
+    /// # Returns
+    ///
+    /// $\sqrt{x}$ (for $x\geq0$) rounded toward zero to `precision` bits, and whether that rounding
+    /// was inexact.
+    pub(crate) fn sqrt(&self, precision: u64) -> (BigFloat, bool) {
+        if self.is_zero() {
+            return (BigFloat::zero(), false);
+        }
+        let mut shift = (2 * precision + 2).saturating_sub(bit_length(&self.mantissa));
+        if (self.exponent - shift as i64) % 2 != 0 {
+            shift += 1;
+        }
+        let n = shl(&self.mantissa, shift);
+        let root = isqrt(&n);
+        let exact = cmp(&mul(&root, &root), &n) == Ordering::Equal;
+        let (root, inexact) =
+            BigFloat::new(false, (self.exponent - shift as i64) / 2, root).truncate(precision);
+        (root, inexact || !exact)
+    }
+
+    /// Compares the magnitude of this number with the magnitude of another number.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - Other number.
+    ///
+    /// # Returns
+    ///
+    /// Ordering of $|x|$ relative to $|y|$.
+    pub(crate) fn cmp_abs(&self, other: &BigFloat) -> Ordering {
+        match (self.is_zero(), other.is_zero()) {
+            (true, true) => return Ordering::Equal,
+            (true, false) => return Ordering::Less,
+            (false, true) => return Ordering::Greater,
+            _ => {}
+        }
+        match self.msb().cmp(&other.msb()) {
+            Ordering::Equal => {}
+            ordering => return ordering,
+        }
+        let exponent = self.exponent.min(other.exponent);
+        cmp(
+            &shl(&self.mantissa, (self.exponent - exponent) as u64),
+            &shl(&other.mantissa, (other.exponent - exponent) as u64),
+        )
+    }
+
+    /// Converts this number to the nearest integer.
+    ///
+    /// # Returns
+    ///
+    /// Integer nearest to `x` (with ties rounded away from zero), and that integer modulo $4$.
+    pub(crate) fn round_integer(&self) -> (BigFloat, u8) {
+        let n = if self.exponent >= 0 {
+            self.clone()
+        } else {
+            // Truncate |x| + 1/2 (exact) to an integer.
+            let shifted = self.abs().add(&BigFloat::new(false, -1, vec![1]));
+            let shift = (-shifted.exponent).max(0) as u64;
+            let m = shr(&shifted.mantissa, shift).0;
+            BigFloat::new(self.negative, shifted.exponent.max(0), m)
+        };
+        let low = match n.exponent {
+            0 => n.mantissa.first().copied().unwrap_or(0) & 3,
+            1 => (n.mantissa.first().copied().unwrap_or(0) << 1) & 3,
+            _ => 0,
+        } as u8;
+        let q = if n.is_negative() { (4 - low) % 4 } else { low };
+        (n, q)
+    }
+
+    /// Approximates this number by an [`f64`].
+    ///
+    /// # Returns
+    ///
+    /// `x` truncated to [`f64`] precision (saturating to $\pm\infty$ and flushing to $\pm0$ outside of
+    /// the normal [`f64`] range).
+    pub(crate) fn to_f64(&self) -> f64 {
+        if self.is_zero() {
+            return 0.0;
+        }
+        let msb = self.msb();
+        let magnitude = if msb > 1023 {
+            f64::INFINITY
+        } else if msb < -1022 {
+            0.0
+        } else {
+            let (top, _) = self.truncate(53);
+            let m = top.mantissa[0] as f64;
+            let e = top.exponent;
+            // Apply the exponent in two steps since 2^e alone may be out of range.
+            m * exp2((e / 2) as i32) * exp2((e - e / 2) as i32)
+        };
+        if self.negative { -magnitude } else { magnitude }
+    }
+
+    /// Rounds this number to nearest in a binary floating-point format.
+    ///
+    /// # Arguments
+    ///
+    /// * `precision` - Precision of the format ($24$ for [`f32`] or $53$ for [`f64`]).
+    ///
+    /// # Returns
+    ///
+    /// `x` rounded to nearest (ties to even), including gradual underflow and overflow to
+    /// $\pm\infty$, as an [`f64`].
+    pub(crate) fn round(&self, precision: u32) -> f64 {
+        let (e_min, e_max) = if precision == 24 {
+            (-126, 127)
+        } else {
+            (-1022, 1023)
+        };
+        let sign = if self.negative { -1.0 } else { 1.0 };
+        if self.is_zero() {
+            return sign * 0.0;
+        }
+        let msb = self.msb();
+        if msb > e_max {
+            return sign * f64::INFINITY;
+        }
+
+        // Quantum (weight of the last bit) of the result, accounting for subnormals.
+        let quantum = (msb - precision as i64 + 1).max(e_min - precision as i64 + 1);
+        let shift = quantum - self.exponent;
+        let n = if shift <= 0 {
+            self.mantissa[0] << (-shift)
+        } else {
+            let (n, _) = shr(&self.mantissa, shift as u64);
+            let n = n.first().copied().unwrap_or(0);
+            let half = bit(&self.mantissa, shift as u64 - 1);
+            let sticky = shift >= 2 && trailing_zeros(&self.mantissa) < (shift - 1) as u64;
+            if half && (sticky || n & 1 == 1) {
+                n + 1
+            } else {
+                n
+            }
+        };
+        if n == 0 {
+            return sign * 0.0;
+        }
+        if quantum + 63 - n.leading_zeros() as i64 > e_max {
+            return sign * f64::INFINITY;
+        }
+        sign * (n as f64) * exp2(quantum as i32)
+    }
+
+    /// Computes $\pi$.
+    ///
+    /// # Arguments
+    ///
+    /// * `precision` - Precision $p$.
+    ///
+    /// # Returns
+    ///
+    /// $\pi$ with a relative error below $2^{-p}$.
+    ///
+    /// # Note
+    ///
+    /// This uses Machin's formula $\pi=16\arctan\left(\frac{1}{5}\right)-4\arctan\left(\frac{1}{239}\right)$.
+    pub(crate) fn pi(precision: u64) -> BigFloat {
+        let w = precision + GUARD_BITS;
+        let a = atan_inverse(5, w);
+        let b = atan_inverse(239, w);
+        from_fixed(sub(&shl(&a, 4), &shl(&b, 2)), w, false)
+    }
+
+    /// Computes $e^{x}$.
+    ///
+    /// # Arguments
+    ///
+    /// * `precision` - Precision $p$.
+    ///
+    /// # Returns
+    ///
+    /// $e^{x}$ (for $|x|<2^{40}$) with a relative error below $2^{-p}$.
+    ///
+    /// # Note
+    ///
+    /// Writing $x=k\ln(2)+t$ with $0\leq t<\ln(2)$, the Taylor series of $e^{t/2^{r}}$ is squared $r$
+    /// times.
+    pub(crate) fn exp(&self, precision: u64) -> BigFloat {
+        if self.is_zero() {
+            return BigFloat::from_i64(1);
+        }
+        let mut k = (self.to_f64() * core::f64::consts::LOG2_E) as i64;
+        let k_bits = 64 - k.unsigned_abs().leading_zeros() as u64 + 2;
+        let r = (isqrt(&[precision]).first().copied().unwrap_or(0) / 2).max(4);
+        let w = precision + GUARD_BITS + r + k_bits;
+
+        // t = x - k ln(2) in fixed point, adjusted so that 0 <= t < ln(2).
+        let ln_2 = ln_2_fixed(w + k_bits);
+        let k_ln_2 = shr(&mul(&ln_2, &[k.unsigned_abs()]), k_bits).0;
+        let ln_2 = shr(&ln_2, k_bits).0;
+        let (mut negative, mut t) = signed_sub(self.negative, &to_fixed(self, w), k < 0, &k_ln_2);
+        while negative && !t.is_empty() {
+            (negative, t) = signed_sub(true, &t, true, &ln_2);
+            k -= 1;
+        }
+        while cmp(&t, &ln_2) != Ordering::Less {
+            t = sub(&t, &ln_2);
+            k += 1;
+        }
+
+        // Taylor series of e^s with s = t / 2^r, followed by r squarings.
+        let one = shl(&[1], w);
+        let mut sum = one.clone();
+        let mut term = one;
+        let mut n = 1;
+        loop {
+            term = div_small(&shr(&mul(&term, &t), w + r).0, n).0;
+            if term.is_empty() {
+                break;
+            }
+            sum = add(&sum, &term);
+            n += 1;
+        }
+        for _ in 0..r {
+            sum = shr(&mul(&sum, &sum), w).0;
+        }
+        from_fixed(sum, w, false).mul_pow2(k)
+    }
+
+    /// Computes $e^{x}-1$.
+    ///
+    /// # Arguments
+    ///
+    /// * `precision` - Precision $p$.
+    ///
+    /// # Returns
+    ///
+    /// $e^{x}-1$ (for $|x|<2^{40}$) with a relative error below $2^{-p}$.
+    pub(crate) fn exp_m1(&self, precision: u64) -> BigFloat {
+        if self.is_zero() {
+            return BigFloat::zero();
+        }
+        if self.msb() >= -1 {
+            // |e^x - 1| >= 0.39 e^x for x <= -1/2 and >= 0.39 for x >= 1/2, so the subtraction loses
+            // fewer than two bits.
+            return self
+                .exp(precision + 2)
+                .sub(&BigFloat::from_i64(1))
+                .truncate(precision + GUARD_BITS)
+                .0;
+        }
+
+        // (e^x - 1) / x = Σ x^n / (n + 1)!, which lies in [0.78, 1.3] for |x| < 1/2.
+        let w = precision + GUARD_BITS;
+        let x = to_fixed(self, w);
+        let one = shl(&[1], w);
+        let (mut positive, mut negative) = (one.clone(), Vec::new());
+        let mut term = one;
+        let mut n = 1;
+        loop {
+            term = div_small(&shr(&mul(&term, &x), w).0, n + 1).0;
+            if term.is_empty() {
+                break;
+            }
+            if self.negative && n % 2 == 1 {
+                negative = add(&negative, &term);
+            } else {
+                positive = add(&positive, &term);
+            }
+            n += 1;
+        }
+        self.mul(&from_fixed(sub(&positive, &negative), w, false))
+            .truncate(w)
+            .0
+    }
+
+    /// Computes the natural logarithm.
+    ///
+    /// # Arguments
+    ///
+    /// * `precision` - Precision $p$.
+    ///
+    /// # Returns
+    ///
+    /// $\ln(x)$ (for $x>0$) with a relative error below $2^{-p}$.
+    ///
+    /// # Note
+    ///
+    /// Writing $x=2^{k}m$ with $\frac{1}{\sqrt{2}}\leq m<\sqrt{2}$,
+    /// $\ln(m)=2\,\text{artanh}\left(\frac{m-1}{m+1}\right)$ is evaluated with its Taylor series.
+    /// Since $m-1$ is computed exactly, the relative accuracy is kept for $x$ close to $1$.
+    pub(crate) fn ln(&self, precision: u64) -> BigFloat {
+        let mut k = self.msb();
+        let mut m = self.mul_pow2(-k);
+        if m.to_f64() > core::f64::consts::SQRT_2 {
+            m = m.mul_pow2(-1);
+            k += 1;
+        }
+        let one = BigFloat::from_i64(1);
+        let k_bits = 64 - k.unsigned_abs().leading_zeros() as u64;
+        let w = precision + GUARD_BITS + k_bits;
+        let (z, _) = m.sub(&one).div(&m.add(&one), w);
+        let ln_m = artanh(&z, w).mul_pow2(1);
+        if k == 0 {
+            return ln_m;
+        }
+        let k_ln_2 = from_fixed(ln_2_fixed(w), w, false).mul(&BigFloat::from_i64(k));
+        k_ln_2.add(&ln_m).truncate(w).0
+    }
+
+    /// Computes $\ln(1+x)$.
+    ///
+    /// # Arguments
+    ///
+    /// * `precision` - Precision $p$.
+    ///
+    /// # Returns
+    ///
+    /// $\ln(1+x)$ (for $x>-1$) with a relative error below $2^{-p}$.
+    pub(crate) fn ln_1p(&self, precision: u64) -> BigFloat {
+        if self.is_zero() {
+            return BigFloat::zero();
+        }
+        let x = self.to_f64();
+        if (-0.25..=0.5).contains(&x) {
+            // ln(1 + x) = 2 artanh(x / (2 + x)), which keeps the relative accuracy for small x.
+            let w = precision + GUARD_BITS;
+            let (z, _) = self.div(&self.add(&BigFloat::from_i64(2)), w);
+            artanh(&z, w).mul_pow2(1)
+        } else {
+            self.add(&BigFloat::from_i64(1)).ln(precision)
+        }
+    }
+
+    /// Computes the sine and cosine.
+    ///
+    /// # Arguments
+    ///
+    /// * `precision` - Precision $p$.
+    ///
+    /// # Returns
+    ///
+    /// $\sin(x)$ and $\cos(x)$ (for $|x|\leq1$), each with a relative error below $2^{-p}$.
+    pub(crate) fn sin_cos(&self, precision: u64) -> (BigFloat, BigFloat) {
+        let w = precision + GUARD_BITS;
+        let x2 = to_fixed(&self.mul(self), w);
+        let one = shl(&[1], w);
+
+        // sin(x) / x = Σ (-1)^n x^(2n) / (2n + 1)! and cos(x) = Σ (-1)^n x^(2n) / (2n)!, both of
+        // which lie in [0.54, 1] for |x| <= 1.
+        let series = |offset: u64| {
+            let (mut positive, mut negative) = (one.clone(), Vec::new());
+            let mut term = one.clone();
+            let mut n = 1;
+            loop {
+                let d = (2 * n + offset - 1) * (2 * n + offset);
+                term = div_small(&shr(&mul(&term, &x2), w).0, d).0;
+                if term.is_empty() {
+                    break;
+                }
+                if n % 2 == 1 {
+                    negative = add(&negative, &term);
+                } else {
+                    positive = add(&positive, &term);
+                }
+                n += 1;
+            }
+            from_fixed(sub(&positive, &negative), w, false)
+        };
+        let sin = self.mul(&series(1)).truncate(w).0;
+        (sin, series(0))
+    }
+
+    /// Computes the inverse tangent.
+    ///
+    /// # Arguments
+    ///
+    /// * `precision` - Precision $p$.
+    ///
+    /// # Returns
+    ///
+    /// $\arctan(x)$ with a relative error below $2^{-p}$.
+    ///
+    /// # Note
+    ///
+    /// The argument is first mapped to $|x|\leq1$ using
+    /// $\arctan(x)=\text{sgn}(x)\frac{\pi}{2}-\arctan\left(\frac{1}{x}\right)$, and then repeatedly
+    /// halved using $\arctan(x)=2\arctan\left(\frac{x}{1+\sqrt{1+x^{2}}}\right)$ before the Taylor
+    /// series is evaluated.
+    pub(crate) fn atan(&self, precision: u64) -> BigFloat {
+        if self.is_zero() {
+            return BigFloat::zero();
+        }
+        let one = BigFloat::from_i64(1);
+        let halvings = 4 + isqrt(&[precision]).first().copied().unwrap_or(0) / 4;
+        let w = precision + GUARD_BITS + halvings;
+        if self.msb() >= 0 && self.cmp_abs(&one) == Ordering::Greater {
+            let half_pi = BigFloat::pi(w).mul_pow2(-1);
+            let a = one.div(self, w).0.atan(w);
+            let half_pi = if self.negative {
+                half_pi.neg()
+            } else {
+                half_pi
+            };
+            return half_pi.sub(&a).truncate(w).0;
+        }
+
+        let mut z = self.clone();
+        for _ in 0..halvings {
+            let root = z.mul(&z).add(&one).sqrt(w).0;
+            z = z.div(&root.add(&one), w).0;
+        }
+
+        // arctan(z) / z = Σ (-1)^n z^(2n) / (2n + 1).
+        let z2 = to_fixed(&z.mul(&z), w);
+        let one = shl(&[1], w);
+        let (mut positive, mut negative) = (one.clone(), Vec::new());
+        let mut power = one;
+        let mut n = 1;
+        loop {
+            power = shr(&mul(&power, &z2), w).0;
+            if power.is_empty() {
+                break;
+            }
+            let term = div_small(&power, 2 * n + 1).0;
+            if n % 2 == 1 {
+                negative = add(&negative, &term);
+            } else {
+                positive = add(&positive, &term);
+            }
+            n += 1;
+        }
+        z.mul(&from_fixed(sub(&positive, &negative), w, false))
+            .mul_pow2(halvings as i64)
+            .truncate(w)
+            .0
+    }
+}
+
+/// Computes $\text{artanh}(z)$ for a small argument.
+///
+/// # Arguments
+///
+/// * `z` - Argument, $|z|\leq\frac{1}{5}$.
+/// * `w` - Working precision.
+///
+/// # Returns
+///
+/// $\text{artanh}(z)=z\sum_{n=0}^{\infty}\frac{z^{2n}}{2n+1}$ with a relative error of a few units
+/// of $2^{-w}$.
+fn artanh(z: &BigFloat, w: u64) -> BigFloat {
+    let z2 = to_fixed(&z.mul(z), w);
+    let one = shl(&[1], w);
+    let mut sum = one.clone();
+    let mut power = one;
+    let mut n = 1;
+    loop {
+        power = shr(&mul(&power, &z2), w).0;
+        if power.is_empty() {
+            break;
+        }
+        sum = add(&sum, &div_small(&power, 2 * n + 1).0);
+        n += 1;
+    }
+    z.mul(&from_fixed(sum, w, false)).truncate(w).0
+}
+
+/// Computes $\ln(2)$ in fixed point.
+///
+/// # Arguments
+///
+/// * `w` - Number of fractional bits.
+///
+/// # Returns
+///
+/// $\lfloor2^{w}\ln(2)\rfloor$, up to an error of a few units per term of the series.
+fn ln_2_fixed(w: u64) -> Vec<u64> {
+    let (guard, w) = (32, w + 32);
+    let mut sum = Vec::new();
+    let mut power = div_small(&shl(&[1], w), 3).0;
+    let mut n = 0;
+    while !power.is_empty() {
+        sum = add(&sum, &div_small(&power, 2 * n + 1).0);
+        power = div_small(&power, 9).0;
+        n += 1;
+    }
+    shr(&shl(&sum, 1), guard).0
+}
+
+/// Computes $\arctan\left(\frac{1}{k}\right)$ in fixed point.
+///
+/// # Arguments
+///
+/// * `k` - Integer, $k\geq2$.
+/// * `w` - Number of fractional bits.
+///
+/// # Returns
+///
+/// $\lfloor2^{w}\arctan\left(\frac{1}{k}\right)\rfloor$, up to an error of a few units.
+fn atan_inverse(k: u64, w: u64) -> Vec<u64> {
+    let (guard, w) = (32, w + 32);
+    let (mut positive, mut negative) = (Vec::new(), Vec::new());
+    let mut power = div_small(&shl(&[1], w), k).0;
+    let mut n = 0;
+    while !power.is_empty() {
+        let term = div_small(&power, 2 * n + 1).0;
+        if n % 2 == 0 {
+            positive = add(&positive, &term);
+        } else {
+            negative = add(&negative, &term);
+        }
+        power = div_small(&power, k * k).0;
+        n += 1;
+    }
+    shr(&sub(&positive, &negative), guard).0
+}
+
+/// Converts the magnitude of a number to fixed point.
+///
+/// # Arguments
+///
+/// * `x` - Number.
+/// * `w` - Number of fractional bits.
+///
+/// # Returns
+///
+/// $\lfloor2^{w}|x|\rfloor$.
+fn to_fixed(x: &BigFloat, w: u64) -> Vec<u64> {
+    let shift = x.exponent + w as i64;
+    if shift >= 0 {
+        shl(&x.mantissa, shift as u64)
+    } else {
+        shr(&x.mantissa, (-shift) as u64).0
+    }
+}
+
+/// Converts a fixed-point number to a [`BigFloat`].
+///
+/// # Arguments
+///
+/// * `v` - Fixed-point magnitude.
+/// * `w` - Number of fractional bits.
+/// * `negative` - Sign.
+///
+/// # Returns
+///
+/// $\pm2^{-w}v$.
+fn from_fixed(v: Vec<u64>, w: u64, negative: bool) -> BigFloat {
+    BigFloat::new(negative, -(w as i64), v)
+}
+
+/// Subtracts two signed fixed-point numbers.
+///
+/// # Arguments
+///
+/// * `a_negative` - Sign of the minuend.
+/// * `a` - Magnitude of the minuend.
+/// * `b_negative` - Sign of the subtrahend.
+/// * `b` - Magnitude of the subtrahend.
+///
+/// # Returns
+///
+/// Sign and magnitude of $a-b$.
+fn signed_sub(a_negative: bool, a: &[u64], b_negative: bool, b: &[u64]) -> (bool, Vec<u64>) {
+    if a_negative != b_negative {
+        return (a_negative, add(a, b));
+    }
+    match cmp(a, b) {
+        Ordering::Less => (!a_negative, sub(b, a)),
+        _ => (a_negative, sub(a, b)),
+    }
+}
+
+/// Computes $2^{n}$ for any exponent in the range of [`f64`] (including subnormals).
+///
+/// # Arguments
+///
+/// * `n` - Exponent, $-1074\leq n\leq1023$.
+///
+/// # Returns
+///
+/// $2^{n}$ (exact).
+fn exp2(n: i32) -> f64 {
+    if n < -1022 {
+        f64::from_bits(1 << (n + 1074))
+    } else {
+        f64::from_bits(((n + 1023) as u64) << 52)
+    }
+}
+
+/// Removes the leading zero limbs of a natural number.
+///
+/// # Arguments
+///
+/// * `a` - Natural number.
+fn trim(a: &mut Vec<u64>) {
+    while a.last() == Some(&0) {
+        a.pop();
+    }
+}
+
+/// Number of bits of a natural number.
+///
+/// # Arguments
+///
+/// * `a` - Natural number.
+///
+/// # Returns
+///
+/// $\lfloor\log_{2}a\rfloor+1$ (and $0$ for $a=0$).
+fn bit_length(a: &[u64]) -> u64 {
+    match a.iter().rposition(|&limb| limb != 0) {
+        Some(i) => 64 * i as u64 + 64 - a[i].leading_zeros() as u64,
+        None => 0,
+    }
+}
+
+/// Number of trailing zero bits of a natural number.
+///
+/// # Arguments
+///
+/// * `a` - Nonzero natural number.
+///
+/// # Returns
+///
+/// Largest $k$ such that $2^{k}$ divides $a$.
+fn trailing_zeros(a: &[u64]) -> u64 {
+    let i = a.iter().position(|&limb| limb != 0).unwrap_or(0);
+    64 * i as u64 + a[i].trailing_zeros() as u64
+}
+
+/// Bit of a natural number.
+///
+/// # Arguments
+///
+/// * `a` - Natural number.
+/// * `i` - Bit index.
+///
+/// # Returns
+///
+/// `true` if bit `i` of `a` is set.
+fn bit(a: &[u64], i: u64) -> bool {
+    let k = (i / 64) as usize;
+    k < a.len() && (a[k] >> (i % 64)) & 1 == 1
+}
+
+/// Compares two natural numbers.
+///
+/// # Arguments
+///
+/// * `a` - First natural number.
+/// * `b` - Second natural number.
+///
+/// # Returns
+///
+/// Ordering of `a` relative to `b`.
+fn cmp(a: &[u64], b: &[u64]) -> Ordering {
+    let (la, lb) = (bit_length(a), bit_length(b));
+    if la != lb {
+        return la.cmp(&lb);
+    }
+    for i in (0..la.div_ceil(64) as usize).rev() {
+        match a[i].cmp(&b[i]) {
+            Ordering::Equal => {}
+            ordering => return ordering,
+        }
+    }
+    Ordering::Equal
+}
+
+/// Adds two natural numbers.
+///
+/// # Arguments
+///
+/// * `a` - First summand.
+/// * `b` - Second summand.
+///
+/// # Returns
+///
+/// $a+b$.
+fn add(a: &[u64], b: &[u64]) -> Vec<u64> {
+    let (a, b) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+    let mut result = Vec::with_capacity(a.len() + 1);
+    let mut carry = 0u64;
+    for i in 0..a.len() {
+        let (s, c1) = a[i].overflowing_add(if i < b.len() { b[i] } else { 0 });
+        let (s, c2) = s.overflowing_add(carry);
+        result.push(s);
+        carry = (c1 || c2) as u64;
+    }
+    if carry != 0 {
+        result.push(carry);
+    }
+    trim(&mut result);
+    result
+}
+
+/// Subtracts two natural numbers.
+///
+/// # Arguments
+///
+/// * `a` - Minuend.
+/// * `b` - Subtrahend, $b\leq a$.
+///
+/// # Returns
+///
+/// $a-b$.
+fn sub(a: &[u64], b: &[u64]) -> Vec<u64> {
+    let mut result = Vec::with_capacity(a.len());
+    let mut borrow = 0u64;
+    for i in 0..a.len() {
+        let (d, b1) = a[i].overflowing_sub(if i < b.len() { b[i] } else { 0 });
+        let (d, b2) = d.overflowing_sub(borrow);
+        result.push(d);
+        borrow = (b1 || b2) as u64;
+    }
+    trim(&mut result);
+    result
+}
+
+/// Multiplies two natural numbers.
+///
+/// # Arguments
+///
+/// * `a` - First factor.
+/// * `b` - Second factor.
+///
+/// # Returns
+///
+/// $ab$.
+fn mul(a: &[u64], b: &[u64]) -> Vec<u64> {
+    if a.is_empty() || b.is_empty() {
+        return Vec::new();
+    }
+    let mut result = vec![0u64; a.len() + b.len()];
+    for (i, &x) in a.iter().enumerate() {
+        let mut carry = 0u128;
+        for (j, &y) in b.iter().enumerate() {
+            let t = (x as u128) * (y as u128) + result[i + j] as u128 + carry;
+            result[i + j] = t as u64;
+            carry = t >> 64;
+        }
+        result[i + b.len()] = carry as u64;
+    }
+    trim(&mut result);
+    result
+}
+
+/// Shifts a natural number to the left.
+///
+/// # Arguments
+///
+/// * `a` - Natural number.
+/// * `n` - Number of bits.
+///
+/// # Returns
+///
+/// $2^{n}a$.
+fn shl(a: &[u64], n: u64) -> Vec<u64> {
+    if a.is_empty() {
+        return Vec::new();
+    }
+    let (k, s) = ((n / 64) as usize, n % 64);
+    let mut result = vec![0u64; k + a.len() + 1];
+    for (i, &limb) in a.iter().enumerate() {
+        if s == 0 {
+            result[k + i] |= limb;
+        } else {
+            result[k + i] |= limb << s;
+            result[k + i + 1] |= limb >> (64 - s);
+        }
+    }
+    trim(&mut result);
+    result
+}
+
+/// Shifts a natural number to the right.
+///
+/// # Arguments
+///
+/// * `a` - Natural number.
+/// * `n` - Number of bits.
+///
+/// # Returns
+///
+/// $\left\lfloor\frac{a}{2^{n}}\right\rfloor$, and whether any nonzero bits were shifted out.
+fn shr(a: &[u64], n: u64) -> (Vec<u64>, bool) {
+    let (k, s) = ((n / 64) as usize, n % 64);
+    if k >= a.len() {
+        return (Vec::new(), a.iter().any(|&limb| limb != 0));
+    }
+    let mut inexact = a[..k].iter().any(|&limb| limb != 0);
+    if s != 0 {
+        inexact |= a[k] & ((1 << s) - 1) != 0;
+    }
+    let mut result = Vec::with_capacity(a.len() - k);
+    for i in k..a.len() {
+        let limb = if s == 0 {
+            a[i]
+        } else {
+            let next = if i + 1 < a.len() { a[i + 1] } else { 0 };
+            (a[i] >> s) | (next << (64 - s))
+        };
+        result.push(limb);
+    }
+    trim(&mut result);
+    (result, inexact)
+}
+
+/// Divides a natural number by a single limb.
+///
+/// # Arguments
+///
+/// * `a` - Dividend.
+/// * `d` - Nonzero divisor.
+///
+/// # Returns
+///
+/// Quotient and remainder.
+fn div_small(a: &[u64], d: u64) -> (Vec<u64>, u64) {
+    let mut result = vec![0u64; a.len()];
+    let mut remainder = 0u128;
+    for i in (0..a.len()).rev() {
+        let t = (remainder << 64) | a[i] as u128;
+        result[i] = (t / d as u128) as u64;
+        remainder = t % d as u128;
+    }
+    trim(&mut result);
+    (result, remainder as u64)
+}
+
+/// Divides two natural numbers (Knuth's Algorithm D).
+///
+/// # Arguments
+///
+/// * `a` - Dividend.
+/// * `b` - Nonzero divisor.
+///
+/// # Returns
+///
+/// Quotient and remainder.
+fn div_rem(a: &[u64], b: &[u64]) -> (Vec<u64>, Vec<u64>) {
+    if cmp(a, b) == Ordering::Less {
+        return (Vec::new(), a.to_vec());
+    }
+    if b.len() == 1 {
+        let (q, r) = div_small(a, b[0]);
+        return (q, if r == 0 { Vec::new() } else { vec![r] });
+    }
+
+    // Normalize so that the leading limb of the divisor has its top bit set.
+    let s = b[b.len() - 1].leading_zeros() as u64;
+    let b = shl(b, s);
+    let mut u = shl(a, s);
+    u.resize(a.len() + 1, 0);
+    let n = b.len();
+    let m = u.len() - n;
+    let mut q = vec![0u64; m];
+    let base = 1u128 << 64;
+
+    for j in (0..m).rev() {
+        let numerator = ((u[j + n] as u128) << 64) | u[j + n - 1] as u128;
+        let mut q_hat = numerator / b[n - 1] as u128;
+        let mut r_hat = numerator % b[n - 1] as u128;
+        while q_hat >= base || q_hat * b[n - 2] as u128 > ((r_hat << 64) | u[j + n - 2] as u128) {
+            q_hat -= 1;
+            r_hat += b[n - 1] as u128;
+            if r_hat >= base {
+                break;
+            }
+        }
+
+        // Multiply and subtract.
+        let mut borrow = 0i128;
+        let mut carry = 0u128;
+        for i in 0..n {
+            let p = q_hat * b[i] as u128 + carry;
+            carry = p >> 64;
+            let t = u[i + j] as i128 - borrow - (p as u64) as i128;
+            u[i + j] = t as u64;
+            borrow = (t < 0) as i128;
+        }
+        let t = u[j + n] as i128 - borrow - carry as i128;
+        u[j + n] = t as u64;
+
+        // Add back if the estimate was one too large.
+        if t < 0 {
+            q_hat -= 1;
+            let mut carry = 0u128;
+            for i in 0..n {
+                let s = u[i + j] as u128 + b[i] as u128 + carry;
+                u[i + j] = s as u64;
+                carry = s >> 64;
+            }
+            u[j + n] = u[j + n].wrapping_add(carry as u64);
+        }
+        q[j] = q_hat as u64;
+    }
+
+    trim(&mut q);
+    u.truncate(n);
+    trim(&mut u);
+    (q, shr(&u, s).0)
+}
+
+/// Computes the integer square root of a natural number.
+///
+/// # Arguments
+///
+/// * `a` - Natural number.
+///
+/// # Returns
+///
+/// $\lfloor\sqrt{a}\rfloor$.
+fn isqrt(a: &[u64]) -> Vec<u64> {
+    if a.is_empty() {
+        return Vec::new();
+    }
+
+    // Newton's method from an initial guess above the root decreases monotonically to the floor.
+    let mut x = shl(&[1], bit_length(a).div_ceil(2));
+    loop {
+        let y = shr(&add(&x, &div_rem(a, &x).0), 1).0;
+        if cmp(&y, &x) != Ordering::Less {
+            return x;
+        }
+        x = y;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::f64::consts::{E, LN_2, PI};
+
+    #[test]
+    fn test_round() {
+        assert_eq!(BigFloat::from_f64(0.1).round(53), 0.1);
+        assert_eq!(BigFloat::from_f64(5e-324).round(53), 5e-324);
+        assert_eq!(BigFloat::from_f64(0.1).round(24), 0.1_f32 as f64);
+        assert_eq!(BigFloat::from_parts(1, 1024).round(53), f64::INFINITY);
+
+        // 1 + 2⁻⁵³ is a tie and rounds to even.
+        let tie = BigFloat::from_i64(1).add(&BigFloat::from_parts(1, -53));
+        assert_eq!(tie.round(53), 1.0);
+    }
+
+    #[test]
+    fn test_div_sqrt() {
+        let (third, inexact) = BigFloat::from_i64(1).div(&BigFloat::from_i64(3), 200);
+        assert!(inexact);
+        assert_eq!(third.round(53), 1.0 / 3.0);
+        let (root, inexact) = BigFloat::from_i64(2).sqrt(200);
+        assert!(inexact);
+        assert_eq!(root.round(53), std::f64::consts::SQRT_2);
+        assert_eq!(
+            BigFloat::from_i64(9).sqrt(200),
+            (BigFloat::from_i64(3), false)
+        );
+    }
+
+    #[test]
+    fn test_constants() {
+        let one = BigFloat::from_i64(1);
+        assert_eq!(BigFloat::pi(200).round(53), PI);
+        assert_eq!(one.exp(200).round(53), E);
+        assert_eq!(BigFloat::from_i64(2).ln(200).round(53), LN_2);
+        assert_eq!(one.atan(200).mul_pow2(2).round(53), PI);
+    }
+
+    #[test]
+    fn test_sin_cos() {
+        let (sin, cos) = BigFloat::from_i64(1).sin_cos(200);
+        assert_eq!(sin.round(53), 0.8414709848078965);
+        assert_eq!(cos.round(53), 0.5403023058681398);
+    }
+}
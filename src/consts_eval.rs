@@ -0,0 +1,662 @@
+//! `const fn` evaluation of trigonometric and hyperbolic functions.
+//!
+//! The [`Trig`](crate::Trig) methods cannot be evaluated at compile time, since trait methods are
+//! not `const` and the default backend calls into the platform's math library. This module
+//! provides `const fn` versions of the core functions for [`f64`] (e.g. [`sin`]) and [`f32`] (with
+//! an `_f32` suffix, e.g. [`sin_f32`]), so that rotation matrices and coefficient tables can be
+//! computed in `const` and `static` initializers.
+//!
+//! These functions are built on the same software routines as the `deterministic` feature, and
+//! they return exactly the same bits as the corresponding [`Trig`](crate::Trig) methods with that
+//! feature enabled (whether they are evaluated at compile time or at run time). Their accuracy is
+//! therefore the accuracy documented for the `deterministic` backend.
+//!
+//! # Example
+//!
+//! ```
+//! use trig::consts_eval::{cosd, sind};
+//!
+//! // Rotation by 30 degrees, computed at compile time.
+//! const ROTATION: [[f64; 2]; 2] = {
+//!     let (s, c) = (sind(30.0), cosd(30.0));
+//!     [[c, -s], [s, c]]
+//! };
+//!
+//! assert_eq!(ROTATION[1][0], 0.5);
+//! ```
+
+use crate::double_double::atan2d_small;
+use crate::rem_pio2::{rem_pio2, rem_pio2_f32};
+use crate::soft_math;
+
+/// Computes the sine of an [`f64`] (see [`Trig::sin`](crate::Trig::sin)).
+///
+/// # Arguments
+///
+/// * `x` - Angle \[rad\].
+///
+/// # Returns
+///
+/// $\sin(x)$.
+pub const fn sin(x: f64) -> f64 {
+    let (q, hi, lo) = rem_pio2(x);
+    match q {
+        0 => sin_reduced(hi, lo),
+        1 => cos_reduced(hi, lo),
+        2 => -sin_reduced(hi, lo),
+        _ => -cos_reduced(hi, lo),
+    }
+}
+
+/// Computes the cosine of an [`f64`] (see [`Trig::cos`](crate::Trig::cos)).
+///
+/// # Arguments
+///
+/// * `x` - Angle \[rad\].
+///
+/// # Returns
+///
+/// $\cos(x)$.
+pub const fn cos(x: f64) -> f64 {
+    let (q, hi, lo) = rem_pio2(x);
+    match q {
+        0 => cos_reduced(hi, lo),
+        1 => -sin_reduced(hi, lo),
+        2 => -cos_reduced(hi, lo),
+        _ => sin_reduced(hi, lo),
+    }
+}
+
+/// Computes the tangent of an [`f64`] (see [`Trig::tan`](crate::Trig::tan)).
+///
+/// # Arguments
+///
+/// * `x` - Angle \[rad\].
+///
+/// # Returns
+///
+/// $\tan(x)$.
+pub const fn tan(x: f64) -> f64 {
+    let (q, hi, lo) = rem_pio2(x);
+    let t = tan_reduced(hi, lo);
+    if q % 2 == 0 { t } else { -1.0 / t }
+}
+
+/// Computes the four-quadrant inverse tangent of two [`f64`]s (see
+/// [`Trig::atan2`](crate::Trig::atan2)).
+///
+/// # Arguments
+///
+/// * `y` - $y$-coordinate.
+/// * `x` - $x$-coordinate.
+///
+/// # Returns
+///
+/// Angle \[rad\] of the point $(x,y)$, in $[-\pi,\pi]$.
+pub const fn atan2(y: f64, x: f64) -> f64 {
+    soft_math::atan2(y, x)
+}
+
+/// Converts an [`f64`] from degrees to radians (see [`Trig::deg2rad`](crate::Trig::deg2rad)).
+///
+/// # Arguments
+///
+/// * `x` - Angle \[deg\].
+///
+/// # Returns
+///
+/// Angle \[rad\].
+pub const fn deg2rad(x: f64) -> f64 {
+    x * (core::f64::consts::PI / 180.0)
+}
+
+/// Converts an [`f64`] from radians to degrees (see [`Trig::rad2deg`](crate::Trig::rad2deg)).
+///
+/// # Arguments
+///
+/// * `x` - Angle \[rad\].
+///
+/// # Returns
+///
+/// Angle \[deg\].
+pub const fn rad2deg(x: f64) -> f64 {
+    x * (180.0 / core::f64::consts::PI)
+}
+
+/// Computes the sine of an [`f64`] in degrees (see [`Trig::sind`](crate::Trig::sind)).
+///
+/// # Arguments
+///
+/// * `x` - Angle \[deg\].
+///
+/// # Returns
+///
+/// $\sin(x)$.
+pub const fn sind(x: f64) -> f64 {
+    let (q, r) = reduce_turn(x.abs(), 90.0);
+    let (s, c) = sincosd_reduced(r);
+    let f = match q {
+        0 => s,
+        1 => c,
+        2 => -s,
+        _ => -c,
+    };
+
+    // Adding zero turns an exact -0 into +0 so that the sign of a zero result follows `x`.
+    if x.is_sign_negative() {
+        -(f + 0.0)
+    } else {
+        f + 0.0
+    }
+}
+
+/// Computes the cosine of an [`f64`] in degrees (see [`Trig::cosd`](crate::Trig::cosd)).
+///
+/// # Arguments
+///
+/// * `x` - Angle \[deg\].
+///
+/// # Returns
+///
+/// $\cos(x)$.
+pub const fn cosd(x: f64) -> f64 {
+    let (q, r) = reduce_turn(x.abs(), 90.0);
+    let (s, c) = sincosd_reduced(r);
+    let f = match q {
+        0 => c,
+        1 => -s,
+        2 => -c,
+        _ => s,
+    };
+    f + 0.0
+}
+
+/// Computes the tangent of an [`f64`] in degrees (see [`Trig::tand`](crate::Trig::tand)).
+///
+/// # Arguments
+///
+/// * `x` - Angle \[deg\].
+///
+/// # Returns
+///
+/// $\tan(x)$.
+pub const fn tand(x: f64) -> f64 {
+    let (q, r) = reduce_turn(x.abs(), 90.0);
+    let t = if r.abs() == 45.0 {
+        1.0_f64.copysign(r)
+    } else {
+        soft_math::tan(deg2rad(r))
+    };
+    let f = if q % 2 == 0 {
+        t
+    } else if r == 0.0 {
+        if q == 1 {
+            f64::INFINITY
+        } else {
+            f64::NEG_INFINITY
+        }
+    } else {
+        -1.0 / t
+    };
+    if x.is_sign_negative() {
+        -(f + 0.0)
+    } else {
+        f + 0.0
+    }
+}
+
+/// Computes the four-quadrant inverse tangent of two [`f64`]s in degrees (see
+/// [`Trig::atan2d`](crate::Trig::atan2d)).
+///
+/// # Arguments
+///
+/// * `y` - $y$-coordinate.
+/// * `x` - $x$-coordinate.
+///
+/// # Returns
+///
+/// Angle \[deg\] of the point $(x,y)$, in $[-180,180]$.
+pub const fn atan2d(y: f64, x: f64) -> f64 {
+    match atan2d_small(y, x) {
+        Some(angle) => angle,
+        None => rad2deg(atan2(y, x)),
+    }
+}
+
+/// Computes the hyperbolic sine of an [`f64`] (see [`Trig::sinh`](crate::Trig::sinh)).
+///
+/// # Arguments
+///
+/// * `x` - Value.
+///
+/// # Returns
+///
+/// $\sinh(x)$.
+pub const fn sinh(x: f64) -> f64 {
+    soft_math::sinh(x)
+}
+
+/// Computes the hyperbolic cosine of an [`f64`] (see [`Trig::cosh`](crate::Trig::cosh)).
+///
+/// # Arguments
+///
+/// * `x` - Value.
+///
+/// # Returns
+///
+/// $\cosh(x)$.
+pub const fn cosh(x: f64) -> f64 {
+    soft_math::cosh(x)
+}
+
+/// Computes the hyperbolic tangent of an [`f64`] (see [`Trig::tanh`](crate::Trig::tanh)).
+///
+/// # Arguments
+///
+/// * `x` - Value.
+///
+/// # Returns
+///
+/// $\tanh(x)$.
+pub const fn tanh(x: f64) -> f64 {
+    soft_math::tanh(x)
+}
+
+/// Computes the sine of an [`f32`] (see [`Trig::sin`](crate::Trig::sin)).
+///
+/// # Arguments
+///
+/// * `x` - Angle \[rad\].
+///
+/// # Returns
+///
+/// $\sin(x)$.
+pub const fn sin_f32(x: f32) -> f32 {
+    let (q, r) = rem_pio2_f32(x);
+    let f = match q {
+        0 => soft_math::sin(r),
+        1 => soft_math::cos(r),
+        2 => -soft_math::sin(r),
+        _ => -soft_math::cos(r),
+    };
+    f as f32
+}
+
+/// Computes the cosine of an [`f32`] (see [`Trig::cos`](crate::Trig::cos)).
+///
+/// # Arguments
+///
+/// * `x` - Angle \[rad\].
+///
+/// # Returns
+///
+/// $\cos(x)$.
+pub const fn cos_f32(x: f32) -> f32 {
+    let (q, r) = rem_pio2_f32(x);
+    let f = match q {
+        0 => soft_math::cos(r),
+        1 => -soft_math::sin(r),
+        2 => -soft_math::cos(r),
+        _ => soft_math::sin(r),
+    };
+    f as f32
+}
+
+/// Computes the tangent of an [`f32`] (see [`Trig::tan`](crate::Trig::tan)).
+///
+/// # Arguments
+///
+/// * `x` - Angle \[rad\].
+///
+/// # Returns
+///
+/// $\tan(x)$.
+pub const fn tan_f32(x: f32) -> f32 {
+    let (q, r) = rem_pio2_f32(x);
+    let t = soft_math::tan(r);
+    let f = if q % 2 == 0 { t } else { -1.0 / t };
+    f as f32
+}
+
+/// Computes the four-quadrant inverse tangent of two [`f32`]s (see
+/// [`Trig::atan2`](crate::Trig::atan2)).
+///
+/// # Arguments
+///
+/// * `y` - $y$-coordinate.
+/// * `x` - $x$-coordinate.
+///
+/// # Returns
+///
+/// Angle \[rad\] of the point $(x,y)$, in $[-\pi,\pi]$.
+pub const fn atan2_f32(y: f32, x: f32) -> f32 {
+    soft_math::atan2_f32(y, x)
+}
+
+/// Converts an [`f32`] from degrees to radians (see [`Trig::deg2rad`](crate::Trig::deg2rad)).
+///
+/// # Arguments
+///
+/// * `x` - Angle \[deg\].
+///
+/// # Returns
+///
+/// Angle \[rad\].
+pub const fn deg2rad_f32(x: f32) -> f32 {
+    x * (core::f32::consts::PI / 180.0)
+}
+
+/// Converts an [`f32`] from radians to degrees (see [`Trig::rad2deg`](crate::Trig::rad2deg)).
+///
+/// # Arguments
+///
+/// * `x` - Angle \[rad\].
+///
+/// # Returns
+///
+/// Angle \[deg\].
+pub const fn rad2deg_f32(x: f32) -> f32 {
+    x * (180.0 / core::f32::consts::PI)
+}
+
+/// Computes the sine of an [`f32`] in degrees (see [`Trig::sind`](crate::Trig::sind)).
+///
+/// # Arguments
+///
+/// * `x` - Angle \[deg\].
+///
+/// # Returns
+///
+/// $\sin(x)$.
+pub const fn sind_f32(x: f32) -> f32 {
+    let (q, r) = reduce_turn_f32(x.abs(), 90.0);
+    let (s, c) = sincosd_reduced_f32(r);
+    let f = match q {
+        0 => s,
+        1 => c,
+        2 => -s,
+        _ => -c,
+    };
+
+    // Adding zero turns an exact -0 into +0 so that the sign of a zero result follows `x`.
+    if x.is_sign_negative() {
+        -(f + 0.0)
+    } else {
+        f + 0.0
+    }
+}
+
+/// Computes the cosine of an [`f32`] in degrees (see [`Trig::cosd`](crate::Trig::cosd)).
+///
+/// # Arguments
+///
+/// * `x` - Angle \[deg\].
+///
+/// # Returns
+///
+/// $\cos(x)$.
+pub const fn cosd_f32(x: f32) -> f32 {
+    let (q, r) = reduce_turn_f32(x.abs(), 90.0);
+    let (s, c) = sincosd_reduced_f32(r);
+    let f = match q {
+        0 => c,
+        1 => -s,
+        2 => -c,
+        _ => s,
+    };
+    f + 0.0
+}
+
+/// Computes the tangent of an [`f32`] in degrees (see [`Trig::tand`](crate::Trig::tand)).
+///
+/// # Arguments
+///
+/// * `x` - Angle \[deg\].
+///
+/// # Returns
+///
+/// $\tan(x)$.
+pub const fn tand_f32(x: f32) -> f32 {
+    let (q, r) = reduce_turn_f32(x.abs(), 90.0);
+    let t = if r.abs() == 45.0 {
+        1.0_f32.copysign(r)
+    } else {
+        soft_math::tan_f32(deg2rad_f32(r))
+    };
+    let f = if q % 2 == 0 {
+        t
+    } else if r == 0.0 {
+        if q == 1 {
+            f32::INFINITY
+        } else {
+            f32::NEG_INFINITY
+        }
+    } else {
+        -1.0 / t
+    };
+    if x.is_sign_negative() {
+        -(f + 0.0)
+    } else {
+        f + 0.0
+    }
+}
+
+/// Computes the four-quadrant inverse tangent of two [`f32`]s in degrees (see
+/// [`Trig::atan2d`](crate::Trig::atan2d)).
+///
+/// # Arguments
+///
+/// * `y` - $y$-coordinate.
+/// * `x` - $x$-coordinate.
+///
+/// # Returns
+///
+/// Angle \[deg\] of the point $(x,y)$, in $[-180,180]$.
+pub const fn atan2d_f32(y: f32, x: f32) -> f32 {
+    atan2d(y as f64, x as f64) as f32
+}
+
+/// Computes the hyperbolic sine of an [`f32`] (see [`Trig::sinh`](crate::Trig::sinh)).
+///
+/// # Arguments
+///
+/// * `x` - Value.
+///
+/// # Returns
+///
+/// $\sinh(x)$.
+pub const fn sinh_f32(x: f32) -> f32 {
+    soft_math::sinh_f32(x)
+}
+
+/// Computes the hyperbolic cosine of an [`f32`] (see [`Trig::cosh`](crate::Trig::cosh)).
+///
+/// # Arguments
+///
+/// * `x` - Value.
+///
+/// # Returns
+///
+/// $\cosh(x)$.
+pub const fn cosh_f32(x: f32) -> f32 {
+    soft_math::cosh_f32(x)
+}
+
+/// Computes the hyperbolic tangent of an [`f32`] (see [`Trig::tanh`](crate::Trig::tanh)).
+///
+/// # Arguments
+///
+/// * `x` - Value.
+///
+/// # Returns
+///
+/// $\tanh(x)$.
+pub const fn tanh_f32(x: f32) -> f32 {
+    soft_math::tanh_f32(x)
+}
+
+/// Computes the sine of a reduced argument given as an unevaluated sum (see
+/// [`rem_pio2::sin_reduced`](crate::rem_pio2::sin_reduced)).
+const fn sin_reduced(hi: f64, lo: f64) -> f64 {
+    if lo == 0.0 {
+        soft_math::sin(hi)
+    } else {
+        soft_math::sin(hi) + lo * (1.0 - 0.5 * hi * hi)
+    }
+}
+
+/// Computes the cosine of a reduced argument given as an unevaluated sum (see
+/// [`rem_pio2::cos_reduced`](crate::rem_pio2::cos_reduced)).
+const fn cos_reduced(hi: f64, lo: f64) -> f64 {
+    soft_math::cos(hi) - lo * hi
+}
+
+/// Computes the tangent of a reduced argument given as an unevaluated sum (see
+/// [`rem_pio2::tan_reduced`](crate::rem_pio2::tan_reduced)).
+const fn tan_reduced(hi: f64, lo: f64) -> f64 {
+    let t = soft_math::tan(hi);
+    if lo == 0.0 { t } else { t + lo * (1.0 + t * t) }
+}
+
+/// Exactly reduces an angle modulo a full turn (see
+/// [`f64_impl::reduce_turn`](crate::f64_impl::reduce_turn)).
+const fn reduce_turn(x: f64, quarter_turn: f64) -> (u8, f64) {
+    let r = x % (4.0 * quarter_turn);
+    let n = soft_math::round(r / quarter_turn);
+    ((n as i32).rem_euclid(4) as u8, r - n * quarter_turn)
+}
+
+/// Exactly reduces an [`f32`] angle modulo a full turn (see [`reduce_turn`]).
+const fn reduce_turn_f32(x: f32, quarter_turn: f32) -> (u8, f32) {
+    let r = x % (4.0 * quarter_turn);
+    let n = soft_math::round_f32(r / quarter_turn);
+    ((n as i32).rem_euclid(4) as u8, r - n * quarter_turn)
+}
+
+/// Computes the sine and cosine of a reduced angle in degrees, with the values at $\pm30$ and
+/// $\pm45$ degrees returned correctly rounded.
+const fn sincosd_reduced(r: f64) -> (f64, f64) {
+    if r.abs() == 30.0 {
+        (0.5_f64.copysign(r), 0.8660254037844386_f64)
+    } else if r.abs() == 45.0 {
+        (
+            core::f64::consts::FRAC_1_SQRT_2.copysign(r),
+            core::f64::consts::FRAC_1_SQRT_2,
+        )
+    } else {
+        let r = deg2rad(r);
+        (soft_math::sin(r), soft_math::cos(r))
+    }
+}
+
+/// Computes the sine and cosine of a reduced [`f32`] angle in degrees (see [`sincosd_reduced`]).
+const fn sincosd_reduced_f32(r: f32) -> (f32, f32) {
+    if r.abs() == 30.0 {
+        (0.5_f32.copysign(r), 0.8660254_f32)
+    } else if r.abs() == 45.0 {
+        (
+            core::f32::consts::FRAC_1_SQRT_2.copysign(r),
+            core::f32::consts::FRAC_1_SQRT_2,
+        )
+    } else {
+        let r = deg2rad_f32(r);
+        (soft_math::sin_f32(r), soft_math::cos_f32(r))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::trig_trait::Trig;
+
+    /// Arguments shared by the tests below.
+    const ARGUMENTS: [f64; 16] = [
+        0.0,
+        -0.0,
+        1e-300,
+        0.5,
+        -1.0,
+        2.0,
+        30.0,
+        -45.0,
+        90.0,
+        135.0,
+        1e6,
+        -3e10,
+        1e22,
+        1e300,
+        f64::INFINITY,
+        f64::NAN,
+    ];
+
+    /// Checks that two values agree, bit for bit under the `deterministic` backend and to within a
+    /// few ulps otherwise.
+    macro_rules! assert_agrees {
+        ($actual:expr, $expected:expr, $ulps:expr) => {{
+            let (actual, expected) = ($actual, $expected);
+            if cfg!(all(
+                feature = "deterministic",
+                not(feature = "correctly-rounded")
+            )) {
+                assert_eq!(actual.to_bits(), expected.to_bits());
+            } else if expected.is_nan() {
+                assert!(actual.is_nan());
+            } else if expected.is_infinite() || expected == 0.0 {
+                assert_eq!(actual, expected);
+            } else {
+                let ulp = expected.abs().next_up() - expected.abs();
+                assert!((actual - expected).abs() <= $ulps * ulp);
+            }
+        }};
+    }
+
+    #[test]
+    fn test_f64() {
+        for x in ARGUMENTS {
+            assert_agrees!(sin(x), Trig::sin(&x), 2.0);
+            assert_agrees!(cos(x), Trig::cos(&x), 2.0);
+            assert_agrees!(tan(x), Trig::tan(&x), 4.0);
+            assert_agrees!(atan2(x, -2.0), Trig::atan2(&x, &-2.0), 2.0);
+            assert_agrees!(deg2rad(x), Trig::deg2rad(&x), 1.0);
+            assert_agrees!(rad2deg(x), Trig::rad2deg(&x), 1.0);
+            assert_agrees!(sind(x), Trig::sind(&x), 2.0);
+            assert_agrees!(cosd(x), Trig::cosd(&x), 2.0);
+            assert_agrees!(tand(x), Trig::tand(&x), 4.0);
+            assert_agrees!(atan2d(x, 3.0), Trig::atan2d(&x, &3.0), 2.0);
+            assert_agrees!(sinh(x), Trig::sinh(&x), 2.0);
+            assert_agrees!(cosh(x), Trig::cosh(&x), 2.0);
+            assert_agrees!(tanh(x), Trig::tanh(&x), 2.0);
+        }
+    }
+
+    #[test]
+    fn test_f32() {
+        for x in ARGUMENTS.map(|x| x as f32) {
+            assert_agrees!(sin_f32(x), Trig::sin(&x), 2.0);
+            assert_agrees!(cos_f32(x), Trig::cos(&x), 2.0);
+            assert_agrees!(tan_f32(x), Trig::tan(&x), 2.0);
+            assert_agrees!(atan2_f32(x, -2.0), Trig::atan2(&x, &-2.0), 2.0);
+            assert_agrees!(deg2rad_f32(x), Trig::deg2rad(&x), 1.0);
+            assert_agrees!(rad2deg_f32(x), Trig::rad2deg(&x), 1.0);
+            assert_agrees!(sind_f32(x), Trig::sind(&x), 2.0);
+            assert_agrees!(cosd_f32(x), Trig::cosd(&x), 2.0);
+            assert_agrees!(tand_f32(x), Trig::tand(&x), 2.0);
+            assert_agrees!(atan2d_f32(x, 3.0), Trig::atan2d(&x, &3.0), 2.0);
+            assert_agrees!(sinh_f32(x), Trig::sinh(&x), 2.0);
+            assert_agrees!(cosh_f32(x), Trig::cosh(&x), 2.0);
+            assert_agrees!(tanh_f32(x), Trig::tanh(&x), 2.0);
+        }
+    }
+
+    #[test]
+    fn test_const_evaluation() {
+        const VALUES: [f64; 4] = [sind(30.0), cosd(-180.0), tand(45.0), sin(1e22)];
+        const VALUES_F32: [f32; 3] = [sind_f32(150.0), cosd_f32(90.0), atan2d_f32(1.0, 1.0)];
+        assert_eq!(VALUES, [0.5, -1.0, 1.0, -0.852_200_849_767_188_8]);
+        assert_eq!(VALUES_F32, [0.5, 0.0, 45.0]);
+
+        // Compile-time and run-time evaluation agree.
+        let x = core::hint::black_box(1e22);
+        assert_eq!(sin(x), VALUES[3]);
+    }
+}
@@ -0,0 +1,738 @@
+use crate::consts_eval;
+
+/// Number of fractional bits of the internal fixed-point format.
+const FRAC: u32 = 60;
+
+/// Maximum number of iterations (see [`Cordic::MAX_ITERATIONS`]).
+const MAX: usize = 50;
+
+/// $\frac{\pi}{2}$ in the internal fixed-point format.
+const PIO2: i64 = 1811004864519280711;
+
+/// $\pi$ in the internal fixed-point format.
+const PI: i64 = 3622009729038561421;
+
+/// $\ln(2)$ in the internal fixed-point format.
+const LN2: i64 = 799144290325165979;
+
+/// $\frac{2}{\pi}$ with 64 fractional bits.
+const TWO_OVER_PI_64: i128 = 0xA2F9_836E_4E44_152A;
+
+/// $\frac{1}{\ln(2)}$ with 30 fractional bits.
+const INV_LN2_30: i128 = 1549082005;
+
+/// $\arctan\left(2^{-k}\right)$ for each iteration $k$ of the circular CORDIC.
+const ATAN: [i64; MAX] = atan_table();
+
+/// Starting $x$-coordinate of the circular CORDIC for each number of iterations $n$, i.e. the
+/// inverse of its gain $\prod_{k=0}^{n-1}\sqrt{1+2^{-2k}}$.
+const CIRCULAR_START: [i64; MAX + 1] = circular_start_table();
+
+/// Shift of each iteration of the hyperbolic CORDIC, i.e. $1,2,3,4,4,5,\dots,13,13,14,\dots$
+/// (iterations $4,13,40,\dots$ are repeated for convergence).
+const HYPERBOLIC_SHIFT: [u32; MAX] = hyperbolic_shift_table();
+
+/// $\operatorname{artanh}\left(2^{-i}\right)$ for the shift $i$ of each iteration of the
+/// hyperbolic CORDIC.
+const ATANH: [i64; MAX] = atanh_table();
+
+/// Starting $x$-coordinate of the hyperbolic CORDIC for each number of iterations $n$, i.e. the
+/// inverse of its gain $\prod\sqrt{1-2^{-2i}}$ over the shifts $i$ of the first $n$ iterations.
+const HYPERBOLIC_START: [i64; MAX + 1] = hyperbolic_start_table();
+
+/// Converts a positive value to the internal fixed-point format.
+///
+/// # Arguments
+///
+/// * `x` - Value, $x\geq0$.
+///
+/// # Returns
+///
+/// `x` in the internal fixed-point format (rounded to nearest).
+const fn to_internal(x: f64) -> i64 {
+    (x * (1u64 << FRAC) as f64 + 0.5) as i64
+}
+
+/// Computes the inverse hyperbolic tangent of a small value from its Taylor series.
+///
+/// # Arguments
+///
+/// * `u` - Value, $0\leq u\leq\frac{1}{2}$.
+///
+/// # Returns
+///
+/// $\operatorname{artanh}(u)$.
+const fn atanh_series(u: f64) -> f64 {
+    let uu = u * u;
+    let mut power = u;
+    let mut sum = 0.0;
+    let mut k = 0;
+    while k < 40 {
+        sum += power / (2 * k + 1) as f64;
+        power *= uu;
+        k += 1;
+    }
+    sum
+}
+
+/// Builds the table of $\arctan\left(2^{-k}\right)$.
+///
+/// # Returns
+///
+/// $\arctan\left(2^{-k}\right)$ for $k=0,\dots$ in the internal fixed-point format.
+const fn atan_table() -> [i64; MAX] {
+    let mut table = [0; MAX];
+    let mut k = 0;
+    while k < MAX {
+        table[k] = to_internal(consts_eval::atan2(1.0 / (1u64 << k) as f64, 1.0));
+        k += 1;
+    }
+    table
+}
+
+/// Builds the table of the starting $x$-coordinates of the circular CORDIC.
+///
+/// # Returns
+///
+/// $\prod_{k=0}^{n-1}\cos\left(\arctan\left(2^{-k}\right)\right)$ for $n=0,\dots$ in the internal
+/// fixed-point format.
+const fn circular_start_table() -> [i64; MAX + 1] {
+    let mut table = [0; MAX + 1];
+    let mut product = 1.0;
+    let mut n = 0;
+    while n <= MAX {
+        table[n] = to_internal(product);
+        if n < MAX {
+            product *= consts_eval::cos(consts_eval::atan2(1.0 / (1u64 << n) as f64, 1.0));
+        }
+        n += 1;
+    }
+    table
+}
+
+/// Builds the table of the shifts of the hyperbolic CORDIC.
+///
+/// # Returns
+///
+/// Shift of each iteration of the hyperbolic CORDIC.
+const fn hyperbolic_shift_table() -> [u32; MAX] {
+    let mut table = [0; MAX];
+    let mut shift = 1;
+    let mut repeat = 4;
+    let mut k = 0;
+    while k < MAX {
+        table[k] = shift;
+        k += 1;
+        if shift == repeat && k < MAX {
+            table[k] = shift;
+            k += 1;
+            repeat = 3 * repeat + 1;
+        }
+        shift += 1;
+    }
+    table
+}
+
+/// Builds the table of $\operatorname{artanh}\left(2^{-i}\right)$.
+///
+/// # Returns
+///
+/// $\operatorname{artanh}\left(2^{-i}\right)$ for the shift $i$ of each iteration of the
+/// hyperbolic CORDIC in the internal fixed-point format.
+const fn atanh_table() -> [i64; MAX] {
+    let mut table = [0; MAX];
+    let mut k = 0;
+    while k < MAX {
+        table[k] = to_internal(atanh_series(1.0 / (1u64 << HYPERBOLIC_SHIFT[k]) as f64));
+        k += 1;
+    }
+    table
+}
+
+/// Builds the table of the starting $x$-coordinates of the hyperbolic CORDIC.
+///
+/// # Returns
+///
+/// $\prod\cosh\left(\operatorname{artanh}\left(2^{-i}\right)\right)$ over the shifts $i$ of the
+/// first $n$ iterations, for $n=0,\dots$ in the internal fixed-point format.
+const fn hyperbolic_start_table() -> [i64; MAX + 1] {
+    let mut table = [0; MAX + 1];
+    let mut product = 1.0;
+    let mut n = 0;
+    while n <= MAX {
+        table[n] = to_internal(product);
+        if n < MAX {
+            let u = 1.0 / (1u64 << HYPERBOLIC_SHIFT[n]) as f64;
+            product *= consts_eval::cosh(atanh_series(u));
+        }
+        n += 1;
+    }
+    table
+}
+
+/// CORDIC engine computing trigonometric and hyperbolic functions of fixed-point numbers with
+/// shifts and adds.
+///
+/// Arguments and results are [`i32`]s with `frac_bits` fractional bits (e.g. `16` for Q16.16,
+/// `15` for Q1.15 stored in the low bits of an [`i32`], or `0` for plain integers), and angles are
+/// in radians. Each iteration adds about one bit of accuracy, so that `frac_bits + 2` iterations
+/// give results accurate to about one unit in the last place. The engine only uses integer shifts,
+/// additions, and comparisons in its iterations, with a few integer multiplications to reduce the
+/// arguments and to remove the gain of the magnitude, so it does not need any floating-point
+/// hardware.
+///
+/// # Note
+///
+/// Results that do not fit the fixed-point format saturate to [`i32::MIN`] or [`i32::MAX`] (e.g.
+/// $\sin(x)=1$ with 31 fractional bits, or $\cosh(x)$ for large $x$).
+///
+/// # References
+///
+/// * J. E. Volder, "The CORDIC trigonometric computing technique", *IRE Transactions on Electronic
+///   Computers*, EC-8(3), pp. 330–334, 1959.
+/// * J. S. Walther, "A unified algorithm for elementary functions", *Proceedings of the Spring
+///   Joint Computer Conference*, pp. 379–385, 1971.
+///
+/// # Example
+///
+/// ```
+/// use trig::Cordic;
+///
+/// // Q16.16 arguments and results, with 18 iterations.
+/// let cordic = Cordic::new(16, 18);
+///
+/// // sin(π/6) = 0.5 and cos(π/6) = 0.8660...
+/// let (s, c) = cordic.sincos(34315);
+/// assert!((s - 32768).abs() <= 2);
+/// assert!((c - 56756).abs() <= 2);
+///
+/// // atan2(1, -1) = 3π/4 and |(3, 4)| = 5.
+/// assert!((cordic.atan2(65536, -65536) - 154415).abs() <= 2);
+/// assert!((cordic.magnitude(3 << 16, 4 << 16) as i64 - (5 << 16)).abs() <= 2);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Cordic {
+    /// Number of fractional bits of arguments and results.
+    frac_bits: u32,
+
+    /// Number of iterations.
+    iterations: u32,
+}
+
+impl Cordic {
+    /// Maximum number of iterations.
+    pub const MAX_ITERATIONS: u32 = MAX as u32;
+
+    /// Creates a CORDIC engine.
+    ///
+    /// # Arguments
+    ///
+    /// * `frac_bits` - Number of fractional bits of arguments and results, at most 31.
+    /// * `iterations` - Number of iterations, from 1 to [`Cordic::MAX_ITERATIONS`].
+    ///
+    /// # Returns
+    ///
+    /// CORDIC engine.
+    ///
+    /// # Panics
+    ///
+    /// If `frac_bits` or `iterations` is out of range.
+    pub const fn new(frac_bits: u32, iterations: u32) -> Self {
+        assert!(frac_bits <= 31, "at most 31 fractional bits are supported");
+        assert!(
+            iterations >= 1 && iterations <= Self::MAX_ITERATIONS,
+            "the number of iterations must be between 1 and Cordic::MAX_ITERATIONS"
+        );
+        Self {
+            frac_bits,
+            iterations,
+        }
+    }
+
+    /// Number of fractional bits of arguments and results.
+    ///
+    /// # Returns
+    ///
+    /// Number of fractional bits.
+    pub const fn frac_bits(&self) -> u32 {
+        self.frac_bits
+    }
+
+    /// Number of iterations.
+    ///
+    /// # Returns
+    ///
+    /// Number of iterations.
+    pub const fn iterations(&self) -> u32 {
+        self.iterations
+    }
+
+    /// Computes the sine and cosine of an angle.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - Angle \[rad\].
+    ///
+    /// # Returns
+    ///
+    /// $\sin(x)$ and $\cos(x)$.
+    pub fn sincos(&self, x: i32) -> (i32, i32) {
+        let (q, r) = self.reduce(x);
+        let (c, s) = self.rotate(r as i64);
+        let (s, c) = match q.rem_euclid(4) {
+            0 => (s, c),
+            1 => (c, -s),
+            2 => (-s, -c),
+            _ => (-c, s),
+        };
+        (self.to_fixed(s as i128), self.to_fixed(c as i128))
+    }
+
+    /// Computes the four-quadrant inverse tangent.
+    ///
+    /// # Arguments
+    ///
+    /// * `y` - $y$-coordinate.
+    /// * `x` - $x$-coordinate.
+    ///
+    /// # Returns
+    ///
+    /// Angle \[rad\] of the point $(x,y)$, in $[-\pi,\pi]$ (`0` for the origin).
+    ///
+    /// # Note
+    ///
+    /// The coordinates only need to share their fixed-point format, which does not have to be the
+    /// one of the engine.
+    pub fn atan2(&self, y: i32, x: i32) -> i32 {
+        let (z, _, _) = self.vector(y, x);
+        self.to_fixed(z as i128)
+    }
+
+    /// Computes the magnitude of a vector.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - $x$-coordinate.
+    /// * `y` - $y$-coordinate.
+    ///
+    /// # Returns
+    ///
+    /// $\sqrt{x^{2}+y^{2}}$, in the fixed-point format of the coordinates.
+    ///
+    /// # Note
+    ///
+    /// The magnitude is returned as a [`u32`], since it can exceed [`i32::MAX`] by up to a factor
+    /// of $\sqrt{2}$.
+    pub fn magnitude(&self, x: i32, y: i32) -> u32 {
+        let (_, r, shift) = self.vector(y, x);
+        let start = CIRCULAR_START[self.iterations as usize] as i128;
+        let shift = FRAC + shift;
+        let r = ((r as i128 * start) + (1 << (shift - 1))) >> shift;
+        r.min(u32::MAX as i128) as u32
+    }
+
+    /// Computes the hyperbolic sine and cosine.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - Value.
+    ///
+    /// # Returns
+    ///
+    /// $\sinh(x)$ and $\cosh(x)$.
+    pub fn sinhcosh(&self, x: i32) -> (i32, i32) {
+        // With x = n ln(2) + r, exp(±x) = 2^(±n) (cosh(r) ± sinh(r)).
+        let a = self.to_internal(x).abs();
+        let n = (a * INV_LN2_30 + (1 << 89)) >> 90;
+        if n > 40 {
+            let s = if x < 0 { i32::MIN } else { i32::MAX };
+            return (s, i32::MAX);
+        }
+        let (c, s) = self.rotate_hyperbolic((a - n * LN2 as i128) as i64);
+        let (c, s) = (c as i128, s as i128);
+        let (ep, em) = ((c + s) << n, (c - s) >> n);
+        let sinh = (ep - em) >> 1;
+        let sinh = if x < 0 { -sinh } else { sinh };
+        (self.to_fixed(sinh), self.to_fixed((ep + em) >> 1))
+    }
+
+    /// Computes the inverse hyperbolic tangent.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - Value, $|x|<1$.
+    ///
+    /// # Returns
+    ///
+    /// $\operatorname{artanh}(x)$ (saturated to [`i32::MAX`] for $x\geq1$ and to [`i32::MIN`] for
+    /// $x\leq-1$).
+    pub fn atanh(&self, x: i32) -> i32 {
+        // artanh(x) = ln(a/b)/2 with a = 1 + x and b = 1 - x.
+        let one = 1i64 << self.frac_bits;
+        let (a, b) = (one + x as i64, one - x as i64);
+        if b <= 0 {
+            return i32::MAX;
+        } else if a <= 0 {
+            return i32::MIN;
+        }
+        self.to_fixed(self.ln_ratio_internal(a, b) >> 1)
+    }
+
+    /// Computes the natural logarithm of a ratio.
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - Numerator, $a>0$.
+    /// * `b` - Denominator, $b>0$ (in the same fixed-point format as `a`).
+    ///
+    /// # Returns
+    ///
+    /// $\ln\left(\frac{a}{b}\right)$.
+    pub(crate) fn ln_ratio(&self, a: i64, b: i64) -> i32 {
+        self.to_fixed(self.ln_ratio_internal(a, b))
+    }
+
+    /// Computes half of the natural logarithm of a ratio.
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - Numerator, $a>0$.
+    /// * `b` - Denominator, $b>0$ (in the same fixed-point format as `a`).
+    ///
+    /// # Returns
+    ///
+    /// $\frac{1}{2}\ln\left(\frac{a}{b}\right)$.
+    pub(crate) fn half_ln_ratio(&self, a: i64, b: i64) -> i32 {
+        self.to_fixed(self.ln_ratio_internal(a, b) >> 1)
+    }
+
+    /// Computes the natural logarithm of a ratio in the internal fixed-point format (hyperbolic
+    /// CORDIC in vectoring mode).
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - Numerator, $a>0$.
+    /// * `b` - Denominator, $b>0$.
+    ///
+    /// # Returns
+    ///
+    /// $\ln\left(\frac{a}{b}\right)$ in the internal fixed-point format.
+    fn ln_ratio_internal(&self, a: i64, b: i64) -> i128 {
+        // Scaling a and b to the same binade gives a/b = 2^m w with 1/2 < w < 2, and
+        // ln(w) = 2 artanh((w - 1)/(w + 1)), where |(w - 1)/(w + 1)| < 1/3 is well within the
+        // convergence range of the vectoring mode.
+        let (la, lb) = (63 - a.leading_zeros(), 63 - b.leading_zeros());
+        let normalize = |v: i64, l: u32| if l > 58 { v >> (l - 58) } else { v << (58 - l) };
+        let (a, b) = (normalize(a, la), normalize(b, lb));
+        let (mut x, mut y, mut z) = (a + b, a - b, 0);
+        for k in 0..self.iterations as usize {
+            if y == 0 {
+                // The remaining angle is exactly zero.
+                break;
+            }
+            let shift = HYPERBOLIC_SHIFT[k];
+            let (dx, dy) = (y >> shift, x >> shift);
+            if y > 0 {
+                x -= dx;
+                y -= dy;
+                z += ATANH[k];
+            } else {
+                x += dx;
+                y += dy;
+                z -= ATANH[k];
+            }
+        }
+        let m = la as i128 - lb as i128;
+        2 * z as i128 + m * LN2 as i128
+    }
+
+    /// Reduces an angle to the nearest multiple of $\frac{\pi}{2}$.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - Angle \[rad\] with [`Cordic::frac_bits`] fractional bits.
+    ///
+    /// # Returns
+    ///
+    /// Quotient $q$ and remainder $r=x-q\frac{\pi}{2}$ \[rad\] (in the internal fixed-point
+    /// format), with $|r|\leq\frac{\pi}{4}$.
+    ///
+    /// # Note
+    ///
+    /// With $64$ fractional bits of $\frac{2}{\pi}$, the quotient is the nearest integer for
+    /// every argument (up to $2^{31}$ \[rad\] without fractional bits), whereas $30$ bits could
+    /// be off by up to $0.85$ there and leave a remainder outside the range of convergence of the
+    /// rotation.
+    fn reduce(self, x: i32) -> (i128, i128) {
+        let shift = 64 + self.frac_bits;
+        let q = (x as i128 * TWO_OVER_PI_64 + (1 << (shift - 1))) >> shift;
+        (q, self.to_internal(x) - q * PIO2 as i128)
+    }
+
+    /// Converts a fixed-point number of the engine to the internal fixed-point format.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - Fixed-point number with [`Cordic::frac_bits`] fractional bits.
+    ///
+    /// # Returns
+    ///
+    /// `x` in the internal fixed-point format.
+    fn to_internal(self, x: i32) -> i128 {
+        (x as i128) << (FRAC - self.frac_bits)
+    }
+
+    /// Converts a number in the internal fixed-point format to a fixed-point number of the engine.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - Number in the internal fixed-point format.
+    ///
+    /// # Returns
+    ///
+    /// `x` with [`Cordic::frac_bits`] fractional bits, rounded to nearest and saturated.
+    fn to_fixed(self, x: i128) -> i32 {
+        let shift = FRAC - self.frac_bits;
+        let x = (x + (1 << (shift - 1))) >> shift;
+        x.clamp(i32::MIN as i128, i32::MAX as i128) as i32
+    }
+
+    /// Rotates the unit vector along the $x$-axis (circular CORDIC in rotation mode).
+    ///
+    /// # Arguments
+    ///
+    /// * `z` - Angle, $|z|\lesssim1.74$ \[rad\] in the internal fixed-point format.
+    ///
+    /// # Returns
+    ///
+    /// $\cos(z)$ and $\sin(z)$ in the internal fixed-point format.
+    fn rotate(&self, mut z: i64) -> (i64, i64) {
+        let (mut x, mut y) = (CIRCULAR_START[self.iterations as usize], 0);
+        for (k, atan) in ATAN.iter().enumerate().take(self.iterations as usize) {
+            let (dx, dy) = (y >> k, x >> k);
+            if z >= 0 {
+                x -= dx;
+                y += dy;
+                z -= atan;
+            } else {
+                x += dx;
+                y -= dy;
+                z += atan;
+            }
+        }
+        (x, y)
+    }
+
+    /// Rotates a vector onto the positive $x$-axis (circular CORDIC in vectoring mode).
+    ///
+    /// # Arguments
+    ///
+    /// * `y` - $y$-coordinate.
+    /// * `x` - $x$-coordinate.
+    ///
+    /// # Returns
+    ///
+    /// Angle \[rad\] of the point $(x,y)$ in the internal fixed-point format, its magnitude scaled
+    /// by the gain of the CORDIC and by $2^{s}$, and the scaling exponent $s$.
+    fn vector(&self, y: i32, x: i32) -> (i64, i64, u32) {
+        let (mut x, mut y) = (x as i64, y as i64);
+        if x == 0 && y == 0 {
+            return (0, 0, 0);
+        }
+
+        // Rotating by π brings the point into the right half-plane, where the iterations converge.
+        let offset = if x < 0 {
+            (x, y) = (-x, -y);
+            if y <= 0 { PI } else { -PI }
+        } else {
+            0
+        };
+
+        // Scaling the coordinates to 59 bits keeps the full precision of the iterations.
+        let shift = x.max(y.abs()).leading_zeros() - 5;
+        let (mut x, mut y, mut z) = (x << shift, y << shift, 0);
+        for (k, atan) in ATAN.iter().enumerate().take(self.iterations as usize) {
+            let (dx, dy) = (y >> k, x >> k);
+            if y > 0 {
+                x += dx;
+                y -= dy;
+                z += atan;
+            } else {
+                x -= dx;
+                y += dy;
+                z -= atan;
+            }
+        }
+        (z + offset, x, shift)
+    }
+
+    /// Rotates the vector $(1,0)$ along the hyperbola $x^{2}-y^{2}=1$ (hyperbolic CORDIC in
+    /// rotation mode).
+    ///
+    /// # Arguments
+    ///
+    /// * `z` - Value, $|z|\lesssim1.11$ in the internal fixed-point format.
+    ///
+    /// # Returns
+    ///
+    /// $\cosh(z)$ and $\sinh(z)$ in the internal fixed-point format.
+    fn rotate_hyperbolic(&self, mut z: i64) -> (i64, i64) {
+        let (mut x, mut y) = (HYPERBOLIC_START[self.iterations as usize], 0);
+        for k in 0..self.iterations as usize {
+            let shift = HYPERBOLIC_SHIFT[k];
+            let (dx, dy) = (y >> shift, x >> shift);
+            if z >= 0 {
+                x += dx;
+                y += dy;
+                z -= ATANH[k];
+            } else {
+                x -= dx;
+                y -= dy;
+                z += ATANH[k];
+            }
+        }
+        (x, y)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Checks a function of the engine against a reference over a sweep of arguments.
+    ///
+    /// # Arguments
+    ///
+    /// * `cordic` - CORDIC engine.
+    /// * `range` - Range of the arguments (before scaling to the fixed-point format).
+    /// * `f` - Function of the engine.
+    /// * `g` - Reference function.
+    /// * `bound` - Maximum allowed error \[units in the last place, relative to the result for
+    ///   results larger than 1\].
+    fn check(
+        cordic: Cordic,
+        range: (f64, f64),
+        f: impl Fn(i32) -> i32,
+        g: impl Fn(f64) -> f64,
+        bound: f64,
+    ) {
+        let scale = (1u64 << cordic.frac_bits()) as f64;
+        for k in 0..=4000 {
+            let x = range.0 + (range.1 - range.0) * k as f64 / 4000.0;
+            let x = (x * scale).round() as i32;
+            let expected = (g(x as f64 / scale) * scale).clamp(i32::MIN as f64, i32::MAX as f64);
+            let e = (f(x) as f64 - expected).abs() / (expected.abs() / scale).max(1.0);
+            assert!(e <= bound, "f({x}) = {}, expected {expected}", f(x));
+        }
+    }
+
+    #[test]
+    fn test_tables() {
+        assert_eq!(
+            &HYPERBOLIC_SHIFT[..16],
+            &[1, 2, 3, 4, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 13, 14]
+        );
+        assert_eq!(CIRCULAR_START[0], 1 << FRAC);
+        assert!(
+            (CIRCULAR_START[MAX] as f64 / (1u64 << FRAC) as f64 - 0.6072529350088813).abs() < 1e-15
+        );
+        assert!(
+            (HYPERBOLIC_START[MAX] as f64 / (1u64 << FRAC) as f64 - 1.2074970677630726).abs()
+                < 1e-15
+        );
+    }
+
+    #[test]
+    fn test_accuracy() {
+        for (frac_bits, range) in [(16, 1000.0), (29, 3.9), (31, 0.99)] {
+            let cordic = Cordic::new(frac_bits, frac_bits + 4);
+            let sin = |x| cordic.sincos(x).0;
+            let cos = |x| cordic.sincos(x).1;
+            check(cordic, (-range, range), sin, f64::sin, 2.0);
+            check(cordic, (-range, range), cos, f64::cos, 2.0);
+            let atan2 = |x| cordic.atan2(x, 1 << (frac_bits - 2));
+            check(cordic, (-range, range), atan2, |x| x.atan2(0.25), 2.0);
+            let atan2 = |x| cordic.atan2(-(1 << (frac_bits - 2)), x);
+            check(
+                cordic,
+                (-range, range),
+                atan2,
+                |x| (-0.25_f64).atan2(x),
+                2.0,
+            );
+            let sinh = |x| cordic.sinhcosh(x).0;
+            let cosh = |x| cordic.sinhcosh(x).1;
+            let h = range.min(10.0);
+            check(cordic, (-h, h), sinh, f64::sinh, 2.0);
+            check(cordic, (-h, h), cosh, f64::cosh, 2.0);
+            check(
+                cordic,
+                (-0.999, 0.999),
+                |x| cordic.atanh(x),
+                f64::atanh,
+                2.0,
+            );
+        }
+    }
+
+    #[test]
+    fn test_iterations() {
+        // Each iteration adds about one bit of accuracy.
+        for iterations in [8, 12, 16] {
+            let cordic = Cordic::new(24, iterations);
+            let bound = (1u64 << (24 - iterations + 2)) as f64;
+            check(cordic, (-3.0, 3.0), |x| cordic.sincos(x).0, f64::sin, bound);
+            check(
+                cordic,
+                (-3.0, 3.0),
+                |x| cordic.atan2(x, 1 << 24),
+                f64::atan,
+                bound,
+            );
+        }
+    }
+
+    #[test]
+    fn test_magnitude() {
+        let cordic = Cordic::new(0, 34);
+        assert_eq!(cordic.magnitude(3, 4), 5);
+        assert_eq!(cordic.magnitude(-300000, 400000), 500000);
+        assert_eq!(cordic.magnitude(i32::MIN, i32::MIN), 3037000500);
+        assert_eq!(cordic.magnitude(0, 0), 0);
+        assert_eq!(cordic.magnitude(0, -7), 7);
+    }
+
+    #[test]
+    fn test_special_values() {
+        let cordic = Cordic::new(16, 20);
+        assert_eq!(cordic.sincos(0), (0, 65536));
+        assert_eq!(cordic.atan2(0, 0), 0);
+        assert_eq!(cordic.atan2(0, -5), 205887);
+        assert_eq!(cordic.atan2(5, 0), 102944);
+        assert_eq!(cordic.atan2(-5, 0), -102944);
+        assert_eq!(cordic.sinhcosh(0), (0, 65536));
+        assert_eq!(cordic.sinhcosh(i32::MAX), (i32::MAX, i32::MAX));
+        assert_eq!(cordic.sinhcosh(i32::MIN), (i32::MIN, i32::MAX));
+        assert_eq!(cordic.atanh(0), 0);
+        assert_eq!(cordic.atanh(65536), i32::MAX);
+        assert_eq!(cordic.atanh(-65536), i32::MIN);
+        assert_eq!(Cordic::new(31, 34).sincos(0), (0, i32::MAX));
+    }
+
+    #[test]
+    fn test_large_arguments() {
+        // Without fractional bits, the arguments reach 2^31 rad, where the quotient of the
+        // reduction is the most sensitive to the precision of 2/pi.
+        let cordic = Cordic::new(0, 34);
+        let quarter = (PIO2 / 2) as i128 + 1;
+        for x in (i32::MAX - 100000..=i32::MAX).chain(i32::MIN..=i32::MIN + 100000) {
+            let (_, r) = cordic.reduce(x);
+            assert!(r.abs() <= quarter, "reduce({x}) = {r}");
+        }
+        // 2_147_457_916 rad is about 1_367_114_169.65 pi/2, which 30 bits of 2/pi rounded up to
+        // 1_367_114_171, leaving a remainder of about -2.12 rad.
+        assert_eq!(cordic.reduce(2_147_457_916).0, 1_367_114_170);
+        assert_eq!(cordic.sincos(2_147_457_916), (1, -1));
+        assert_eq!(cordic.sincos(i32::MAX), (-1, -1));
+    }
+}
@@ -0,0 +1,776 @@
+//! Correctly rounded evaluation of the [`Trig`](crate::Trig) methods.
+//!
+//! Each function is evaluated in ball arithmetic (a midpoint together with a rigorous error
+//! radius), first in double-double arithmetic and then, only if that ball straddles a rounding
+//! boundary, in multiprecision arithmetic at increasing precision until the ball rounds to a single
+//! value (Ziv's strategy). Special values (zeros, infinities, NaNs, poles, and exact results at the
+//! boundaries of the domains) are handled separately, since a ball around an exact zero can never
+//! decide the sign of its rounding.
+//!
+//! All functions operate on [`f64`] arguments and round their results to a binary format with a
+//! given precision (`f32::MANTISSA_DIGITS` or `f64::MANTISSA_DIGITS`), returned as an [`f64`].
+//!
+//! # References
+//!
+//! * Ziv, A. (1991). Fast evaluation of elementary mathematical functions with correctly rounded
+//!   last bit. *ACM Transactions on Mathematical Software*, *17*(3), 410-423.
+
+use crate::ball::{Ball, DdBall, Function, MpBall, Unit, atan2_ball};
+use crate::f64_impl::reduce_turn;
+
+/// Largest working precision of the multiprecision evaluation, in bits.
+///
+/// No argument is known to need more than a few hundred bits, so this cap is never reached in
+/// practice; if it were, the midpoint of the final ball would be returned.
+const MAX_PRECISION: u64 = 1 << 14;
+
+/// Rounds a ball computation to nearest using Ziv's strategy.
+///
+/// # Arguments
+///
+/// * `precision` - Precision of the target format.
+/// * `dd` - Evaluation in double-double ball arithmetic.
+/// * `mp` - Evaluation in multiprecision ball arithmetic at a given working precision.
+///
+/// # Returns
+///
+/// Correctly rounded result.
+fn ziv(precision: u32, dd: impl Fn() -> Option<DdBall>, mp: impl Fn(u64) -> Option<MpBall>) -> f64 {
+    if let Some(y) = dd().and_then(|ball| ball.round(precision)) {
+        return y;
+    }
+    let mut working_precision = 128;
+    loop {
+        let ball = mp(working_precision);
+        if let Some(y) = ball.as_ref().and_then(|ball| ball.round(precision)) {
+            return y;
+        }
+        if working_precision >= MAX_PRECISION {
+            return ball.map_or(f64::NAN, |ball| ball.round_midpoint(precision));
+        }
+        working_precision *= 2;
+    }
+}
+
+/// Correctly rounds a function of one argument.
+///
+/// # Arguments
+///
+/// * `f` - Function.
+/// * `x` - Finite argument.
+/// * `precision` - Precision of the target format.
+///
+/// # Returns
+///
+/// $f(x)$ rounded to nearest.
+fn round(f: Function, x: f64, precision: u32) -> f64 {
+    ziv(
+        precision,
+        || f.eval(&DdBall::new(x, 0)?),
+        |w| f.eval(&MpBall::new(x, w)?),
+    )
+}
+
+/// Correctly rounds a fraction of a half turn.
+///
+/// # Arguments
+///
+/// * `fraction` - Fraction of a half turn.
+/// * `unit` - Angular unit of the result.
+/// * `precision` - Precision of the target format.
+///
+/// # Returns
+///
+/// `fraction` half-turns in `unit`, rounded to nearest.
+fn angle(fraction: f64, unit: Unit, precision: u32) -> f64 {
+    match unit {
+        Unit::Radians => round(Function::ToRadians(Unit::HalfTurns), fraction, precision),
+        Unit::Degrees => 180.0 * fraction,
+        Unit::HalfTurns => fraction,
+    }
+}
+
+/// Applies the sign of an angle to the value of an odd function of its reduced magnitude.
+///
+/// # Arguments
+///
+/// * `f` - Function value at $|x|$.
+/// * `x` - Angle.
+///
+/// # Returns
+///
+/// Function value at $x$, where an exact zero takes the sign of `x`.
+fn with_sign_of(f: f64, x: f64) -> f64 {
+    if x.is_sign_negative() {
+        -(f + 0.0)
+    } else {
+        f + 0.0
+    }
+}
+
+/// Sine of an angle in units with an exact quarter turn.
+fn sin_turn(x: f64, unit: Unit, precision: u32) -> f64 {
+    if !x.is_finite() {
+        return f64::NAN;
+    }
+    let (q, r) = reduce_turn(x.abs(), unit.quarter_turn());
+    let sin = |r: f64| {
+        if r == 0.0 {
+            r
+        } else {
+            round(Function::Sin(unit), r, precision)
+        }
+    };
+    let cos = |r: f64| round(Function::Cos(unit), r, precision);
+    let f = match q {
+        0 => sin(r),
+        1 => cos(r),
+        2 => -sin(r),
+        _ => -cos(r),
+    };
+    with_sign_of(f, x)
+}
+
+/// Cosine of an angle in units with an exact quarter turn.
+fn cos_turn(x: f64, unit: Unit, precision: u32) -> f64 {
+    if !x.is_finite() {
+        return f64::NAN;
+    }
+    let (q, r) = reduce_turn(x.abs(), unit.quarter_turn());
+    let sin = |r: f64| {
+        if r == 0.0 {
+            r
+        } else {
+            round(Function::Sin(unit), r, precision)
+        }
+    };
+    let cos = |r: f64| round(Function::Cos(unit), r, precision);
+    let f = match q {
+        0 => cos(r),
+        1 => -sin(r),
+        2 => -cos(r),
+        _ => sin(r),
+    };
+    f + 0.0
+}
+
+/// Tangent of an angle in units with an exact quarter turn.
+fn tan_turn(x: f64, unit: Unit, precision: u32) -> f64 {
+    if !x.is_finite() {
+        return f64::NAN;
+    }
+    let (q, r) = reduce_turn(x.abs(), unit.quarter_turn());
+    let f = if r == 0.0 {
+        match q {
+            1 => f64::INFINITY,
+            3 => f64::NEG_INFINITY,
+            _ => r,
+        }
+    } else if q % 2 == 0 {
+        round(Function::Tan(unit), r, precision)
+    } else {
+        -round(Function::Cot(unit), r, precision)
+    };
+    with_sign_of(f, x)
+}
+
+/// Cosecant of an angle in units with an exact quarter turn.
+fn csc_turn(x: f64, unit: Unit, precision: u32) -> f64 {
+    if !x.is_finite() {
+        return f64::NAN;
+    }
+    let (q, r) = reduce_turn(x.abs(), unit.quarter_turn());
+    if r == 0.0 && q % 2 == 0 {
+        return 1.0 / sin_turn(x, unit, precision);
+    }
+    let f = match q {
+        0 => round(Function::Csc(unit), r, precision),
+        1 => round(Function::Sec(unit), r, precision),
+        2 => -round(Function::Csc(unit), r, precision),
+        _ => -round(Function::Sec(unit), r, precision),
+    };
+    if x.is_sign_negative() { -f } else { f }
+}
+
+/// Secant of an angle in units with an exact quarter turn.
+fn sec_turn(x: f64, unit: Unit, precision: u32) -> f64 {
+    if !x.is_finite() {
+        return f64::NAN;
+    }
+    let (q, r) = reduce_turn(x.abs(), unit.quarter_turn());
+    if r == 0.0 && q % 2 == 1 {
+        return 1.0 / cos_turn(x, unit, precision);
+    }
+    match q {
+        0 => round(Function::Sec(unit), r, precision),
+        1 => -round(Function::Csc(unit), r, precision),
+        2 => -round(Function::Sec(unit), r, precision),
+        _ => round(Function::Csc(unit), r, precision),
+    }
+}
+
+/// Cotangent of an angle in units with an exact quarter turn.
+fn cot_turn(x: f64, unit: Unit, precision: u32) -> f64 {
+    if !x.is_finite() {
+        return f64::NAN;
+    }
+    let (q, r) = reduce_turn(x.abs(), unit.quarter_turn());
+    if r == 0.0 {
+        return 1.0 / tan_turn(x, unit, precision);
+    }
+    let f = if q % 2 == 0 {
+        round(Function::Cot(unit), r, precision)
+    } else {
+        -round(Function::Tan(unit), r, precision)
+    };
+    if x.is_sign_negative() { -f } else { f }
+}
+
+/// Inverse sine in a given unit.
+fn asin_unit(x: f64, unit: Unit, precision: u32) -> f64 {
+    if x.is_nan() || x.abs() > 1.0 {
+        f64::NAN
+    } else if x == 0.0 {
+        x
+    } else if x.abs() == 1.0 {
+        angle(0.5, unit, precision).copysign(x)
+    } else {
+        round(Function::Asin(unit), x, precision)
+    }
+}
+
+/// Inverse cosine in a given unit.
+fn acos_unit(x: f64, unit: Unit, precision: u32) -> f64 {
+    if x.is_nan() || x.abs() > 1.0 {
+        f64::NAN
+    } else if x == 1.0 {
+        0.0
+    } else if x == -1.0 {
+        angle(1.0, unit, precision)
+    } else {
+        round(Function::Acos(unit), x, precision)
+    }
+}
+
+/// Inverse tangent in a given unit.
+fn atan_unit(x: f64, unit: Unit, precision: u32) -> f64 {
+    if x.is_nan() || x == 0.0 {
+        x
+    } else if x.is_infinite() {
+        angle(0.5, unit, precision).copysign(x)
+    } else {
+        round(Function::Atan(unit), x, precision)
+    }
+}
+
+/// Two-argument inverse tangent in a given unit.
+fn atan2_unit(y: f64, x: f64, unit: Unit, precision: u32) -> f64 {
+    if y.is_nan() || x.is_nan() {
+        f64::NAN
+    } else if y == 0.0 {
+        if x > 0.0 || (x == 0.0 && x.is_sign_positive()) {
+            y
+        } else {
+            angle(1.0, unit, precision).copysign(y)
+        }
+    } else if y.is_infinite() {
+        let fraction = match x {
+            f64::INFINITY => 0.25,
+            f64::NEG_INFINITY => 0.75,
+            _ => 0.5,
+        };
+        angle(fraction, unit, precision).copysign(y)
+    } else if x == f64::INFINITY {
+        0.0_f64.copysign(y)
+    } else if x == f64::NEG_INFINITY {
+        angle(1.0, unit, precision).copysign(y)
+    } else if x == 0.0 {
+        angle(0.5, unit, precision).copysign(y)
+    } else {
+        ziv(
+            precision,
+            || unit.radians_to(&atan2_ball(&DdBall::new(y, 0)?, &DdBall::new(x, 0)?)?),
+            |w| unit.radians_to(&atan2_ball(&MpBall::new(y, w)?, &MpBall::new(x, w)?)?),
+        )
+    }
+}
+
+/// Inverse cosecant in a given unit.
+fn acsc_unit(x: f64, unit: Unit, precision: u32) -> f64 {
+    if x.is_nan() || x.abs() < 1.0 {
+        f64::NAN
+    } else if x.is_infinite() {
+        0.0_f64.copysign(x)
+    } else if x.abs() == 1.0 {
+        angle(0.5, unit, precision).copysign(x)
+    } else {
+        round(Function::Acsc(unit), x, precision)
+    }
+}
+
+/// Inverse secant in a given unit.
+fn asec_unit(x: f64, unit: Unit, precision: u32) -> f64 {
+    if x.is_nan() || x.abs() < 1.0 {
+        f64::NAN
+    } else if x.is_infinite() {
+        angle(0.5, unit, precision)
+    } else if x == 1.0 {
+        0.0
+    } else if x == -1.0 {
+        angle(1.0, unit, precision)
+    } else {
+        round(Function::Asec(unit), x, precision)
+    }
+}
+
+/// Inverse cotangent in a given unit.
+fn acot_unit(x: f64, unit: Unit, precision: u32) -> f64 {
+    if x.is_nan() {
+        x
+    } else if x.is_infinite() {
+        0.0_f64.copysign(x)
+    } else if x == 0.0 {
+        angle(0.5, unit, precision).copysign(x)
+    } else {
+        round(Function::Acot(unit), x, precision)
+    }
+}
+
+/// Correctly rounded sine.
+pub(crate) fn sin(x: f64, precision: u32) -> f64 {
+    if !x.is_finite() {
+        f64::NAN
+    } else if x == 0.0 {
+        x
+    } else {
+        round(Function::Sin(Unit::Radians), x, precision)
+    }
+}
+
+/// Correctly rounded cosine.
+pub(crate) fn cos(x: f64, precision: u32) -> f64 {
+    if !x.is_finite() {
+        f64::NAN
+    } else {
+        round(Function::Cos(Unit::Radians), x, precision)
+    }
+}
+
+/// Correctly rounded tangent.
+pub(crate) fn tan(x: f64, precision: u32) -> f64 {
+    if !x.is_finite() {
+        f64::NAN
+    } else if x == 0.0 {
+        x
+    } else {
+        round(Function::Tan(Unit::Radians), x, precision)
+    }
+}
+
+/// Correctly rounded cosecant.
+pub(crate) fn csc(x: f64, precision: u32) -> f64 {
+    if !x.is_finite() {
+        f64::NAN
+    } else if x == 0.0 {
+        1.0 / x
+    } else {
+        round(Function::Csc(Unit::Radians), x, precision)
+    }
+}
+
+/// Correctly rounded secant.
+pub(crate) fn sec(x: f64, precision: u32) -> f64 {
+    if !x.is_finite() {
+        f64::NAN
+    } else {
+        round(Function::Sec(Unit::Radians), x, precision)
+    }
+}
+
+/// Correctly rounded cotangent.
+pub(crate) fn cot(x: f64, precision: u32) -> f64 {
+    if !x.is_finite() {
+        f64::NAN
+    } else if x == 0.0 {
+        1.0 / x
+    } else {
+        round(Function::Cot(Unit::Radians), x, precision)
+    }
+}
+
+/// Correctly rounded sine and cosine.
+#[cfg(feature = "correctly-rounded")]
+pub(crate) fn sincos(x: f64, precision: u32) -> (f64, f64) {
+    (sin(x, precision), cos(x, precision))
+}
+
+/// Correctly rounded inverse sine.
+pub(crate) fn asin(x: f64, precision: u32) -> f64 {
+    asin_unit(x, Unit::Radians, precision)
+}
+
+/// Correctly rounded inverse cosine.
+pub(crate) fn acos(x: f64, precision: u32) -> f64 {
+    acos_unit(x, Unit::Radians, precision)
+}
+
+/// Correctly rounded inverse tangent.
+pub(crate) fn atan(x: f64, precision: u32) -> f64 {
+    atan_unit(x, Unit::Radians, precision)
+}
+
+/// Correctly rounded two-argument inverse tangent.
+pub(crate) fn atan2(y: f64, x: f64, precision: u32) -> f64 {
+    atan2_unit(y, x, Unit::Radians, precision)
+}
+
+/// Correctly rounded inverse cosecant.
+pub(crate) fn acsc(x: f64, precision: u32) -> f64 {
+    acsc_unit(x, Unit::Radians, precision)
+}
+
+/// Correctly rounded inverse secant.
+pub(crate) fn asec(x: f64, precision: u32) -> f64 {
+    asec_unit(x, Unit::Radians, precision)
+}
+
+/// Correctly rounded inverse cotangent.
+pub(crate) fn acot(x: f64, precision: u32) -> f64 {
+    acot_unit(x, Unit::Radians, precision)
+}
+
+/// Correctly rounded conversion from degrees to radians.
+pub(crate) fn deg2rad(x: f64, precision: u32) -> f64 {
+    if !x.is_finite() || x == 0.0 {
+        x
+    } else {
+        round(Function::ToRadians(Unit::Degrees), x, precision)
+    }
+}
+
+/// Correctly rounded conversion from radians to degrees.
+pub(crate) fn rad2deg(x: f64, precision: u32) -> f64 {
+    if !x.is_finite() || x == 0.0 {
+        x
+    } else {
+        round(Function::FromRadians(Unit::Degrees), x, precision)
+    }
+}
+
+/// Correctly rounded sine of an angle in degrees.
+pub(crate) fn sind(x: f64, precision: u32) -> f64 {
+    sin_turn(x, Unit::Degrees, precision)
+}
+
+/// Correctly rounded cosine of an angle in degrees.
+pub(crate) fn cosd(x: f64, precision: u32) -> f64 {
+    cos_turn(x, Unit::Degrees, precision)
+}
+
+/// Correctly rounded tangent of an angle in degrees.
+pub(crate) fn tand(x: f64, precision: u32) -> f64 {
+    tan_turn(x, Unit::Degrees, precision)
+}
+
+/// Correctly rounded cosecant of an angle in degrees.
+pub(crate) fn cscd(x: f64, precision: u32) -> f64 {
+    csc_turn(x, Unit::Degrees, precision)
+}
+
+/// Correctly rounded secant of an angle in degrees.
+pub(crate) fn secd(x: f64, precision: u32) -> f64 {
+    sec_turn(x, Unit::Degrees, precision)
+}
+
+/// Correctly rounded cotangent of an angle in degrees.
+pub(crate) fn cotd(x: f64, precision: u32) -> f64 {
+    cot_turn(x, Unit::Degrees, precision)
+}
+
+/// Correctly rounded sine and cosine of an angle in degrees.
+#[cfg(feature = "correctly-rounded")]
+pub(crate) fn sincosd(x: f64, precision: u32) -> (f64, f64) {
+    (sind(x, precision), cosd(x, precision))
+}
+
+/// Correctly rounded inverse sine in degrees.
+pub(crate) fn asind(x: f64, precision: u32) -> f64 {
+    asin_unit(x, Unit::Degrees, precision)
+}
+
+/// Correctly rounded inverse cosine in degrees.
+pub(crate) fn acosd(x: f64, precision: u32) -> f64 {
+    acos_unit(x, Unit::Degrees, precision)
+}
+
+/// Correctly rounded inverse tangent in degrees.
+pub(crate) fn atand(x: f64, precision: u32) -> f64 {
+    atan_unit(x, Unit::Degrees, precision)
+}
+
+/// Correctly rounded two-argument inverse tangent in degrees.
+pub(crate) fn atan2d(y: f64, x: f64, precision: u32) -> f64 {
+    atan2_unit(y, x, Unit::Degrees, precision)
+}
+
+/// Correctly rounded inverse cosecant in degrees.
+pub(crate) fn acscd(x: f64, precision: u32) -> f64 {
+    acsc_unit(x, Unit::Degrees, precision)
+}
+
+/// Correctly rounded inverse secant in degrees.
+pub(crate) fn asecd(x: f64, precision: u32) -> f64 {
+    asec_unit(x, Unit::Degrees, precision)
+}
+
+/// Correctly rounded inverse cotangent in degrees.
+pub(crate) fn acotd(x: f64, precision: u32) -> f64 {
+    acot_unit(x, Unit::Degrees, precision)
+}
+
+/// Correctly rounded sine of an angle in half-turns.
+pub(crate) fn sinpi(x: f64, precision: u32) -> f64 {
+    sin_turn(x, Unit::HalfTurns, precision)
+}
+
+/// Correctly rounded cosine of an angle in half-turns.
+pub(crate) fn cospi(x: f64, precision: u32) -> f64 {
+    cos_turn(x, Unit::HalfTurns, precision)
+}
+
+/// Correctly rounded tangent of an angle in half-turns.
+pub(crate) fn tanpi(x: f64, precision: u32) -> f64 {
+    tan_turn(x, Unit::HalfTurns, precision)
+}
+
+/// Correctly rounded inverse sine in half-turns.
+pub(crate) fn asinpi(x: f64, precision: u32) -> f64 {
+    asin_unit(x, Unit::HalfTurns, precision)
+}
+
+/// Correctly rounded inverse cosine in half-turns.
+pub(crate) fn acospi(x: f64, precision: u32) -> f64 {
+    acos_unit(x, Unit::HalfTurns, precision)
+}
+
+/// Correctly rounded inverse tangent in half-turns.
+pub(crate) fn atanpi(x: f64, precision: u32) -> f64 {
+    atan_unit(x, Unit::HalfTurns, precision)
+}
+
+/// Correctly rounded two-argument inverse tangent in half-turns.
+pub(crate) fn atan2pi(y: f64, x: f64, precision: u32) -> f64 {
+    atan2_unit(y, x, Unit::HalfTurns, precision)
+}
+
+/// Correctly rounded hyperbolic sine.
+pub(crate) fn sinh(x: f64, precision: u32) -> f64 {
+    if x.is_nan() || x == 0.0 {
+        x
+    } else if x.abs() > 1000.0 {
+        f64::INFINITY.copysign(x)
+    } else {
+        round(Function::Sinh, x.abs(), precision).copysign(x)
+    }
+}
+
+/// Correctly rounded hyperbolic cosine.
+pub(crate) fn cosh(x: f64, precision: u32) -> f64 {
+    if x.is_nan() {
+        x
+    } else if x.abs() > 1000.0 {
+        f64::INFINITY
+    } else {
+        round(Function::Cosh, x.abs(), precision)
+    }
+}
+
+/// Correctly rounded hyperbolic tangent.
+pub(crate) fn tanh(x: f64, precision: u32) -> f64 {
+    if x.is_nan() || x == 0.0 {
+        x
+    } else if x.abs() > 40.0 {
+        1.0_f64.copysign(x)
+    } else {
+        round(Function::Tanh, x.abs(), precision).copysign(x)
+    }
+}
+
+/// Correctly rounded hyperbolic cosecant.
+pub(crate) fn csch(x: f64, precision: u32) -> f64 {
+    if x.is_nan() {
+        x
+    } else if x == 0.0 {
+        1.0 / x
+    } else if x.abs() > 1000.0 {
+        0.0_f64.copysign(x)
+    } else {
+        round(Function::Csch, x.abs(), precision).copysign(x)
+    }
+}
+
+/// Correctly rounded hyperbolic secant.
+pub(crate) fn sech(x: f64, precision: u32) -> f64 {
+    if x.is_nan() {
+        x
+    } else if x.abs() > 1000.0 {
+        0.0
+    } else {
+        round(Function::Sech, x.abs(), precision)
+    }
+}
+
+/// Correctly rounded hyperbolic cotangent.
+pub(crate) fn coth(x: f64, precision: u32) -> f64 {
+    if x.is_nan() {
+        x
+    } else if x == 0.0 {
+        1.0 / x
+    } else if x.abs() > 40.0 {
+        1.0_f64.copysign(x)
+    } else {
+        round(Function::Coth, x.abs(), precision).copysign(x)
+    }
+}
+
+/// Correctly rounded hyperbolic sine and cosine.
+#[cfg(feature = "correctly-rounded")]
+pub(crate) fn sinhcosh(x: f64, precision: u32) -> (f64, f64) {
+    (sinh(x, precision), cosh(x, precision))
+}
+
+/// Correctly rounded inverse hyperbolic sine.
+pub(crate) fn asinh(x: f64, precision: u32) -> f64 {
+    if !x.is_finite() || x == 0.0 {
+        x
+    } else {
+        round(Function::Asinh, x.abs(), precision).copysign(x)
+    }
+}
+
+/// Correctly rounded inverse hyperbolic cosine.
+pub(crate) fn acosh(x: f64, precision: u32) -> f64 {
+    if x.is_nan() || x < 1.0 {
+        f64::NAN
+    } else if x == 1.0 {
+        0.0
+    } else if x.is_infinite() {
+        x
+    } else {
+        round(Function::Acosh, x, precision)
+    }
+}
+
+/// Correctly rounded inverse hyperbolic tangent.
+pub(crate) fn atanh(x: f64, precision: u32) -> f64 {
+    if x.is_nan() || x.abs() > 1.0 {
+        f64::NAN
+    } else if x.abs() == 1.0 {
+        f64::INFINITY.copysign(x)
+    } else if x == 0.0 {
+        x
+    } else {
+        round(Function::Atanh, x.abs(), precision).copysign(x)
+    }
+}
+
+/// Correctly rounded inverse hyperbolic cosecant.
+pub(crate) fn acsch(x: f64, precision: u32) -> f64 {
+    if x.is_nan() {
+        x
+    } else if x == 0.0 {
+        1.0 / x
+    } else if x.is_infinite() {
+        0.0_f64.copysign(x)
+    } else {
+        round(Function::Acsch, x.abs(), precision).copysign(x)
+    }
+}
+
+/// Correctly rounded inverse hyperbolic secant.
+pub(crate) fn asech(x: f64, precision: u32) -> f64 {
+    if !(0.0..=1.0).contains(&x) {
+        f64::NAN
+    } else if x == 0.0 {
+        f64::INFINITY
+    } else if x == 1.0 {
+        0.0
+    } else {
+        round(Function::Asech, x, precision)
+    }
+}
+
+/// Correctly rounded inverse hyperbolic cotangent.
+pub(crate) fn acoth(x: f64, precision: u32) -> f64 {
+    if x.is_nan() || x.abs() < 1.0 {
+        f64::NAN
+    } else if x.abs() == 1.0 {
+        f64::INFINITY.copysign(x)
+    } else if x.is_infinite() {
+        0.0_f64.copysign(x)
+    } else {
+        round(Function::Acoth, x.abs(), precision).copysign(x)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_f64() {
+        // Reference values computed with 1000-bit arithmetic.
+        for (f, x, expected) in [
+            (sin as fn(f64, u32) -> f64, 1e22, -0.8522008497671888),
+            (
+                cos,
+                6381956970095103.0 * 2.0_f64.powi(797),
+                -4.687165924254628e-19,
+            ),
+            (tan, std::f64::consts::FRAC_PI_2, 1.633123935319537e16),
+            (csc, std::f64::consts::PI, 8165619676597685.0),
+            (asin, 0.5, std::f64::consts::FRAC_PI_6),
+            (acos, -0.9999999999999999, 3.141592638688632),
+            (acsc, 1.0000000000000002, 1.5707963057214724),
+            (deg2rad, 1e300, 1.7453292519943297e298),
+            (rad2deg, f64::MAX, f64::INFINITY),
+            (tand, 1e-300, 1.7453292519943295e-302),
+            (sinpi, 0.1, 0.30901699437494745),
+            (asinpi, 0.5, 0.16666666666666666),
+            (atanpi, 1e300, 0.5),
+            (sinh, 1.0, 1.1752011936438014),
+            (cosh, 710.0, 1.1169973830808555e308),
+            (sech, 700.0, 1.971935308751954e-304),
+            (acosh, 1.0000000000000002, 2.1073424255447014e-8),
+            (atanh, 0.5, 0.5493061443340549),
+            (acsch, 1e-300, 691.4686750787737),
+            (asech, 0.5, 1.3169578969248168),
+            (acoth, 1e300, 1e-300),
+        ] {
+            assert_eq!(f(x, f64::MANTISSA_DIGITS), expected);
+        }
+    }
+
+    #[test]
+    fn test_f32() {
+        // Reference values computed with 1000-bit arithmetic.
+        for (f, x, expected) in [
+            (sin as fn(f64, u32) -> f64, 1e22_f32, -0.734_081_5_f32),
+            (cos, f32::MAX, 0.853_021),
+            (tan, std::f32::consts::FRAC_PI_2, -22_877_332.0),
+            (asin, 0.5, std::f32::consts::FRAC_PI_6),
+            (sinh, 88.7, 1.662_988_4e38),
+            (sind, 1e-30, 1.745_329_2e-32),
+            (acosh, 1.000_000_1, 0.000_488_281_25),
+        ] {
+            assert_eq!(f(x as f64, f32::MANTISSA_DIGITS) as f32, expected);
+        }
+    }
+
+    #[test]
+    fn test_atan2() {
+        assert_eq!(atan2d(1e-300, -1.0, f64::MANTISSA_DIGITS), 180.0);
+        assert_eq!(atan2(-5e-324, 1e300, f64::MANTISSA_DIGITS), -0.0);
+        assert!(atan2(-5e-324, 1e300, f64::MANTISSA_DIGITS).is_sign_negative());
+    }
+}
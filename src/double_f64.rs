@@ -0,0 +1,1344 @@
+use crate::double_double::{self, DoubleDouble, PI, two_sum};
+use crate::rem_pio2::rem_pio2_dd;
+use crate::trig_trait::Trig;
+use core::ops::{Add, Div, Mul, Neg, Sub};
+
+/// $\frac{\pi}{180}$ as a double-double.
+const DEG2RAD: DoubleDouble = DoubleDouble::new(0.017453292519943295, 2.9486522708701687e-19);
+
+/// $\frac{180}{\pi}$ as a double-double.
+const RAD2DEG: DoubleDouble = DoubleDouble::new(57.29577951308232, -1.9878495670576283e-15);
+
+/// $\frac{1}{\pi}$ as a double-double.
+const FRAC_1_PI: DoubleDouble =
+    DoubleDouble::new(core::f64::consts::FRAC_1_PI, -1.9678676675182486e-17);
+
+/// $\ln(2)$ as a double-double.
+const LN_2: DoubleDouble = DoubleDouble::new(core::f64::consts::LN_2, 2.3190468138462996e-17);
+
+/// $\frac{1}{\sqrt{2}}$ as a double-double.
+const FRAC_1_SQRT_2: DoubleDouble =
+    DoubleDouble::new(core::f64::consts::FRAC_1_SQRT_2, -4.833646656726457e-17);
+
+/// $\frac{\sqrt{3}}{2}$ as a double-double.
+const SQRT_3_OVER_2: DoubleDouble = DoubleDouble::new(0.8660254037844386, 5.0175421109034514e-17);
+
+/// Magnitude above which $x^{2}$ is replaced by asymptotic expansions (so that it cannot overflow,
+/// and the neglected terms are below double-double precision).
+const LARGE: f64 = 1e150;
+
+/// Magnitude above which $\sinh(x)$ and $\cosh(x)$ are computed as $\frac{e^{|x|}}{2}$.
+const HYPERBOLIC_LARGE: f64 = 350.0;
+
+/// Angular unit.
+#[derive(Clone, Copy)]
+enum Unit {
+    /// Radians.
+    Radians,
+
+    /// Degrees.
+    Degrees,
+
+    /// Half-turns.
+    HalfTurns,
+}
+
+impl Unit {
+    /// A half-turn in this unit.
+    ///
+    /// # Returns
+    ///
+    /// $\pi$, $180$, or $1$.
+    fn half_turn(self) -> DoubleDouble {
+        match self {
+            Unit::Radians => PI,
+            Unit::Degrees => DoubleDouble::from_f64(180.0),
+            Unit::HalfTurns => DoubleDouble::from_f64(1.0),
+        }
+    }
+
+    /// Converts an angle from radians to this unit.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - Angle \[rad\].
+    ///
+    /// # Returns
+    ///
+    /// `x` in this unit.
+    fn rad2unit(self, x: DoubleDouble) -> DoubleDouble {
+        match self {
+            Unit::Radians => x,
+            Unit::Degrees => x.mul(RAD2DEG),
+            Unit::HalfTurns => x.mul(FRAC_1_PI),
+        }
+    }
+
+    /// Converts an angle from this unit to radians.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - Angle in this unit.
+    ///
+    /// # Returns
+    ///
+    /// `x` \[rad\].
+    fn unit2rad(self, x: DoubleDouble) -> DoubleDouble {
+        match self {
+            Unit::Radians => x,
+            Unit::Degrees => x.mul(DEG2RAD),
+            Unit::HalfTurns => x.mul(PI),
+        }
+    }
+}
+
+/// Double-double floating-point number.
+///
+/// A `DoubleF64` represents a real number as the unevaluated sum `hi + lo` of two [`f64`]s, where
+/// `lo` is at most half an ulp of `hi`, which gives about $106$ bits of precision (roughly $32$
+/// decimal digits) with the exponent range of an [`f64`]. It supports the basic arithmetic
+/// operators, and the [`Trig`] methods are evaluated in double-double arithmetic (reducing
+/// arguments modulo $\frac{\pi}{2}$ against $384$ bits of $\frac{2}{\pi}$) with a relative error of
+/// about $2^{-100}$.
+///
+/// # Note
+///
+/// As with any double-double type, the full precision is only available for magnitudes between
+/// about $2^{-969}$ (below which `lo` becomes subnormal) and $2^{996}$ (above which the exact
+/// products used by multiplication overflow).
+///
+/// # References
+///
+/// * Joldes, Muller, and Popescu, "Tight and rigorous error bounds for basic building blocks of
+///   double-word arithmetic", ACM Transactions on Mathematical Software 44(2), 2017.
+///
+/// # Example
+///
+/// ```
+/// use trig::{DoubleF64, Trig};
+///
+/// // sin(π/6) = 1/2, which is not exact in f64.
+/// let x = DoubleF64::PI / DoubleF64::from_f64(6.0);
+/// let error = x.sin() - DoubleF64::from_f64(0.5);
+/// assert!(error.to_f64().abs() < 1e-31);
+///
+/// // The trailing part holds the bits beyond f64 precision.
+/// let third = DoubleF64::from_f64(1.0) / DoubleF64::from_f64(3.0);
+/// assert_eq!(third.hi(), 1.0 / 3.0);
+/// assert!((third.lo() - 1.850371707708594e-17).abs() < 1e-32);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+pub struct DoubleF64 {
+    /// Leading part.
+    hi: f64,
+
+    /// Trailing part.
+    lo: f64,
+}
+
+impl DoubleF64 {
+    /// $\pi$.
+    pub const PI: DoubleF64 = DoubleF64::from_dd(PI);
+
+    /// Creates a double-double from the unevaluated sum of two [`f64`]s.
+    ///
+    /// # Arguments
+    ///
+    /// * `hi` - Leading part.
+    /// * `lo` - Trailing part.
+    ///
+    /// # Returns
+    ///
+    /// `hi + lo` (exact, barring overflow), normalized so that the trailing part is at most half an
+    /// ulp of the leading part.
+    pub const fn new(hi: f64, lo: f64) -> DoubleF64 {
+        let (s, e) = two_sum(hi, lo);
+        if s.is_finite() {
+            DoubleF64 { hi: s, lo: e }
+        } else {
+            DoubleF64 { hi: s, lo: 0.0 }
+        }
+    }
+
+    /// Converts an [`f64`] to a double-double.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - Value.
+    ///
+    /// # Returns
+    ///
+    /// `x` as a double-double (exact).
+    pub const fn from_f64(x: f64) -> DoubleF64 {
+        DoubleF64 { hi: x, lo: 0.0 }
+    }
+
+    /// Leading part of the double-double.
+    ///
+    /// # Returns
+    ///
+    /// Leading part, i.e. the value rounded to an [`f64`].
+    pub const fn hi(self) -> f64 {
+        self.hi
+    }
+
+    /// Trailing part of the double-double.
+    ///
+    /// # Returns
+    ///
+    /// Trailing part, i.e. the difference between the value and its leading part.
+    pub const fn lo(self) -> f64 {
+        self.lo
+    }
+
+    /// Converts the double-double to an [`f64`].
+    ///
+    /// # Returns
+    ///
+    /// Value rounded to an [`f64`] (i.e. its leading part).
+    pub const fn to_f64(self) -> f64 {
+        self.hi
+    }
+
+    /// Computes the absolute value of the double-double.
+    ///
+    /// # Returns
+    ///
+    /// $|x|$ (exact).
+    pub const fn abs(self) -> DoubleF64 {
+        if self.hi.is_sign_negative() {
+            DoubleF64 {
+                hi: -self.hi,
+                lo: -self.lo,
+            }
+        } else {
+            self
+        }
+    }
+
+    /// Computes the square root of the double-double.
+    ///
+    /// # Returns
+    ///
+    /// $\sqrt{x}$ (`NaN` for $x<0$).
+    pub const fn sqrt(self) -> DoubleF64 {
+        if self.hi == 0.0 || self.hi == f64::INFINITY {
+            self
+        } else if self.hi.is_nan() || self.hi < 0.0 {
+            DoubleF64::from_f64(f64::NAN)
+        } else {
+            DoubleF64::from_dd(self.dd().sqrt())
+        }
+    }
+
+    /// Converts the double-double to the internal double-double type.
+    ///
+    /// # Returns
+    ///
+    /// Double-double with the same value.
+    const fn dd(self) -> DoubleDouble {
+        DoubleDouble::new(self.hi, self.lo)
+    }
+
+    /// Converts from the internal double-double type.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - Double-double.
+    ///
+    /// # Returns
+    ///
+    /// Double-double with the same value (with a zero trailing part if the value is not finite).
+    const fn from_dd(x: DoubleDouble) -> DoubleF64 {
+        if x.hi.is_finite() {
+            DoubleF64 { hi: x.hi, lo: x.lo }
+        } else {
+            DoubleF64 { hi: x.hi, lo: 0.0 }
+        }
+    }
+
+    /// Determines whether the double-double is zero, infinite, or `NaN`.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the leading part is not a nonzero finite number.
+    fn is_special(self) -> bool {
+        self.hi == 0.0 || !self.hi.is_finite()
+    }
+
+    /// Evaluates an odd function, returning signed zeros (and `NaN`s) unchanged.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - Function of a double-double, evaluated unless this value is zero or `NaN`.
+    ///
+    /// # Returns
+    ///
+    /// $f(x)$.
+    fn odd(self, f: impl FnOnce(DoubleDouble) -> DoubleDouble) -> DoubleF64 {
+        if self.hi == 0.0 || self.hi.is_nan() {
+            self
+        } else {
+            DoubleF64::from_dd(f(self.dd()))
+        }
+    }
+
+    /// Computes the reciprocal of the double-double.
+    ///
+    /// # Returns
+    ///
+    /// $\frac{1}{x}$.
+    fn recip(self) -> DoubleF64 {
+        DoubleF64::from_f64(1.0) / self
+    }
+}
+
+impl From<f64> for DoubleF64 {
+    fn from(x: f64) -> DoubleF64 {
+        DoubleF64::from_f64(x)
+    }
+}
+
+impl Neg for DoubleF64 {
+    type Output = DoubleF64;
+    fn neg(self) -> DoubleF64 {
+        DoubleF64 {
+            hi: -self.hi,
+            lo: -self.lo,
+        }
+    }
+}
+
+impl Add for DoubleF64 {
+    type Output = DoubleF64;
+    fn add(self, other: DoubleF64) -> DoubleF64 {
+        let s = self.hi + other.hi;
+        if s == 0.0 && self.lo == 0.0 && other.lo == 0.0 || !s.is_finite() {
+            DoubleF64::from_f64(s)
+        } else {
+            DoubleF64::from_dd(self.dd().add(other.dd()))
+        }
+    }
+}
+
+impl Sub for DoubleF64 {
+    type Output = DoubleF64;
+    fn sub(self, other: DoubleF64) -> DoubleF64 {
+        self + -other
+    }
+}
+
+impl Mul for DoubleF64 {
+    type Output = DoubleF64;
+    fn mul(self, other: DoubleF64) -> DoubleF64 {
+        let p = self.hi * other.hi;
+        if p == 0.0 || !p.is_finite() {
+            DoubleF64::from_f64(p)
+        } else {
+            let (a, m) = frexp(self.dd());
+            let (b, n) = frexp(other.dd());
+            DoubleF64::from_dd(ldexp(a.mul(b), m + n))
+        }
+    }
+}
+
+impl Div for DoubleF64 {
+    type Output = DoubleF64;
+    fn div(self, other: DoubleF64) -> DoubleF64 {
+        let q = self.hi / other.hi;
+        if q == 0.0 || !q.is_finite() {
+            DoubleF64::from_f64(q)
+        } else {
+            let (a, m) = frexp(self.dd());
+            let (b, n) = frexp(other.dd());
+            DoubleF64::from_dd(ldexp(a.div(b), m - n))
+        }
+    }
+}
+
+/// Splits a nonzero finite double-double into a significand and a power of two.
+///
+/// # Arguments
+///
+/// * `x` - Nonzero finite value.
+///
+/// # Returns
+///
+/// `(m, e)` such that $x=m\cdot2^{e}$ (exactly) with $1\leq|m|<2$.
+///
+/// # Note
+///
+/// Multiplication and division operate on the significands, since the exact products used by
+/// double-double arithmetic overflow for operands above about $2^{996}$.
+fn frexp(x: DoubleDouble) -> (DoubleDouble, i32) {
+    let biased = ((x.hi.to_bits() >> 52) & 0x7ff) as i32;
+    if biased == 0 {
+        let (m, e) = frexp(x.scale(64));
+        (m, e - 64)
+    } else {
+        (ldexp(x, 1023 - biased), biased - 1023)
+    }
+}
+
+/// Multiplies a double-double by an arbitrary power of two.
+///
+/// # Arguments
+///
+/// * `x` - Value.
+/// * `n` - Exponent.
+///
+/// # Returns
+///
+/// $2^{n}x$ (exact barring overflow and underflow).
+fn ldexp(x: DoubleDouble, n: i32) -> DoubleDouble {
+    if n > 1000 {
+        ldexp(x.scale(1000), n - 1000)
+    } else if n < -1000 {
+        ldexp(x.scale(-1000), n + 1000)
+    } else {
+        x.scale(n)
+    }
+}
+
+/// Computes $\sqrt{x^{2}-1}$ without cancellation near $x=1$ and without overflow for large $x$.
+///
+/// # Arguments
+///
+/// * `x` - Value, $x\geq1$.
+///
+/// # Returns
+///
+/// $\sqrt{x^{2}-1}$.
+fn sqrt_x2_minus_1(x: DoubleDouble) -> DoubleDouble {
+    if x.hi < LARGE {
+        x.add_f64(-1.0).mul(x.add_f64(1.0)).sqrt()
+    } else {
+        x
+    }
+}
+
+/// Computes $\sqrt{1-x^{2}}$ without cancellation near $|x|=1$.
+///
+/// # Arguments
+///
+/// * `x` - Value, $|x|\leq1$.
+///
+/// # Returns
+///
+/// $\sqrt{1-x^{2}}$.
+fn sqrt_1_minus_x2(x: DoubleDouble) -> DoubleDouble {
+    x.neg().add_f64(1.0).mul(x.add_f64(1.0)).sqrt()
+}
+
+/// Computes the four-quadrant inverse tangent in a given unit.
+///
+/// # Arguments
+///
+/// * `y` - $y$-coordinate.
+/// * `x` - $x$-coordinate.
+/// * `unit` - Unit of the result.
+///
+/// # Returns
+///
+/// Angle of the point $(x,y)$ in `unit`, with the angles that are multiples of an eighth of a turn
+/// returned exactly in degrees and half-turns.
+fn atan2_unit(y: DoubleF64, x: DoubleF64, unit: Unit) -> DoubleF64 {
+    let half = unit.half_turn();
+    let signed = |a: DoubleDouble| {
+        if y.hi.is_sign_negative() {
+            DoubleF64::from_dd(a.neg())
+        } else {
+            DoubleF64::from_dd(a)
+        }
+    };
+    if y.hi.is_nan() || x.hi.is_nan() {
+        DoubleF64::from_f64(f64::NAN)
+    } else if y.hi == 0.0 {
+        if x.hi > 0.0 || (x.hi == 0.0 && x.hi.is_sign_positive()) {
+            y
+        } else {
+            signed(half)
+        }
+    } else if y.hi.is_infinite() {
+        match x.hi {
+            f64::INFINITY => signed(half.scale(-2)),
+            f64::NEG_INFINITY => signed(half.mul_f64(0.75)),
+            _ => signed(half.scale(-1)),
+        }
+    } else if x.hi == f64::INFINITY {
+        DoubleF64::from_f64(0.0_f64.copysign(y.hi))
+    } else if x.hi == f64::NEG_INFINITY {
+        signed(half)
+    } else if x.hi == 0.0 {
+        signed(half.scale(-1))
+    } else if y.abs() == x.abs() {
+        if x.hi > 0.0 {
+            signed(half.scale(-2))
+        } else {
+            signed(half.mul_f64(0.75))
+        }
+    } else if y.abs() < x.abs() {
+        let a = unit.rad2unit((y / x).dd().atan());
+        if x.hi > 0.0 {
+            DoubleF64::from_dd(a)
+        } else if y.hi > 0.0 {
+            DoubleF64::from_dd(a.add(half))
+        } else {
+            DoubleF64::from_dd(a.sub(half))
+        }
+    } else {
+        let a = unit.rad2unit((x / y).dd().atan());
+        if y.hi > 0.0 {
+            DoubleF64::from_dd(half.scale(-1).sub(a))
+        } else {
+            DoubleF64::from_dd(half.scale(-1).neg().sub(a))
+        }
+    }
+}
+
+/// Exactly reduces a nonnegative angle modulo a full turn.
+///
+/// # Arguments
+///
+/// * `x` - Finite angle, $x\geq0$.
+/// * `quarter_turn` - A quarter turn in the units of `x` (e.g. `90.0` for degrees).
+///
+/// # Returns
+///
+/// Quadrant `q` (in $\\{0,1,2,3\\}$) and remainder `r` (with $|r|\leq$ `quarter_turn / 2`) such
+/// that `x` is equal to `q * quarter_turn + r` modulo a full turn.
+fn reduce_turn(x: DoubleF64, quarter_turn: f64) -> (u8, DoubleDouble) {
+    // Both parts are reduced separately (which is exact), since the trailing part of a large angle
+    // can exceed a full turn.
+    let full_turn = 4.0 * quarter_turn;
+    let (s, e) = two_sum(x.hi % full_turn, x.lo % full_turn);
+    let n = double_double::round(s / quarter_turn);
+    let r = DoubleDouble::new(s, e).add_f64(-(n as f64) * quarter_turn);
+    (n.rem_euclid(4) as u8, r)
+}
+
+/// Computes the sine and cosine of a reduced angle in a given unit.
+///
+/// # Arguments
+///
+/// * `r` - Reduced angle, at most an eighth of a turn in magnitude.
+/// * `unit` - Unit of `r`.
+///
+/// # Returns
+///
+/// Sine and cosine of `r`, with the values at $\pm30$ and $\pm45$ degrees and $\pm\frac{1}{4}$
+/// half-turns returned as the correctly rounded double-doubles.
+fn sincos_reduced(r: DoubleDouble, unit: Unit) -> (DoubleDouble, DoubleDouble) {
+    let sign = |a: DoubleDouble| if r.hi < 0.0 { a.neg() } else { a };
+    match (unit, r.hi.abs(), r.lo) {
+        (Unit::Degrees, 30.0, 0.0) => (sign(DoubleDouble::from_f64(0.5)), SQRT_3_OVER_2),
+        (Unit::Degrees, 45.0, 0.0) | (Unit::HalfTurns, 0.25, 0.0) => {
+            (sign(FRAC_1_SQRT_2), FRAC_1_SQRT_2)
+        }
+        _ => unit.unit2rad(r).sin_cos(),
+    }
+}
+
+/// Computes the sine and cosine of an angle in a given unit.
+///
+/// # Arguments
+///
+/// * `x` - Angle in `unit`.
+/// * `unit` - Unit of `x`.
+///
+/// # Returns
+///
+/// $\sin(x)$ and $\cos(x)$, where exact zeros are returned with the sign of $x$ (sine) or as $+0$
+/// (cosine).
+fn sincos_unit(x: DoubleF64, unit: Unit) -> (DoubleF64, DoubleF64) {
+    if !x.hi.is_finite() {
+        let nan = DoubleF64::from_f64(f64::NAN);
+        return (nan, nan);
+    } else if x.hi == 0.0 {
+        return (x, DoubleF64::from_f64(1.0));
+    }
+    let (q, s, c) = match unit {
+        Unit::Radians => {
+            let (q, hi, lo) = rem_pio2_dd(x.hi, x.lo);
+            let (s, c) = DoubleDouble::new(hi, lo).sin_cos();
+            (q, s, c)
+        }
+        _ => {
+            let quarter_turn = unit.half_turn().hi / 2.0;
+            let (q, r) = reduce_turn(x.abs(), quarter_turn);
+            let (s, c) = sincos_reduced(r, unit);
+            if x.hi < 0.0 {
+                ((4 - q) % 4, s.neg(), c)
+            } else {
+                (q, s, c)
+            }
+        }
+    };
+    let (s, c) = match q {
+        0 => (s, c),
+        1 => (c, s.neg()),
+        2 => (s.neg(), c.neg()),
+        _ => (c.neg(), s),
+    };
+
+    // Exact zeros (at multiples of a half-turn in degrees and half-turns) take the sign of x for the
+    // sine and are positive for the cosine.
+    let s = if s.hi == 0.0 {
+        DoubleF64::from_f64(0.0_f64.copysign(x.hi))
+    } else {
+        DoubleF64::from_dd(s)
+    };
+    let c = if c.hi == 0.0 {
+        DoubleF64::from_f64(0.0)
+    } else {
+        DoubleF64::from_dd(c)
+    };
+    (s, c)
+}
+
+/// Computes the tangent of an angle in a given unit.
+///
+/// # Arguments
+///
+/// * `x` - Angle in `unit`.
+/// * `unit` - Unit of `x`.
+///
+/// # Returns
+///
+/// $\tan(x)$, which is infinite at the (exactly representable) poles.
+fn tan_unit(x: DoubleF64, unit: Unit) -> DoubleF64 {
+    let (s, c) = sincos_unit(x, unit);
+    if c.hi == 0.0 {
+        // An exact pole (only possible in degrees and half-turns), where the sine is ±1.
+        DoubleF64::from_f64(f64::INFINITY.copysign(s.hi))
+    } else {
+        s / c
+    }
+}
+
+/// Computes $e^{|x|}$ for a large argument.
+///
+/// # Arguments
+///
+/// * `a` - Value, $|x|\geq$ [`HYPERBOLIC_LARGE`].
+///
+/// # Returns
+///
+/// $\frac{e^{|x|}}{2}$ (infinite if it overflows).
+fn half_exp_large(a: DoubleDouble) -> DoubleF64 {
+    if a.hi > 711.0 {
+        return DoubleF64::from_f64(f64::INFINITY);
+    }
+
+    // e^|x| / 2 = e^(|x| / 2) · (e^(|x| / 2) / 2), where e^(|x| / 2) cannot overflow.
+    let h = DoubleF64::from_dd(a.scale(-1).exp_m1().add_f64(1.0));
+    h * DoubleF64::from_dd(h.dd().scale(-1))
+}
+
+/// Computes the hyperbolic sine and cosine.
+///
+/// # Arguments
+///
+/// * `x` - Value.
+///
+/// # Returns
+///
+/// $\sinh(x)$ and $\cosh(x)$.
+fn sinhcosh(x: DoubleF64) -> (DoubleF64, DoubleF64) {
+    if x.hi.is_nan() {
+        return (x, x);
+    } else if x.hi == 0.0 {
+        return (x, DoubleF64::from_f64(1.0));
+    }
+    let a = x.abs().dd();
+    let (s, c) = if a.hi >= HYPERBOLIC_LARGE {
+        let h = half_exp_large(a);
+        (h, h)
+    } else {
+        // With m = e^|x| - 1: sinh(|x|) = (m + m / (m + 1)) / 2 and cosh(|x|) = 1 + m² / (2(m + 1)),
+        // which keep the relative accuracy for small |x|.
+        let m = a.exp_m1();
+        let m1 = m.add_f64(1.0);
+        (
+            DoubleF64::from_dd(m.add(m.div(m1)).scale(-1)),
+            DoubleF64::from_dd(m.mul(m).div(m1).scale(-1).add_f64(1.0)),
+        )
+    };
+    if x.hi < 0.0 { (-s, c) } else { (s, c) }
+}
+
+/// Computes the hyperbolic tangent.
+///
+/// # Arguments
+///
+/// * `x` - Value.
+///
+/// # Returns
+///
+/// $\tanh(x)$.
+fn tanh(x: DoubleF64) -> DoubleF64 {
+    x.odd(|x| {
+        let a = if x.hi < 0.0 { x.neg() } else { x };
+        let t = if a.hi > 354.0 {
+            DoubleDouble::from_f64(1.0)
+        } else {
+            // With m = e^(-2|x|) - 1: tanh(|x|) = -m / (2 + m).
+            let m = a.scale(1).neg().exp_m1();
+            m.neg().div(m.add_f64(2.0))
+        };
+        if x.hi < 0.0 { t.neg() } else { t }
+    })
+}
+
+/// Computes $\ln(2x)$ for a large argument.
+///
+/// # Arguments
+///
+/// * `x` - Value, $x\geq$ [`LARGE`].
+///
+/// # Returns
+///
+/// $\ln(2x)$, which is the limit of $\text{arsinh}(x)$ and $\text{arcosh}(x)$ as $x\to\infty$.
+fn ln_2x(x: DoubleDouble) -> DoubleDouble {
+    if x.hi == f64::INFINITY {
+        x
+    } else {
+        x.ln().add(LN_2)
+    }
+}
+
+impl Trig for DoubleF64 {
+    fn sin(&self) -> DoubleF64 {
+        sincos_unit(*self, Unit::Radians).0
+    }
+    fn cos(&self) -> DoubleF64 {
+        sincos_unit(*self, Unit::Radians).1
+    }
+    fn tan(&self) -> DoubleF64 {
+        tan_unit(*self, Unit::Radians)
+    }
+    fn csc(&self) -> DoubleF64 {
+        self.sin().recip()
+    }
+    fn sec(&self) -> DoubleF64 {
+        self.cos().recip()
+    }
+    fn cot(&self) -> DoubleF64 {
+        self.tan().recip()
+    }
+    fn sincos(&self) -> (DoubleF64, DoubleF64) {
+        sincos_unit(*self, Unit::Radians)
+    }
+    fn asin(&self) -> DoubleF64 {
+        self.asin_unit(Unit::Radians)
+    }
+    fn acos(&self) -> DoubleF64 {
+        self.acos_unit(Unit::Radians)
+    }
+    fn atan(&self) -> DoubleF64 {
+        atan2_unit(*self, DoubleF64::from_f64(1.0), Unit::Radians)
+    }
+    fn atan2(&self, other: &DoubleF64) -> DoubleF64 {
+        atan2_unit(*self, *other, Unit::Radians)
+    }
+    fn acsc(&self) -> DoubleF64 {
+        self.acsc_unit(Unit::Radians)
+    }
+    fn asec(&self) -> DoubleF64 {
+        self.asec_unit(Unit::Radians)
+    }
+    fn acot(&self) -> DoubleF64 {
+        self.acot_unit(Unit::Radians)
+    }
+    fn deg2rad(&self) -> DoubleF64 {
+        *self * DoubleF64::from_dd(DEG2RAD)
+    }
+    fn rad2deg(&self) -> DoubleF64 {
+        *self * DoubleF64::from_dd(RAD2DEG)
+    }
+    fn sind(&self) -> DoubleF64 {
+        sincos_unit(*self, Unit::Degrees).0
+    }
+    fn cosd(&self) -> DoubleF64 {
+        sincos_unit(*self, Unit::Degrees).1
+    }
+    fn tand(&self) -> DoubleF64 {
+        tan_unit(*self, Unit::Degrees)
+    }
+    fn cscd(&self) -> DoubleF64 {
+        self.sind().recip()
+    }
+    fn secd(&self) -> DoubleF64 {
+        self.cosd().recip()
+    }
+    fn cotd(&self) -> DoubleF64 {
+        self.tand().recip()
+    }
+    fn sincosd(&self) -> (DoubleF64, DoubleF64) {
+        sincos_unit(*self, Unit::Degrees)
+    }
+    fn asind(&self) -> DoubleF64 {
+        self.asin_unit(Unit::Degrees)
+    }
+    fn acosd(&self) -> DoubleF64 {
+        self.acos_unit(Unit::Degrees)
+    }
+    fn atand(&self) -> DoubleF64 {
+        atan2_unit(*self, DoubleF64::from_f64(1.0), Unit::Degrees)
+    }
+    fn atan2d(&self, other: &DoubleF64) -> DoubleF64 {
+        atan2_unit(*self, *other, Unit::Degrees)
+    }
+    fn acscd(&self) -> DoubleF64 {
+        self.acsc_unit(Unit::Degrees)
+    }
+    fn asecd(&self) -> DoubleF64 {
+        self.asec_unit(Unit::Degrees)
+    }
+    fn acotd(&self) -> DoubleF64 {
+        self.acot_unit(Unit::Degrees)
+    }
+    fn sinpi(&self) -> DoubleF64 {
+        sincos_unit(*self, Unit::HalfTurns).0
+    }
+    fn cospi(&self) -> DoubleF64 {
+        sincos_unit(*self, Unit::HalfTurns).1
+    }
+    fn tanpi(&self) -> DoubleF64 {
+        tan_unit(*self, Unit::HalfTurns)
+    }
+    fn asinpi(&self) -> DoubleF64 {
+        self.asin_unit(Unit::HalfTurns)
+    }
+    fn acospi(&self) -> DoubleF64 {
+        self.acos_unit(Unit::HalfTurns)
+    }
+    fn atanpi(&self) -> DoubleF64 {
+        atan2_unit(*self, DoubleF64::from_f64(1.0), Unit::HalfTurns)
+    }
+    fn atan2pi(&self, other: &DoubleF64) -> DoubleF64 {
+        atan2_unit(*self, *other, Unit::HalfTurns)
+    }
+    fn sinh(&self) -> DoubleF64 {
+        sinhcosh(*self).0
+    }
+    fn cosh(&self) -> DoubleF64 {
+        sinhcosh(*self).1
+    }
+    fn tanh(&self) -> DoubleF64 {
+        tanh(*self)
+    }
+    fn csch(&self) -> DoubleF64 {
+        self.sinh().recip()
+    }
+    fn sech(&self) -> DoubleF64 {
+        self.cosh().recip()
+    }
+    fn coth(&self) -> DoubleF64 {
+        self.tanh().recip()
+    }
+    fn sinhcosh(&self) -> (DoubleF64, DoubleF64) {
+        sinhcosh(*self)
+    }
+    fn asinh(&self) -> DoubleF64 {
+        self.odd(|x| {
+            let a = if x.hi < 0.0 { x.neg() } else { x };
+            let f = if a.hi >= LARGE {
+                ln_2x(a)
+            } else {
+                // arsinh(|x|) = ln(1 + |x| + x² / (1 + sqrt(1 + x²))).
+                let a2 = a.mul(a);
+                a.add(a2.div(a2.add_f64(1.0).sqrt().add_f64(1.0))).ln_1p()
+            };
+            if x.hi < 0.0 { f.neg() } else { f }
+        })
+    }
+    fn acosh(&self) -> DoubleF64 {
+        let t = self.dd().add_f64(-1.0);
+        if self.hi.is_nan() || t.hi < 0.0 {
+            DoubleF64::from_f64(f64::NAN)
+        } else if self.hi >= LARGE {
+            DoubleF64::from_dd(ln_2x(self.dd()))
+        } else {
+            // arcosh(x) = ln(1 + t + sqrt(t(t + 2))) with t = x - 1.
+            DoubleF64::from_dd(t.add(t.mul(t.add_f64(2.0)).sqrt()).ln_1p())
+        }
+    }
+    fn atanh(&self) -> DoubleF64 {
+        let a = self.abs();
+        if a.hi > 1.0 || (a.hi == 1.0 && a.lo > 0.0) {
+            DoubleF64::from_f64(f64::NAN)
+        } else if a.hi == 1.0 && a.lo == 0.0 {
+            DoubleF64::from_f64(f64::INFINITY.copysign(self.hi))
+        } else {
+            // artanh(|x|) = ln(1 + 2|x| / (1 - |x|)) / 2.
+            self.odd(|x| {
+                let a = a.dd();
+                let f = a.scale(1).div(a.neg().add_f64(1.0)).ln_1p().scale(-1);
+                if x.hi < 0.0 { f.neg() } else { f }
+            })
+        }
+    }
+    fn acsch(&self) -> DoubleF64 {
+        if self.is_special() {
+            self.recip()
+        } else if self.hi.abs() * LARGE <= 1.0 {
+            // arcsch(x) = ln(2 / |x|) up to terms below double-double precision.
+            let f = LN_2.sub(self.abs().dd().ln());
+            DoubleF64::from_dd(if self.hi < 0.0 { f.neg() } else { f })
+        } else {
+            self.recip().asinh()
+        }
+    }
+    fn asech(&self) -> DoubleF64 {
+        let x = self.dd();
+        if self.hi.is_nan() || x.hi < 0.0 || x.hi > 1.0 || (x.hi == 1.0 && x.lo > 0.0) {
+            DoubleF64::from_f64(f64::NAN)
+        } else if x.hi == 0.0 {
+            DoubleF64::from_f64(f64::INFINITY)
+        } else if x.hi * LARGE <= 1.0 {
+            DoubleF64::from_dd(LN_2.sub(x.ln()))
+        } else {
+            // arsech(x) = ln(1 + (1 - x + sqrt(1 - x²)) / x).
+            let u = x.neg().add_f64(1.0).add(sqrt_1_minus_x2(x)).div(x);
+            DoubleF64::from_dd(u.ln_1p())
+        }
+    }
+    fn acoth(&self) -> DoubleF64 {
+        let a = self.abs();
+        if self.hi.is_nan() || a.hi < 1.0 || (a.hi == 1.0 && a.lo < 0.0) {
+            DoubleF64::from_f64(f64::NAN)
+        } else if a.hi == 1.0 && a.lo == 0.0 {
+            DoubleF64::from_f64(f64::INFINITY.copysign(self.hi))
+        } else if a.hi == f64::INFINITY {
+            DoubleF64::from_f64(0.0_f64.copysign(self.hi))
+        } else {
+            // arcoth(|x|) = ln(1 + 2 / (|x| - 1)) / 2.
+            let f = DoubleDouble::from_f64(2.0)
+                .div(a.dd().add_f64(-1.0))
+                .ln_1p()
+                .scale(-1);
+            DoubleF64::from_dd(if self.hi < 0.0 { f.neg() } else { f })
+        }
+    }
+}
+
+impl DoubleF64 {
+    /// Computes the inverse sine in a given unit.
+    ///
+    /// # Arguments
+    ///
+    /// * `unit` - Unit of the result.
+    ///
+    /// # Returns
+    ///
+    /// $\arcsin(x)$ in `unit` (`NaN` for $|x|>1$).
+    fn asin_unit(self, unit: Unit) -> DoubleF64 {
+        if self.abs() > DoubleF64::from_f64(1.0) {
+            return DoubleF64::from_f64(f64::NAN);
+        }
+        let c = DoubleF64::from_dd(sqrt_1_minus_x2(self.dd()));
+        atan2_unit(self, c, unit)
+    }
+
+    /// Computes the inverse cosine in a given unit.
+    ///
+    /// # Arguments
+    ///
+    /// * `unit` - Unit of the result.
+    ///
+    /// # Returns
+    ///
+    /// $\arccos(x)$ in `unit` (`NaN` for $|x|>1$).
+    fn acos_unit(self, unit: Unit) -> DoubleF64 {
+        if self.abs() > DoubleF64::from_f64(1.0) {
+            return DoubleF64::from_f64(f64::NAN);
+        }
+        let s = DoubleF64::from_dd(sqrt_1_minus_x2(self.dd()));
+        atan2_unit(s, self, unit)
+    }
+
+    /// Computes the inverse cosecant in a given unit.
+    ///
+    /// # Arguments
+    ///
+    /// * `unit` - Unit of the result.
+    ///
+    /// # Returns
+    ///
+    /// $\text{arccsc}(x)$ in `unit` (`NaN` for $|x|<1$).
+    fn acsc_unit(self, unit: Unit) -> DoubleF64 {
+        if self.hi.is_nan() || self.abs() < DoubleF64::from_f64(1.0) {
+            return DoubleF64::from_f64(f64::NAN);
+        }
+        let c = DoubleF64::from_dd(sqrt_x2_minus_1(self.abs().dd()));
+        atan2_unit(DoubleF64::from_f64(1.0_f64.copysign(self.hi)), c, unit)
+    }
+
+    /// Computes the inverse secant in a given unit.
+    ///
+    /// # Arguments
+    ///
+    /// * `unit` - Unit of the result.
+    ///
+    /// # Returns
+    ///
+    /// $\text{arcsec}(x)$ in `unit` (`NaN` for $|x|<1$).
+    fn asec_unit(self, unit: Unit) -> DoubleF64 {
+        if self.hi.is_nan() || self.abs() < DoubleF64::from_f64(1.0) {
+            return DoubleF64::from_f64(f64::NAN);
+        }
+        let s = DoubleF64::from_dd(sqrt_x2_minus_1(self.abs().dd()));
+        atan2_unit(s, DoubleF64::from_f64(1.0_f64.copysign(self.hi)), unit)
+    }
+
+    /// Computes the inverse cotangent in a given unit.
+    ///
+    /// # Arguments
+    ///
+    /// * `unit` - Unit of the result.
+    ///
+    /// # Returns
+    ///
+    /// $\text{arccot}(x)$ in `unit`, in $\left(-\frac{1}{4},\frac{1}{4}\right]$ turns.
+    fn acot_unit(self, unit: Unit) -> DoubleF64 {
+        atan2_unit(
+            DoubleF64::from_f64(1.0_f64.copysign(self.hi)),
+            self.abs(),
+            unit,
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Asserts that a double-double is within a relative error of $2^{-100}$ of a reference value.
+    ///
+    /// # Arguments
+    ///
+    /// * `actual` - Computed value.
+    /// * `expected` - Leading and trailing parts of the reference value (computed with 4000-bit
+    ///   arithmetic).
+    #[track_caller]
+    fn assert_close(actual: DoubleF64, expected: (f64, f64)) {
+        let expected = DoubleF64::new(expected.0, expected.1);
+        let error = ((actual - expected) / expected).to_f64().abs();
+        assert!(
+            error <= double_double::pow2(-100),
+            "{actual:?} != {expected:?} (relative error {error:e})"
+        );
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let third = DoubleF64::from_f64(1.0) / DoubleF64::from_f64(3.0);
+        assert_close(third, (0.3333333333333333, 1.850371707708594e-17));
+        assert_eq!(third * DoubleF64::from_f64(3.0), DoubleF64::from_f64(1.0));
+        assert_eq!(
+            DoubleF64::from_f64(1.0) + DoubleF64::from_f64(1e-20),
+            DoubleF64::new(1.0, 1e-20)
+        );
+        assert_eq!(
+            DoubleF64::new(1.0, 1e-20) - DoubleF64::from_f64(1.0),
+            DoubleF64::from(1e-20)
+        );
+        assert_eq!(DoubleF64::new(1.0, 1.0), DoubleF64::from_f64(2.0));
+        assert_eq!(-DoubleF64::new(1.0, 1e-20), DoubleF64::new(-1.0, -1e-20));
+        assert_eq!(
+            DoubleF64::new(-1.0, 1e-20).abs(),
+            DoubleF64::new(1.0, -1e-20)
+        );
+        assert_close(
+            DoubleF64::from_f64(2.0).sqrt(),
+            (core::f64::consts::SQRT_2, -9.667293313452913e-17),
+        );
+        assert!(DoubleF64::from_f64(-2.0).sqrt().to_f64().is_nan());
+
+        // Products and quotients beyond the range of the exact products.
+        let big = DoubleF64::from_f64(1e300);
+        assert_close(
+            big * DoubleF64::from_f64(1e-300),
+            (1.0, 7.756385209041318e-17),
+        );
+        assert_close(
+            DoubleF64::from_f64(1.0) / big.sqrt().sqrt(),
+            (1e-75, 2.9223796235235127e-92),
+        );
+        assert_eq!((big * big).to_f64(), f64::INFINITY);
+        assert_eq!((big / DoubleF64::from_f64(0.0)).to_f64(), f64::INFINITY);
+    }
+
+    #[test]
+    fn test_trig() {
+        let x = DoubleF64::new(0.5, 1e-20);
+        assert_close(x.sin(), (0.479425538604203, -5.095194034937109e-18));
+        assert_close(x.cos(), (0.8775825618903728, -4.262794411966604e-17));
+        assert_close(x.tan(), (0.5463024898437905, 2.910956068094127e-17));
+        assert_close(x.csc(), (2.085829642933488, 2.6305686430180268e-17));
+        assert_close(x.sec(), (1.139493927324549, 1.0598623129137807e-16));
+        assert_close(x.cot(), (1.830487721712452, -7.913258368625426e-17));
+        assert_eq!(x.sincos(), (x.sin(), x.cos()));
+
+        // sin(π) is the (small) error of π in f64, which must not be lost to cancellation.
+        assert_close(
+            DoubleF64::from_f64(core::f64::consts::PI).sin(),
+            (1.2246467991473532e-16, -2.99476980971834e-33),
+        );
+        assert!(DoubleF64::PI.sin().to_f64().abs() < 1e-31);
+
+        // Large arguments, reduced against 384 bits of 2/π.
+        assert_close(
+            DoubleF64::from_f64(1e22).sin(),
+            (-0.8522008497671888, -6.7806825896773284e-18),
+        );
+        assert_close(
+            DoubleF64::from_f64(1e22).cos(),
+            (0.523214785395139, -4.7143201076575164e-17),
+        );
+        assert_close(
+            DoubleF64::new(1e300, 1e280).sin(),
+            (0.9899054828892435, 2.2298871541800942e-18),
+        );
+    }
+
+    #[test]
+    fn test_inverse_trig() {
+        let x = DoubleF64::new(0.5, 1e-20);
+        assert_close(
+            x.asin(),
+            (core::f64::consts::FRAC_PI_6, -5.359254131717076e-17),
+        );
+        assert_close(
+            x.acos(),
+            (core::f64::consts::FRAC_PI_3, -1.072197236504929e-16),
+        );
+        assert_close(x.atan(), (0.4636476090008061, 2.2706777452961686e-17));
+        let x = DoubleF64::from_f64(3.0);
+        assert_close(x.acsc(), (0.3398369094541219, 1.001975061755438e-17));
+        assert_close(x.asec(), (1.2309594173407747, -5.980971312270238e-17));
+        assert_close(x.acot(), (0.3217505543966422, 7.917392525722143e-18));
+        let y = DoubleF64::from_f64(-3.0);
+        let x = DoubleF64::new(0.7, 1e-19);
+        assert_close(y.atan2(&x), (-1.3415643935179014, 5.222462069783735e-17));
+        assert_close(
+            DoubleF64::from_f64(1e-5).atan2(&y),
+            (3.1415893202564598, 1.5664743299584605e-16),
+        );
+        assert_eq!(
+            DoubleF64::from_f64(1.0).asin(),
+            DoubleF64::PI / DoubleF64::from(2.0)
+        );
+        assert_eq!(DoubleF64::from_f64(-1.0).acos(), DoubleF64::PI);
+        assert!(DoubleF64::from_f64(1.5).asin().to_f64().is_nan());
+        assert!(DoubleF64::from_f64(0.5).asec().to_f64().is_nan());
+    }
+
+    #[test]
+    fn test_degrees() {
+        let x = DoubleF64::new(0.5, 1e-20);
+        assert_close(x.deg2rad(), (0.008726646259971648, 1.4760714646870786e-19));
+        assert_close(x.rad2deg(), (28.64788975654116, -9.933518257336834e-16));
+        assert_close(x.acosd(), (60.0, -6.615946745061504e-19));
+        let x = DoubleF64::new(100.0, 3e-15);
+        assert_close(x.sind(), (0.984807753012208, 2.995889143686492e-17));
+        assert_close(x.cosd(), (-0.1736481776669304, -1.3718344401217859e-17));
+        assert_close(x.tand(), (-5.6712818196177075, -2.964785108639382e-16));
+        assert_close(x.cscd(), (1.015426611885745, 9.377740615541049e-17));
+        assert_close(x.secd(), (-5.758770483143632, 1.0910009074455577e-16));
+        assert_close(x.cotd(), (-0.17632698070846503, 3.4525696966357586e-18));
+        assert_eq!(x.sincosd(), (x.sind(), x.cosd()));
+        let x = DoubleF64::from_f64(3.0);
+        assert_close(x.acscd(), (19.47122063449069, -3.6732735406980473e-16));
+        assert_close(x.asecd(), (70.52877936550931, 3.6732735406980473e-16));
+        assert_close(x.acotd(), (18.43494882292201, 6.673432494950659e-16));
+        assert_close(
+            DoubleF64::new(-2.0, 1e-17).atand(),
+            (-63.43494882292201, -5.527516904689012e-16),
+        );
+        assert_close(
+            DoubleF64::from_f64(-3.0).atan2d(&DoubleF64::new(0.7, 1e-19)),
+            (-76.86597769360368, 3.8442999342670865e-15),
+        );
+
+        // Exact values.
+        assert_eq!(DoubleF64::from_f64(30.0).sind(), DoubleF64::from_f64(0.5));
+        assert_eq!(
+            DoubleF64::from_f64(-750.0).sind(),
+            DoubleF64::from_f64(-0.5)
+        );
+        assert_eq!(DoubleF64::from_f64(60.0).cosd(), DoubleF64::from_f64(0.5));
+        assert_eq!(DoubleF64::from_f64(135.0).tand(), DoubleF64::from_f64(-1.0));
+        assert_close(DoubleF64::from_f64(0.5).asind(), (30.0, 0.0));
+        assert_eq!(
+            DoubleF64::from_f64(-1.0).atand(),
+            DoubleF64::from_f64(-45.0)
+        );
+        assert_eq!(
+            DoubleF64::from_f64(-1.0).acosd(),
+            DoubleF64::from_f64(180.0)
+        );
+        assert_eq!(DoubleF64::from_f64(90.0).tand().to_f64(), f64::INFINITY);
+        assert_eq!(
+            DoubleF64::from_f64(-90.0).tand().to_f64(),
+            f64::NEG_INFINITY
+        );
+        assert_eq!(DoubleF64::from_f64(90.0).cosd().to_f64().to_bits(), 0);
+        assert_eq!(
+            DoubleF64::from_f64(-180.0).sind().to_f64().to_bits(),
+            (-0.0_f64).to_bits()
+        );
+
+        // A trailing part larger than a full turn.
+        let y = DoubleF64::from_f64(1e300 % 360.0) + DoubleF64::from_f64(1e280 % 360.0);
+        let expected = y.sind();
+        assert_close(
+            DoubleF64::new(1e300, 1e280).sind(),
+            (expected.hi(), expected.lo()),
+        );
+    }
+
+    #[test]
+    fn test_half_turns() {
+        let x = DoubleF64::new(-0.6666666666666666, -3.367410082083855e-17);
+        assert_close(x.sinpi(), (-0.8660254037844386, -5.5411408865017506e-17));
+        assert_close(x.cospi(), (-0.5, 9.068996821171097e-18));
+        assert_close(x.tanpi(), (1.7320508075688774, -7.980586065909833e-17));
+        assert_close(
+            DoubleF64::new(0.5, 1e-20).asinpi(),
+            (0.16666666666666666, 9.25553406451245e-18),
+        );
+        assert_close(
+            DoubleF64::new(-2.0, 1e-17).atanpi(),
+            (-0.35241638234956674, 1.1732130936841525e-17),
+        );
+        assert_close(
+            DoubleF64::from_f64(-3.0).atan2pi(&DoubleF64::new(0.7, 1e-19)),
+            (-0.4270332094089093, -3.3144009124085526e-18),
+        );
+
+        // Exact values.
+        assert_eq!(
+            DoubleF64::from_f64(0.25).sinpi(),
+            DoubleF64::from_f64(0.25).cospi()
+        );
+        assert_eq!(DoubleF64::from_f64(-0.75).tanpi(), DoubleF64::from_f64(1.0));
+        assert_eq!(DoubleF64::from_f64(1.0).cospi(), DoubleF64::from_f64(-1.0));
+        assert_eq!(DoubleF64::from_f64(1.0).acospi(), DoubleF64::from_f64(0.0));
+        assert_eq!(
+            DoubleF64::from_f64(-1.0).asinpi(),
+            DoubleF64::from_f64(-0.5)
+        );
+        assert_eq!(DoubleF64::from_f64(0.5).tanpi().to_f64(), f64::INFINITY);
+        assert_eq!(DoubleF64::from_f64(1.5).tanpi().to_f64(), f64::NEG_INFINITY);
+        assert_eq!(DoubleF64::from_f64(2.0).sinpi().to_f64().to_bits(), 0);
+    }
+
+    #[test]
+    fn test_hyperbolic() {
+        let x = DoubleF64::new(0.5, 1e-20);
+        assert_close(x.sinh(), (0.5210953054937474, -2.3316907216752532e-17));
+        assert_close(x.cosh(), (1.1276259652063807, 8.704001209761686e-17));
+        assert_close(x.tanh(), (0.46211715726000974, 2.192446771559059e-17));
+        assert_close(x.csch(), (1.9190347513349437, -3.0609630919955375e-18));
+        assert_close(x.sech(), (0.8868188839700739, -3.682484472149214e-18));
+        assert_close(x.coth(), (2.163953413738653, -6.01710371339976e-17));
+        assert_eq!(x.sinhcosh(), (x.sinh(), x.cosh()));
+        assert_close(
+            DoubleF64::from_f64(10.0).sinh(),
+            (11013.232874703393, -8.604166474664442e-14),
+        );
+        assert_close(
+            DoubleF64::from_f64(700.0).cosh(),
+            (5.0711602736750225e303, 8.333285960367336e286),
+        );
+        assert_close(
+            DoubleF64::from_f64(1e-10).sinh(),
+            (1e-10, 1.6666666666666669e-31),
+        );
+        assert_eq!(
+            DoubleF64::from_f64(-1000.0).sinh().to_f64(),
+            f64::NEG_INFINITY
+        );
+        assert_eq!(
+            DoubleF64::from_f64(-1000.0).tanh(),
+            DoubleF64::from_f64(-1.0)
+        );
+    }
+
+    #[test]
+    fn test_inverse_hyperbolic() {
+        let x = DoubleF64::new(0.5, 1e-20);
+        assert_close(x.asinh(), (0.48121182505960347, -2.3248872741552736e-17));
+        assert_close(x.atanh(), (0.5493061443340549, -4.534315284167432e-17));
+        assert_close(x.acsch(), (1.4436354751788103, 4.123096287830745e-17));
+        assert_close(x.asech(), (1.3169578969248168, -8.68456024592878e-17));
+        let x = DoubleF64::from_f64(3.0);
+        assert_close(x.acosh(), (1.762747174039086, -4.501091785651732e-17));
+        assert_close(x.acoth(), (0.34657359027997264, 1.1595234069231498e-17));
+        assert_close(
+            DoubleF64::from_f64(1e200).acosh(),
+            (461.2101657793691, -2.3993297946353776e-14),
+        );
+        assert_close(
+            DoubleF64::from_f64(-1e200).asinh(),
+            (-461.2101657793691, 2.3993297946353776e-14),
+        );
+        assert_eq!(DoubleF64::from_f64(1.0).acosh(), DoubleF64::from_f64(0.0));
+        assert_eq!(
+            DoubleF64::from_f64(-1.0).atanh().to_f64(),
+            f64::NEG_INFINITY
+        );
+        assert_eq!(DoubleF64::from_f64(1.0).acoth().to_f64(), f64::INFINITY);
+        assert!(DoubleF64::from_f64(0.5).acosh().to_f64().is_nan());
+        assert!(DoubleF64::from_f64(2.0).atanh().to_f64().is_nan());
+        assert!(DoubleF64::from_f64(0.5).acoth().to_f64().is_nan());
+        assert!(DoubleF64::from_f64(1.5).asech().to_f64().is_nan());
+    }
+
+    #[test]
+    fn test_special_values() {
+        let nan = DoubleF64::from_f64(f64::NAN);
+        let inf = DoubleF64::from_f64(f64::INFINITY);
+        let zero = DoubleF64::from_f64(0.0);
+        let neg_zero = DoubleF64::from_f64(-0.0);
+        for x in [nan, inf, -inf] {
+            assert!(x.sin().to_f64().is_nan());
+            assert!(x.cosd().to_f64().is_nan());
+            assert!(x.tanpi().to_f64().is_nan());
+        }
+        assert!(nan.atan2(&zero).to_f64().is_nan());
+        assert!(nan.sinh().to_f64().is_nan());
+        assert!(nan.acosh().to_f64().is_nan());
+        for x in [zero, neg_zero] {
+            for f in [
+                DoubleF64::sin,
+                DoubleF64::tan,
+                DoubleF64::asin,
+                DoubleF64::atan,
+                DoubleF64::sind,
+                DoubleF64::sinpi,
+                DoubleF64::sinh,
+                DoubleF64::tanh,
+                DoubleF64::asinh,
+                DoubleF64::atanh,
+            ] {
+                assert_eq!(f(&x).to_f64().to_bits(), x.to_f64().to_bits());
+            }
+            assert_eq!(x.cos(), DoubleF64::from_f64(1.0));
+            assert_eq!(x.cosh(), DoubleF64::from_f64(1.0));
+            assert_eq!(x.csc().to_f64(), 1.0 / x.to_f64());
+            assert_eq!(x.coth().to_f64(), 1.0 / x.to_f64());
+        }
+        assert_eq!(inf.atan(), DoubleF64::PI / DoubleF64::from(2.0));
+        assert_eq!((-inf).atand(), DoubleF64::from_f64(-90.0));
+        assert_eq!(inf.atan2d(&-inf), DoubleF64::from_f64(135.0));
+        assert_eq!(zero.atan2pi(&neg_zero), DoubleF64::from_f64(1.0));
+        assert_eq!(inf.tanh(), DoubleF64::from_f64(1.0));
+        assert_eq!(inf.asinh(), inf);
+        assert_eq!(inf.acosh(), inf);
+        assert_eq!(zero.asech(), inf);
+        assert_eq!(inf.acoth().to_f64().to_bits(), 0);
+    }
+}
@@ -0,0 +1,499 @@
+use crate::real::{Real, deg2rad, one_minus_square, pi, rad2deg, square_minus_one};
+use crate::trig_trait::Trig;
+use core::ops::{Add, Div, Mul, Neg, Sub};
+
+/// Dual number for forward-mode automatic differentiation.
+///
+/// A dual number $a+b\varepsilon$ (with $\varepsilon^2=0$) carries a value $a$ together with its
+/// derivative $b$ with respect to some input. Arithmetic on dual numbers follows the sum, product,
+/// and quotient rules, and every [`Trig`] method applies the chain rule with the closed-form
+/// derivative of the function, e.g.
+///
+/// * $\frac{d}{dx}\text{acoth}(x)=\frac{1}{1-x^2}$,
+/// * $\frac{d}{dx}\text{secd}(x)=\frac{\pi}{180}\text{secd}(x)\text{tand}(x)$, and
+/// * $\frac{\partial}{\partial y}\text{atan2d}(y,x)=\frac{180}{\pi}\frac{x}{x^2+y^2}$ and
+///   $\frac{\partial}{\partial x}\text{atan2d}(y,x)=-\frac{180}{\pi}\frac{y}{x^2+y^2}$,
+///
+/// so that the derivative of any expression built from the [`Trig`] methods and the arithmetic
+/// operators is exact up to rounding (no finite differencing).
+///
+/// # Note
+///
+/// The derivatives of the degree and half-turn methods include the chain factors $\frac{\pi}{180}$
+/// and $\pi$ (or their inverses for the inverse functions), computed in the precision of `T`. At a
+/// point where a function is not differentiable (e.g. `asin` at $\pm1$ or `acosh` at $1$), the
+/// derivative is infinite or `NaN`.
+///
+/// # Example
+///
+/// ```
+/// use trig::{Dual, Trig};
+///
+/// // d/dx acoth(x) = 1 / (1 - x²).
+/// let y = Dual::variable(3.0_f64).acoth();
+/// assert!((y.derivative - 1.0 / (1.0 - 9.0)).abs() < 1e-15);
+///
+/// // The gradient of atan2d(y, x) at (1, 1) is (180/π)(1/2, -1/2).
+/// let (y, x) = (Dual::variable(1.0), Dual::constant(1.0));
+/// assert!((y.atan2d(&x).derivative - 90.0 / std::f64::consts::PI).abs() < 1e-13);
+/// let (y, x) = (Dual::constant(1.0), Dual::variable(1.0));
+/// assert!((y.atan2d(&x).derivative + 90.0 / std::f64::consts::PI).abs() < 1e-13);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+pub struct Dual<T> {
+    /// Value.
+    pub value: T,
+
+    /// Derivative.
+    pub derivative: T,
+}
+
+impl<T: Real> Dual<T> {
+    /// Constructs a dual number.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Value.
+    /// * `derivative` - Derivative.
+    ///
+    /// # Returns
+    ///
+    /// Dual number $\text{value}+\text{derivative}\cdot\varepsilon$.
+    pub fn new(value: T, derivative: T) -> Dual<T> {
+        Dual { value, derivative }
+    }
+
+    /// Constructs the independent variable of a differentiation.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Value.
+    ///
+    /// # Returns
+    ///
+    /// Dual number with a derivative of $1$.
+    pub fn variable(value: T) -> Dual<T> {
+        Dual::new(value, T::from_f64(1.0))
+    }
+
+    /// Constructs a constant.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Value.
+    ///
+    /// # Returns
+    ///
+    /// Dual number with a derivative of $0$.
+    pub fn constant(value: T) -> Dual<T> {
+        Dual::new(value, T::from_f64(0.0))
+    }
+
+    /// Applies the chain rule.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - $f(x)$.
+    /// * `derivative` - $f'(x)$.
+    ///
+    /// # Returns
+    ///
+    /// $f(x)+f'(x)x'\varepsilon$.
+    fn chain(&self, value: T, derivative: T) -> Dual<T> {
+        Dual::new(value, derivative * self.derivative)
+    }
+
+    /// Derivative of a two-argument inverse tangent.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - $x$-coordinate (with this number holding the $y$-coordinate).
+    /// * `value` - $\text{atan2}(y,x)$ in some unit.
+    /// * `scale` - A radian in that unit.
+    ///
+    /// # Returns
+    ///
+    /// $\text{atan2}(y,x)+s\frac{xy'-yx'}{x^2+y^2}\varepsilon$.
+    fn atan2_chain(&self, other: &Dual<T>, value: T, scale: T) -> Dual<T> {
+        let (y, x) = (self.value, other.value);
+        let numerator = x * self.derivative - y * other.derivative;
+        Dual::new(value, scale * numerator / (x * x + y * y))
+    }
+}
+
+impl<T: Real> From<T> for Dual<T> {
+    fn from(value: T) -> Dual<T> {
+        Dual::constant(value)
+    }
+}
+
+impl<T: Real> Neg for Dual<T> {
+    type Output = Dual<T>;
+    fn neg(self) -> Dual<T> {
+        Dual::new(-self.value, -self.derivative)
+    }
+}
+
+impl<T: Real> Add for Dual<T> {
+    type Output = Dual<T>;
+    fn add(self, other: Dual<T>) -> Dual<T> {
+        Dual::new(self.value + other.value, self.derivative + other.derivative)
+    }
+}
+
+impl<T: Real> Sub for Dual<T> {
+    type Output = Dual<T>;
+    fn sub(self, other: Dual<T>) -> Dual<T> {
+        Dual::new(self.value - other.value, self.derivative - other.derivative)
+    }
+}
+
+impl<T: Real> Mul for Dual<T> {
+    type Output = Dual<T>;
+    fn mul(self, other: Dual<T>) -> Dual<T> {
+        Dual::new(
+            self.value * other.value,
+            self.derivative * other.value + self.value * other.derivative,
+        )
+    }
+}
+
+impl<T: Real> Div for Dual<T> {
+    type Output = Dual<T>;
+    fn div(self, other: Dual<T>) -> Dual<T> {
+        let value = self.value / other.value;
+        Dual::new(
+            value,
+            (self.derivative - value * other.derivative) / other.value,
+        )
+    }
+}
+
+impl<T: Real> Real for Dual<T> {
+    fn from_f64(x: f64) -> Dual<T> {
+        Dual::constant(T::from_f64(x))
+    }
+    fn sqrt(&self) -> Dual<T> {
+        let f = self.value.sqrt();
+        self.chain(f, T::from_f64(0.5) / f)
+    }
+    fn abs(&self) -> Dual<T> {
+        if self.value < T::from_f64(0.0) {
+            -*self
+        } else {
+            *self
+        }
+    }
+}
+
+impl<T: Real> Trig for Dual<T> {
+    fn sin(&self) -> Dual<T> {
+        self.chain(self.value.sin(), self.value.cos())
+    }
+    fn cos(&self) -> Dual<T> {
+        self.chain(self.value.cos(), -self.value.sin())
+    }
+    fn tan(&self) -> Dual<T> {
+        let f = self.value.tan();
+        self.chain(f, T::from_f64(1.0) + f * f)
+    }
+    fn csc(&self) -> Dual<T> {
+        let f = self.value.csc();
+        self.chain(f, -f * self.value.cot())
+    }
+    fn sec(&self) -> Dual<T> {
+        let f = self.value.sec();
+        self.chain(f, f * self.value.tan())
+    }
+    fn cot(&self) -> Dual<T> {
+        let f = self.value.cot();
+        self.chain(f, -(T::from_f64(1.0) + f * f))
+    }
+    fn sincos(&self) -> (Dual<T>, Dual<T>) {
+        let (s, c) = self.value.sincos();
+        (self.chain(s, c), self.chain(c, -s))
+    }
+    fn asin(&self) -> Dual<T> {
+        let x = self.value;
+        self.chain(x.asin(), T::from_f64(1.0) / one_minus_square(x).sqrt())
+    }
+    fn acos(&self) -> Dual<T> {
+        let x = self.value;
+        self.chain(x.acos(), -T::from_f64(1.0) / one_minus_square(x).sqrt())
+    }
+    fn atan(&self) -> Dual<T> {
+        let x = self.value;
+        self.chain(x.atan(), T::from_f64(1.0) / (T::from_f64(1.0) + x * x))
+    }
+    fn atan2(&self, other: &Dual<T>) -> Dual<T> {
+        let f = self.value.atan2(&other.value);
+        self.atan2_chain(other, f, T::from_f64(1.0))
+    }
+    fn acsc(&self) -> Dual<T> {
+        let x = self.value;
+        let d = -T::from_f64(1.0) / (x.abs() * square_minus_one(x).sqrt());
+        self.chain(x.acsc(), d)
+    }
+    fn asec(&self) -> Dual<T> {
+        let x = self.value;
+        let d = T::from_f64(1.0) / (x.abs() * square_minus_one(x).sqrt());
+        self.chain(x.asec(), d)
+    }
+    fn acot(&self) -> Dual<T> {
+        let x = self.value;
+        self.chain(x.acot(), -T::from_f64(1.0) / (T::from_f64(1.0) + x * x))
+    }
+    fn deg2rad(&self) -> Dual<T> {
+        self.chain(self.value.deg2rad(), deg2rad())
+    }
+    fn rad2deg(&self) -> Dual<T> {
+        self.chain(self.value.rad2deg(), rad2deg())
+    }
+    fn sind(&self) -> Dual<T> {
+        self.chain(self.value.sind(), deg2rad::<T>() * self.value.cosd())
+    }
+    fn cosd(&self) -> Dual<T> {
+        self.chain(self.value.cosd(), -deg2rad::<T>() * self.value.sind())
+    }
+    fn tand(&self) -> Dual<T> {
+        let f = self.value.tand();
+        self.chain(f, deg2rad::<T>() * (T::from_f64(1.0) + f * f))
+    }
+    fn cscd(&self) -> Dual<T> {
+        let f = self.value.cscd();
+        self.chain(f, -deg2rad::<T>() * f * self.value.cotd())
+    }
+    fn secd(&self) -> Dual<T> {
+        let f = self.value.secd();
+        self.chain(f, deg2rad::<T>() * f * self.value.tand())
+    }
+    fn cotd(&self) -> Dual<T> {
+        let f = self.value.cotd();
+        self.chain(f, -deg2rad::<T>() * (T::from_f64(1.0) + f * f))
+    }
+    fn sincosd(&self) -> (Dual<T>, Dual<T>) {
+        let (s, c) = self.value.sincosd();
+        let k = deg2rad::<T>();
+        (self.chain(s, k * c), self.chain(c, -k * s))
+    }
+    fn asind(&self) -> Dual<T> {
+        let x = self.value;
+        self.chain(x.asind(), rad2deg::<T>() / one_minus_square(x).sqrt())
+    }
+    fn acosd(&self) -> Dual<T> {
+        let x = self.value;
+        self.chain(x.acosd(), -rad2deg::<T>() / one_minus_square(x).sqrt())
+    }
+    fn atand(&self) -> Dual<T> {
+        let x = self.value;
+        self.chain(x.atand(), rad2deg::<T>() / (T::from_f64(1.0) + x * x))
+    }
+    fn atan2d(&self, other: &Dual<T>) -> Dual<T> {
+        let f = self.value.atan2d(&other.value);
+        self.atan2_chain(other, f, rad2deg())
+    }
+    fn acscd(&self) -> Dual<T> {
+        let x = self.value;
+        let d = -rad2deg::<T>() / (x.abs() * square_minus_one(x).sqrt());
+        self.chain(x.acscd(), d)
+    }
+    fn asecd(&self) -> Dual<T> {
+        let x = self.value;
+        let d = rad2deg::<T>() / (x.abs() * square_minus_one(x).sqrt());
+        self.chain(x.asecd(), d)
+    }
+    fn acotd(&self) -> Dual<T> {
+        let x = self.value;
+        self.chain(x.acotd(), -rad2deg::<T>() / (T::from_f64(1.0) + x * x))
+    }
+    fn sinpi(&self) -> Dual<T> {
+        self.chain(self.value.sinpi(), pi::<T>() * self.value.cospi())
+    }
+    fn cospi(&self) -> Dual<T> {
+        self.chain(self.value.cospi(), -pi::<T>() * self.value.sinpi())
+    }
+    fn tanpi(&self) -> Dual<T> {
+        let f = self.value.tanpi();
+        self.chain(f, pi::<T>() * (T::from_f64(1.0) + f * f))
+    }
+    fn asinpi(&self) -> Dual<T> {
+        let x = self.value;
+        let d = T::from_f64(1.0) / (pi::<T>() * one_minus_square(x).sqrt());
+        self.chain(x.asinpi(), d)
+    }
+    fn acospi(&self) -> Dual<T> {
+        let x = self.value;
+        let d = -T::from_f64(1.0) / (pi::<T>() * one_minus_square(x).sqrt());
+        self.chain(x.acospi(), d)
+    }
+    fn atanpi(&self) -> Dual<T> {
+        let x = self.value;
+        let d = T::from_f64(1.0) / (pi::<T>() * (T::from_f64(1.0) + x * x));
+        self.chain(x.atanpi(), d)
+    }
+    fn atan2pi(&self, other: &Dual<T>) -> Dual<T> {
+        let f = self.value.atan2pi(&other.value);
+        self.atan2_chain(other, f, T::from_f64(1.0) / pi::<T>())
+    }
+    fn sinh(&self) -> Dual<T> {
+        self.chain(self.value.sinh(), self.value.cosh())
+    }
+    fn cosh(&self) -> Dual<T> {
+        self.chain(self.value.cosh(), self.value.sinh())
+    }
+    fn tanh(&self) -> Dual<T> {
+        let s = self.value.sech();
+        self.chain(self.value.tanh(), s * s)
+    }
+    fn csch(&self) -> Dual<T> {
+        let f = self.value.csch();
+        self.chain(f, -f * self.value.coth())
+    }
+    fn sech(&self) -> Dual<T> {
+        let f = self.value.sech();
+        self.chain(f, -f * self.value.tanh())
+    }
+    fn coth(&self) -> Dual<T> {
+        let s = self.value.csch();
+        self.chain(self.value.coth(), -s * s)
+    }
+    fn sinhcosh(&self) -> (Dual<T>, Dual<T>) {
+        let (s, c) = self.value.sinhcosh();
+        (self.chain(s, c), self.chain(c, s))
+    }
+    fn asinh(&self) -> Dual<T> {
+        let x = self.value;
+        self.chain(
+            x.asinh(),
+            T::from_f64(1.0) / (x * x + T::from_f64(1.0)).sqrt(),
+        )
+    }
+    fn acosh(&self) -> Dual<T> {
+        let x = self.value;
+        self.chain(x.acosh(), T::from_f64(1.0) / square_minus_one(x).sqrt())
+    }
+    fn atanh(&self) -> Dual<T> {
+        let x = self.value;
+        self.chain(x.atanh(), T::from_f64(1.0) / one_minus_square(x))
+    }
+    fn acsch(&self) -> Dual<T> {
+        let x = self.value;
+        let d = -T::from_f64(1.0) / (x.abs() * (T::from_f64(1.0) + x * x).sqrt());
+        self.chain(x.acsch(), d)
+    }
+    fn asech(&self) -> Dual<T> {
+        let x = self.value;
+        let d = -T::from_f64(1.0) / (x * one_minus_square(x).sqrt());
+        self.chain(x.asech(), d)
+    }
+    fn acoth(&self) -> Dual<T> {
+        let x = self.value;
+        self.chain(x.acoth(), T::from_f64(1.0) / one_minus_square(x))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::DoubleF64;
+
+    /// Asserts that the derivatives of unary [`Trig`] methods match central finite differences.
+    macro_rules! check_unary {
+        ($($name:ident),* $(,)?) => {
+            $(
+                for x in [0.3, -0.45, 1.7, -2.5, 30.0, 100.0] {
+                    let f = |x: f64| Trig::$name(&x);
+                    let h = 1e-6 * f64::max(1.0, x.abs());
+                    let (lo, hi) = (f(x - h), f(x + h));
+                    if !(lo.is_finite() && hi.is_finite() && f(x).is_finite()) {
+                        continue;
+                    }
+                    let expected = (hi - lo) / (2.0 * h);
+                    let y = Trig::$name(&Dual::variable(x));
+                    assert_eq!(y.value.to_bits(), f(x).to_bits(), "{}({x})", stringify!($name));
+                    assert!(
+                        (y.derivative - expected).abs() <= 1e-5 * f64::max(1.0, expected.abs()),
+                        "{}'({x}) = {}, expected {expected}",
+                        stringify!($name),
+                        y.derivative
+                    );
+                }
+            )*
+        };
+    }
+
+    #[test]
+    fn test_derivatives() {
+        check_unary!(
+            sin, cos, tan, csc, sec, cot, asin, acos, atan, acsc, asec, acot, deg2rad, rad2deg,
+            sind, cosd, tand, cscd, secd, cotd, asind, acosd, atand, acscd, asecd, acotd, sinpi,
+            cospi, tanpi, asinpi, acospi, atanpi, sinh, cosh, tanh, csch, sech, coth, asinh, acosh,
+            atanh, acsch, asech, acoth,
+        );
+    }
+
+    #[test]
+    fn test_closed_forms() {
+        let x = 3.0;
+        assert!((Dual::variable(x).acoth().derivative - 1.0 / (1.0 - x * x)).abs() < 1e-15);
+        let x = 40.0;
+        let expected = 1.0.deg2rad() * x.secd() * x.tand();
+        assert!((Dual::variable(x).secd().derivative - expected).abs() < 1e-15);
+        let x = 0.5;
+        let expected = -1.0 / (x * (1.0 - x * x).sqrt());
+        assert!((Dual::variable(x).asech().derivative - expected).abs() < 1e-14);
+        let (s, c) = Dual::variable(0.7).sincos();
+        assert_eq!((s.derivative, c.derivative), (0.7.cos(), -(0.7.sin())));
+        let (s, c) = Dual::variable(2.0).sinhcosh();
+        assert_eq!((s.derivative, c.derivative), (2.0.cosh(), 2.0.sinh()));
+    }
+
+    #[test]
+    fn test_atan2() {
+        let (y, x) = (0.6, -1.3);
+        let r2 = x * x + y * y;
+        for (f, scale) in [
+            (Trig::atan2 as fn(&Dual<f64>, &Dual<f64>) -> Dual<f64>, 1.0),
+            (Trig::atan2d, 1.0.rad2deg()),
+            (Trig::atan2pi, 1.0 / 180.0.deg2rad()),
+        ] {
+            let dy = f(&Dual::variable(y), &Dual::constant(x)).derivative;
+            let dx = f(&Dual::constant(y), &Dual::variable(x)).derivative;
+            assert!((dy - scale * x / r2).abs() < 1e-13);
+            assert!((dx + scale * y / r2).abs() < 1e-13);
+        }
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        // d/dx (x sin(x) - cos(x) / x) = sin(x) + x cos(x) + sin(x) / x + cos(x) / x².
+        let x = 1.3;
+        let d = Dual::variable(x);
+        let y = d * d.sin() - d.cos() / d;
+        let expected = x.sin() + x * x.cos() + x.sin() / x + x.cos() / (x * x);
+        assert!((y.derivative - expected).abs() < 1e-14);
+        assert_eq!((-d).derivative, -1.0);
+        assert_eq!(Dual::from(2.0).derivative, 0.0);
+        assert_eq!(Real::sqrt(&Dual::variable(4.0)).derivative, 0.25);
+        assert_eq!(Real::abs(&Dual::variable(-4.0)).derivative, -1.0);
+    }
+
+    #[test]
+    fn test_nested() {
+        // Nested dual numbers give second derivatives: sin''(x) = -sin(x).
+        let x = 0.9;
+        let d = Dual::new(Dual::variable(x), Dual::constant(1.0));
+        let y = d.sin();
+        assert!((y.derivative.derivative + x.sin()).abs() < 1e-15);
+        let y = d.atand();
+        let expected = -1.0.rad2deg() * 2.0 * x / ((1.0 + x * x) * (1.0 + x * x));
+        assert!((y.derivative.derivative - expected).abs() < 1e-13);
+    }
+
+    #[test]
+    fn test_double_f64() {
+        let y = Dual::variable(DoubleF64::from_f64(1.0)).sind();
+        let expected = DoubleF64::from_f64(1.0).deg2rad() * DoubleF64::from_f64(1.0).cosd();
+        assert!((y.derivative - expected).abs().to_f64() < 1e-33);
+    }
+}
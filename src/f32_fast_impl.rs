@@ -0,0 +1,493 @@
+use crate::fast_math;
+use crate::math;
+use crate::trig_fast_trait::{Accuracy, TrigFast};
+use crate::trig_trait::Trig;
+use core::f32::consts::{FRAC_1_PI, LN_2, PI};
+
+/// $\frac{\pi}{180}$.
+const DEG2RAD: f32 = PI / 180.0;
+
+/// $\frac{180}{\pi}$.
+const RAD2DEG: f32 = 180.0 / PI;
+
+/// Returns the result of a [`Trig`] method for [`Accuracy::Full`].
+macro_rules! full_accuracy {
+    ($accuracy:ident, $full:expr) => {
+        if $accuracy == Accuracy::Full {
+            return $full;
+        }
+    };
+}
+
+impl TrigFast for f32 {
+    fn sin_fast(&self, accuracy: Accuracy) -> f32 {
+        full_accuracy!(accuracy, Trig::sin(self));
+        let (q, r) = fast_math::rem_pio2(*self);
+        fast_math::sin_reduced(q, r, accuracy)
+    }
+    fn cos_fast(&self, accuracy: Accuracy) -> f32 {
+        full_accuracy!(accuracy, Trig::cos(self));
+        let (q, r) = fast_math::rem_pio2(*self);
+        fast_math::cos_reduced(q, r, accuracy)
+    }
+    fn tan_fast(&self, accuracy: Accuracy) -> f32 {
+        full_accuracy!(accuracy, Trig::tan(self));
+        let (q, r) = fast_math::rem_pio2(*self);
+        fast_math::tan_reduced(q, r, accuracy)
+    }
+    fn csc_fast(&self, accuracy: Accuracy) -> f32 {
+        full_accuracy!(accuracy, Trig::csc(self));
+        1.0 / self.sin_fast(accuracy)
+    }
+    fn sec_fast(&self, accuracy: Accuracy) -> f32 {
+        full_accuracy!(accuracy, Trig::sec(self));
+        1.0 / self.cos_fast(accuracy)
+    }
+    fn cot_fast(&self, accuracy: Accuracy) -> f32 {
+        full_accuracy!(accuracy, Trig::cot(self));
+        1.0 / self.tan_fast(accuracy)
+    }
+    fn sincos_fast(&self, accuracy: Accuracy) -> (f32, f32) {
+        full_accuracy!(accuracy, Trig::sincos(self));
+        let (q, r) = fast_math::rem_pio2(*self);
+        fast_math::sincos_reduced(q, r, accuracy)
+    }
+    fn asin_fast(&self, accuracy: Accuracy) -> f32 {
+        full_accuracy!(accuracy, Trig::asin(self));
+        fast_math::asin(*self, accuracy)
+    }
+    fn acos_fast(&self, accuracy: Accuracy) -> f32 {
+        full_accuracy!(accuracy, Trig::acos(self));
+        fast_math::acos(*self, accuracy)
+    }
+    fn atan_fast(&self, accuracy: Accuracy) -> f32 {
+        full_accuracy!(accuracy, Trig::atan(self));
+        fast_math::atan(*self, accuracy)
+    }
+    fn atan2_fast(&self, other: &f32, accuracy: Accuracy) -> f32 {
+        full_accuracy!(accuracy, Trig::atan2(self, other));
+        fast_math::atan2(*self, *other, accuracy)
+    }
+    fn acsc_fast(&self, accuracy: Accuracy) -> f32 {
+        full_accuracy!(accuracy, Trig::acsc(self));
+        fast_math::atan2(
+            1.0_f32.copysign(*self),
+            sqrt_x2_minus_1(self.abs()),
+            accuracy,
+        )
+    }
+    fn asec_fast(&self, accuracy: Accuracy) -> f32 {
+        full_accuracy!(accuracy, Trig::asec(self));
+        fast_math::atan2(
+            sqrt_x2_minus_1(self.abs()),
+            1.0_f32.copysign(*self),
+            accuracy,
+        )
+    }
+    fn acot_fast(&self, accuracy: Accuracy) -> f32 {
+        full_accuracy!(accuracy, Trig::acot(self));
+        fast_math::atan2(1.0_f32.copysign(*self), self.abs(), accuracy)
+    }
+    fn deg2rad_fast(&self, _accuracy: Accuracy) -> f32 {
+        self * DEG2RAD
+    }
+    fn rad2deg_fast(&self, _accuracy: Accuracy) -> f32 {
+        self * RAD2DEG
+    }
+    fn sind_fast(&self, accuracy: Accuracy) -> f32 {
+        full_accuracy!(accuracy, Trig::sind(self));
+        let (q, r) = fast_math::reduce_turn(*self, 90.0);
+        fast_math::sin_reduced(q, r * DEG2RAD, accuracy)
+    }
+    fn cosd_fast(&self, accuracy: Accuracy) -> f32 {
+        full_accuracy!(accuracy, Trig::cosd(self));
+        let (q, r) = fast_math::reduce_turn(*self, 90.0);
+        fast_math::cos_reduced(q, r * DEG2RAD, accuracy)
+    }
+    fn tand_fast(&self, accuracy: Accuracy) -> f32 {
+        full_accuracy!(accuracy, Trig::tand(self));
+        let (q, r) = fast_math::reduce_turn(*self, 90.0);
+        fast_math::tan_reduced(q, r * DEG2RAD, accuracy)
+    }
+    fn cscd_fast(&self, accuracy: Accuracy) -> f32 {
+        full_accuracy!(accuracy, Trig::cscd(self));
+        1.0 / self.sind_fast(accuracy)
+    }
+    fn secd_fast(&self, accuracy: Accuracy) -> f32 {
+        full_accuracy!(accuracy, Trig::secd(self));
+        1.0 / self.cosd_fast(accuracy)
+    }
+    fn cotd_fast(&self, accuracy: Accuracy) -> f32 {
+        full_accuracy!(accuracy, Trig::cotd(self));
+        1.0 / self.tand_fast(accuracy)
+    }
+    fn sincosd_fast(&self, accuracy: Accuracy) -> (f32, f32) {
+        full_accuracy!(accuracy, Trig::sincosd(self));
+        let (q, r) = fast_math::reduce_turn(*self, 90.0);
+        fast_math::sincos_reduced(q, r * DEG2RAD, accuracy)
+    }
+    fn asind_fast(&self, accuracy: Accuracy) -> f32 {
+        full_accuracy!(accuracy, Trig::asind(self));
+        self.asin_fast(accuracy) * RAD2DEG
+    }
+    fn acosd_fast(&self, accuracy: Accuracy) -> f32 {
+        full_accuracy!(accuracy, Trig::acosd(self));
+        self.acos_fast(accuracy) * RAD2DEG
+    }
+    fn atand_fast(&self, accuracy: Accuracy) -> f32 {
+        full_accuracy!(accuracy, Trig::atand(self));
+        self.atan_fast(accuracy) * RAD2DEG
+    }
+    fn atan2d_fast(&self, other: &f32, accuracy: Accuracy) -> f32 {
+        full_accuracy!(accuracy, Trig::atan2d(self, other));
+        self.atan2_fast(other, accuracy) * RAD2DEG
+    }
+    fn acscd_fast(&self, accuracy: Accuracy) -> f32 {
+        full_accuracy!(accuracy, Trig::acscd(self));
+        self.acsc_fast(accuracy) * RAD2DEG
+    }
+    fn asecd_fast(&self, accuracy: Accuracy) -> f32 {
+        full_accuracy!(accuracy, Trig::asecd(self));
+        self.asec_fast(accuracy) * RAD2DEG
+    }
+    fn acotd_fast(&self, accuracy: Accuracy) -> f32 {
+        full_accuracy!(accuracy, Trig::acotd(self));
+        self.acot_fast(accuracy) * RAD2DEG
+    }
+    fn sinpi_fast(&self, accuracy: Accuracy) -> f32 {
+        full_accuracy!(accuracy, Trig::sinpi(self));
+        let (q, r) = fast_math::reduce_turn(*self, 0.5);
+        fast_math::sin_reduced(q, r * PI, accuracy)
+    }
+    fn cospi_fast(&self, accuracy: Accuracy) -> f32 {
+        full_accuracy!(accuracy, Trig::cospi(self));
+        let (q, r) = fast_math::reduce_turn(*self, 0.5);
+        fast_math::cos_reduced(q, r * PI, accuracy)
+    }
+    fn tanpi_fast(&self, accuracy: Accuracy) -> f32 {
+        full_accuracy!(accuracy, Trig::tanpi(self));
+        let (q, r) = fast_math::reduce_turn(*self, 0.5);
+        fast_math::tan_reduced(q, r * PI, accuracy)
+    }
+    fn asinpi_fast(&self, accuracy: Accuracy) -> f32 {
+        full_accuracy!(accuracy, Trig::asinpi(self));
+        self.asin_fast(accuracy) * FRAC_1_PI
+    }
+    fn acospi_fast(&self, accuracy: Accuracy) -> f32 {
+        full_accuracy!(accuracy, Trig::acospi(self));
+        self.acos_fast(accuracy) * FRAC_1_PI
+    }
+    fn atanpi_fast(&self, accuracy: Accuracy) -> f32 {
+        full_accuracy!(accuracy, Trig::atanpi(self));
+        self.atan_fast(accuracy) * FRAC_1_PI
+    }
+    fn atan2pi_fast(&self, other: &f32, accuracy: Accuracy) -> f32 {
+        full_accuracy!(accuracy, Trig::atan2pi(self, other));
+        self.atan2_fast(other, accuracy) * FRAC_1_PI
+    }
+    fn sinh_fast(&self, accuracy: Accuracy) -> f32 {
+        full_accuracy!(accuracy, Trig::sinh(self));
+        fast_math::sinhcosh(*self, accuracy).0
+    }
+    fn cosh_fast(&self, accuracy: Accuracy) -> f32 {
+        full_accuracy!(accuracy, Trig::cosh(self));
+        fast_math::sinhcosh(*self, accuracy).1
+    }
+    fn tanh_fast(&self, accuracy: Accuracy) -> f32 {
+        full_accuracy!(accuracy, Trig::tanh(self));
+        fast_math::tanh(*self, accuracy)
+    }
+    fn csch_fast(&self, accuracy: Accuracy) -> f32 {
+        full_accuracy!(accuracy, Trig::csch(self));
+        1.0 / self.sinh_fast(accuracy)
+    }
+    fn sech_fast(&self, accuracy: Accuracy) -> f32 {
+        full_accuracy!(accuracy, Trig::sech(self));
+        1.0 / self.cosh_fast(accuracy)
+    }
+    fn coth_fast(&self, accuracy: Accuracy) -> f32 {
+        full_accuracy!(accuracy, Trig::coth(self));
+        1.0 / self.tanh_fast(accuracy)
+    }
+    fn sinhcosh_fast(&self, accuracy: Accuracy) -> (f32, f32) {
+        full_accuracy!(accuracy, Trig::sinhcosh(self));
+        fast_math::sinhcosh(*self, accuracy)
+    }
+    fn asinh_fast(&self, accuracy: Accuracy) -> f32 {
+        full_accuracy!(accuracy, Trig::asinh(self));
+        fast_math::asinh(*self, accuracy)
+    }
+    fn acosh_fast(&self, accuracy: Accuracy) -> f32 {
+        full_accuracy!(accuracy, Trig::acosh(self));
+        fast_math::acosh(*self, accuracy)
+    }
+    fn atanh_fast(&self, accuracy: Accuracy) -> f32 {
+        full_accuracy!(accuracy, Trig::atanh(self));
+        fast_math::atanh(*self, accuracy)
+    }
+    fn acsch_fast(&self, accuracy: Accuracy) -> f32 {
+        full_accuracy!(accuracy, Trig::acsch(self));
+        let x = self.abs();
+        let f = if x < 1e-30 {
+            // ln((1 + sqrt(1 + x²)) / x) = ln(2) - ln(x), since 1 / x could overflow.
+            LN_2 - fast_math::ln(x, accuracy)
+        } else {
+            // ln(1 + u) with u = (1 + sqrt(1 + x²)) / x - 1 = (1 + 1 / (x + sqrt(x² + 1))) / x.
+            let h = math::sqrt_f32(x * x + 1.0);
+            fast_math::ln_1p((1.0 + 1.0 / (x + h)) / x, accuracy)
+        };
+        f.copysign(*self)
+    }
+    fn asech_fast(&self, accuracy: Accuracy) -> f32 {
+        full_accuracy!(accuracy, Trig::asech(self));
+        // ln((1 + sqrt(1 - x²)) / x), split so that 1 / x cannot overflow.
+        let s = math::sqrt_f32((1.0 - self) * (1.0 + self));
+        fast_math::ln_1p(s, accuracy) - fast_math::ln(*self, accuracy)
+    }
+    fn acoth_fast(&self, accuracy: Accuracy) -> f32 {
+        full_accuracy!(accuracy, Trig::acoth(self));
+        // (1/2)ln((x + 1) / (x - 1)) = (1/2)ln(1 + 2 / (x - 1)), where x - 1 is exact near x = 1.
+        (0.5 * fast_math::ln_1p(2.0 / (self.abs() - 1.0), accuracy)).copysign(*self)
+    }
+}
+
+/// Computes $\sqrt{x^{2}-1}$ for $x\geq1$ without overflow or cancellation.
+///
+/// # Arguments
+///
+/// * `x` - Value, $x\geq1$.
+///
+/// # Returns
+///
+/// $\sqrt{x^{2}-1}$ (`NaN` for $x<1$).
+fn sqrt_x2_minus_1(x: f32) -> f32 {
+    if x < 1e18 {
+        // x - 1 is exact near x = 1, so there is no cancellation in the product.
+        math::sqrt_f32((x - 1.0) * (x + 1.0))
+    } else {
+        x
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Tiers paired with their maximum errors (for [`Accuracy::Full`], the $4$ ulps of the least
+    /// accurate [`Trig`] methods on [`f32`]).
+    const TIERS: [(Accuracy, f64); 3] = [
+        (Accuracy::Low, 1e-3),
+        (Accuracy::Medium, 1e-6),
+        (Accuracy::Full, 4.76837158203125e-7),
+    ];
+
+    /// Generator of uniformly distributed pseudo-random samples (SplitMix64).
+    struct Rng(u64);
+
+    impl Rng {
+        /// Returns a sample uniformly distributed in `[a, b)`.
+        fn uniform(&mut self, a: f32, b: f32) -> f32 {
+            self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+            let mut z = self.0;
+            z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+            let u = (z ^ (z >> 31)) as f64 / 2.0_f64.powi(64);
+            (a as f64 + (b - a) as f64 * u) as f32
+        }
+    }
+
+    /// Asserts that a fast method is within the documented error of its [`Trig`] counterpart
+    /// (evaluated in [`f64`]) on samples drawn from a range, and that both are `NaN` outside the
+    /// domain of the function.
+    fn check(
+        name: &str,
+        fast: impl Fn(f32, f32, Accuracy) -> f32,
+        reference: impl Fn(f64, f64) -> f64,
+        x_range: (f32, f32),
+        y_range: (f32, f32),
+    ) {
+        for (accuracy, bound) in TIERS {
+            let mut rng = Rng(0x5eed);
+            let mut max_error = 0.0_f64;
+            for _ in 0..20000 {
+                let x = rng.uniform(x_range.0, x_range.1);
+                let y = rng.uniform(y_range.0, y_range.1);
+                let (f, g) = (fast(x, y, accuracy) as f64, reference(x as f64, y as f64));
+                if g.is_nan() {
+                    assert!(f.is_nan(), "{name}({x}, {y}) = {f} instead of NaN");
+                } else {
+                    max_error = max_error.max((f - g).abs() / g.abs().max(1.0));
+                }
+            }
+            assert!(
+                max_error <= bound,
+                "{name} ({accuracy:?}): maximum error {max_error:e} exceeds {bound:e}"
+            );
+        }
+    }
+
+    /// Checks methods of one argument, each given as `name => fast_name` followed by the range
+    /// to sample (and, for methods returning a tuple, the index of the element to check).
+    macro_rules! check_unary {
+        ($($name:ident => $fast:ident $(.$i:tt)?: $a:expr, $b:expr;)*) => {
+            $(
+                check(
+                    concat!(stringify!($fast) $(, ".", stringify!($i))?),
+                    |x, _, accuracy| x.$fast(accuracy)$(.$i)?,
+                    |x, _| Trig::$name(&x)$(.$i)?,
+                    ($a, $b),
+                    (0.0, 0.0),
+                );
+            )*
+        };
+    }
+
+    /// Checks methods of two arguments, each given as `name => fast_name` followed by the ranges
+    /// to sample for `self` (`y`) and `other` (`x`).
+    macro_rules! check_binary {
+        ($($name:ident => $fast:ident: $range_y:expr, $range_x:expr;)*) => {
+            $(
+                check(
+                    stringify!($fast),
+                    |y, x, accuracy| y.$fast(&x, accuracy),
+                    |y, x| Trig::$name(&y, &x),
+                    $range_y,
+                    $range_x,
+                );
+            )*
+        };
+    }
+
+    #[test]
+    fn test_accuracy() {
+        check_unary! {
+            sin => sin_fast: -100.0, 100.0;
+            sin => sin_fast: -1e7, 1e7;
+            cos => cos_fast: -100.0, 100.0;
+            cos => cos_fast: -1e7, 1e7;
+            tan => tan_fast: -100.0, 100.0;
+            csc => csc_fast: -100.0, 100.0;
+            sec => sec_fast: -100.0, 100.0;
+            cot => cot_fast: -100.0, 100.0;
+            sincos => sincos_fast.0: -100.0, 100.0;
+            sincos => sincos_fast.1: -100.0, 100.0;
+            asin => asin_fast: -1.1, 1.1;
+            acos => acos_fast: -1.1, 1.1;
+            atan => atan_fast: -100.0, 100.0;
+            acsc => acsc_fast: -100.0, 100.0;
+            asec => asec_fast: -100.0, 100.0;
+            acot => acot_fast: -100.0, 100.0;
+            deg2rad => deg2rad_fast: -1000.0, 1000.0;
+            rad2deg => rad2deg_fast: -100.0, 100.0;
+            sind => sind_fast: -1000.0, 1000.0;
+            sind => sind_fast: -1e9, 1e9;
+            cosd => cosd_fast: -1000.0, 1000.0;
+            tand => tand_fast: -1000.0, 1000.0;
+            cscd => cscd_fast: -1000.0, 1000.0;
+            secd => secd_fast: -1000.0, 1000.0;
+            cotd => cotd_fast: -1000.0, 1000.0;
+            sincosd => sincosd_fast.0: -1000.0, 1000.0;
+            sincosd => sincosd_fast.1: -1000.0, 1000.0;
+            asind => asind_fast: -1.1, 1.1;
+            acosd => acosd_fast: -1.1, 1.1;
+            atand => atand_fast: -100.0, 100.0;
+            acscd => acscd_fast: -100.0, 100.0;
+            asecd => asecd_fast: -100.0, 100.0;
+            acotd => acotd_fast: -100.0, 100.0;
+            sinpi => sinpi_fast: -10.0, 10.0;
+            cospi => cospi_fast: -10.0, 10.0;
+            tanpi => tanpi_fast: -10.0, 10.0;
+            asinpi => asinpi_fast: -1.1, 1.1;
+            acospi => acospi_fast: -1.1, 1.1;
+            atanpi => atanpi_fast: -100.0, 100.0;
+            sinh => sinh_fast: -2.0, 2.0;
+            sinh => sinh_fast: -89.0, 89.0;
+            cosh => cosh_fast: -89.0, 89.0;
+            tanh => tanh_fast: -10.0, 10.0;
+            csch => csch_fast: -2.0, 2.0;
+            sech => sech_fast: -20.0, 20.0;
+            coth => coth_fast: -2.0, 2.0;
+            sinhcosh => sinhcosh_fast.0: -20.0, 20.0;
+            sinhcosh => sinhcosh_fast.1: -20.0, 20.0;
+            asinh => asinh_fast: -2.0, 2.0;
+            asinh => asinh_fast: -1e6, 1e6;
+            acosh => acosh_fast: 0.9, 2.0;
+            acosh => acosh_fast: 1.0, 1e6;
+            acosh => acosh_fast: -1e10, -1.0;
+            atanh => atanh_fast: -1.1, 1.1;
+            acsch => acsch_fast: -2.0, 2.0;
+            acsch => acsch_fast: -1e6, 1e6;
+            asech => asech_fast: -0.1, 1.1;
+            acoth => acoth_fast: -3.0, 3.0;
+            acoth => acoth_fast: -1e6, 1e6;
+        }
+        check_binary! {
+            atan2 => atan2_fast: (-10.0, 10.0), (-10.0, 10.0);
+            atan2d => atan2d_fast: (-10.0, 10.0), (-10.0, 10.0);
+            atan2pi => atan2pi_fast: (-10.0, 10.0), (-10.0, 10.0);
+        }
+    }
+
+    #[test]
+    fn test_full_accuracy() {
+        let full = Accuracy::Full;
+        for x in [
+            -1e6_f32,
+            -2.5,
+            -0.7,
+            -0.0,
+            0.3,
+            1.0,
+            1.5,
+            45.0,
+            1e20,
+            f32::INFINITY,
+            f32::NAN,
+        ] {
+            let pairs = [
+                (x.sin_fast(full), Trig::sin(&x)),
+                (x.tan_fast(full), Trig::tan(&x)),
+                (x.acos_fast(full), Trig::acos(&x)),
+                (x.atan2_fast(&-2.0, full), Trig::atan2(&x, &-2.0)),
+                (x.asec_fast(full), Trig::asec(&x)),
+                (x.cosd_fast(full), Trig::cosd(&x)),
+                (x.atan2d_fast(&3.0, full), Trig::atan2d(&x, &3.0)),
+                (x.tanpi_fast(full), Trig::tanpi(&x)),
+                (x.sincos_fast(full).1, Trig::sincos(&x).1),
+                (x.coth_fast(full), Trig::coth(&x)),
+                (x.acosh_fast(full), Trig::acosh(&x)),
+                (x.asech_fast(full), Trig::asech(&x)),
+            ];
+            for (fast, expected) in pairs {
+                assert_eq!(fast.to_bits(), expected.to_bits(), "{x}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_special_values() {
+        let tiers = TIERS.map(|(accuracy, _)| accuracy);
+        for accuracy in tiers {
+            assert_eq!(0.0_f32.sin_fast(accuracy), 0.0);
+            assert_eq!(0.0_f32.cos_fast(accuracy), 1.0);
+            assert_eq!(180.0_f32.sind_fast(accuracy), 0.0);
+            assert_eq!(90.0_f32.cosd_fast(accuracy), 0.0);
+            assert_eq!(1.0_f32.sinpi_fast(accuracy), 0.0);
+            assert_eq!(0.0_f32.atan2_fast(&-1.0, accuracy), PI);
+            assert!(f32::INFINITY.sin_fast(accuracy).is_nan());
+            assert!(f32::NAN.cosd_fast(accuracy).is_nan());
+            assert!(2.0_f32.asin_fast(accuracy).is_nan());
+            assert_eq!(
+                f32::INFINITY.atan_fast(accuracy),
+                core::f32::consts::FRAC_PI_2
+            );
+            assert_eq!(100.0_f32.cosh_fast(accuracy), f32::INFINITY);
+            assert_eq!(f32::NEG_INFINITY.tanh_fast(accuracy), -1.0);
+            assert_eq!(1.0_f32.atanh_fast(accuracy), f32::INFINITY);
+            assert_eq!(1.0_f32.acosh_fast(accuracy), 0.0);
+            assert!((-1e5_f32).acosh_fast(accuracy).is_nan());
+            assert!((-84588.09_f32).acosh_fast(accuracy).is_nan());
+            assert!((-1e10_f32).acosh_fast(accuracy).is_nan());
+        }
+    }
+}
@@ -1,14 +1,38 @@
+#[cfg(feature = "correctly-rounded")]
+use crate::correctly_rounded;
+#[cfg(not(feature = "correctly-rounded"))]
+use crate::math;
+#[cfg(not(feature = "correctly-rounded"))]
+use crate::rem_pio2::rem_pio2_f32;
 use crate::trig_trait::Trig;
 
+#[cfg(not(feature = "correctly-rounded"))]
 impl Trig for f32 {
     fn sin(&self) -> f32 {
-        f32::sin(*self)
+        let (q, r) = rem_pio2_f32(*self);
+        let f = match q {
+            0 => math::sin(r),
+            1 => math::cos(r),
+            2 => -math::sin(r),
+            _ => -math::cos(r),
+        };
+        f as f32
     }
     fn cos(&self) -> f32 {
-        f32::cos(*self)
+        let (q, r) = rem_pio2_f32(*self);
+        let f = match q {
+            0 => math::cos(r),
+            1 => -math::sin(r),
+            2 => -math::cos(r),
+            _ => math::sin(r),
+        };
+        f as f32
     }
     fn tan(&self) -> f32 {
-        f32::tan(*self)
+        let (q, r) = rem_pio2_f32(*self);
+        let t = math::tan(r);
+        let f = if q % 2 == 0 { t } else { -1.0 / t };
+        f as f32
     }
     fn csc(&self) -> f32 {
         1.0 / self.sin()
@@ -19,80 +43,210 @@ impl Trig for f32 {
     fn cot(&self) -> f32 {
         1.0 / self.tan()
     }
+    fn sincos(&self) -> (f32, f32) {
+        let (q, r) = rem_pio2_f32(*self);
+        let (s, c) = (math::sin(r) as f32, math::cos(r) as f32);
+        match q {
+            0 => (s, c),
+            1 => (c, -s),
+            2 => (-s, -c),
+            _ => (-c, s),
+        }
+    }
     fn asin(&self) -> f32 {
-        f32::asin(*self)
+        math::asin_f32(*self)
     }
     fn acos(&self) -> f32 {
-        f32::acos(*self)
+        math::acos_f32(*self)
     }
     fn atan(&self) -> f32 {
-        f32::atan(*self)
+        math::atan_f32(*self)
     }
     fn atan2(&self, other: &f32) -> f32 {
-        f32::atan2(*self, *other)
+        math::atan2_f32(*self, *other)
     }
     fn acsc(&self) -> f32 {
-        (1.0 / self).asin()
+        math::atan2_f32(1.0_f32.copysign(*self), sqrt_x2_minus_1(self.abs()))
     }
     fn asec(&self) -> f32 {
-        (1.0 / self).acos()
+        math::atan2_f32(sqrt_x2_minus_1(self.abs()), 1.0_f32.copysign(*self))
     }
     fn acot(&self) -> f32 {
-        (1.0 / self).atan()
+        math::atan2_f32(1.0_f32.copysign(*self), self.abs())
     }
     fn deg2rad(&self) -> f32 {
-        self * (std::f32::consts::PI / 180.0)
+        self * (core::f32::consts::PI / 180.0)
     }
     fn rad2deg(&self) -> f32 {
-        self * (180.0 / std::f32::consts::PI)
+        self * (180.0 / core::f32::consts::PI)
     }
     fn sind(&self) -> f32 {
-        self.deg2rad().sin()
+        let (q, r) = reduce_turn(self.abs(), 90.0);
+        let (s, c) = sincosd_reduced(r);
+        let f = match q {
+            0 => s,
+            1 => c,
+            2 => -s,
+            _ => -c,
+        };
+
+        // Adding zero turns an exact -0 into +0 so that the sign of a zero result follows `self`.
+        if self.is_sign_negative() {
+            -(f + 0.0)
+        } else {
+            f + 0.0
+        }
     }
     fn cosd(&self) -> f32 {
-        self.deg2rad().cos()
+        let (q, r) = reduce_turn(self.abs(), 90.0);
+        let (s, c) = sincosd_reduced(r);
+        let f = match q {
+            0 => c,
+            1 => -s,
+            2 => -c,
+            _ => s,
+        };
+        f + 0.0
     }
     fn tand(&self) -> f32 {
-        self.deg2rad().tan()
+        let (q, r) = reduce_turn(self.abs(), 90.0);
+        let t = if r.abs() == 45.0 {
+            1.0_f32.copysign(r)
+        } else {
+            math::tan_f32(r.deg2rad())
+        };
+        let f = if q % 2 == 0 {
+            t
+        } else if r == 0.0 {
+            if q == 1 {
+                f32::INFINITY
+            } else {
+                f32::NEG_INFINITY
+            }
+        } else {
+            -1.0 / t
+        };
+        if self.is_sign_negative() {
+            -(f + 0.0)
+        } else {
+            f + 0.0
+        }
     }
     fn cscd(&self) -> f32 {
-        self.deg2rad().csc()
+        1.0 / self.sind()
     }
     fn secd(&self) -> f32 {
-        self.deg2rad().sec()
+        1.0 / self.cosd()
     }
     fn cotd(&self) -> f32 {
-        self.deg2rad().cot()
+        1.0 / self.tand()
+    }
+    fn sincosd(&self) -> (f32, f32) {
+        let (q, r) = reduce_turn(self.abs(), 90.0);
+        let (s, c) = sincosd_reduced(r);
+        let (s, c) = match q {
+            0 => (s, c),
+            1 => (c, -s),
+            2 => (-s, -c),
+            _ => (-c, s),
+        };
+        if self.is_sign_negative() {
+            (-(s + 0.0), c + 0.0)
+        } else {
+            (s + 0.0, c + 0.0)
+        }
     }
     fn asind(&self) -> f32 {
-        self.asin().rad2deg()
+        (*self as f64).asind() as f32
     }
     fn acosd(&self) -> f32 {
-        self.acos().rad2deg()
+        (*self as f64).acosd() as f32
     }
     fn atand(&self) -> f32 {
-        self.atan().rad2deg()
+        (*self as f64).atand() as f32
     }
     fn atan2d(&self, other: &f32) -> f32 {
-        self.atan2(other).rad2deg()
+        (*self as f64).atan2d(&(*other as f64)) as f32
     }
     fn acscd(&self) -> f32 {
-        self.acsc().rad2deg()
+        (*self as f64).acscd() as f32
     }
     fn asecd(&self) -> f32 {
-        self.asec().rad2deg()
+        (*self as f64).asecd() as f32
     }
     fn acotd(&self) -> f32 {
-        self.acot().rad2deg()
+        (*self as f64).acotd() as f32
+    }
+    fn sinpi(&self) -> f32 {
+        let (q, r) = reduce_turn(self.abs(), 0.5);
+        let (s, c) = sincospi_reduced(r);
+        let f = match q {
+            0 => s,
+            1 => c,
+            2 => -s,
+            _ => -c,
+        };
+        if self.is_sign_negative() {
+            -(f + 0.0)
+        } else {
+            f + 0.0
+        }
+    }
+    fn cospi(&self) -> f32 {
+        let (q, r) = reduce_turn(self.abs(), 0.5);
+        let (s, c) = sincospi_reduced(r);
+        let f = match q {
+            0 => c,
+            1 => -s,
+            2 => -c,
+            _ => s,
+        };
+        f + 0.0
+    }
+    fn tanpi(&self) -> f32 {
+        let (q, r) = reduce_turn(self.abs(), 0.5);
+        let t = if r.abs() == 0.25 {
+            1.0_f32.copysign(r)
+        } else {
+            math::tan_f32(r * core::f32::consts::PI)
+        };
+        let f = if q % 2 == 0 {
+            t
+        } else if r == 0.0 {
+            if q == 1 {
+                f32::INFINITY
+            } else {
+                f32::NEG_INFINITY
+            }
+        } else {
+            -1.0 / t
+        };
+        if self.is_sign_negative() {
+            -(f + 0.0)
+        } else {
+            f + 0.0
+        }
+    }
+    fn asinpi(&self) -> f32 {
+        (*self as f64).asinpi() as f32
+    }
+    fn acospi(&self) -> f32 {
+        (*self as f64).acospi() as f32
+    }
+    fn atanpi(&self) -> f32 {
+        (*self as f64).atanpi() as f32
+    }
+    fn atan2pi(&self, other: &f32) -> f32 {
+        (*self as f64).atan2pi(&(*other as f64)) as f32
     }
     fn sinh(&self) -> f32 {
-        f32::sinh(*self)
+        math::sinh_f32(*self)
     }
     fn cosh(&self) -> f32 {
-        f32::cosh(*self)
+        math::cosh_f32(*self)
     }
     fn tanh(&self) -> f32 {
-        f32::tanh(*self)
+        math::tanh_f32(*self)
     }
     fn csch(&self) -> f32 {
         1.0 / self.sinh()
@@ -103,23 +257,299 @@ impl Trig for f32 {
     fn coth(&self) -> f32 {
         1.0 / self.tanh()
     }
+    fn sinhcosh(&self) -> (f32, f32) {
+        let x = self.abs();
+        let (s, c) = if x < 9.0 {
+            // sinh(x) = (e^x - 1 + (e^x - 1) / e^x) / 2 avoids cancellation for small x.
+            let em1 = math::exp_m1_f32(x);
+            let e = em1 + 1.0;
+            (0.5 * (em1 + em1 / e), 0.5 * (e + 1.0 / e))
+        } else if x < 88.0 {
+            // e^(-x) is negligible compared to e^x.
+            let h = 0.5 * math::exp_f32(x);
+            (h, h)
+        } else {
+            // Split e^x into two factors so that it does not overflow before being halved.
+            let h = math::exp_f32(0.5 * x);
+            let h = (0.5 * h) * h;
+            (h, h)
+        };
+        (s.copysign(*self), c)
+    }
     fn asinh(&self) -> f32 {
-        f32::asinh(*self)
+        math::asinh_f32(*self)
     }
     fn acosh(&self) -> f32 {
-        f32::acosh(*self)
+        math::acosh_f32(*self)
     }
     fn atanh(&self) -> f32 {
-        f32::atanh(*self)
+        math::atanh_f32(*self)
     }
     fn acsch(&self) -> f32 {
-        (1.0 / self).asinh()
+        let x = self.abs();
+        let f = if x < 1e-30 {
+            // ln((1 + sqrt(1 + x²)) / x) = ln(2) - ln(x), since 1 / x could overflow.
+            core::f32::consts::LN_2 - math::ln_f32(x)
+        } else {
+            // ln(1 + u) with u = (1 + sqrt(1 + x²)) / x - 1 = (1 + 1 / (x + sqrt(x² + 1))) / x.
+            math::ln_1p_f32((1.0 + 1.0 / (x + math::hypot_f32(x, 1.0))) / x)
+        };
+        f.copysign(*self)
     }
     fn asech(&self) -> f32 {
-        (1.0 / self).acosh()
+        // ln((1 + sqrt(1 - x²)) / x), split so that 1 / x cannot overflow.
+        math::ln_1p_f32(math::sqrt_f32((1.0 - self) * (1.0 + self))) - math::ln_f32(*self)
     }
     fn acoth(&self) -> f32 {
-        (1.0 / self).atanh()
+        // (1/2)ln((x + 1) / (x - 1)) = (1/2)ln(1 + 2 / (x - 1)), where x - 1 is exact near x = 1.
+        (0.5 * math::ln_1p_f32(2.0 / (self.abs() - 1.0))).copysign(*self)
+    }
+}
+
+#[cfg(feature = "correctly-rounded")]
+impl Trig for f32 {
+    fn sin(&self) -> f32 {
+        correctly_rounded::sin(*self as f64, f32::MANTISSA_DIGITS) as f32
+    }
+    fn cos(&self) -> f32 {
+        correctly_rounded::cos(*self as f64, f32::MANTISSA_DIGITS) as f32
+    }
+    fn tan(&self) -> f32 {
+        correctly_rounded::tan(*self as f64, f32::MANTISSA_DIGITS) as f32
+    }
+    fn csc(&self) -> f32 {
+        correctly_rounded::csc(*self as f64, f32::MANTISSA_DIGITS) as f32
+    }
+    fn sec(&self) -> f32 {
+        correctly_rounded::sec(*self as f64, f32::MANTISSA_DIGITS) as f32
+    }
+    fn cot(&self) -> f32 {
+        correctly_rounded::cot(*self as f64, f32::MANTISSA_DIGITS) as f32
+    }
+    fn sincos(&self) -> (f32, f32) {
+        let (a, b) = correctly_rounded::sincos(*self as f64, f32::MANTISSA_DIGITS);
+        (a as f32, b as f32)
+    }
+    fn asin(&self) -> f32 {
+        correctly_rounded::asin(*self as f64, f32::MANTISSA_DIGITS) as f32
+    }
+    fn acos(&self) -> f32 {
+        correctly_rounded::acos(*self as f64, f32::MANTISSA_DIGITS) as f32
+    }
+    fn atan(&self) -> f32 {
+        correctly_rounded::atan(*self as f64, f32::MANTISSA_DIGITS) as f32
+    }
+    fn atan2(&self, other: &f32) -> f32 {
+        correctly_rounded::atan2(*self as f64, *other as f64, f32::MANTISSA_DIGITS) as f32
+    }
+    fn acsc(&self) -> f32 {
+        correctly_rounded::acsc(*self as f64, f32::MANTISSA_DIGITS) as f32
+    }
+    fn asec(&self) -> f32 {
+        correctly_rounded::asec(*self as f64, f32::MANTISSA_DIGITS) as f32
+    }
+    fn acot(&self) -> f32 {
+        correctly_rounded::acot(*self as f64, f32::MANTISSA_DIGITS) as f32
+    }
+    fn deg2rad(&self) -> f32 {
+        correctly_rounded::deg2rad(*self as f64, f32::MANTISSA_DIGITS) as f32
+    }
+    fn rad2deg(&self) -> f32 {
+        correctly_rounded::rad2deg(*self as f64, f32::MANTISSA_DIGITS) as f32
+    }
+    fn sind(&self) -> f32 {
+        correctly_rounded::sind(*self as f64, f32::MANTISSA_DIGITS) as f32
+    }
+    fn cosd(&self) -> f32 {
+        correctly_rounded::cosd(*self as f64, f32::MANTISSA_DIGITS) as f32
+    }
+    fn tand(&self) -> f32 {
+        correctly_rounded::tand(*self as f64, f32::MANTISSA_DIGITS) as f32
+    }
+    fn cscd(&self) -> f32 {
+        correctly_rounded::cscd(*self as f64, f32::MANTISSA_DIGITS) as f32
+    }
+    fn secd(&self) -> f32 {
+        correctly_rounded::secd(*self as f64, f32::MANTISSA_DIGITS) as f32
+    }
+    fn cotd(&self) -> f32 {
+        correctly_rounded::cotd(*self as f64, f32::MANTISSA_DIGITS) as f32
+    }
+    fn sincosd(&self) -> (f32, f32) {
+        let (a, b) = correctly_rounded::sincosd(*self as f64, f32::MANTISSA_DIGITS);
+        (a as f32, b as f32)
+    }
+    fn asind(&self) -> f32 {
+        correctly_rounded::asind(*self as f64, f32::MANTISSA_DIGITS) as f32
+    }
+    fn acosd(&self) -> f32 {
+        correctly_rounded::acosd(*self as f64, f32::MANTISSA_DIGITS) as f32
+    }
+    fn atand(&self) -> f32 {
+        correctly_rounded::atand(*self as f64, f32::MANTISSA_DIGITS) as f32
+    }
+    fn atan2d(&self, other: &f32) -> f32 {
+        correctly_rounded::atan2d(*self as f64, *other as f64, f32::MANTISSA_DIGITS) as f32
+    }
+    fn acscd(&self) -> f32 {
+        correctly_rounded::acscd(*self as f64, f32::MANTISSA_DIGITS) as f32
+    }
+    fn asecd(&self) -> f32 {
+        correctly_rounded::asecd(*self as f64, f32::MANTISSA_DIGITS) as f32
+    }
+    fn acotd(&self) -> f32 {
+        correctly_rounded::acotd(*self as f64, f32::MANTISSA_DIGITS) as f32
+    }
+    fn sinpi(&self) -> f32 {
+        correctly_rounded::sinpi(*self as f64, f32::MANTISSA_DIGITS) as f32
+    }
+    fn cospi(&self) -> f32 {
+        correctly_rounded::cospi(*self as f64, f32::MANTISSA_DIGITS) as f32
+    }
+    fn tanpi(&self) -> f32 {
+        correctly_rounded::tanpi(*self as f64, f32::MANTISSA_DIGITS) as f32
+    }
+    fn asinpi(&self) -> f32 {
+        correctly_rounded::asinpi(*self as f64, f32::MANTISSA_DIGITS) as f32
+    }
+    fn acospi(&self) -> f32 {
+        correctly_rounded::acospi(*self as f64, f32::MANTISSA_DIGITS) as f32
+    }
+    fn atanpi(&self) -> f32 {
+        correctly_rounded::atanpi(*self as f64, f32::MANTISSA_DIGITS) as f32
+    }
+    fn atan2pi(&self, other: &f32) -> f32 {
+        correctly_rounded::atan2pi(*self as f64, *other as f64, f32::MANTISSA_DIGITS) as f32
+    }
+    fn sinh(&self) -> f32 {
+        correctly_rounded::sinh(*self as f64, f32::MANTISSA_DIGITS) as f32
+    }
+    fn cosh(&self) -> f32 {
+        correctly_rounded::cosh(*self as f64, f32::MANTISSA_DIGITS) as f32
+    }
+    fn tanh(&self) -> f32 {
+        correctly_rounded::tanh(*self as f64, f32::MANTISSA_DIGITS) as f32
+    }
+    fn csch(&self) -> f32 {
+        correctly_rounded::csch(*self as f64, f32::MANTISSA_DIGITS) as f32
+    }
+    fn sech(&self) -> f32 {
+        correctly_rounded::sech(*self as f64, f32::MANTISSA_DIGITS) as f32
+    }
+    fn coth(&self) -> f32 {
+        correctly_rounded::coth(*self as f64, f32::MANTISSA_DIGITS) as f32
+    }
+    fn sinhcosh(&self) -> (f32, f32) {
+        let (a, b) = correctly_rounded::sinhcosh(*self as f64, f32::MANTISSA_DIGITS);
+        (a as f32, b as f32)
+    }
+    fn asinh(&self) -> f32 {
+        correctly_rounded::asinh(*self as f64, f32::MANTISSA_DIGITS) as f32
+    }
+    fn acosh(&self) -> f32 {
+        correctly_rounded::acosh(*self as f64, f32::MANTISSA_DIGITS) as f32
+    }
+    fn atanh(&self) -> f32 {
+        correctly_rounded::atanh(*self as f64, f32::MANTISSA_DIGITS) as f32
+    }
+    fn acsch(&self) -> f32 {
+        correctly_rounded::acsch(*self as f64, f32::MANTISSA_DIGITS) as f32
+    }
+    fn asech(&self) -> f32 {
+        correctly_rounded::asech(*self as f64, f32::MANTISSA_DIGITS) as f32
+    }
+    fn acoth(&self) -> f32 {
+        correctly_rounded::acoth(*self as f64, f32::MANTISSA_DIGITS) as f32
+    }
+}
+
+/// Computes $\sqrt{x^{2}-1}$ without cancellation near $x=1$ and without overflow for large $x$.
+///
+/// # Arguments
+///
+/// * `x` - Non-negative value.
+///
+/// # Returns
+///
+/// $\sqrt{x^{2}-1}$ (`NaN` for $x<1$).
+#[cfg(not(feature = "correctly-rounded"))]
+fn sqrt_x2_minus_1(x: f32) -> f32 {
+    if x < 1e18 {
+        // x - 1 is exact near x = 1, so there is no cancellation in the product.
+        math::sqrt_f32((x - 1.0) * (x + 1.0))
+    } else {
+        x
+    }
+}
+
+/// Exactly reduces an angle modulo a full turn.
+///
+/// # Arguments
+///
+/// * `x` - Angle.
+/// * `quarter_turn` - A quarter turn in the units of `x` (e.g. `90.0` for degrees).
+///
+/// # Returns
+///
+/// Quadrant `q` (in $\{0,1,2,3\}$) and remainder `r` (with $|r|\leq$ `quarter_turn / 2`) such that
+/// `x` is equal to `q * quarter_turn + r` modulo a full turn.
+///
+/// # Note
+///
+/// Both the remainder and the subtraction of the quadrant are exact in binary floating point, so
+/// the reduction introduces no rounding error regardless of the magnitude of `x`.
+#[cfg(not(feature = "correctly-rounded"))]
+fn reduce_turn(x: f32, quarter_turn: f32) -> (u8, f32) {
+    let r = x % (4.0 * quarter_turn);
+    let n = math::round_f32(r / quarter_turn);
+    ((n as i32).rem_euclid(4) as u8, r - n * quarter_turn)
+}
+
+/// Computes the sine and cosine of a reduced angle in degrees.
+///
+/// # Arguments
+///
+/// * `r` - Reduced angle, $|r|\leq45$ \[deg\].
+///
+/// # Returns
+///
+/// Sine and cosine of `r`, with the values at $\pm30$ and $\pm45$ degrees returned correctly
+/// rounded.
+#[cfg(not(feature = "correctly-rounded"))]
+fn sincosd_reduced(r: f32) -> (f32, f32) {
+    if r.abs() == 30.0 {
+        (0.5_f32.copysign(r), 0.8660254_f32)
+    } else if r.abs() == 45.0 {
+        (
+            core::f32::consts::FRAC_1_SQRT_2.copysign(r),
+            core::f32::consts::FRAC_1_SQRT_2,
+        )
+    } else {
+        let r = r.deg2rad();
+        (math::sin_f32(r), math::cos_f32(r))
+    }
+}
+
+/// Computes the sine and cosine of a reduced angle in half-turns.
+///
+/// # Arguments
+///
+/// * `r` - Reduced angle, $|r|\leq\frac{1}{4}$ \[half-turns\].
+///
+/// # Returns
+///
+/// Sine and cosine of $\pi r$, with the values at $\pm\frac{1}{4}$ half-turns returned
+/// correctly rounded.
+#[cfg(not(feature = "correctly-rounded"))]
+fn sincospi_reduced(r: f32) -> (f32, f32) {
+    if r.abs() == 0.25 {
+        (
+            core::f32::consts::FRAC_1_SQRT_2.copysign(r),
+            core::f32::consts::FRAC_1_SQRT_2,
+        )
+    } else {
+        let r = r * core::f32::consts::PI;
+        (math::sin_f32(r), math::cos_f32(r))
     }
 }
 
@@ -131,17 +561,43 @@ mod test {
 
     #[test]
     fn test_sin() {
-        assert_eq!(std::f32::consts::FRAC_PI_2.sin(), 1.0);
+        assert_eq!(Trig::sin(&std::f32::consts::FRAC_PI_2), 1.0);
     }
 
     #[test]
     fn test_cos() {
-        assert_eq!((2.0 * std::f32::consts::PI).cos(), 1.0);
+        assert_eq!(Trig::cos(&(2.0 * std::f32::consts::PI)), 1.0);
     }
 
     #[test]
     fn test_tan() {
-        assert_eq!(std::f32::consts::FRAC_PI_4.tan(), 1.0);
+        assert_eq!(Trig::tan(&std::f32::consts::FRAC_PI_4), 1.0);
+    }
+
+    #[test]
+    fn test_sin_cos_tan_huge_arguments() {
+        // Reference values computed with 3000-bit arithmetic.
+        for (x, sin_ref, cos_ref, tan_ref) in [
+            (1e10, -0.48750603, 0.87311965, -0.5583496),
+            (1e30, -0.79116344, -0.6116048, 1.2935861),
+            (-7.5e20, 0.9666904, 0.25594854, 3.7768934),
+            (f32::MAX, -0.5218765, 0.853021, -0.6117979),
+        ] {
+            assert_equal_to_rtol!(Trig::sin(&x), sin_ref, f32::EPSILON);
+            assert_equal_to_rtol!(Trig::cos(&x), cos_ref, f32::EPSILON);
+            assert_equal_to_rtol!(Trig::tan(&x), tan_ref, f32::EPSILON);
+            assert_equal_to_rtol!(x.sincos().0, sin_ref, f32::EPSILON);
+            assert_equal_to_rtol!(x.sincos().1, cos_ref, f32::EPSILON);
+        }
+    }
+
+    #[test]
+    fn test_sin_cos_tan_non_finite() {
+        assert!(Trig::sin(&f32::INFINITY).is_nan());
+        assert!(Trig::cos(&f32::NEG_INFINITY).is_nan());
+        assert!(Trig::tan(&f32::NAN).is_nan());
+        assert!(Trig::sin(&-0.0_f32).is_sign_negative());
+        assert!(Trig::tan(&-0.0_f32).is_sign_negative());
     }
 
     #[test]
@@ -156,7 +612,30 @@ mod test {
 
     #[test]
     fn test_cot() {
+        #[cfg(not(feature = "correctly-rounded"))]
         assert_eq!(std::f32::consts::FRAC_PI_4.cot(), 1.0);
+        #[cfg(feature = "correctly-rounded")]
+        assert_eq!(std::f32::consts::FRAC_PI_4.cot(), 0.999_999_94);
+    }
+
+    #[test]
+    fn test_sincos() {
+        for x in [
+            0.0,
+            -0.0,
+            0.5,
+            -1.0,
+            2.0,
+            3.0,
+            -10.0,
+            1e3,
+            1e6,
+            1e9,
+            f32::MAX,
+        ] {
+            assert_eq!(x.sincos(), (Trig::sin(&x), Trig::cos(&x)));
+        }
+        assert_eq!(std::f32::consts::FRAC_PI_2.sincos().0, 1.0);
     }
 
     #[test]
@@ -166,6 +645,7 @@ mod test {
             std::f32::consts::FRAC_PI_2,
             1e-6
         );
+        assert_eq!(Trig::asin(&-1.0_f32), -std::f32::consts::FRAC_PI_2);
     }
 
     #[test]
@@ -174,11 +654,15 @@ mod test {
             std::f32::consts::FRAC_PI_4.cos().acos(),
             std::f32::consts::FRAC_PI_4
         );
+        assert_eq!(Trig::acos(&-1.0_f32), std::f32::consts::PI);
+        assert_eq!(Trig::acos(&0.0_f32), std::f32::consts::FRAC_PI_2);
     }
 
     #[test]
     fn test_atan() {
         assert_eq!(1.0_f32.tan().atan(), 1.0_f32);
+        assert_eq!(Trig::atan(&f32::INFINITY), std::f32::consts::FRAC_PI_2);
+        assert_eq!(Trig::atan2(&-1.0_f32, &0.0), -std::f32::consts::FRAC_PI_2);
     }
 
     #[test]
@@ -208,10 +692,16 @@ mod test {
 
     #[test]
     fn test_asec() {
+        #[cfg(not(feature = "correctly-rounded"))]
         assert_eq!(
             std::f32::consts::FRAC_PI_4.sec().asec(),
             std::f32::consts::FRAC_PI_4
         );
+        #[cfg(feature = "correctly-rounded")]
+        assert_eq!(
+            std::f32::consts::FRAC_PI_4.sec().asec(),
+            std::f32::consts::FRAC_PI_4.next_down()
+        );
     }
 
     #[test]
@@ -219,6 +709,52 @@ mod test {
         assert_eq!(1.0_f32.cot().acot(), 1.0_f32);
     }
 
+    #[test]
+    fn test_acsc_edges() {
+        assert_eq!(1.0_f32.acsc(), std::f32::consts::FRAC_PI_2);
+        assert_eq!((-1.0_f32).acsc(), -std::f32::consts::FRAC_PI_2);
+        assert_equal_to_rtol!(
+            (1.0_f32 + f32::EPSILON).acsc(),
+            1.5703081,
+            2.0 * f32::EPSILON
+        );
+        assert_equal_to_rtol!(1e30_f32.acsc(), 1e-30, 2.0 * f32::EPSILON);
+        assert_equal_to_rtol!((-1e30_f32).acsc(), -1e-30, 2.0 * f32::EPSILON);
+        assert_eq!(f32::INFINITY.acsc(), 0.0);
+        assert!(0.5_f32.acsc().is_nan());
+    }
+
+    #[test]
+    fn test_asec_edges() {
+        assert_eq!(1.0_f32.asec(), 0.0);
+        assert_eq!((-1.0_f32).asec(), std::f32::consts::PI);
+        assert_equal_to_rtol!(
+            (1.0_f32 + f32::EPSILON).asec(),
+            0.00048828122,
+            2.0 * f32::EPSILON
+        );
+        assert_equal_to_rtol!(
+            (-1.0_f32 - f32::EPSILON).asec(),
+            3.1411045,
+            2.0 * f32::EPSILON
+        );
+        assert_equal_to_rtol!(
+            1e30_f32.asec(),
+            std::f32::consts::FRAC_PI_2,
+            2.0 * f32::EPSILON
+        );
+        assert_eq!(f32::NEG_INFINITY.asec(), std::f32::consts::FRAC_PI_2);
+        assert!((-0.5_f32).asec().is_nan());
+    }
+
+    #[test]
+    fn test_acot_edges() {
+        assert_eq!(0.0_f32.acot(), std::f32::consts::FRAC_PI_2);
+        assert_eq!(1e-45_f32.acot(), std::f32::consts::FRAC_PI_2);
+        assert_equal_to_rtol!((-1e30_f32).acot(), -1e-30, 2.0 * f32::EPSILON);
+        assert_eq!(f32::INFINITY.acot(), 0.0);
+    }
+
     #[test]
     fn test_deg2rad() {
         assert_eq!(30.0_f32.deg2rad(), std::f32::consts::FRAC_PI_6);
@@ -259,19 +795,87 @@ mod test {
         assert_eq!(45.0_f32.cotd(), 1.0);
     }
 
+    #[test]
+    fn test_sind_exact_multiples() {
+        assert_eq!(180.0_f32.sind(), 0.0);
+        assert_eq!((-90.0_f32).sind(), -1.0);
+        assert_eq!(30.0_f32.sind(), 0.5);
+        assert_eq!(150.0_f32.sind(), 0.5);
+        assert_eq!((-210.0_f32).sind(), 0.5);
+        assert_eq!(45.0_f32.sind(), std::f32::consts::FRAC_1_SQRT_2);
+        assert!((-180.0_f32).sind().is_sign_negative());
+    }
+
+    #[test]
+    fn test_cosd_exact_multiples() {
+        assert_eq!(90.0_f32.cosd(), 0.0);
+        assert_eq!(270.0_f32.cosd(), 0.0);
+        assert_eq!(180.0_f32.cosd(), -1.0);
+        assert_eq!(60.0_f32.cosd(), 0.5);
+        assert_eq!((-120.0_f32).cosd(), -0.5);
+        assert_eq!(45.0_f32.cosd(), 45.0_f32.sind());
+        assert_eq!(30.0_f32.cosd(), 3.0_f32.sqrt() / 2.0);
+        assert_eq!((-390.0_f32).cosd(), 3.0_f32.sqrt() / 2.0);
+        assert_eq!(120.0_f32.sind(), 3.0_f32.sqrt() / 2.0);
+    }
+
+    #[test]
+    fn test_tand_exact_multiples() {
+        assert_eq!(180.0_f32.tand(), 0.0);
+        assert_eq!(135.0_f32.tand(), -1.0);
+        assert_eq!((-45.0_f32).tand(), -1.0);
+        assert_eq!(90.0_f32.tand(), f32::INFINITY);
+        assert_eq!((-90.0_f32).tand(), f32::NEG_INFINITY);
+        assert_eq!(270.0_f32.tand(), f32::NEG_INFINITY);
+        assert_eq!(90.0_f32.cotd(), 0.0);
+    }
+
+    #[test]
+    fn test_degree_functions_huge_argument() {
+        // 1e22 and f32::MAX are congruent to 176 and 0 degrees, respectively.
+        assert_eq!(1e22_f32.sind(), 176.0_f32.sind());
+        assert_eq!(1e22_f32.cosd(), 176.0_f32.cosd());
+        assert_eq!(f32::MAX.sind(), 0.0_f32.sind());
+        assert_eq!(f32::MAX.cosd(), 0.0_f32.cosd());
+    }
+
+    #[test]
+    fn test_degree_functions_non_finite() {
+        assert!(f32::INFINITY.sind().is_nan());
+        assert!(f32::NEG_INFINITY.cosd().is_nan());
+        assert!(f32::NAN.tand().is_nan());
+    }
+
+    #[test]
+    fn test_sincosd() {
+        for x in [
+            0.0, -0.0, 30.0, 45.0, -60.0, 90.0, 135.0, 180.0, -270.0, 1234.5, 1e22_f32,
+        ] {
+            assert_eq!(x.sincosd(), (x.sind(), x.cosd()));
+        }
+        assert_eq!(90.0_f32.sincosd(), (1.0, 0.0));
+        assert!((-0.0_f32).sincosd().0.is_sign_negative());
+    }
+
     #[test]
     fn test_asind() {
         assert_equal_to_atol!(90.0_f32.sind().asind(), 90.0_f32, 1e-5);
+        assert_eq!((-1.0_f32).asind(), -90.0);
     }
 
     #[test]
     fn test_acosd() {
         assert_eq!(45.0_f32.cosd().acosd(), 45.0_f32);
+        assert_eq!(1.0_f32.acosd(), 0.0);
+        assert_eq!(0.0_f32.acosd(), 90.0);
+        assert_eq!((-1.0_f32).acosd(), 180.0);
     }
 
     #[test]
     fn test_atand() {
-        assert_eq!(30.0_f32.tand().atand(), 30.0_f32);
+        assert_eq!(3.0_f32.sqrt().recip().atand(), 30.0_f32);
+        assert_eq!(f32::INFINITY.atand(), 90.0);
+        assert_eq!(f32::NEG_INFINITY.atand(), -90.0);
     }
 
     #[test]
@@ -290,6 +894,11 @@ mod test {
         assert_eq!(y.atan2d(&x), angle_expected);
     }
 
+    #[test]
+    fn test_atan2d_tiny_ratio() {
+        assert_eq!(1e-40_f32.atan2d(&1e-3), 5.7295467e-36_f32);
+    }
+
     #[test]
     fn test_acscd() {
         assert_equal_to_atol!(90.0_f32.cscd().acscd(), 90.0_f32, 1e-5);
@@ -302,7 +911,74 @@ mod test {
 
     #[test]
     fn test_acotd() {
-        assert_eq!(30.0_f32.cotd().acotd(), 30.0_f32);
+        // √3 rounds to the f32 nearest the cotangent of 30°.
+        assert_eq!(3.0_f32.sqrt().acotd(), 30.0_f32);
+    }
+
+    #[test]
+    fn test_sinpi() {
+        assert_eq!(0.5_f32.sinpi(), 1.0);
+        assert_eq!(1.0_f32.sinpi(), 0.0);
+        assert_eq!((-1.5_f32).sinpi(), 1.0);
+        assert_eq!(0.25_f32.sinpi(), std::f32::consts::FRAC_1_SQRT_2);
+        assert!(1.0_f32.sinpi().is_sign_positive());
+        assert!((-1.0_f32).sinpi().is_sign_negative());
+        assert_eq!(1e20_f32.sinpi(), 0.0);
+    }
+
+    #[test]
+    fn test_cospi() {
+        assert_eq!(0.0_f32.cospi(), 1.0);
+        assert_eq!(0.5_f32.cospi(), 0.0);
+        assert_eq!(1.0_f32.cospi(), -1.0);
+        assert_eq!((-2.5_f32).cospi(), 0.0);
+        assert_eq!(0.25_f32.cospi(), 0.25_f32.sinpi());
+        assert_eq!(3.0e7_f32.cospi(), 1.0);
+    }
+
+    #[test]
+    fn test_tanpi() {
+        assert_eq!(0.25_f32.tanpi(), 1.0);
+        assert_eq!((-0.25_f32).tanpi(), -1.0);
+        assert_eq!(0.75_f32.tanpi(), -1.0);
+        assert_eq!(1.0_f32.tanpi(), 0.0);
+        assert_eq!(0.5_f32.tanpi(), f32::INFINITY);
+        assert_eq!((-0.5_f32).tanpi(), f32::NEG_INFINITY);
+    }
+
+    #[test]
+    fn test_asinpi() {
+        assert_eq!(1.0_f32.asinpi(), 0.5);
+        assert_eq!((-1.0_f32).asinpi(), -0.5);
+        assert_eq!(0.0_f32.asinpi(), 0.0);
+    }
+
+    #[test]
+    fn test_acospi() {
+        assert_eq!(1.0_f32.acospi(), 0.0);
+        assert_eq!(0.0_f32.acospi(), 0.5);
+        assert_eq!((-1.0_f32).acospi(), 1.0);
+    }
+
+    #[test]
+    fn test_atanpi() {
+        assert_eq!(1.0_f32.atanpi(), 0.25);
+        assert_eq!(f32::INFINITY.atanpi(), 0.5);
+        assert_eq!(f32::NEG_INFINITY.atanpi(), -0.5);
+    }
+
+    #[test]
+    fn test_atan2pi_45_deg_clockwise() {
+        let x = 3.0_f32;
+        let y = -3.0_f32;
+        assert_eq!(y.atan2pi(&x), -0.25);
+    }
+
+    #[test]
+    fn test_atan2pi_180_deg_counterclockwise() {
+        let x = -3.0_f32;
+        let y = 0.0_f32;
+        assert_eq!(y.atan2pi(&x), 1.0);
     }
 
     #[test]
@@ -343,6 +1019,21 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_sinhcosh() {
+        for x in [0.0, 1e-10, 0.3, -1.0, 5.0, 8.9, 20.0, -80.0, 88.5, 89.4] {
+            let (s, c) = x.sinhcosh();
+            assert_equal_to_rtol!(s, x.sinh(), 2.0 * f32::EPSILON);
+            assert_equal_to_rtol!(c, x.cosh(), 2.0 * f32::EPSILON);
+        }
+        assert_eq!(0.0_f32.sinhcosh(), (0.0, 1.0));
+        assert_eq!(f32::INFINITY.sinhcosh(), (f32::INFINITY, f32::INFINITY));
+        assert_eq!(
+            f32::NEG_INFINITY.sinhcosh(),
+            (f32::NEG_INFINITY, f32::INFINITY)
+        );
+    }
+
     #[test]
     fn test_asinh() {
         assert_equal_to_atol!(1.0_f32.sinh().asinh(), 1.0, 1e-7);
@@ -372,4 +1063,43 @@ mod test {
     fn test_acoth() {
         assert_equal_to_atol!(1.5_f32.coth().acoth(), 1.5, 1e-6);
     }
+
+    #[test]
+    fn test_acsch_edges() {
+        assert_equal_to_rtol!(1e-45_f32.acsch(), 103.97208, 2.0 * f32::EPSILON);
+        assert_equal_to_rtol!(1e30_f32.acsch(), 1e-30, 2.0 * f32::EPSILON);
+        assert_equal_to_rtol!((-0.75_f32).acsch(), -1.0986123, 2.0 * f32::EPSILON);
+        assert_eq!(0.0_f32.acsch(), f32::INFINITY);
+        assert_eq!((-0.0_f32).acsch(), f32::NEG_INFINITY);
+        assert_eq!(f32::INFINITY.acsch(), 0.0);
+    }
+
+    #[test]
+    fn test_asech_edges() {
+        assert_eq!(1.0_f32.asech(), 0.0);
+        assert_equal_to_rtol!(
+            (1.0_f32 - f32::EPSILON / 2.0).asech(),
+            0.00034526698,
+            2.0 * f32::EPSILON
+        );
+        assert_equal_to_rtol!(1e-40_f32.asech(), 92.796555, 2.0 * f32::EPSILON);
+        assert_equal_to_rtol!(0.75_f32.asech(), 0.79536545, 2.0 * f32::EPSILON);
+        assert_eq!(0.0_f32.asech(), f32::INFINITY);
+        assert!(1.5_f32.asech().is_nan());
+        assert!((-0.5_f32).asech().is_nan());
+    }
+
+    #[test]
+    fn test_acoth_edges() {
+        assert_equal_to_rtol!(
+            (1.0_f32 + f32::EPSILON).acoth(),
+            8.317766,
+            2.0 * f32::EPSILON
+        );
+        assert_equal_to_rtol!((-1e30_f32).acoth(), -1e-30, 2.0 * f32::EPSILON);
+        assert_equal_to_rtol!(1.5_f32.acoth(), 0.804719, 2.0 * f32::EPSILON);
+        assert_eq!(1.0_f32.acoth(), f32::INFINITY);
+        assert_eq!((-1.0_f32).acoth(), f32::NEG_INFINITY);
+        assert!(0.5_f32.acoth().is_nan());
+    }
 }
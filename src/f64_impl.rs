@@ -1,14 +1,38 @@
+#[cfg(feature = "correctly-rounded")]
+use crate::correctly_rounded;
+#[cfg(not(feature = "correctly-rounded"))]
+use crate::double_double::{atan2d_small, pow2};
+#[cfg(not(feature = "correctly-rounded"))]
+use crate::double_f64::DoubleF64;
+use crate::math;
+#[cfg(not(feature = "correctly-rounded"))]
+use crate::rem_pio2::{cos_reduced, rem_pio2, sin_reduced, sincos_reduced, tan_reduced};
 use crate::trig_trait::Trig;
 
+#[cfg(not(feature = "correctly-rounded"))]
 impl Trig for f64 {
     fn sin(&self) -> f64 {
-        f64::sin(*self)
+        let (q, hi, lo) = rem_pio2(*self);
+        match q {
+            0 => sin_reduced(hi, lo),
+            1 => cos_reduced(hi, lo),
+            2 => -sin_reduced(hi, lo),
+            _ => -cos_reduced(hi, lo),
+        }
     }
     fn cos(&self) -> f64 {
-        f64::cos(*self)
+        let (q, hi, lo) = rem_pio2(*self);
+        match q {
+            0 => cos_reduced(hi, lo),
+            1 => -sin_reduced(hi, lo),
+            2 => -cos_reduced(hi, lo),
+            _ => sin_reduced(hi, lo),
+        }
     }
     fn tan(&self) -> f64 {
-        f64::tan(*self)
+        let (q, hi, lo) = rem_pio2(*self);
+        let t = tan_reduced(hi, lo);
+        if q % 2 == 0 { t } else { -1.0 / t }
     }
     fn csc(&self) -> f64 {
         1.0 / self.sin()
@@ -19,50 +43,128 @@ impl Trig for f64 {
     fn cot(&self) -> f64 {
         1.0 / self.tan()
     }
+    fn sincos(&self) -> (f64, f64) {
+        let (q, hi, lo) = rem_pio2(*self);
+        let (s, c) = sincos_reduced(hi, lo);
+        match q {
+            0 => (s, c),
+            1 => (c, -s),
+            2 => (-s, -c),
+            _ => (-c, s),
+        }
+    }
     fn asin(&self) -> f64 {
-        f64::asin(*self)
+        math::asin(*self)
     }
     fn acos(&self) -> f64 {
-        f64::acos(*self)
+        math::acos(*self)
     }
     fn atan(&self) -> f64 {
-        f64::atan(*self)
+        math::atan(*self)
     }
     fn atan2(&self, other: &f64) -> f64 {
-        f64::atan2(*self, *other)
+        math::atan2(*self, *other)
     }
     fn acsc(&self) -> f64 {
-        (1.0 / self).asin()
+        if self.abs() < 1e150 {
+            DoubleF64::from_f64(*self).acsc().to_f64()
+        } else {
+            // The result is 1/x to within rounding, and may be subnormal.
+            math::atan2(1.0_f64.copysign(*self), self.abs())
+        }
     }
     fn asec(&self) -> f64 {
-        (1.0 / self).acos()
+        DoubleF64::from_f64(*self).asec().to_f64()
     }
     fn acot(&self) -> f64 {
-        (1.0 / self).atan()
+        if self.abs() < 1e150 {
+            DoubleF64::from_f64(*self).acot().to_f64()
+        } else {
+            // The result is 1/x to within rounding, and may be subnormal.
+            math::atan2(1.0_f64.copysign(*self), self.abs())
+        }
     }
     fn deg2rad(&self) -> f64 {
-        self * (std::f64::consts::PI / 180.0)
+        self * (core::f64::consts::PI / 180.0)
     }
     fn rad2deg(&self) -> f64 {
-        self * (180.0 / std::f64::consts::PI)
+        self * (180.0 / core::f64::consts::PI)
     }
     fn sind(&self) -> f64 {
-        self.deg2rad().sin()
+        let (q, r) = reduce_turn(self.abs(), 90.0);
+        let (s, c) = sincosd_reduced(r);
+        let f = match q {
+            0 => s,
+            1 => c,
+            2 => -s,
+            _ => -c,
+        };
+
+        // Adding zero turns an exact -0 into +0 so that the sign of a zero result follows `self`.
+        if self.is_sign_negative() {
+            -(f + 0.0)
+        } else {
+            f + 0.0
+        }
     }
     fn cosd(&self) -> f64 {
-        self.deg2rad().cos()
+        let (q, r) = reduce_turn(self.abs(), 90.0);
+        let (s, c) = sincosd_reduced(r);
+        let f = match q {
+            0 => c,
+            1 => -s,
+            2 => -c,
+            _ => s,
+        };
+        f + 0.0
     }
     fn tand(&self) -> f64 {
-        self.deg2rad().tan()
+        let (q, r) = reduce_turn(self.abs(), 90.0);
+        let t = if r.abs() == 45.0 {
+            1.0_f64.copysign(r)
+        } else {
+            math::tan(r.deg2rad())
+        };
+        let f = if q % 2 == 0 {
+            t
+        } else if r == 0.0 {
+            if q == 1 {
+                f64::INFINITY
+            } else {
+                f64::NEG_INFINITY
+            }
+        } else {
+            -1.0 / t
+        };
+        if self.is_sign_negative() {
+            -(f + 0.0)
+        } else {
+            f + 0.0
+        }
     }
     fn cscd(&self) -> f64 {
-        self.deg2rad().csc()
+        1.0 / self.sind()
     }
     fn secd(&self) -> f64 {
-        self.deg2rad().sec()
+        1.0 / self.cosd()
     }
     fn cotd(&self) -> f64 {
-        self.deg2rad().cot()
+        1.0 / self.tand()
+    }
+    fn sincosd(&self) -> (f64, f64) {
+        let (q, r) = reduce_turn(self.abs(), 90.0);
+        let (s, c) = sincosd_reduced(r);
+        let (s, c) = match q {
+            0 => (s, c),
+            1 => (c, -s),
+            2 => (-s, -c),
+            _ => (-c, s),
+        };
+        if self.is_sign_negative() {
+            (-(s + 0.0), c + 0.0)
+        } else {
+            (s + 0.0, c + 0.0)
+        }
     }
     fn asind(&self) -> f64 {
         self.asin().rad2deg()
@@ -74,25 +176,100 @@ impl Trig for f64 {
         self.atan().rad2deg()
     }
     fn atan2d(&self, other: &f64) -> f64 {
-        self.atan2(other).rad2deg()
+        match atan2d_small(*self, *other) {
+            Some(angle) => angle,
+            None => self.atan2(other).rad2deg(),
+        }
     }
     fn acscd(&self) -> f64 {
-        self.acsc().rad2deg()
+        if self.abs() < 1e150 {
+            DoubleF64::from_f64(*self).acscd().to_f64()
+        } else {
+            // The result is 180/(πx) to within rounding.
+            reciprocal_degrees(*self)
+        }
     }
     fn asecd(&self) -> f64 {
-        self.asec().rad2deg()
+        DoubleF64::from_f64(*self).asecd().to_f64()
     }
     fn acotd(&self) -> f64 {
-        self.acot().rad2deg()
+        if self.abs() < 1e150 {
+            DoubleF64::from_f64(*self).acotd().to_f64()
+        } else {
+            // The result is 180/(πx) to within rounding.
+            reciprocal_degrees(*self)
+        }
+    }
+    fn sinpi(&self) -> f64 {
+        let (q, r) = reduce_turn(self.abs(), 0.5);
+        let (s, c) = sincospi_reduced(r);
+        let f = match q {
+            0 => s,
+            1 => c,
+            2 => -s,
+            _ => -c,
+        };
+        if self.is_sign_negative() {
+            -(f + 0.0)
+        } else {
+            f + 0.0
+        }
+    }
+    fn cospi(&self) -> f64 {
+        let (q, r) = reduce_turn(self.abs(), 0.5);
+        let (s, c) = sincospi_reduced(r);
+        let f = match q {
+            0 => c,
+            1 => -s,
+            2 => -c,
+            _ => s,
+        };
+        f + 0.0
+    }
+    fn tanpi(&self) -> f64 {
+        let (q, r) = reduce_turn(self.abs(), 0.5);
+        let t = if r.abs() == 0.25 {
+            1.0_f64.copysign(r)
+        } else {
+            math::tan(r * core::f64::consts::PI)
+        };
+        let f = if q % 2 == 0 {
+            t
+        } else if r == 0.0 {
+            if q == 1 {
+                f64::INFINITY
+            } else {
+                f64::NEG_INFINITY
+            }
+        } else {
+            -1.0 / t
+        };
+        if self.is_sign_negative() {
+            -(f + 0.0)
+        } else {
+            f + 0.0
+        }
+    }
+    fn asinpi(&self) -> f64 {
+        self.asin() / core::f64::consts::PI
+    }
+    fn acospi(&self) -> f64 {
+        self.acos() / core::f64::consts::PI
+    }
+    fn atanpi(&self) -> f64 {
+        self.atan() / core::f64::consts::PI
+    }
+    fn atan2pi(&self, other: &f64) -> f64 {
+        self.atan2(other) / core::f64::consts::PI
     }
     fn sinh(&self) -> f64 {
-        f64::sinh(*self)
+        math::sinh(*self)
     }
     fn cosh(&self) -> f64 {
-        f64::cosh(*self)
+        math::cosh(*self)
     }
     fn tanh(&self) -> f64 {
-        f64::tanh(*self)
+        math::tanh(*self)
     }
     fn csch(&self) -> f64 {
         1.0 / self.sinh()
@@ -101,25 +278,309 @@ impl Trig for f64 {
         1.0 / self.cosh()
     }
     fn coth(&self) -> f64 {
-        1.0 / self.tanh()
+        let x = self.abs();
+        let f = if x > 0.55 {
+            // coth(x) = 1 + 2 / (e^(2x) - 1), where the correction is below 1, so that its
+            // rounding error is only a fraction of an ulp of the result.
+            1.0 + 2.0 / math::exp_m1(2.0 * x)
+        } else {
+            1.0 / math::tanh(x)
+        };
+        f.copysign(*self)
+    }
+    fn sinhcosh(&self) -> (f64, f64) {
+        let x = self.abs();
+        let (s, c) = if x < 22.0 {
+            // sinh(x) = (e^x - 1 + (e^x - 1) / e^x) / 2 avoids cancellation for small x.
+            let em1 = math::exp_m1(x);
+            let e = em1 + 1.0;
+            (0.5 * (em1 + em1 / e), 0.5 * (e + 1.0 / e))
+        } else if x < 709.0 {
+            // e^(-x) is negligible compared to e^x.
+            let h = 0.5 * math::exp(x);
+            (h, h)
+        } else {
+            // Split e^x into two factors so that it does not overflow before being halved.
+            let h = math::exp(0.5 * x);
+            let h = (0.5 * h) * h;
+            (h, h)
+        };
+        (s.copysign(*self), c)
     }
     fn asinh(&self) -> f64 {
-        f64::asinh(*self)
+        math::asinh(*self)
     }
     fn acosh(&self) -> f64 {
-        f64::acosh(*self)
+        math::acosh(*self)
     }
     fn atanh(&self) -> f64 {
-        f64::atanh(*self)
+        math::atanh(*self)
     }
     fn acsch(&self) -> f64 {
-        (1.0 / self).asinh()
+        let x = self.abs();
+        let f = if x < 1e-150 {
+            // ln((1 + sqrt(1 + x²)) / x) = ln(2) - ln(x), since 1 / x could overflow.
+            core::f64::consts::LN_2 - math::ln(x)
+        } else {
+            // ln(1 + u) with u = (1 + sqrt(1 + x²)) / x - 1 = (1 + 1 / (x + sqrt(x² + 1))) / x.
+            math::ln_1p((1.0 + 1.0 / (x + math::hypot(x, 1.0))) / x)
+        };
+        f.copysign(*self)
     }
     fn asech(&self) -> f64 {
-        (1.0 / self).acosh()
+        // ln((1 + sqrt(1 - x²)) / x), split so that 1 / x cannot overflow.
+        math::ln_1p(math::sqrt((1.0 - self) * (1.0 + self))) - math::ln(*self)
     }
     fn acoth(&self) -> f64 {
-        (1.0 / self).atanh()
+        // (1/2)ln((x + 1) / (x - 1)) = (1/2)ln(1 + 2 / (x - 1)), where x - 1 is exact near x = 1.
+        (0.5 * math::ln_1p(2.0 / (self.abs() - 1.0))).copysign(*self)
+    }
+}
+
+#[cfg(feature = "correctly-rounded")]
+impl Trig for f64 {
+    fn sin(&self) -> f64 {
+        correctly_rounded::sin(*self, f64::MANTISSA_DIGITS)
+    }
+    fn cos(&self) -> f64 {
+        correctly_rounded::cos(*self, f64::MANTISSA_DIGITS)
+    }
+    fn tan(&self) -> f64 {
+        correctly_rounded::tan(*self, f64::MANTISSA_DIGITS)
+    }
+    fn csc(&self) -> f64 {
+        correctly_rounded::csc(*self, f64::MANTISSA_DIGITS)
+    }
+    fn sec(&self) -> f64 {
+        correctly_rounded::sec(*self, f64::MANTISSA_DIGITS)
+    }
+    fn cot(&self) -> f64 {
+        correctly_rounded::cot(*self, f64::MANTISSA_DIGITS)
+    }
+    fn sincos(&self) -> (f64, f64) {
+        correctly_rounded::sincos(*self, f64::MANTISSA_DIGITS)
+    }
+    fn asin(&self) -> f64 {
+        correctly_rounded::asin(*self, f64::MANTISSA_DIGITS)
+    }
+    fn acos(&self) -> f64 {
+        correctly_rounded::acos(*self, f64::MANTISSA_DIGITS)
+    }
+    fn atan(&self) -> f64 {
+        correctly_rounded::atan(*self, f64::MANTISSA_DIGITS)
+    }
+    fn atan2(&self, other: &f64) -> f64 {
+        correctly_rounded::atan2(*self, *other, f64::MANTISSA_DIGITS)
+    }
+    fn acsc(&self) -> f64 {
+        correctly_rounded::acsc(*self, f64::MANTISSA_DIGITS)
+    }
+    fn asec(&self) -> f64 {
+        correctly_rounded::asec(*self, f64::MANTISSA_DIGITS)
+    }
+    fn acot(&self) -> f64 {
+        correctly_rounded::acot(*self, f64::MANTISSA_DIGITS)
+    }
+    fn deg2rad(&self) -> f64 {
+        correctly_rounded::deg2rad(*self, f64::MANTISSA_DIGITS)
+    }
+    fn rad2deg(&self) -> f64 {
+        correctly_rounded::rad2deg(*self, f64::MANTISSA_DIGITS)
+    }
+    fn sind(&self) -> f64 {
+        correctly_rounded::sind(*self, f64::MANTISSA_DIGITS)
+    }
+    fn cosd(&self) -> f64 {
+        correctly_rounded::cosd(*self, f64::MANTISSA_DIGITS)
+    }
+    fn tand(&self) -> f64 {
+        correctly_rounded::tand(*self, f64::MANTISSA_DIGITS)
+    }
+    fn cscd(&self) -> f64 {
+        correctly_rounded::cscd(*self, f64::MANTISSA_DIGITS)
+    }
+    fn secd(&self) -> f64 {
+        correctly_rounded::secd(*self, f64::MANTISSA_DIGITS)
+    }
+    fn cotd(&self) -> f64 {
+        correctly_rounded::cotd(*self, f64::MANTISSA_DIGITS)
+    }
+    fn sincosd(&self) -> (f64, f64) {
+        correctly_rounded::sincosd(*self, f64::MANTISSA_DIGITS)
+    }
+    fn asind(&self) -> f64 {
+        correctly_rounded::asind(*self, f64::MANTISSA_DIGITS)
+    }
+    fn acosd(&self) -> f64 {
+        correctly_rounded::acosd(*self, f64::MANTISSA_DIGITS)
+    }
+    fn atand(&self) -> f64 {
+        correctly_rounded::atand(*self, f64::MANTISSA_DIGITS)
+    }
+    fn atan2d(&self, other: &f64) -> f64 {
+        correctly_rounded::atan2d(*self, *other, f64::MANTISSA_DIGITS)
+    }
+    fn acscd(&self) -> f64 {
+        correctly_rounded::acscd(*self, f64::MANTISSA_DIGITS)
+    }
+    fn asecd(&self) -> f64 {
+        correctly_rounded::asecd(*self, f64::MANTISSA_DIGITS)
+    }
+    fn acotd(&self) -> f64 {
+        correctly_rounded::acotd(*self, f64::MANTISSA_DIGITS)
+    }
+    fn sinpi(&self) -> f64 {
+        correctly_rounded::sinpi(*self, f64::MANTISSA_DIGITS)
+    }
+    fn cospi(&self) -> f64 {
+        correctly_rounded::cospi(*self, f64::MANTISSA_DIGITS)
+    }
+    fn tanpi(&self) -> f64 {
+        correctly_rounded::tanpi(*self, f64::MANTISSA_DIGITS)
+    }
+    fn asinpi(&self) -> f64 {
+        correctly_rounded::asinpi(*self, f64::MANTISSA_DIGITS)
+    }
+    fn acospi(&self) -> f64 {
+        correctly_rounded::acospi(*self, f64::MANTISSA_DIGITS)
+    }
+    fn atanpi(&self) -> f64 {
+        correctly_rounded::atanpi(*self, f64::MANTISSA_DIGITS)
+    }
+    fn atan2pi(&self, other: &f64) -> f64 {
+        correctly_rounded::atan2pi(*self, *other, f64::MANTISSA_DIGITS)
+    }
+    fn sinh(&self) -> f64 {
+        correctly_rounded::sinh(*self, f64::MANTISSA_DIGITS)
+    }
+    fn cosh(&self) -> f64 {
+        correctly_rounded::cosh(*self, f64::MANTISSA_DIGITS)
+    }
+    fn tanh(&self) -> f64 {
+        correctly_rounded::tanh(*self, f64::MANTISSA_DIGITS)
+    }
+    fn csch(&self) -> f64 {
+        correctly_rounded::csch(*self, f64::MANTISSA_DIGITS)
+    }
+    fn sech(&self) -> f64 {
+        correctly_rounded::sech(*self, f64::MANTISSA_DIGITS)
+    }
+    fn coth(&self) -> f64 {
+        correctly_rounded::coth(*self, f64::MANTISSA_DIGITS)
+    }
+    fn sinhcosh(&self) -> (f64, f64) {
+        correctly_rounded::sinhcosh(*self, f64::MANTISSA_DIGITS)
+    }
+    fn asinh(&self) -> f64 {
+        correctly_rounded::asinh(*self, f64::MANTISSA_DIGITS)
+    }
+    fn acosh(&self) -> f64 {
+        correctly_rounded::acosh(*self, f64::MANTISSA_DIGITS)
+    }
+    fn atanh(&self) -> f64 {
+        correctly_rounded::atanh(*self, f64::MANTISSA_DIGITS)
+    }
+    fn acsch(&self) -> f64 {
+        correctly_rounded::acsch(*self, f64::MANTISSA_DIGITS)
+    }
+    fn asech(&self) -> f64 {
+        correctly_rounded::asech(*self, f64::MANTISSA_DIGITS)
+    }
+    fn acoth(&self) -> f64 {
+        correctly_rounded::acoth(*self, f64::MANTISSA_DIGITS)
+    }
+}
+
+/// Computes $\frac{180}{\pi x}$ for a large value.
+///
+/// # Arguments
+///
+/// * `x` - Value, $|x|\geq10^{150}$ (or `NaN`).
+///
+/// # Returns
+///
+/// $\frac{180}{\pi x}$ (with the sign of `x` for infinite `x`).
+///
+/// # Note
+///
+/// The quotient is evaluated in double-double precision with `x` scaled down by $2^{600}$ (exactly),
+/// which keeps the trailing part of the quotient out of the subnormal range.
+#[cfg(not(feature = "correctly-rounded"))]
+fn reciprocal_degrees(x: f64) -> f64 {
+    if x.is_infinite() {
+        return 0.0_f64.copysign(x);
+    }
+    let degrees = DoubleF64::from_f64(180.0) / DoubleF64::PI;
+    (degrees / DoubleF64::from_f64(x * pow2(-600))).to_f64() * pow2(-600)
+}
+
+/// Exactly reduces an angle modulo a full turn.
+///
+/// # Arguments
+///
+/// * `x` - Angle.
+/// * `quarter_turn` - A quarter turn in the units of `x` (e.g. `90.0` for degrees).
+///
+/// # Returns
+///
+/// Quadrant `q` (in $\{0,1,2,3\}$) and remainder `r` (with $|r|\leq$ `quarter_turn / 2`) such that
+/// `x` is equal to `q * quarter_turn + r` modulo a full turn.
+///
+/// # Note
+///
+/// Both the remainder and the subtraction of the quadrant are exact in binary floating point, so
+/// the reduction introduces no rounding error regardless of the magnitude of `x`.
+pub(crate) fn reduce_turn(x: f64, quarter_turn: f64) -> (u8, f64) {
+    let r = x % (4.0 * quarter_turn);
+    let n = math::round(r / quarter_turn);
+    ((n as i32).rem_euclid(4) as u8, r - n * quarter_turn)
+}
+
+/// Computes the sine and cosine of a reduced angle in degrees.
+///
+/// # Arguments
+///
+/// * `r` - Reduced angle, $|r|\leq45$ \[deg\].
+///
+/// # Returns
+///
+/// Sine and cosine of `r`, with the values at $\pm30$ and $\pm45$ degrees returned correctly
+/// rounded.
+#[cfg(not(feature = "correctly-rounded"))]
+fn sincosd_reduced(r: f64) -> (f64, f64) {
+    if r.abs() == 30.0 {
+        (0.5_f64.copysign(r), 0.8660254037844386_f64)
+    } else if r.abs() == 45.0 {
+        (
+            core::f64::consts::FRAC_1_SQRT_2.copysign(r),
+            core::f64::consts::FRAC_1_SQRT_2,
+        )
+    } else {
+        let r = r.deg2rad();
+        (math::sin(r), math::cos(r))
+    }
+}
+
+/// Computes the sine and cosine of a reduced angle in half-turns.
+///
+/// # Arguments
+///
+/// * `r` - Reduced angle, $|r|\leq\frac{1}{4}$ \[half-turns\].
+///
+/// # Returns
+///
+/// Sine and cosine of $\pi r$, with the values at $\pm\frac{1}{4}$ half-turns returned
+/// correctly rounded.
+#[cfg(not(feature = "correctly-rounded"))]
+fn sincospi_reduced(r: f64) -> (f64, f64) {
+    if r.abs() == 0.25 {
+        (
+            core::f64::consts::FRAC_1_SQRT_2.copysign(r),
+            core::f64::consts::FRAC_1_SQRT_2,
+        )
+    } else {
+        let r = r * core::f64::consts::PI;
+        (math::sin(r), math::cos(r))
     }
 }
 
@@ -131,17 +592,69 @@ mod test {
 
     #[test]
     fn test_sin() {
-        assert_eq!(std::f64::consts::FRAC_PI_2.sin(), 1.0);
+        assert_eq!(Trig::sin(&std::f64::consts::FRAC_PI_2), 1.0);
     }
 
     #[test]
     fn test_cos() {
-        assert_eq!((2.0 * std::f64::consts::PI).cos(), 1.0);
+        assert_eq!(Trig::cos(&(2.0 * std::f64::consts::PI)), 1.0);
     }
 
     #[test]
     fn test_tan() {
-        assert_equal_to_atol!(std::f64::consts::FRAC_PI_4.tan(), 1.0, 1e-15);
+        assert_equal_to_atol!(Trig::tan(&std::f64::consts::FRAC_PI_4), 1.0, 1e-15);
+    }
+
+    #[test]
+    fn test_sin_cos_tan_huge_arguments() {
+        // Reference values computed with 3000-bit arithmetic.
+        for (x, sin_ref, cos_ref, tan_ref) in [
+            (
+                1e22,
+                -0.8522008497671888,
+                0.523214785395139,
+                -1.6287782256068988,
+            ),
+            (
+                1e300,
+                -0.8178819121159085,
+                -0.5753861119575491,
+                1.4214488238747245,
+            ),
+            (
+                -3.4e100,
+                -0.33614440345646035,
+                0.941810458651262,
+                -0.3569130076744343,
+            ),
+            (
+                f64::MAX,
+                0.004961954789184062,
+                -0.9999876894265599,
+                -0.004962015874444895,
+            ),
+            (
+                6381956970095103.0 * 2f64.powi(797),
+                1.0,
+                -4.687165924254628e-19,
+                -2.133485385753704e18,
+            ),
+        ] {
+            assert_equal_to_rtol!(Trig::sin(&x), sin_ref, f64::EPSILON);
+            assert_equal_to_rtol!(Trig::cos(&x), cos_ref, f64::EPSILON);
+            assert_equal_to_rtol!(Trig::tan(&x), tan_ref, f64::EPSILON);
+            assert_equal_to_rtol!(x.sincos().0, sin_ref, f64::EPSILON);
+            assert_equal_to_rtol!(x.sincos().1, cos_ref, f64::EPSILON);
+        }
+    }
+
+    #[test]
+    fn test_sin_cos_tan_non_finite() {
+        assert!(Trig::sin(&f64::INFINITY).is_nan());
+        assert!(Trig::cos(&f64::NEG_INFINITY).is_nan());
+        assert!(Trig::tan(&f64::NAN).is_nan());
+        assert!(Trig::sin(&-0.0_f64).is_sign_negative());
+        assert!(Trig::tan(&-0.0_f64).is_sign_negative());
     }
 
     #[test]
@@ -159,6 +672,16 @@ mod test {
         assert_equal_to_atol!(std::f64::consts::FRAC_PI_4.cot(), 1.0, 1e-15);
     }
 
+    #[test]
+    fn test_sincos() {
+        for x in [
+            0.0, -0.0, 0.5, -1.0, 2.0, 3.0, -10.0, 1e3, 1e6, 1e10, 1e300_f64,
+        ] {
+            assert_eq!(x.sincos(), (Trig::sin(&x), Trig::cos(&x)));
+        }
+        assert_eq!(std::f64::consts::FRAC_PI_2.sincos().0, 1.0);
+    }
+
     #[test]
     fn test_asin() {
         assert_eq!(
@@ -206,9 +729,16 @@ mod test {
 
     #[test]
     fn test_asec() {
+        assert_eq!(
+            std::f64::consts::FRAC_PI_3.sec().asec(),
+            std::f64::consts::FRAC_PI_3
+        );
+
+        // sec(π/4) rounds to just below √2, whose correctly rounded inverse secant is the double
+        // just below π/4.
         assert_eq!(
             std::f64::consts::FRAC_PI_4.sec().asec(),
-            std::f64::consts::FRAC_PI_4
+            std::f64::consts::FRAC_PI_4.next_down()
         );
     }
 
@@ -217,6 +747,56 @@ mod test {
         assert_eq!(1.0_f64.cot().acot(), 1.0_f64);
     }
 
+    #[test]
+    fn test_acsc_edges() {
+        assert_eq!(1.0_f64.acsc(), std::f64::consts::FRAC_PI_2);
+        assert_eq!((-1.0_f64).acsc(), -std::f64::consts::FRAC_PI_2);
+        assert_equal_to_rtol!(
+            (1.0_f64 + f64::EPSILON).acsc(),
+            1.5707963057214724,
+            2.0 * f64::EPSILON
+        );
+        assert_equal_to_rtol!(1e300_f64.acsc(), 1e-300, 2.0 * f64::EPSILON);
+        assert_equal_to_rtol!(
+            (-1.7e308_f64).acsc(),
+            -5.88235294117647e-309,
+            2.0 * f64::EPSILON
+        );
+        assert_eq!(f64::INFINITY.acsc(), 0.0);
+        assert!(0.5_f64.acsc().is_nan());
+    }
+
+    #[test]
+    fn test_asec_edges() {
+        assert_eq!(1.0_f64.asec(), 0.0);
+        assert_eq!((-1.0_f64).asec(), std::f64::consts::PI);
+        assert_equal_to_rtol!(
+            (1.0_f64 + f64::EPSILON).asec(),
+            2.1073424255447014e-8,
+            2.0 * f64::EPSILON
+        );
+        assert_equal_to_rtol!(
+            (-1.0_f64 - f64::EPSILON).asec(),
+            3.1415926325163688,
+            2.0 * f64::EPSILON
+        );
+        assert_equal_to_rtol!(
+            1e300_f64.asec(),
+            std::f64::consts::FRAC_PI_2,
+            2.0 * f64::EPSILON
+        );
+        assert_eq!(f64::NEG_INFINITY.asec(), std::f64::consts::FRAC_PI_2);
+        assert!((-0.5_f64).asec().is_nan());
+    }
+
+    #[test]
+    fn test_acot_edges() {
+        assert_eq!(0.0_f64.acot(), std::f64::consts::FRAC_PI_2);
+        assert_eq!(5e-324_f64.acot(), std::f64::consts::FRAC_PI_2);
+        assert_equal_to_rtol!((-1e300_f64).acot(), -1e-300, 2.0 * f64::EPSILON);
+        assert_eq!(f64::INFINITY.acot(), 0.0);
+    }
+
     #[test]
     fn test_deg2rad() {
         assert_equal_to_atol!(30.0_f64.deg2rad(), std::f64::consts::FRAC_PI_6, 1e-15);
@@ -257,6 +837,68 @@ mod test {
         assert_equal_to_atol!(45.0_f64.cotd(), 1.0, 1e-15);
     }
 
+    #[test]
+    fn test_sind_exact_multiples() {
+        assert_eq!(180.0_f64.sind(), 0.0);
+        assert_eq!((-90.0_f64).sind(), -1.0);
+        assert_eq!(30.0_f64.sind(), 0.5);
+        assert_eq!(150.0_f64.sind(), 0.5);
+        assert_eq!((-210.0_f64).sind(), 0.5);
+        assert_eq!(45.0_f64.sind(), std::f64::consts::FRAC_1_SQRT_2);
+        assert!((-180.0_f64).sind().is_sign_negative());
+    }
+
+    #[test]
+    fn test_cosd_exact_multiples() {
+        assert_eq!(90.0_f64.cosd(), 0.0);
+        assert_eq!(270.0_f64.cosd(), 0.0);
+        assert_eq!(180.0_f64.cosd(), -1.0);
+        assert_eq!(60.0_f64.cosd(), 0.5);
+        assert_eq!((-120.0_f64).cosd(), -0.5);
+        assert_eq!(45.0_f64.cosd(), 45.0_f64.sind());
+        assert_eq!(30.0_f64.cosd(), 3.0_f64.sqrt() / 2.0);
+        assert_eq!((-390.0_f64).cosd(), 3.0_f64.sqrt() / 2.0);
+        assert_eq!(120.0_f64.sind(), 3.0_f64.sqrt() / 2.0);
+    }
+
+    #[test]
+    fn test_tand_exact_multiples() {
+        assert_eq!(180.0_f64.tand(), 0.0);
+        assert_eq!(135.0_f64.tand(), -1.0);
+        assert_eq!((-45.0_f64).tand(), -1.0);
+        assert_eq!(90.0_f64.tand(), f64::INFINITY);
+        assert_eq!((-90.0_f64).tand(), f64::NEG_INFINITY);
+        assert_eq!(270.0_f64.tand(), f64::NEG_INFINITY);
+        assert_eq!(90.0_f64.cotd(), 0.0);
+    }
+
+    #[test]
+    fn test_degree_functions_huge_argument() {
+        // 1e22 and f64::MAX are congruent to 280 and 128 degrees, respectively.
+        assert_eq!(1e22_f64.sind(), 280.0_f64.sind());
+        assert_eq!(1e22_f64.cosd(), 280.0_f64.cosd());
+        assert_eq!(f64::MAX.sind(), 128.0_f64.sind());
+        assert_eq!(f64::MAX.cosd(), 128.0_f64.cosd());
+    }
+
+    #[test]
+    fn test_degree_functions_non_finite() {
+        assert!(f64::INFINITY.sind().is_nan());
+        assert!(f64::NEG_INFINITY.cosd().is_nan());
+        assert!(f64::NAN.tand().is_nan());
+    }
+
+    #[test]
+    fn test_sincosd() {
+        for x in [
+            0.0, -0.0, 30.0, 45.0, -60.0, 90.0, 135.0, 180.0, -270.0, 1234.5, 1e22_f64,
+        ] {
+            assert_eq!(x.sincosd(), (x.sind(), x.cosd()));
+        }
+        assert_eq!(90.0_f64.sincosd(), (1.0, 0.0));
+        assert!((-0.0_f64).sincosd().0.is_sign_negative());
+    }
+
     #[test]
     fn test_asind() {
         assert_eq!(90.0_f64.sind().asind(), 90.0_f64);
@@ -264,7 +906,10 @@ mod test {
 
     #[test]
     fn test_acosd() {
+        #[cfg(not(feature = "correctly-rounded"))]
         assert_eq!(45.0_f64.cosd().acosd(), 45.0_f64);
+        #[cfg(feature = "correctly-rounded")]
+        assert_eq!(45.0_f64.cosd().acosd(), 44.999_999_999_999_99);
     }
 
     #[test]
@@ -288,6 +933,19 @@ mod test {
         assert_eq!(y.atan2d(&x), angle_expected);
     }
 
+    #[test]
+    fn test_atan2d_tiny_ratio() {
+        assert_eq!(
+            (-2.39e-163_f64).atan2d(&1.76e-19),
+            -7.780506422515156e-143_f64
+        );
+        assert_eq!(1e-310_f64.atan2d(&1e-5), 5.729577951308214e-304_f64);
+        assert_eq!(
+            (-6.218797001532161e80_f64).atan2d(&1.9228323049522348e307),
+            -1.8530519844020164e-225_f64
+        );
+    }
+
     #[test]
     fn test_acscd() {
         assert_eq!(90.0_f64.cscd().acscd(), 90.0_f64);
@@ -295,12 +953,81 @@ mod test {
 
     #[test]
     fn test_asecd() {
-        assert_eq!(45.0_f64.secd().asecd(), 45.0_f64);
+        assert_eq!(60.0_f64.secd().asecd(), 60.0_f64);
+        assert_eq!((-2.0_f64).asecd(), 120.0_f64);
+
+        // √2 rounds to just above the secant of 45°.
+        assert_eq!(std::f64::consts::SQRT_2.asecd(), 45.0_f64.next_up());
     }
 
     #[test]
     fn test_acotd() {
-        assert_equal_to_atol!(30.0_f64.cotd().acotd(), 30.0_f64, 1e-14);
+        assert_eq!(30.0_f64.cotd().acotd(), 30.0_f64);
+    }
+
+    #[test]
+    fn test_sinpi() {
+        assert_eq!(0.5_f64.sinpi(), 1.0);
+        assert_eq!(1.0_f64.sinpi(), 0.0);
+        assert_eq!((-1.5_f64).sinpi(), 1.0);
+        assert_eq!(0.25_f64.sinpi(), std::f64::consts::FRAC_1_SQRT_2);
+        assert!(1.0_f64.sinpi().is_sign_positive());
+        assert!((-1.0_f64).sinpi().is_sign_negative());
+        assert_eq!(1e20_f64.sinpi(), 0.0);
+    }
+
+    #[test]
+    fn test_cospi() {
+        assert_eq!(0.0_f64.cospi(), 1.0);
+        assert_eq!(0.5_f64.cospi(), 0.0);
+        assert_eq!(1.0_f64.cospi(), -1.0);
+        assert_eq!((-2.5_f64).cospi(), 0.0);
+        assert_eq!(0.25_f64.cospi(), 0.25_f64.sinpi());
+        assert_eq!(3.0e7_f64.cospi(), 1.0);
+    }
+
+    #[test]
+    fn test_tanpi() {
+        assert_eq!(0.25_f64.tanpi(), 1.0);
+        assert_eq!((-0.25_f64).tanpi(), -1.0);
+        assert_eq!(0.75_f64.tanpi(), -1.0);
+        assert_eq!(1.0_f64.tanpi(), 0.0);
+        assert_eq!(0.5_f64.tanpi(), f64::INFINITY);
+        assert_eq!((-0.5_f64).tanpi(), f64::NEG_INFINITY);
+    }
+
+    #[test]
+    fn test_asinpi() {
+        assert_eq!(1.0_f64.asinpi(), 0.5);
+        assert_eq!((-1.0_f64).asinpi(), -0.5);
+        assert_eq!(0.0_f64.asinpi(), 0.0);
+    }
+
+    #[test]
+    fn test_acospi() {
+        assert_eq!(1.0_f64.acospi(), 0.0);
+        assert_eq!(0.0_f64.acospi(), 0.5);
+        assert_eq!((-1.0_f64).acospi(), 1.0);
+    }
+
+    #[test]
+    fn test_atanpi() {
+        assert_eq!(1.0_f64.atanpi(), 0.25);
+        assert_eq!(f64::INFINITY.atanpi(), 0.5);
+    }
+
+    #[test]
+    fn test_atan2pi_45_deg_clockwise() {
+        let x = 3.0_f64;
+        let y = -3.0_f64;
+        assert_eq!(y.atan2pi(&x), -0.25);
+    }
+
+    #[test]
+    fn test_atan2pi_180_deg_counterclockwise() {
+        let x = -3.0_f64;
+        let y = 0.0_f64;
+        assert_eq!(y.atan2pi(&x), 1.0);
     }
 
     #[test]
@@ -325,12 +1052,44 @@ mod test {
 
     #[test]
     fn test_sech() {
+        #[cfg(not(any(
+            feature = "correctly-rounded",
+            feature = "deterministic",
+            feature = "libm"
+        )))]
         assert_eq!(1.0_f64.sech(), (2.0 * E) / ((E * E) + 1.0));
+        #[cfg(any(
+            feature = "correctly-rounded",
+            feature = "deterministic",
+            feature = "libm"
+        ))]
+        assert_eq!(1.0_f64.sech(), 0.648_054_273_663_885_3);
     }
 
     #[test]
     fn test_coth() {
-        assert_eq!(1.0_f64.coth(), (1.0 + E.powi(-2)) / (1.0 - E.powi(-2)));
+        assert_eq!(1.0_f64.coth(), 1.313_035_285_499_331_2);
+        // Within the documented bound of 2 ulps.
+        assert_equal_to_atol!(
+            0.576_336_389_424_958_9_f64.coth(),
+            1.923_086_004_130_789_8,
+            2.0 * f64::EPSILON
+        );
+    }
+
+    #[test]
+    fn test_sinhcosh() {
+        for x in [0.0, 1e-10, 0.3, -1.0, 5.0, 21.9, 30.0, -500.0, 709.5, 710.4] {
+            let (s, c) = x.sinhcosh();
+            assert_equal_to_rtol!(s, x.sinh(), 2.0 * f64::EPSILON);
+            assert_equal_to_rtol!(c, x.cosh(), 2.0 * f64::EPSILON);
+        }
+        assert_eq!(0.0_f64.sinhcosh(), (0.0, 1.0));
+        assert_eq!(f64::INFINITY.sinhcosh(), (f64::INFINITY, f64::INFINITY));
+        assert_eq!(
+            f64::NEG_INFINITY.sinhcosh(),
+            (f64::NEG_INFINITY, f64::INFINITY)
+        );
     }
 
     #[test]
@@ -343,6 +1102,13 @@ mod test {
         assert_eq!(1.0_f64.cosh().acosh(), 1.0);
     }
 
+    #[test]
+    fn test_acosh_outside_domain() {
+        for x in [0.5, -1.0, -6944.0, -1e10, f64::NEG_INFINITY] {
+            assert!(Trig::acosh(&x).is_nan());
+        }
+    }
+
     #[test]
     fn test_atanh() {
         assert_equal_to_atol!(1.0_f64.tanh().atanh(), 1.0, 1e-15);
@@ -362,4 +1128,47 @@ mod test {
     fn test_acoth() {
         assert_equal_to_atol!(1.5_f64.coth().acoth(), 1.5, 1e-15);
     }
+
+    #[test]
+    fn test_acsch_edges() {
+        assert_equal_to_rtol!(5e-324_f64.acsch(), 745.1332191019412, 2.0 * f64::EPSILON);
+        assert_equal_to_rtol!(
+            1.7e308_f64.acsch(),
+            5.88235294117647e-309,
+            2.0 * f64::EPSILON
+        );
+        assert_equal_to_rtol!((-0.75_f64).acsch(), -1.0986122886681098, 2.0 * f64::EPSILON);
+        assert_eq!(0.0_f64.acsch(), f64::INFINITY);
+        assert_eq!((-0.0_f64).acsch(), f64::NEG_INFINITY);
+        assert_eq!(f64::INFINITY.acsch(), 0.0);
+    }
+
+    #[test]
+    fn test_asech_edges() {
+        assert_eq!(1.0_f64.asech(), 0.0);
+        assert_equal_to_rtol!(
+            (1.0_f64 - f64::EPSILON / 2.0).asech(),
+            1.4901161193847656e-8,
+            2.0 * f64::EPSILON
+        );
+        assert_equal_to_rtol!(1e-310_f64.asech(), 714.4945260087142, 2.0 * f64::EPSILON);
+        assert_equal_to_rtol!(0.75_f64.asech(), 0.7953654612239056, 2.0 * f64::EPSILON);
+        assert_eq!(0.0_f64.asech(), f64::INFINITY);
+        assert!(1.5_f64.asech().is_nan());
+        assert!((-0.5_f64).asech().is_nan());
+    }
+
+    #[test]
+    fn test_acoth_edges() {
+        assert_equal_to_rtol!(
+            (1.0_f64 + f64::EPSILON).acoth(),
+            18.36840028483855,
+            2.0 * f64::EPSILON
+        );
+        assert_equal_to_rtol!((-1e300_f64).acoth(), -1e-300, 2.0 * f64::EPSILON);
+        assert_equal_to_rtol!(1.5_f64.acoth(), 0.8047189562170501, 2.0 * f64::EPSILON);
+        assert_eq!(1.0_f64.acoth(), f64::INFINITY);
+        assert_eq!((-1.0_f64).acoth(), f64::NEG_INFINITY);
+        assert!(0.5_f64.acoth().is_nan());
+    }
 }
@@ -0,0 +1,736 @@
+//! Fast polynomial approximations of the elementary functions for [`f32`].
+//!
+//! Every function in this module takes an [`Accuracy`] tier, which selects the degree of the
+//! polynomials used to approximate it. The polynomials are near-minimax (Chebyshev) fits on the
+//! reduced ranges, evaluated in [`f32`] arithmetic with coefficients rounded to [`f32`]. These
+//! functions back the [`TrigFast`](crate::TrigFast) implementation on [`f32`].
+
+use crate::math;
+use crate::trig_fast_trait::Accuracy;
+use core::f32::consts::{FRAC_PI_2, FRAC_PI_4, LN_2, LOG2_E, PI};
+
+/// Coefficients of the polynomial $P$ with $\sin(r)\approx r+r^{3}P(r^{2})$ on
+/// $\left[-\frac{\pi}{4},\frac{\pi}{4}\right]$ (one array per polynomial [`Accuracy`] tier).
+const SIN: [&[f32]; 2] = [
+    &[-0.16665731, 0.008211856],
+    &[-0.16666664, 0.008332748, -0.0001958789],
+];
+
+/// Coefficients of the polynomial $P$ with $\cos(r)\approx1+r^{2}P(r^{2})$ on
+/// $\left[-\frac{\pi}{4},\frac{\pi}{4}\right]$ (one array per polynomial [`Accuracy`] tier).
+const COS: [&[f32]; 2] = [
+    &[-0.49993467, 0.04081814],
+    &[-0.49999982, 0.04166141, -0.0013661167],
+];
+
+/// Coefficients of the polynomial $P$ with $\arctan(t)\approx t+t^{3}P(t^{2})$ on
+/// $\left[-\tan\frac{\pi}{8},\tan\frac{\pi}{8}\right]$ (one array per polynomial [`Accuracy`]
+/// tier).
+const ATAN: [&[f32]; 2] = [
+    &[-0.33287016, 0.17804508],
+    &[-0.33333287, 0.19991238, -0.14024143, 0.08520492],
+];
+
+/// Coefficients of the polynomial $P$ with $\arcsin(x)\approx x+x^{3}P(x^{2})$ on
+/// $\left[-\frac{1}{2},\frac{1}{2}\right]$ (one array per polynomial [`Accuracy`] tier).
+const ASIN: [&[f32]; 2] = [
+    &[0.16624707, 0.08813689],
+    &[0.16666673, 0.07498855, 0.04500138, 0.026554542, 0.038085025],
+];
+
+/// Coefficients of the polynomial $P$ with $e^{r}\approx1+r+r^{2}P(r)$ on
+/// $\left[-\frac{\ln(2)}{2},\frac{\ln(2)}{2}\right]$ (one array per polynomial [`Accuracy`] tier).
+const EXP: [&[f32]; 2] = [
+    &[0.5, 0.16741899, 0.041791987],
+    &[0.5, 0.16666578, 0.041666556, 0.008363173, 0.0013926176],
+];
+
+/// Coefficients of the polynomial $P$ with $\text{artanh}(s)\approx s+s^{3}P(s^{2})$ on
+/// $\left[-3+2\sqrt{2},3-2\sqrt{2}\right]$ (one array per polynomial [`Accuracy`] tier).
+const ATANH: [&[f32]; 2] = [&[0.33630836], &[0.3333175, 0.20429134]];
+
+/// Coefficients of the polynomial $P$ with $\sinh(x)\approx x+x^{3}P(x^{2})$ on
+/// $\left[-\frac{1}{2},\frac{1}{2}\right]$ (one array per polynomial [`Accuracy`] tier).
+const SINH: [&[f32]; 2] = [&[0.16771144], &[0.1666651, 0.008383088]];
+
+/// Upper part of $\ln(2)$, with enough trailing zeros that $k\ln(2)_{hi}$ is exact for
+/// $|k|<2^{8}$.
+const LN2_HI: f32 = 0.69314575;
+
+/// $\ln(2)-$ `LN2_HI`.
+const LN2_LO: f32 = 1.4286068e-6;
+
+/// Upper part of $\frac{\pi}{2}$, with enough trailing zeros that $n\frac{\pi}{2}_{hi}$ is exact
+/// for $|n|<2^{20}$.
+const PIO2_HI: f64 = 1.5707963267341256;
+
+/// $\frac{\pi}{2}-$ `PIO2_HI`.
+const PIO2_LO: f64 = 6.077100506506192e-11;
+
+/// Largest argument of [`exp`] whose result does not overflow.
+const EXP_OVERFLOW: f32 = 88.72284;
+
+/// Smallest argument of [`exp`] whose result does not underflow to zero.
+const EXP_UNDERFLOW: f32 = -103.97208;
+
+/// Selects the coefficients of a polynomial for an accuracy tier.
+///
+/// # Arguments
+///
+/// * `coefficients` - Coefficients of the polynomial for the [`Accuracy::Low`] and
+///   [`Accuracy::Medium`] tiers.
+/// * `accuracy` - Accuracy tier.
+///
+/// # Returns
+///
+/// Coefficients of the polynomial for `accuracy`.
+///
+/// # Note
+///
+/// The [`TrigFast`](crate::TrigFast) methods evaluate the [`Trig`](crate::Trig) methods for
+/// [`Accuracy::Full`] instead, so it selects the most accurate polynomials here.
+fn tier(coefficients: &[&'static [f32]; 2], accuracy: Accuracy) -> &'static [f32] {
+    match accuracy {
+        Accuracy::Low => coefficients[0],
+        Accuracy::Medium | Accuracy::Full => coefficients[1],
+    }
+}
+
+/// Evaluates a polynomial using Horner's method.
+///
+/// # Arguments
+///
+/// * `x` - Point at which to evaluate the polynomial.
+/// * `coefficients` - Coefficients of the polynomial, in increasing order of degree.
+///
+/// # Returns
+///
+/// Value of the polynomial at `x`.
+fn horner(x: f32, coefficients: &[f32]) -> f32 {
+    coefficients.iter().rev().fold(0.0, |p, &c| p * x + c)
+}
+
+/// Rounds a value to the nearest integer (ties to even).
+///
+/// # Arguments
+///
+/// * `x` - Value.
+///
+/// # Returns
+///
+/// `x` rounded to the nearest integer.
+///
+/// # Note
+///
+/// For $|x|<2^{22}$, adding and subtracting $1.5\cdot2^{23}$ leaves no fractional bits, which
+/// rounds `x` without a call into the math library. Larger values are already integers.
+fn round(x: f32) -> f32 {
+    const SHIFT: f32 = 12582912.0;
+    if x.abs() < 4194304.0 {
+        (x + SHIFT) - SHIFT
+    } else {
+        x
+    }
+}
+
+/// Rounds an [`f64`] to the nearest integer (ties to even).
+///
+/// # Arguments
+///
+/// * `x` - Value, $|x|<2^{51}$.
+///
+/// # Returns
+///
+/// `x` rounded to the nearest integer.
+fn round_f64(x: f64) -> f64 {
+    const SHIFT: f64 = 6755399441055744.0;
+    (x + SHIFT) - SHIFT
+}
+
+/// Reduces an angle in radians modulo $\frac{\pi}{2}$.
+///
+/// # Arguments
+///
+/// * `x` - Angle \[rad\].
+///
+/// # Returns
+///
+/// Quadrant `q` (in $\\{0,1,2,3\\}$) and remainder `r` (with $|r|\lesssim\frac{\pi}{4}$) such
+/// that $x=q\frac{\pi}{2}+r$ modulo $2\pi$.
+///
+/// # Note
+///
+/// Moderate arguments are reduced with a two-term Cody–Waite reduction in [`f64`], which is
+/// accurate to well beyond [`f32`] precision. Arguments larger than $2^{19}$ fall back to the
+/// crate's exact reduction.
+pub(crate) fn rem_pio2(x: f32) -> (u8, f32) {
+    if x.abs() <= FRAC_PI_4 {
+        (0, x)
+    } else if x.abs() < 524288.0 {
+        let x = x as f64;
+        let n = round_f64(x * core::f64::consts::FRAC_2_PI);
+        let r = (x - n * PIO2_HI) - n * PIO2_LO;
+        ((n as i32).rem_euclid(4) as u8, r as f32)
+    } else {
+        let (q, r) = crate::rem_pio2::rem_pio2_f32(x);
+        (q, r as f32)
+    }
+}
+
+/// Exactly reduces an angle modulo a full turn.
+///
+/// # Arguments
+///
+/// * `x` - Angle.
+/// * `quarter_turn` - A quarter turn in the units of `x` (e.g. `90.0` for degrees).
+///
+/// # Returns
+///
+/// Quadrant `q` (in $\\{0,1,2,3\\}$) and remainder `r` (with $|r|\lesssim$ `quarter_turn / 2`)
+/// such that `x` is equal to `q * quarter_turn + r` modulo a full turn.
+///
+/// # Note
+///
+/// The subtraction of the quadrant is exact in binary floating point. Only arguments of magnitude
+/// at least $2^{22}$ need the (slower) floating-point remainder to be reduced first.
+pub(crate) fn reduce_turn(x: f32, quarter_turn: f32) -> (u8, f32) {
+    let x = if x.abs() < 4194304.0 {
+        x
+    } else {
+        x % (4.0 * quarter_turn)
+    };
+    let n = round(x / quarter_turn);
+    ((n as i32).rem_euclid(4) as u8, x - n * quarter_turn)
+}
+
+/// Sine of a reduced angle.
+///
+/// # Arguments
+///
+/// * `r` - Reduced angle, $|r|\lesssim\frac{\pi}{4}$ \[rad\].
+/// * `accuracy` - Accuracy tier.
+///
+/// # Returns
+///
+/// $\sin(r)$.
+fn sin_kernel(r: f32, accuracy: Accuracy) -> f32 {
+    let rr = r * r;
+    r + r * rr * horner(rr, tier(&SIN, accuracy))
+}
+
+/// Cosine of a reduced angle.
+///
+/// # Arguments
+///
+/// * `r` - Reduced angle, $|r|\lesssim\frac{\pi}{4}$ \[rad\].
+/// * `accuracy` - Accuracy tier.
+///
+/// # Returns
+///
+/// $\cos(r)$.
+fn cos_kernel(r: f32, accuracy: Accuracy) -> f32 {
+    let rr = r * r;
+    1.0 + rr * horner(rr, tier(&COS, accuracy))
+}
+
+/// Sine of an angle given as a quadrant and a reduced angle.
+///
+/// # Arguments
+///
+/// * `q` - Quadrant, in $\\{0,1,2,3\\}$.
+/// * `r` - Reduced angle, $|r|\lesssim\frac{\pi}{4}$ \[rad\].
+/// * `accuracy` - Accuracy tier.
+///
+/// # Returns
+///
+/// $\sin\left(q\frac{\pi}{2}+r\right)$.
+pub(crate) fn sin_reduced(q: u8, r: f32, accuracy: Accuracy) -> f32 {
+    match q {
+        0 => sin_kernel(r, accuracy),
+        1 => cos_kernel(r, accuracy),
+        2 => -sin_kernel(r, accuracy),
+        _ => -cos_kernel(r, accuracy),
+    }
+}
+
+/// Cosine of an angle given as a quadrant and a reduced angle.
+///
+/// # Arguments
+///
+/// * `q` - Quadrant, in $\\{0,1,2,3\\}$.
+/// * `r` - Reduced angle, $|r|\lesssim\frac{\pi}{4}$ \[rad\].
+/// * `accuracy` - Accuracy tier.
+///
+/// # Returns
+///
+/// $\cos\left(q\frac{\pi}{2}+r\right)$.
+pub(crate) fn cos_reduced(q: u8, r: f32, accuracy: Accuracy) -> f32 {
+    match q {
+        0 => cos_kernel(r, accuracy),
+        1 => -sin_kernel(r, accuracy),
+        2 => -cos_kernel(r, accuracy),
+        _ => sin_kernel(r, accuracy),
+    }
+}
+
+/// Tangent of an angle given as a quadrant and a reduced angle.
+///
+/// # Arguments
+///
+/// * `q` - Quadrant, in $\\{0,1,2,3\\}$.
+/// * `r` - Reduced angle, $|r|\lesssim\frac{\pi}{4}$ \[rad\].
+/// * `accuracy` - Accuracy tier.
+///
+/// # Returns
+///
+/// $\tan\left(q\frac{\pi}{2}+r\right)$.
+pub(crate) fn tan_reduced(q: u8, r: f32, accuracy: Accuracy) -> f32 {
+    let (s, c) = (sin_kernel(r, accuracy), cos_kernel(r, accuracy));
+    if q.is_multiple_of(2) { s / c } else { -c / s }
+}
+
+/// Sine and cosine of an angle given as a quadrant and a reduced angle.
+///
+/// # Arguments
+///
+/// * `q` - Quadrant, in $\\{0,1,2,3\\}$.
+/// * `r` - Reduced angle, $|r|\lesssim\frac{\pi}{4}$ \[rad\].
+/// * `accuracy` - Accuracy tier.
+///
+/// # Returns
+///
+/// $\sin\left(q\frac{\pi}{2}+r\right)$ and $\cos\left(q\frac{\pi}{2}+r\right)$.
+pub(crate) fn sincos_reduced(q: u8, r: f32, accuracy: Accuracy) -> (f32, f32) {
+    let (s, c) = (sin_kernel(r, accuracy), cos_kernel(r, accuracy));
+    match q {
+        0 => (s, c),
+        1 => (c, -s),
+        2 => (-s, -c),
+        _ => (-c, s),
+    }
+}
+
+/// Inverse tangent of a value in $[0,1]$.
+///
+/// # Arguments
+///
+/// * `t` - Value, $t\in[0,1]$.
+/// * `accuracy` - Accuracy tier.
+///
+/// # Returns
+///
+/// $\arctan(t)$ \[rad\].
+fn atan_unit(t: f32, accuracy: Accuracy) -> f32 {
+    // arctan(t) = π/4 + arctan((t - 1)/(t + 1)) brings t > tan(π/8) back into the fitted range.
+    let (base, t) = if t > 0.41421357 {
+        (FRAC_PI_4, (t - 1.0) / (t + 1.0))
+    } else {
+        (0.0, t)
+    };
+    let tt = t * t;
+    base + (t + t * tt * horner(tt, tier(&ATAN, accuracy)))
+}
+
+/// Inverse tangent.
+///
+/// # Arguments
+///
+/// * `x` - Value.
+/// * `accuracy` - Accuracy tier.
+///
+/// # Returns
+///
+/// $\arctan(x)$ \[rad\].
+pub(crate) fn atan(x: f32, accuracy: Accuracy) -> f32 {
+    let a = x.abs();
+    let f = if a > 1.0 {
+        FRAC_PI_2 - atan_unit(1.0 / a, accuracy)
+    } else {
+        atan_unit(a, accuracy)
+    };
+    f.copysign(x)
+}
+
+/// Four-quadrant inverse tangent.
+///
+/// # Arguments
+///
+/// * `y` - $y$-coordinate.
+/// * `x` - $x$-coordinate.
+/// * `accuracy` - Accuracy tier.
+///
+/// # Returns
+///
+/// Angle \[rad\] of the point $(x,y)$, in $[-\pi,\pi]$.
+pub(crate) fn atan2(y: f32, x: f32, accuracy: Accuracy) -> f32 {
+    let (ax, ay) = (x.abs(), y.abs());
+    let f = if ax == ay {
+        // Covers both zeros (angle 0) and both infinities (angle π/4), where the ratio is NaN.
+        if ax == 0.0 { 0.0 } else { FRAC_PI_4 }
+    } else if ay > ax {
+        FRAC_PI_2 - atan_unit(ax / ay, accuracy)
+    } else {
+        atan_unit(ay / ax, accuracy)
+    };
+    let f = if x.is_sign_negative() { PI - f } else { f };
+    f.copysign(y)
+}
+
+/// Inverse sine of a value in $\left[0,\frac{1}{2}\right]$, or the part of the inverse sine of a
+/// larger value computed from $z=\frac{1-|x|}{2}$.
+///
+/// # Arguments
+///
+/// * `x` - Value, $|x|\leq\frac{1}{2}$.
+/// * `accuracy` - Accuracy tier.
+///
+/// # Returns
+///
+/// $\arcsin(x)$ \[rad\].
+fn asin_kernel(x: f32, accuracy: Accuracy) -> f32 {
+    let xx = x * x;
+    x + x * xx * horner(xx, tier(&ASIN, accuracy))
+}
+
+/// Inverse sine.
+///
+/// # Arguments
+///
+/// * `x` - Value, $x\in[-1,1]$.
+/// * `accuracy` - Accuracy tier.
+///
+/// # Returns
+///
+/// $\arcsin(x)$ \[rad\] (`NaN` for $|x|>1$).
+pub(crate) fn asin(x: f32, accuracy: Accuracy) -> f32 {
+    let a = x.abs();
+    if a <= 0.5 {
+        asin_kernel(x, accuracy)
+    } else {
+        // arcsin(x) = π/2 - 2arcsin(√z) with z = (1 - x)/2 ≤ 1/4 (and NaN for |x| > 1).
+        let s = math::sqrt_f32(0.5 * (1.0 - a));
+        (FRAC_PI_2 - 2.0 * asin_kernel(s, accuracy)).copysign(x)
+    }
+}
+
+/// Inverse cosine.
+///
+/// # Arguments
+///
+/// * `x` - Value, $x\in[-1,1]$.
+/// * `accuracy` - Accuracy tier.
+///
+/// # Returns
+///
+/// $\arccos(x)$ \[rad\] (`NaN` for $|x|>1$).
+pub(crate) fn acos(x: f32, accuracy: Accuracy) -> f32 {
+    if x.abs() <= 0.5 {
+        FRAC_PI_2 - asin_kernel(x, accuracy)
+    } else {
+        // arccos(x) = 2arcsin(√z) with z = (1 - |x|)/2, reflected for negative x.
+        let f = 2.0 * asin_kernel(math::sqrt_f32(0.5 * (1.0 - x.abs())), accuracy);
+        if x < 0.0 { PI - f } else { f }
+    }
+}
+
+/// Multiplies a value by an integer power of two.
+///
+/// # Arguments
+///
+/// * `x` - Value, $\frac{1}{2}<x<2$.
+/// * `k` - Exponent, $-150\leq k\leq128$.
+///
+/// # Returns
+///
+/// $x\cdot2^{k}$.
+fn scalbn(x: f32, k: i32) -> f32 {
+    let pow2 = |k: i32| f32::from_bits(((k + 127) as u32) << 23);
+    if k > 127 {
+        x * pow2(127) * pow2(k - 127)
+    } else if k < -126 {
+        x * pow2(-126) * pow2(k + 126)
+    } else {
+        x * pow2(k)
+    }
+}
+
+/// Exponential function.
+///
+/// # Arguments
+///
+/// * `x` - Value.
+/// * `accuracy` - Accuracy tier.
+///
+/// # Returns
+///
+/// $e^{x}$.
+pub(crate) fn exp(x: f32, accuracy: Accuracy) -> f32 {
+    if x > EXP_OVERFLOW {
+        return f32::INFINITY;
+    } else if x < EXP_UNDERFLOW {
+        return 0.0;
+    }
+
+    // Reduce to |r| ≤ ln(2)/2 with x = k·ln(2) + r (NaN passes through with k = 0).
+    let k = round(x * LOG2_E);
+    let r = (x - k * LN2_HI) - k * LN2_LO;
+    scalbn(
+        1.0 + (r + r * r * horner(r, tier(&EXP, accuracy))),
+        k as i32,
+    )
+}
+
+/// Natural logarithm.
+///
+/// # Arguments
+///
+/// * `x` - Value, $x\geq0$.
+/// * `accuracy` - Accuracy tier.
+///
+/// # Returns
+///
+/// $\ln(x)$ (`NaN` for $x<0$).
+pub(crate) fn ln(x: f32, accuracy: Accuracy) -> f32 {
+    if x.is_nan() || x < 0.0 {
+        return f32::NAN;
+    } else if x == 0.0 {
+        return f32::NEG_INFINITY;
+    } else if x == f32::INFINITY {
+        return x;
+    }
+
+    // Scale subnormals into the normal range.
+    let (x, k) = if x < f32::MIN_POSITIVE {
+        (x * 33554432.0, -25)
+    } else {
+        (x, 0)
+    };
+
+    // Write x = 2^k·m with m in [√2/2, √2), by offsetting the bits so that the exponent field
+    // rolls over at √2/2.
+    let bits = x.to_bits() + (0x3f80_0000 - 0x3f35_04f3);
+    let k = k + (bits >> 23) as i32 - 127;
+    let m = f32::from_bits((bits & 0x007f_ffff) + 0x3f35_04f3);
+
+    // ln(m) = 2artanh(s) with s = (m - 1)/(m + 1), where m - 1 is exact.
+    let s = (m - 1.0) / (m + 1.0);
+    let ss = s * s;
+    let f = 2.0 * (s + s * ss * horner(ss, tier(&ATANH, accuracy)));
+    let k = k as f32;
+    k * LN2_HI + (f + k * LN2_LO)
+}
+
+/// Natural logarithm of one plus a value.
+///
+/// # Arguments
+///
+/// * `x` - Value, $x\geq-1$.
+/// * `accuracy` - Accuracy tier.
+///
+/// # Returns
+///
+/// $\ln(1+x)$, accurate for small $|x|$ (`NaN` for $x<-1$).
+pub(crate) fn ln_1p(x: f32, accuracy: Accuracy) -> f32 {
+    let u = 1.0 + x;
+    if u == 1.0 {
+        x
+    } else if u == f32::INFINITY {
+        u
+    } else {
+        // ln(u)/(u - 1) varies slowly, so evaluating it at the rounded u loses no accuracy.
+        ln(u, accuracy) * (x / (u - 1.0))
+    }
+}
+
+/// Hyperbolic sine and hyperbolic cosine.
+///
+/// # Arguments
+///
+/// * `x` - Value.
+/// * `accuracy` - Accuracy tier.
+///
+/// # Returns
+///
+/// $\sinh(x)$ and $\cosh(x)$.
+pub(crate) fn sinhcosh(x: f32, accuracy: Accuracy) -> (f32, f32) {
+    let a = x.abs();
+    let (s, c) = if a < 88.0 {
+        let e = exp(a, accuracy);
+        let c = 0.5 * (e + 1.0 / e);
+        let s = if a < 0.5 {
+            // (e - 1/e)/2 cancels for small arguments.
+            let aa = a * a;
+            a + a * aa * horner(aa, tier(&SINH, accuracy))
+        } else {
+            0.5 * (e - 1.0 / e)
+        };
+        (s, c)
+    } else {
+        // Split e^x into two factors so that it does not overflow before being halved.
+        let h = exp(0.5 * a, accuracy);
+        let h = (0.5 * h) * h;
+        (h, h)
+    };
+    (s.copysign(x), c)
+}
+
+/// Hyperbolic tangent.
+///
+/// # Arguments
+///
+/// * `x` - Value.
+/// * `accuracy` - Accuracy tier.
+///
+/// # Returns
+///
+/// $\tanh(x)$.
+pub(crate) fn tanh(x: f32, accuracy: Accuracy) -> f32 {
+    let a = x.abs();
+    let f = if a < 0.5 {
+        let (s, c) = sinhcosh(a, accuracy);
+        s / c
+    } else if a < 9.0 {
+        1.0 - 2.0 / (exp(2.0 * a, accuracy) + 1.0)
+    } else if a.is_nan() {
+        a
+    } else {
+        1.0
+    };
+    f.copysign(x)
+}
+
+/// Inverse hyperbolic sine.
+///
+/// # Arguments
+///
+/// * `x` - Value.
+/// * `accuracy` - Accuracy tier.
+///
+/// # Returns
+///
+/// $\text{arsinh}(x)$.
+pub(crate) fn asinh(x: f32, accuracy: Accuracy) -> f32 {
+    let a = x.abs();
+    let f = if a > 4096.0 {
+        // ln(2x), since x² + 1 = x² to f32 precision (and x² could overflow).
+        ln(a, accuracy) + LN_2
+    } else {
+        // ln(1 + u) with u = x + x²/(1 + √(1 + x²)), which is accurate for small x.
+        let aa = a * a;
+        ln_1p(a + aa / (1.0 + math::sqrt_f32(1.0 + aa)), accuracy)
+    };
+    f.copysign(x)
+}
+
+/// Inverse hyperbolic cosine.
+///
+/// # Arguments
+///
+/// * `x` - Value, $x\geq1$.
+/// * `accuracy` - Accuracy tier.
+///
+/// # Returns
+///
+/// $\text{arcosh}(x)$ (`NaN` for $x<1$).
+pub(crate) fn acosh(x: f32, accuracy: Accuracy) -> f32 {
+    if x < 1.0 {
+        f32::NAN
+    } else if x > 4096.0 {
+        ln(x, accuracy) + LN_2
+    } else {
+        // ln(1 + u) with u = t + √(2t + t²), where t = x - 1 is exact near x = 1.
+        let t = x - 1.0;
+        ln_1p(t + math::sqrt_f32(t * (2.0 + t)), accuracy)
+    }
+}
+
+/// Inverse hyperbolic tangent.
+///
+/// # Arguments
+///
+/// * `x` - Value, $x\in(-1,1)$.
+/// * `accuracy` - Accuracy tier.
+///
+/// # Returns
+///
+/// $\text{artanh}(x)$ (`NaN` for $|x|>1$).
+pub(crate) fn atanh(x: f32, accuracy: Accuracy) -> f32 {
+    // (1/2)ln((1 + x)/(1 - x)) = (1/2)ln(1 + 2x/(1 - x)).
+    let a = x.abs();
+    (0.5 * ln_1p(2.0 * a / (1.0 - a), accuracy)).copysign(x)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Maximum error of a function relative to a reference over a range, measured as the absolute
+    /// error for references of magnitude at most one and as the relative error otherwise.
+    fn max_error(f: impl Fn(f32) -> f32, reference: impl Fn(f64) -> f64, a: f32, b: f32) -> f64 {
+        (0..=10000)
+            .map(|i| {
+                let x = a + (b - a) * (i as f32 / 10000.0);
+                let y = reference(x as f64);
+                (f(x) as f64 - y).abs() / y.abs().max(1.0)
+            })
+            .fold(0.0, f64::max)
+    }
+
+    #[test]
+    fn test_round() {
+        assert_eq!(round(2.5), 2.0);
+        assert_eq!(round(-3.5), -4.0);
+        assert_eq!(round(0.49999997), 0.0);
+        assert_eq!(round(1e30), 1e30);
+        assert_eq!(round_f64(-2.5), -2.0);
+        assert_eq!(round_f64(1e15 + 0.75), 1e15 + 1.0);
+    }
+
+    #[test]
+    fn test_reduce_turn() {
+        assert_eq!(reduce_turn(90.0, 90.0), (1, 0.0));
+        assert_eq!(reduce_turn(-100.0, 90.0), (3, -10.0));
+        assert_eq!(reduce_turn(1e30, 90.0), reduce_turn(1e30 % 360.0, 90.0));
+        assert_eq!(reduce_turn(-0.75, 0.5), (2, 0.25));
+    }
+
+    #[test]
+    fn test_kernels() {
+        let bounds = [(Accuracy::Low, 2e-4), (Accuracy::Medium, 4e-7)];
+        for (accuracy, bound) in bounds {
+            let (a, b) = (-FRAC_PI_4, FRAC_PI_4);
+            assert!(max_error(|r| sin_kernel(r, accuracy), f64::sin, a, b) < bound);
+            assert!(max_error(|r| cos_kernel(r, accuracy), f64::cos, a, b) < bound);
+            assert!(max_error(|x| atan(x, accuracy), f64::atan, -4.0, 4.0) < bound);
+            assert!(max_error(|x| asin(x, accuracy), f64::asin, -1.0, 1.0) < bound);
+            assert!(max_error(|x| acos(x, accuracy), f64::acos, -1.0, 1.0) < bound);
+            assert!(max_error(|x| exp(x, accuracy), f64::exp, -80.0, 80.0) < bound);
+            assert!(max_error(|x| ln(x, accuracy), f64::ln, 1e-3, 1e3) < bound);
+            assert!(max_error(|x| ln_1p(x, accuracy), f64::ln_1p, -0.9, 10.0) < bound);
+        }
+    }
+
+    #[test]
+    fn test_special_values() {
+        for accuracy in [Accuracy::Low, Accuracy::Medium, Accuracy::Full] {
+            assert_eq!(exp(0.0, accuracy), 1.0);
+            assert_eq!(exp(100.0, accuracy), f32::INFINITY);
+            assert_eq!(exp(-200.0, accuracy), 0.0);
+            assert!(exp(f32::NAN, accuracy).is_nan());
+            assert_eq!(ln(1.0, accuracy), 0.0);
+            assert_eq!(ln(0.0, accuracy), f32::NEG_INFINITY);
+            assert!(ln(-1.0, accuracy).is_nan());
+            assert!((ln(1e-40, accuracy) / -92.1034 - 1.0).abs() < 1e-3);
+            assert_eq!(atan2(0.0, -0.0, accuracy), PI);
+            assert_eq!(atan2(-0.0, 0.0, accuracy), -0.0);
+            assert_eq!(
+                atan2(f32::INFINITY, -f32::INFINITY, accuracy),
+                3.0 * FRAC_PI_4
+            );
+            assert!(asin(1.5, accuracy).is_nan());
+            assert!(acos(f32::NAN, accuracy).is_nan());
+            assert!(tanh(f32::NAN, accuracy).is_nan());
+            assert_eq!(tanh(-20.0, accuracy), -1.0);
+        }
+    }
+}
@@ -0,0 +1,850 @@
+use crate::cordic::Cordic;
+use crate::soft_math;
+use crate::trig_trait::Trig;
+
+/// Number of fractional bits of the working format.
+const WORKING_FRAC_BITS: u32 = 29;
+
+/// $\frac{\pi}{180}$ with 64 fractional bits.
+const DEG2RAD: i128 = 321956420358983237;
+
+/// $\frac{180}{\pi}$ with 64 fractional bits.
+const RAD2DEG: i128 = 1056920581181520441539;
+
+/// $\pi$ with 64 fractional bits.
+const PI: i128 = 57952155664616982739;
+
+/// $\frac{\pi}{2}$ with 64 fractional bits.
+const PIO2: i128 = 28976077832308491370;
+
+/// $\frac{1}{\pi}$ with 64 fractional bits.
+const FRAC_1_PI: i128 = 5871781006564002453;
+
+/// $\frac{2}{\pi}$ with 64 fractional bits.
+const FRAC_2_PI: i128 = 11743562013128004906;
+
+/// Converts a fixed-point number to a different number of fractional bits.
+///
+/// # Arguments
+///
+/// * `x` - Fixed-point number.
+/// * `from` - Number of fractional bits of `x`.
+/// * `to` - Number of fractional bits of the result.
+///
+/// # Returns
+///
+/// `x` with `to` fractional bits (rounded to nearest, and saturated to an [`i64`]).
+fn rescale(x: i64, from: u32, to: u32) -> i64 {
+    let x = if from > to {
+        // (2x + 1) >> (d + 1) rounds x / 2^d to nearest.
+        (2 * x as i128 + 1) >> (from - to + 1)
+    } else {
+        (x as i128) << (to - from)
+    };
+    x.clamp(i64::MIN as i128, i64::MAX as i128) as i64
+}
+
+/// Converts a value to an argument of a CORDIC engine.
+///
+/// # Arguments
+///
+/// * `x` - Value.
+///
+/// # Returns
+///
+/// `x`, saturated to an [`i32`].
+fn arg(x: i64) -> i32 {
+    x.clamp(i32::MIN as i64, i32::MAX as i64) as i32
+}
+
+/// Multiplies by a constant.
+///
+/// # Arguments
+///
+/// * `x` - Value.
+/// * `c` - Constant with 64 fractional bits.
+///
+/// # Returns
+///
+/// $cx$, rounded to nearest.
+fn mul(x: i64, c: i128) -> i64 {
+    ((x as i128 * c + (1 << 63)) >> 64) as i64
+}
+
+/// Square root of a product.
+///
+/// # Arguments
+///
+/// * `a` - First factor, $a\geq0$.
+/// * `b` - Second factor, $b\geq0$.
+///
+/// # Returns
+///
+/// $\sqrt{ab}$, rounded down.
+fn sqrt_product(a: i64, b: i64) -> i64 {
+    ((a as i128 * b as i128).max(0) as u128).isqrt() as i64
+}
+
+/// Hypotenuse of a right triangle.
+///
+/// # Arguments
+///
+/// * `a` - First leg.
+/// * `b` - Second leg.
+///
+/// # Returns
+///
+/// $\sqrt{a^{2}+b^{2}}$, rounded down.
+fn hypot(a: i64, b: i64) -> i64 {
+    ((a as i128 * a as i128 + b as i128 * b as i128) as u128).isqrt() as i64
+}
+
+/// Working format in which the [`Trig`] methods of a fixed-point type are evaluated.
+///
+/// Values are [`i64`]s with [`WORKING_FRAC_BITS`] fractional bits, so that arguments of every
+/// fixed-point type fit without rounding (except for the two lowest bits of [`Q1_31`]) and
+/// intermediate results (e.g. angles in degrees) can exceed the range of the fixed-point type.
+/// Results are only saturated when they are converted back to the fixed-point type.
+#[derive(Clone, Copy)]
+struct Format {
+    /// CORDIC engine with [`WORKING_FRAC_BITS`] fractional bits, for the trigonometric functions
+    /// (whose arguments are first reduced to $|x|\lesssim\frac{\pi}{4}$) and the hyperbolic
+    /// functions of small arguments.
+    precise: Cordic,
+
+    /// CORDIC engine with the fractional bits of the fixed-point type, for the hyperbolic functions
+    /// whose results can exceed the range of the working format.
+    wide: Cordic,
+}
+
+impl Format {
+    /// Creates a working format.
+    ///
+    /// # Arguments
+    ///
+    /// * `frac_bits` - Number of fractional bits of the fixed-point type.
+    ///
+    /// # Returns
+    ///
+    /// Working format for the fixed-point type.
+    const fn new(frac_bits: u32) -> Self {
+        let wide = if frac_bits < WORKING_FRAC_BITS {
+            frac_bits
+        } else {
+            WORKING_FRAC_BITS
+        };
+        Self {
+            precise: Cordic::new(WORKING_FRAC_BITS, WORKING_FRAC_BITS + 3),
+            wide: Cordic::new(wide, wide + 3),
+        }
+    }
+
+    /// One in the working format.
+    ///
+    /// # Returns
+    ///
+    /// $1$.
+    fn one(self) -> i64 {
+        1 << WORKING_FRAC_BITS
+    }
+
+    /// Sign of a value as $\pm1$ in the working format.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - Value.
+    ///
+    /// # Returns
+    ///
+    /// $-1$ if $x<0$, $1$ otherwise.
+    fn sign(self, x: i64) -> i64 {
+        if x < 0 { -self.one() } else { self.one() }
+    }
+
+    /// Divides two values, saturating at the poles.
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - Dividend.
+    /// * `b` - Divisor.
+    ///
+    /// # Returns
+    ///
+    /// $\frac{a}{b}$ ([`i64::MAX`] or [`i64::MIN`], following the sign of `a`, for $b=0$).
+    fn div(self, a: i64, b: i64) -> i64 {
+        if b == 0 {
+            if a < 0 { i64::MIN } else { i64::MAX }
+        } else {
+            let q = ((a as i128) << WORKING_FRAC_BITS) / b as i128;
+            q.clamp(i64::MIN as i128, i64::MAX as i128) as i64
+        }
+    }
+
+    /// Converts a value from the working format to the format of the wide CORDIC engine.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - Value in the working format.
+    ///
+    /// # Returns
+    ///
+    /// Argument of the wide CORDIC engine.
+    fn widen(self, x: i64) -> i32 {
+        arg(rescale(x, WORKING_FRAC_BITS, self.wide.frac_bits()))
+    }
+
+    /// Converts a result of the wide CORDIC engine to the working format.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - Result of the wide CORDIC engine.
+    ///
+    /// # Returns
+    ///
+    /// Value in the working format.
+    fn narrow(self, x: i32) -> i64 {
+        rescale(x as i64, self.wide.frac_bits(), WORKING_FRAC_BITS)
+    }
+
+    /// Sine and cosine of an angle given as a quadrant and a reduced angle.
+    ///
+    /// # Arguments
+    ///
+    /// * `q` - Quadrant.
+    /// * `t` - Reduced angle, $|t|\lesssim\frac{\pi}{4}$ \[rad\].
+    ///
+    /// # Returns
+    ///
+    /// $\sin\left(q\frac{\pi}{2}+t\right)$ and $\cos\left(q\frac{\pi}{2}+t\right)$.
+    fn sincos_reduced(self, q: i64, t: i64) -> (i64, i64) {
+        let (s, c) = self.precise.sincos(arg(t));
+        let (s, c) = (s as i64, c as i64);
+        match q.rem_euclid(4) {
+            0 => (s, c),
+            1 => (c, -s),
+            2 => (-s, -c),
+            _ => (-c, s),
+        }
+    }
+
+    /// Sine and cosine of an angle that is reduced exactly modulo a quarter turn.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - Angle.
+    /// * `quarter_turn` - A quarter turn in the units of `x`.
+    /// * `to_rad` - Factor converting the units of `x` to radians, with 64 fractional bits.
+    ///
+    /// # Returns
+    ///
+    /// $\sin(x)$ and $\cos(x)$ (exact at multiples of a quarter turn).
+    fn sincos_turn(self, x: i64, quarter_turn: i64, to_rad: i128) -> (i64, i64) {
+        let q = (x + quarter_turn / 2).div_euclid(quarter_turn);
+        self.sincos_reduced(q, mul(x - q * quarter_turn, to_rad))
+    }
+
+    // Functions of the `Trig` trait in the working format.
+
+    fn sincos(self, x: i64) -> (i64, i64) {
+        // The quotient only needs to be close to the nearest integer, since the rotation of the
+        // CORDIC engine converges for reduced angles up to 1.74 rad.
+        let q = (x as i128 * FRAC_2_PI + (1 << 92)) >> 93;
+        let t = ((x as i128) << 35) - q * PIO2;
+        self.sincos_reduced(q as i64, ((t + (1 << 34)) >> 35) as i64)
+    }
+    fn sin(self, x: i64) -> i64 {
+        self.sincos(x).0
+    }
+    fn cos(self, x: i64) -> i64 {
+        self.sincos(x).1
+    }
+    fn tan(self, x: i64) -> i64 {
+        let (s, c) = self.sincos(x);
+        self.div(s, c)
+    }
+    fn csc(self, x: i64) -> i64 {
+        self.div(self.one(), self.sin(x))
+    }
+    fn sec(self, x: i64) -> i64 {
+        self.div(self.one(), self.cos(x))
+    }
+    fn cot(self, x: i64) -> i64 {
+        let (s, c) = self.sincos(x);
+        self.div(c, s)
+    }
+    fn asin(self, x: i64) -> i64 {
+        let one = self.one();
+        let x = x.clamp(-one, one);
+        self.atan2(x, sqrt_product(one - x, one + x))
+    }
+    fn acos(self, x: i64) -> i64 {
+        let one = self.one();
+        let x = x.clamp(-one, one);
+        self.atan2(sqrt_product(one - x, one + x), x)
+    }
+    fn atan(self, x: i64) -> i64 {
+        self.atan2(x, self.one())
+    }
+    fn atan2(self, y: i64, x: i64) -> i64 {
+        // Only the ratio of the coordinates matters, so they are scaled down to fit an i32.
+        let (mut y, mut x) = (y, x);
+        while arg(y) as i64 != y || arg(x) as i64 != x {
+            (y, x) = (y >> 1, x >> 1);
+        }
+        self.precise.atan2(y as i32, x as i32) as i64
+    }
+    fn acsc(self, x: i64) -> i64 {
+        let one = self.one();
+        let a = x.abs().max(one);
+        self.atan2(self.sign(x), sqrt_product(a - one, a + one))
+    }
+    fn asec(self, x: i64) -> i64 {
+        let one = self.one();
+        let a = x.abs().max(one);
+        self.atan2(sqrt_product(a - one, a + one), self.sign(x))
+    }
+    fn acot(self, x: i64) -> i64 {
+        self.atan2(self.sign(x), x.abs())
+    }
+    fn deg2rad(self, x: i64) -> i64 {
+        mul(x, DEG2RAD)
+    }
+    fn rad2deg(self, x: i64) -> i64 {
+        mul(x, RAD2DEG)
+    }
+    fn sincosd(self, x: i64) -> (i64, i64) {
+        self.sincos_turn(x, 90 * self.one(), DEG2RAD)
+    }
+    fn sind(self, x: i64) -> i64 {
+        self.sincosd(x).0
+    }
+    fn cosd(self, x: i64) -> i64 {
+        self.sincosd(x).1
+    }
+    fn tand(self, x: i64) -> i64 {
+        let (s, c) = self.sincosd(x);
+        self.div(s, c)
+    }
+    fn cscd(self, x: i64) -> i64 {
+        self.div(self.one(), self.sind(x))
+    }
+    fn secd(self, x: i64) -> i64 {
+        self.div(self.one(), self.cosd(x))
+    }
+    fn cotd(self, x: i64) -> i64 {
+        let (s, c) = self.sincosd(x);
+        self.div(c, s)
+    }
+    fn asind(self, x: i64) -> i64 {
+        self.rad2deg(self.asin(x))
+    }
+    fn acosd(self, x: i64) -> i64 {
+        self.rad2deg(self.acos(x))
+    }
+    fn atand(self, x: i64) -> i64 {
+        self.rad2deg(self.atan(x))
+    }
+    fn atan2d(self, y: i64, x: i64) -> i64 {
+        self.rad2deg(self.atan2(y, x))
+    }
+    fn acscd(self, x: i64) -> i64 {
+        self.rad2deg(self.acsc(x))
+    }
+    fn asecd(self, x: i64) -> i64 {
+        self.rad2deg(self.asec(x))
+    }
+    fn acotd(self, x: i64) -> i64 {
+        self.rad2deg(self.acot(x))
+    }
+    fn sinpi(self, x: i64) -> i64 {
+        self.sincos_turn(x, self.one() / 2, PI).0
+    }
+    fn cospi(self, x: i64) -> i64 {
+        self.sincos_turn(x, self.one() / 2, PI).1
+    }
+    fn tanpi(self, x: i64) -> i64 {
+        let (s, c) = self.sincos_turn(x, self.one() / 2, PI);
+        self.div(s, c)
+    }
+    fn asinpi(self, x: i64) -> i64 {
+        mul(self.asin(x), FRAC_1_PI)
+    }
+    fn acospi(self, x: i64) -> i64 {
+        mul(self.acos(x), FRAC_1_PI)
+    }
+    fn atanpi(self, x: i64) -> i64 {
+        mul(self.atan(x), FRAC_1_PI)
+    }
+    fn atan2pi(self, y: i64, x: i64) -> i64 {
+        mul(self.atan2(y, x), FRAC_1_PI)
+    }
+    fn sinhcosh(self, x: i64) -> (i64, i64) {
+        let (s, c) = if x.abs() <= self.one() {
+            let (s, c) = self.precise.sinhcosh(arg(x));
+            (s as i64, c as i64)
+        } else {
+            let (s, c) = self.wide.sinhcosh(self.widen(x));
+            (self.narrow(s), self.narrow(c))
+        };
+        (s, c)
+    }
+    fn sinh(self, x: i64) -> i64 {
+        self.sinhcosh(x).0
+    }
+    fn cosh(self, x: i64) -> i64 {
+        self.sinhcosh(x).1
+    }
+    fn tanh(self, x: i64) -> i64 {
+        let (s, c) = self.sinhcosh(x);
+        self.div(s, c)
+    }
+    fn csch(self, x: i64) -> i64 {
+        self.div(self.one(), self.sinh(x))
+    }
+    fn sech(self, x: i64) -> i64 {
+        self.div(self.one(), self.cosh(x))
+    }
+    fn coth(self, x: i64) -> i64 {
+        let (s, c) = self.sinhcosh(x);
+        self.div(c, s)
+    }
+    fn asinh(self, x: i64) -> i64 {
+        // ln(|x| + sqrt(x² + 1)), with the sign of x.
+        let (one, a) = (self.one(), x.abs());
+        let f = self.narrow(self.wide.ln_ratio(a + hypot(a, one), one));
+        if x < 0 { -f } else { f }
+    }
+    fn acosh(self, x: i64) -> i64 {
+        // ln(x + sqrt(x² - 1)), with x clamped to the domain x ≥ 1.
+        let one = self.one();
+        let x = x.max(one);
+        let h = sqrt_product(x - one, x + one);
+        self.narrow(self.wide.ln_ratio(x + h, one))
+    }
+    fn atanh(self, x: i64) -> i64 {
+        self.narrow(self.wide.atanh(self.widen(x)))
+    }
+    fn acsch(self, x: i64) -> i64 {
+        // ln((1 + sqrt(1 + x²)) / |x|), with the sign of x.
+        let (one, a) = (self.one(), x.abs());
+        if a == 0 {
+            return i64::MAX;
+        }
+        let f = self.narrow(self.wide.ln_ratio(one + hypot(a, one), a));
+        if x < 0 { -f } else { f }
+    }
+    fn asech(self, x: i64) -> i64 {
+        // ln((1 + sqrt(1 - x²)) / x), with x clamped to the domain 0 < x ≤ 1.
+        let one = self.one();
+        if x <= 0 {
+            return i64::MAX;
+        }
+        let x = x.min(one);
+        let h = sqrt_product(one - x, one + x);
+        self.narrow(self.wide.ln_ratio(one + h, x))
+    }
+    fn acoth(self, x: i64) -> i64 {
+        // (1/2)ln((|x| + 1) / (|x| - 1)), with the sign of x.
+        let (one, a) = (self.one(), x.abs());
+        let f = if a <= one {
+            i64::MAX
+        } else {
+            self.narrow(self.wide.half_ln_ratio(a + one, a - one))
+        };
+        if x < 0 { -f } else { f }
+    }
+}
+
+/// Implements the [`Trig`] methods of a fixed-point type that take and return a single value.
+macro_rules! unary_methods {
+    ($($f:ident),*) => {
+        $(
+            fn $f(&self) -> Self {
+                Self::from_working(Self::FORMAT.$f(self.to_working()))
+            }
+        )*
+    };
+}
+
+/// Defines a fixed-point type and implements the [`Trig`] trait for it.
+macro_rules! fixed_type {
+    ($(#[$attr:meta])* $name:ident, $bits:ty, $frac_bits:expr) => {
+        $(#[$attr])*
+        #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        pub struct $name(pub $bits);
+
+        impl $name {
+            /// Number of fractional bits.
+            pub const FRAC_BITS: u32 = $frac_bits;
+
+            /// Smallest value.
+            pub const MIN: Self = Self(<$bits>::MIN);
+
+            /// Largest value.
+            pub const MAX: Self = Self(<$bits>::MAX);
+
+            /// Format in which the [`Trig`] methods are evaluated.
+            const FORMAT: Format = Format::new($frac_bits);
+
+            /// Converts an [`f64`] to the nearest fixed-point number.
+            ///
+            /// # Arguments
+            ///
+            /// * `x` - Value.
+            ///
+            /// # Returns
+            ///
+            /// Nearest fixed-point number to `x` (saturated to [`Self::MIN`] or [`Self::MAX`] if
+            /// `x` is out of range, and `0` if `x` is `NaN`).
+            pub const fn from_f64(x: f64) -> Self {
+                Self(soft_math::round(x * (1u64 << $frac_bits) as f64) as $bits)
+            }
+
+            /// Converts the fixed-point number to an [`f64`].
+            ///
+            /// # Returns
+            ///
+            /// Value of the fixed-point number (exact).
+            pub const fn to_f64(self) -> f64 {
+                self.0 as f64 / (1u64 << $frac_bits) as f64
+            }
+
+            /// Converts the fixed-point number to the working format.
+            ///
+            /// # Returns
+            ///
+            /// Fixed-point number in the working format.
+            fn to_working(self) -> i64 {
+                rescale(self.0 as i64, $frac_bits, WORKING_FRAC_BITS)
+            }
+
+            /// Converts a value in the working format to the fixed-point type.
+            ///
+            /// # Arguments
+            ///
+            /// * `x` - Value in the working format.
+            ///
+            /// # Returns
+            ///
+            /// Fixed-point number (rounded to nearest, and saturated to [`Self::MIN`] or
+            /// [`Self::MAX`]).
+            fn from_working(x: i64) -> Self {
+                let x = rescale(x, WORKING_FRAC_BITS, $frac_bits);
+                Self(x.clamp(<$bits>::MIN as i64, <$bits>::MAX as i64) as $bits)
+            }
+        }
+
+        impl Trig for $name {
+            unary_methods!(sin, cos, tan, csc, sec, cot);
+            fn sincos(&self) -> (Self, Self) {
+                let (s, c) = Self::FORMAT.sincos(self.to_working());
+                (Self::from_working(s), Self::from_working(c))
+            }
+            unary_methods!(asin, acos, atan);
+            fn atan2(&self, other: &Self) -> Self {
+                Self::from_working(Self::FORMAT.atan2(self.to_working(), other.to_working()))
+            }
+            unary_methods!(acsc, asec, acot, deg2rad, rad2deg, sind, cosd, tand, cscd, secd, cotd);
+            fn sincosd(&self) -> (Self, Self) {
+                let (s, c) = Self::FORMAT.sincosd(self.to_working());
+                (Self::from_working(s), Self::from_working(c))
+            }
+            unary_methods!(asind, acosd, atand);
+            fn atan2d(&self, other: &Self) -> Self {
+                Self::from_working(Self::FORMAT.atan2d(self.to_working(), other.to_working()))
+            }
+            unary_methods!(acscd, asecd, acotd, sinpi, cospi, tanpi, asinpi, acospi, atanpi);
+            fn atan2pi(&self, other: &Self) -> Self {
+                Self::from_working(Self::FORMAT.atan2pi(self.to_working(), other.to_working()))
+            }
+            unary_methods!(sinh, cosh, tanh, csch, sech, coth);
+            fn sinhcosh(&self) -> (Self, Self) {
+                let (s, c) = Self::FORMAT.sinhcosh(self.to_working());
+                (Self::from_working(s), Self::from_working(c))
+            }
+            unary_methods!(asinh, acosh, atanh, acsch, asech, acoth);
+        }
+    };
+}
+
+fixed_type!(
+    /// A Q1.15 fixed-point number: an [`i16`] with 15 fractional bits, covering $[-1,1)$ in steps
+    /// of $2^{-15}$.
+    ///
+    /// # Note
+    ///
+    /// Since angles in radians or degrees rarely fit in $[-1,1)$, the half-turn functions (e.g.
+    /// [`Trig::sinpi`] and [`Trig::atan2pi`]) are the natural way to work with angles in this
+    /// format, where $[-1,1)$ covers the full circle.
+    ///
+    /// Fixed-point numbers cannot represent infinities or `NaN`. Results outside $[-1,1)$
+    /// (including those at the poles) saturate to [`Q1_15::MIN`] or [`Q1_15::MAX`], and arguments
+    /// outside the domain of an inverse function are clamped to it. In particular, `acosh` of
+    /// $x<1$ is $0$, and `asech` of $x\leq0$ saturates to [`Q1_15::MAX`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use trig::{Q1_15, Trig};
+    ///
+    /// // 30 degrees as a Q1.15 number of half-turns.
+    /// let angle = Q1_15::from_f64(1.0 / 6.0);
+    /// assert!((angle.sinpi().to_f64() - 0.5).abs() < 1e-4);
+    ///
+    /// // Angle of the point (-0.5, 0.5), i.e. 3/4 of a half-turn.
+    /// let (y, x) = (Q1_15::from_f64(0.5), Q1_15::from_f64(-0.5));
+    /// assert!((y.atan2pi(&x).to_f64() - 0.75).abs() < 1e-4);
+    ///
+    /// // Results outside [-1, 1) saturate.
+    /// assert_eq!(Q1_15::from_f64(0.5).tanpi(), Q1_15::MAX);
+    ///
+    /// // Arguments outside the domain are clamped to it.
+    /// assert_eq!(Q1_15::from_f64(0.5).acosh(), Q1_15(0));
+    /// assert_eq!(Q1_15(-32768).asech(), Q1_15::MAX);
+    /// ```
+    Q1_15,
+    i16,
+    15
+);
+
+fixed_type!(
+    /// A Q16.16 fixed-point number: an [`i32`] with 16 fractional bits, covering $[-32768,32768)$
+    /// in steps of $2^{-16}$.
+    ///
+    /// # Note
+    ///
+    /// Results that do not fit the type (e.g. at the poles) saturate to [`Q16_16::MIN`] or
+    /// [`Q16_16::MAX`], and arguments outside the domain of an inverse function are clamped to it
+    /// (e.g. `asin` of $2$ is $\frac{\pi}{2}$), since fixed-point numbers have no infinities or
+    /// `NaN`. In particular, `acosh` of $x<1$ is $0$, and `asech` of $x\leq0$ saturates to
+    /// [`Q16_16::MAX`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use trig::{Q16_16, Trig};
+    ///
+    /// let x = Q16_16::from_f64(30.0);
+    /// assert!((x.sind().to_f64() - 0.5).abs() < 1e-4);
+    /// assert_eq!(Q16_16::from_f64(90.0).tand(), Q16_16::MAX);
+    ///
+    /// // Arguments outside the domain are clamped to it.
+    /// assert_eq!(Q16_16::from_f64(0.5).acosh(), Q16_16(0));
+    /// assert_eq!(Q16_16::from_f64(-2.0).asech(), Q16_16::MAX);
+    /// ```
+    Q16_16,
+    i32,
+    16
+);
+
+fixed_type!(
+    /// A Q1.31 fixed-point number: an [`i32`] with 31 fractional bits, covering $[-1,1)$ in steps
+    /// of $2^{-31}$.
+    ///
+    /// # Note
+    ///
+    /// The [`Trig`] methods are evaluated with 29 fractional bits, so that angles up to $\pi$ fit
+    /// in their intermediate results. Their arguments are therefore rounded to $2^{-29}$, and
+    /// their results are accurate to about $2^{-29}$ rather than $2^{-31}$.
+    ///
+    /// As with [`Q1_15`], results outside $[-1,1)$ saturate to [`Q1_31::MIN`] or [`Q1_31::MAX`]
+    /// and arguments outside the domain of an inverse function are clamped to it, so that `acosh`
+    /// of $x<1$ is $0$ and `asech` of $x\leq0$ saturates to [`Q1_31::MAX`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use trig::{Q1_31, Trig};
+    ///
+    /// let x = Q1_31::from_f64(0.5);
+    /// assert!((x.asinpi().to_f64() - 1.0 / 6.0).abs() < 1e-8);
+    /// assert_eq!(x.acosh(), Q1_31(0));
+    /// assert_eq!(Q1_31::MIN.asech(), Q1_31::MAX);
+    /// ```
+    Q1_31,
+    i32,
+    31
+);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Checks a method of a fixed-point type against a reference.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Name of the method.
+    /// * `x` - Argument.
+    /// * `ulp` - Unit in the last place of the working format.
+    /// * `(min, max)` - Range of the fixed-point type.
+    /// * `f` - Result of the method.
+    /// * `g` - Reference function.
+    ///
+    /// # Note
+    ///
+    /// The allowed error is a few units in the last place of the working format (relative to the
+    /// result when it is larger than 1), plus the change of the reference over one unit in the
+    /// last place of the argument, since the argument is itself rounded to the working format.
+    fn check(name: &str, x: f64, ulp: f64, (min, max): (f64, f64), f: f64, g: impl Fn(f64) -> f64) {
+        let expected = g(x);
+        if expected.is_nan() {
+            return;
+        }
+        let clamp = |v: f64| v.clamp(min, max);
+        // Differences across the boundary of the domain are NaN, and ignored by `max`.
+        let change = |y: f64| (clamp(g(y)) - clamp(expected)).abs().max(0.0);
+        let conditioning = change(x + ulp) + change(x - ulp);
+        let bound = 8.0 * ulp * expected.abs().max(1.0) + conditioning;
+        let expected = clamp(expected);
+        assert!(
+            (f - expected).abs() <= bound,
+            "{name}({x}) = {f}, expected {expected}"
+        );
+    }
+
+    /// Checks every method of a fixed-point type against [`f64`] references.
+    macro_rules! check_type {
+        ($T:ident, $working_frac_bits:expr, $step:expr) => {{
+            let ulp = 1.0 / (1u64 << $working_frac_bits) as f64;
+            let range = ($T::MIN.to_f64(), $T::MAX.to_f64());
+            let y = $T::from_f64(0.3);
+            for k in ($T::MIN.0 as i64..=$T::MAX.0 as i64).step_by($step) {
+                let t = $T(k as _);
+                let x = t.to_f64();
+                macro_rules! unary {
+                    ($f:ident, $g:expr) => {
+                        check(stringify!($f), x, ulp, range, Trig::$f(&t).to_f64(), $g)
+                    };
+                }
+                unary!(sin, f64::sin);
+                unary!(cos, f64::cos);
+                unary!(tan, f64::tan);
+                unary!(csc, |x| 1.0 / x.sin());
+                unary!(sec, |x| 1.0 / x.cos());
+                unary!(cot, |x| 1.0 / x.tan());
+                unary!(asin, f64::asin);
+                unary!(acos, f64::acos);
+                unary!(atan, f64::atan);
+                unary!(acsc, |x| (1.0 / x).asin());
+                unary!(asec, |x| (1.0 / x).acos());
+                unary!(acot, |x| (1.0 / x).atan());
+                unary!(deg2rad, f64::to_radians);
+                unary!(rad2deg, f64::to_degrees);
+                unary!(sind, |x| x.to_radians().sin());
+                unary!(cosd, |x| x.to_radians().cos());
+                unary!(tand, |x| x.to_radians().tan());
+                unary!(cscd, |x| 1.0 / x.to_radians().sin());
+                unary!(secd, |x| 1.0 / x.to_radians().cos());
+                unary!(cotd, |x| 1.0 / x.to_radians().tan());
+                unary!(asind, |x| x.asin().to_degrees());
+                unary!(acosd, |x| x.acos().to_degrees());
+                unary!(atand, |x| x.atan().to_degrees());
+                unary!(acscd, |x| (1.0 / x).asin().to_degrees());
+                unary!(asecd, |x| (1.0 / x).acos().to_degrees());
+                unary!(acotd, |x| (1.0 / x).atan().to_degrees());
+                unary!(sinpi, |x| (x * core::f64::consts::PI).sin());
+                unary!(cospi, |x| (x * core::f64::consts::PI).cos());
+                unary!(tanpi, |x| (x * core::f64::consts::PI).tan());
+                unary!(asinpi, |x| x.asin() / core::f64::consts::PI);
+                unary!(acospi, |x| x.acos() / core::f64::consts::PI);
+                unary!(atanpi, |x| x.atan() / core::f64::consts::PI);
+                unary!(sinh, f64::sinh);
+                unary!(cosh, f64::cosh);
+                unary!(tanh, f64::tanh);
+                unary!(csch, |x| 1.0 / x.sinh());
+                unary!(sech, |x| 1.0 / x.cosh());
+                unary!(coth, |x| 1.0 / x.tanh());
+                unary!(asinh, f64::asinh);
+                unary!(acosh, f64::acosh);
+                unary!(atanh, f64::atanh);
+                unary!(acsch, |x| (1.0 / x).asinh());
+                unary!(asech, |x| (1.0 / x).acosh());
+                unary!(acoth, |x| (1.0 / x).atanh());
+                let (s, c) = t.sincos();
+                check("sincos", x, ulp, range, s.to_f64(), f64::sin);
+                check("sincos", x, ulp, range, c.to_f64(), f64::cos);
+                let (s, c) = t.sincosd();
+                check("sincosd", x, ulp, range, s.to_f64(), |x| {
+                    x.to_radians().sin()
+                });
+                check("sincosd", x, ulp, range, c.to_f64(), |x| {
+                    x.to_radians().cos()
+                });
+                let (s, c) = t.sinhcosh();
+                check("sinhcosh", x, ulp, range, s.to_f64(), f64::sinh);
+                check("sinhcosh", x, ulp, range, c.to_f64(), f64::cosh);
+                let y0 = y.to_f64();
+                check("atan2", x, ulp, range, y.atan2(&t).to_f64(), |x| {
+                    y0.atan2(x)
+                });
+                check("atan2", x, ulp, range, t.atan2(&y).to_f64(), |x| {
+                    x.atan2(y0)
+                });
+                let atan2d = |x: f64| x.atan2(y0).to_degrees();
+                check("atan2d", x, ulp, range, t.atan2d(&y).to_f64(), atan2d);
+                let atan2pi = |x: f64| x.atan2(-y0) / core::f64::consts::PI;
+                check(
+                    "atan2pi",
+                    x,
+                    ulp,
+                    range,
+                    t.atan2pi(&$T(-y.0)).to_f64(),
+                    atan2pi,
+                );
+            }
+        }};
+    }
+
+    #[test]
+    fn test_accuracy() {
+        check_type!(Q1_15, 15, 7);
+        check_type!(Q16_16, 16, 104729);
+        check_type!(Q1_31, 29, 1299709);
+    }
+
+    #[test]
+    fn test_conversions() {
+        assert_eq!(Q1_15::from_f64(0.5), Q1_15(16384));
+        assert_eq!(Q1_15::from_f64(2.0), Q1_15::MAX);
+        assert_eq!(Q1_15::from_f64(-2.0), Q1_15::MIN);
+        assert_eq!(Q1_15::from_f64(f64::NAN), Q1_15(0));
+        assert_eq!(Q16_16::from_f64(-1.5).to_f64(), -1.5);
+        assert_eq!(Q1_31::from_f64(0.25), Q1_31(1 << 29));
+        assert_eq!(Q1_31::MIN.to_f64(), -1.0);
+    }
+
+    #[test]
+    fn test_special_values() {
+        let q = Q16_16::from_f64;
+
+        // Exact values at multiples of a quarter turn.
+        assert_eq!(q(90.0).sind(), q(1.0));
+        assert_eq!(q(-270.0).sind(), q(1.0));
+        assert_eq!(q(180.0).cosd(), q(-1.0));
+        assert_eq!(q(360.0).sind(), q(0.0));
+        assert_eq!(q(1.5).sinpi(), q(-1.0));
+
+        // Saturation at the poles.
+        assert_eq!(q(90.0).tand(), Q16_16::MAX);
+        assert_eq!(q(-90.0).tand(), Q16_16::MIN);
+        assert_eq!(q(0.0).cotd(), Q16_16::MAX);
+        assert_eq!(q(0.0).csc(), Q16_16::MAX);
+        assert_eq!(q(0.0).coth(), Q16_16::MAX);
+        assert_eq!(q(0.0).acsch(), Q16_16::MAX);
+        assert_eq!(q(0.0).asech(), Q16_16::MAX);
+        assert_eq!(q(1.0).atanh(), Q16_16::MAX);
+        assert_eq!(q(-1.0).atanh(), Q16_16::MIN);
+        assert_eq!(q(-1.0).acoth(), Q16_16::MIN);
+        assert_eq!(q(20.0).sinh(), Q16_16::MAX);
+        assert_eq!(q(-20.0).sinh(), Q16_16::MIN);
+        assert_eq!(q(20.0).cosh(), Q16_16::MAX);
+        assert_eq!(Q1_15::from_f64(0.0).cos(), Q1_15::MAX);
+
+        // Arguments outside the domain are clamped to it.
+        assert!((q(2.0).asind().0 - q(90.0).0).abs() <= 1);
+        assert_eq!(q(-2.0).acos(), q(-1.0).acos());
+        assert_eq!(q(0.5).acosh(), q(0.0));
+        assert_eq!(q(0.5).asecd(), q(0.0));
+    }
+}
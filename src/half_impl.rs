@@ -0,0 +1,292 @@
+use crate::trig_trait::Trig;
+use half::{bf16, f16};
+
+/// Largest distance (in [`f32`] ulps) from a rounding boundary of the half-precision type at which
+/// an [`f32`] result is re-evaluated in [`f64`].
+///
+/// This exceeds the maximum error of every [`Trig`] method on [`f32`] (see the "Accuracy" section of
+/// the crate documentation), so an [`f32`] result farther than this from a rounding boundary rounds
+/// to the same half-precision value as the exact result.
+const TOLERANCE: u32 = 64;
+
+/// Half-precision floating-point format.
+trait Half: Copy {
+    /// Number of significand bits of an [`f32`] that do not fit a normal number of this format.
+    const DISCARDED_BITS: u32;
+
+    /// Smallest positive normal number of this format.
+    const MIN_POSITIVE: f32;
+
+    /// Largest finite number of this format.
+    const MAX: f32;
+
+    /// Rounds an [`f32`] to this format (with ties to even).
+    fn from_f32(x: f32) -> Self;
+
+    /// Converts to an [`f32`] (exactly).
+    fn to_f32(self) -> f32;
+}
+
+impl Half for f16 {
+    const DISCARDED_BITS: u32 = f32::MANTISSA_DIGITS - f16::MANTISSA_DIGITS;
+    const MIN_POSITIVE: f32 = f16::MIN_POSITIVE.to_f32_const();
+    const MAX: f32 = f16::MAX.to_f32_const();
+    fn from_f32(x: f32) -> f16 {
+        f16::from_f32(x)
+    }
+    fn to_f32(self) -> f32 {
+        f16::to_f32(self)
+    }
+}
+
+impl Half for bf16 {
+    const DISCARDED_BITS: u32 = f32::MANTISSA_DIGITS - bf16::MANTISSA_DIGITS;
+    const MIN_POSITIVE: f32 = bf16::MIN_POSITIVE.to_f32_const();
+    const MAX: f32 = bf16::MAX.to_f32_const();
+    fn from_f32(x: f32) -> bf16 {
+        bf16::from_f32(x)
+    }
+    fn to_f32(self) -> f32 {
+        bf16::to_f32(self)
+    }
+}
+
+/// Rounds an [`f64`] to an [`f32`] with round-to-odd.
+///
+/// # Note
+///
+/// An inexact result has an odd significand, so that rounding it again (with ties to even) to a
+/// format with at least two fewer bits gives the same result as rounding the [`f64`] directly.
+fn round_to_odd(x: f64) -> f32 {
+    let f = x as f32;
+    if !f.is_finite() || f as f64 == x || f.to_bits() & 1 == 1 {
+        f
+    } else if (f as f64).abs() < x.abs() {
+        f32::from_bits(f.to_bits() + 1)
+    } else {
+        f32::from_bits(f.to_bits() - 1)
+    }
+}
+
+/// Rounds the result of a [`Trig`] method evaluated on [`f32`] to a half-precision type.
+///
+/// # Arguments
+///
+/// * `f` - Result of the method evaluated on [`f32`].
+/// * `fallback` - Evaluates the method on [`f64`].
+///
+/// # Returns
+///
+/// Correctly rounded result.
+///
+/// # Note
+///
+/// If `f` lies within [`TOLERANCE`] ulps of a rounding boundary, or outside the normal range of the
+/// half-precision type, it cannot be rounded reliably, so the method is re-evaluated in [`f64`]
+/// instead.
+fn round<T: Half>(f: f32, fallback: impl FnOnce() -> f64) -> T {
+    if (T::MIN_POSITIVE..=T::MAX).contains(&f.abs()) {
+        let discarded = f.to_bits() & ((1 << T::DISCARDED_BITS) - 1);
+        if discarded.abs_diff(1 << (T::DISCARDED_BITS - 1)) > TOLERANCE {
+            return T::from_f32(f);
+        }
+    }
+    T::from_f32(round_to_odd(fallback()))
+}
+
+/// Implements unary [`Trig`] methods on a half-precision type.
+macro_rules! unary_methods {
+    ($t:ty; $($name:ident),* $(,)?) => {
+        $(
+            fn $name(&self) -> $t {
+                round(Trig::$name(&Half::to_f32(*self)), || Trig::$name(&self.to_f64()))
+            }
+        )*
+    };
+}
+
+/// Implements pair-valued [`Trig`] methods on a half-precision type.
+macro_rules! pair_methods {
+    ($t:ty; $($name:ident),* $(,)?) => {
+        $(
+            fn $name(&self) -> ($t, $t) {
+                let (a, b) = Trig::$name(&Half::to_f32(*self));
+                (
+                    round(a, || Trig::$name(&self.to_f64()).0),
+                    round(b, || Trig::$name(&self.to_f64()).1),
+                )
+            }
+        )*
+    };
+}
+
+/// Implements binary [`Trig`] methods on a half-precision type.
+macro_rules! binary_methods {
+    ($t:ty; $($name:ident),* $(,)?) => {
+        $(
+            fn $name(&self, other: &$t) -> $t {
+                round(
+                    Trig::$name(&Half::to_f32(*self), &Half::to_f32(*other)),
+                    || Trig::$name(&self.to_f64(), &other.to_f64()),
+                )
+            }
+        )*
+    };
+}
+
+/// Implements the [`Trig`] trait on a half-precision type.
+///
+/// Every method is evaluated on [`f32`] (which represents every half-precision value exactly) and
+/// rounded once to the half-precision type, falling back to [`f64`] in the rare cases where the
+/// [`f32`] result is too close to a rounding boundary (see [`round`]).
+macro_rules! impl_trig {
+    ($t:ty) => {
+        impl Trig for $t {
+            unary_methods!(
+                $t;
+                sin, cos, tan, csc, sec, cot,
+                asin, acos, atan, acsc, asec, acot,
+                deg2rad, rad2deg,
+                sind, cosd, tand, cscd, secd, cotd,
+                asind, acosd, atand, acscd, asecd, acotd,
+                sinpi, cospi, tanpi,
+                asinpi, acospi, atanpi,
+                sinh, cosh, tanh, csch, sech, coth,
+                asinh, acosh, atanh, acsch, asech, acoth,
+            );
+            pair_methods!($t; sincos, sincosd, sinhcosh);
+            binary_methods!($t; atan2, atan2d, atan2pi);
+        }
+    };
+}
+
+impl_trig!(f16);
+impl_trig!(bf16);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Step between the bit patterns of the inputs tested (every input is tested unless the
+    /// correctly rounded [`f32`] methods, which are much slower, are enabled).
+    const STEP: usize = if cfg!(feature = "correctly-rounded") {
+        17
+    } else {
+        1
+    };
+
+    /// Rounds an [`f64`] to the nearest value of a half-precision type (with ties to even).
+    macro_rules! round {
+        ($t:ty, $x:expr) => {{
+            let x: f64 = $x;
+            let max = <$t>::MAX.to_f64();
+            let below_max = <$t>::from_bits(<$t>::MAX.to_bits() - 1).to_f64();
+            if x.is_nan() {
+                <$t>::NAN
+            } else if x.abs() >= max + (max - below_max) / 2.0 {
+                <$t>::INFINITY.copysign(<$t>::from_f64(x))
+            } else {
+                // The nearest value is the conversion or one of its neighbors.
+                let c = <$t>::from_f64(x).to_bits();
+                let mut nearest = <$t>::from_bits(c);
+                for bits in [c.wrapping_sub(1), c.wrapping_add(1)] {
+                    let candidate = <$t>::from_bits(bits);
+                    let (d, e) = ((candidate.to_f64() - x).abs(), (nearest.to_f64() - x).abs());
+                    if d < e || (d == e && bits % 2 == 0) {
+                        nearest = candidate;
+                    }
+                }
+                nearest
+            }
+        }};
+    }
+
+    /// Counts the inputs of a half-precision type whose result differs from the correctly rounded
+    /// result, taken as the [`f64`] result rounded to the half-precision type.
+    macro_rules! count_misrounded {
+        ($t:ty) => {{
+            let mut misrounded = 0;
+            for bits in (0..=u16::MAX).step_by(STEP) {
+                let x = <$t>::from_bits(bits);
+                let xf = x.to_f64();
+                let mut check = |name: &str, actual: $t, expected: f64| {
+                    let expected = round!($t, expected);
+                    let same = actual.to_bits() == expected.to_bits()
+                        || (actual.is_nan() && expected.is_nan());
+                    if !same {
+                        misrounded += 1;
+                        println!("{name}({x}): {actual} != {expected}");
+                    }
+                };
+                check("sin", x.sin(), xf.sin());
+                check("cos", x.cos(), xf.cos());
+                check("tan", x.tan(), xf.tan());
+                check("csc", x.csc(), xf.csc());
+                check("sec", x.sec(), xf.sec());
+                check("cot", x.cot(), xf.cot());
+                check("sincos (sin)", x.sincos().0, xf.sincos().0);
+                check("sincos (cos)", x.sincos().1, xf.sincos().1);
+                check("asin", x.asin(), xf.asin());
+                check("acos", x.acos(), xf.acos());
+                check("atan", x.atan(), xf.atan());
+                check("acsc", x.acsc(), xf.acsc());
+                check("asec", x.asec(), xf.asec());
+                check("acot", x.acot(), xf.acot());
+                check("deg2rad", x.deg2rad(), xf.deg2rad());
+                check("rad2deg", x.rad2deg(), xf.rad2deg());
+                check("sind", x.sind(), xf.sind());
+                check("cosd", x.cosd(), xf.cosd());
+                check("tand", x.tand(), xf.tand());
+                check("cscd", x.cscd(), xf.cscd());
+                check("secd", x.secd(), xf.secd());
+                check("cotd", x.cotd(), xf.cotd());
+                check("sincosd (sin)", x.sincosd().0, xf.sincosd().0);
+                check("sincosd (cos)", x.sincosd().1, xf.sincosd().1);
+                check("asind", x.asind(), xf.asind());
+                check("acosd", x.acosd(), xf.acosd());
+                check("atand", x.atand(), xf.atand());
+                check("acscd", x.acscd(), xf.acscd());
+                check("asecd", x.asecd(), xf.asecd());
+                check("acotd", x.acotd(), xf.acotd());
+                check("sinpi", x.sinpi(), xf.sinpi());
+                check("cospi", x.cospi(), xf.cospi());
+                check("tanpi", x.tanpi(), xf.tanpi());
+                check("asinpi", x.asinpi(), xf.asinpi());
+                check("acospi", x.acospi(), xf.acospi());
+                check("atanpi", x.atanpi(), xf.atanpi());
+                check("sinh", x.sinh(), xf.sinh());
+                check("cosh", x.cosh(), xf.cosh());
+                check("tanh", x.tanh(), xf.tanh());
+                check("csch", x.csch(), xf.csch());
+                check("sech", x.sech(), xf.sech());
+                check("coth", x.coth(), xf.coth());
+                check("sinhcosh (sinh)", x.sinhcosh().0, xf.sinhcosh().0);
+                check("sinhcosh (cosh)", x.sinhcosh().1, xf.sinhcosh().1);
+                check("asinh", x.asinh(), xf.asinh());
+                check("acosh", x.acosh(), xf.acosh());
+                check("atanh", x.atanh(), xf.atanh());
+                check("acsch", x.acsch(), xf.acsch());
+                check("asech", x.asech(), xf.asech());
+                check("acoth", x.acoth(), xf.acoth());
+                for y in [0.0, -0.0, 0.5, -1.0, 3.0, -7.25, 1e-3] {
+                    let y = <$t>::from_f64(y);
+                    let yf = y.to_f64();
+                    check("atan2", x.atan2(&y), Trig::atan2(&xf, &yf));
+                    check("atan2d", x.atan2d(&y), xf.atan2d(&yf));
+                    check("atan2pi", x.atan2pi(&y), xf.atan2pi(&yf));
+                }
+            }
+            misrounded
+        }};
+    }
+
+    #[test]
+    fn test_f16_correctly_rounded() {
+        assert_eq!(count_misrounded!(f16), 0);
+    }
+
+    #[test]
+    fn test_bf16_correctly_rounded() {
+        assert_eq!(count_misrounded!(bf16), 0);
+    }
+}
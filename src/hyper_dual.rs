@@ -0,0 +1,325 @@
+use crate::dual::Dual;
+use crate::real::Real;
+use crate::trig_trait::Trig;
+use core::ops::{Add, Div, Mul, Neg, Sub};
+
+/// Hyper-dual number for exact second derivatives.
+///
+/// A hyper-dual number $a+b\varepsilon_1+c\varepsilon_2+d\varepsilon_1\varepsilon_2$ (with
+/// $\varepsilon_1^2=\varepsilon_2^2=0$ and $\varepsilon_1\varepsilon_2\neq0$) carries a value
+/// together with two first derivatives and a mixed second derivative. Every function is applied as
+///
+/// $$f(x)=f(a)+f'(a)b\varepsilon_1+f'(a)c\varepsilon_2+\left(f'(a)d+f''(a)bc\right)\varepsilon_1\varepsilon_2,$$
+///
+/// so seeding $x_i$ with $\varepsilon_1$ and $x_j$ with $\varepsilon_2$ gives the Hessian entry
+/// $\frac{\partial^2f}{\partial x_i\partial x_j}$ in the $\varepsilon_1\varepsilon_2$ part, exact up
+/// to rounding (no finite differencing and no subtractive cancellation).
+///
+/// # Note
+///
+/// A hyper-dual number is a [`Dual`] number whose parts are themselves [`Dual`] numbers (with
+/// $\varepsilon_1$ as the inner and $\varepsilon_2$ as the outer perturbation), and the [`Trig`]
+/// methods are evaluated that way, so that the second derivatives come from differentiating the
+/// closed-form first derivatives of [`Dual`].
+///
+/// # References
+///
+/// * Fike, J. A., & Alonso, J. J. (2011). The development of hyper-dual numbers for exact
+///   second-derivative calculations. *49th AIAA Aerospace Sciences Meeting*, AIAA 2011-886.
+///
+/// # Example
+///
+/// ```
+/// use trig::{HyperDual, Trig};
+///
+/// // d²/dx² sech(x) = sech(x)(2 tanh²(x) - 1).
+/// let x = 0.7_f64;
+/// let y = HyperDual::variable(x).sech();
+/// assert!((y.e1 + x.sech() * x.tanh()).abs() < 1e-15);
+/// assert!((y.e12 - x.sech() * (2.0 * x.tanh() * x.tanh() - 1.0)).abs() < 1e-15);
+///
+/// // ∂²/∂y∂x atan2(y, x) = (y² - x²) / (x² + y²)².
+/// let (y, x) = (HyperDual::new(1.0_f64, 1.0, 0.0, 0.0), HyperDual::new(2.0, 0.0, 1.0, 0.0));
+/// assert!((y.atan2(&x).e12 - (1.0 - 4.0) / 25.0).abs() < 1e-15);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+pub struct HyperDual<T> {
+    /// Value.
+    pub value: T,
+
+    /// First derivative along $\varepsilon_1$.
+    pub e1: T,
+
+    /// First derivative along $\varepsilon_2$.
+    pub e2: T,
+
+    /// Mixed second derivative (along $\varepsilon_1\varepsilon_2$).
+    pub e12: T,
+}
+
+impl<T: Real> HyperDual<T> {
+    /// Constructs a hyper-dual number.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Value.
+    /// * `e1` - First derivative along $\varepsilon_1$.
+    /// * `e2` - First derivative along $\varepsilon_2$.
+    /// * `e12` - Mixed second derivative.
+    ///
+    /// # Returns
+    ///
+    /// Hyper-dual number with the given parts.
+    pub fn new(value: T, e1: T, e2: T, e12: T) -> HyperDual<T> {
+        HyperDual { value, e1, e2, e12 }
+    }
+
+    /// Constructs the independent variable of a (single-variable) differentiation.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Value.
+    ///
+    /// # Returns
+    ///
+    /// Hyper-dual number with both first derivatives equal to $1$, so that `e1` and `e2` hold
+    /// $f'(x)$ and `e12` holds $f''(x)$.
+    pub fn variable(value: T) -> HyperDual<T> {
+        let (zero, one) = (T::from_f64(0.0), T::from_f64(1.0));
+        HyperDual::new(value, one, one, zero)
+    }
+
+    /// Constructs a constant.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Value.
+    ///
+    /// # Returns
+    ///
+    /// Hyper-dual number with zero derivatives.
+    pub fn constant(value: T) -> HyperDual<T> {
+        let zero = T::from_f64(0.0);
+        HyperDual::new(value, zero, zero, zero)
+    }
+
+    /// Converts this number to a nested dual number.
+    ///
+    /// # Returns
+    ///
+    /// $(a+b\varepsilon_1)+(c+d\varepsilon_1)\varepsilon_2$.
+    fn to_dual(self) -> Dual<Dual<T>> {
+        Dual::new(Dual::new(self.value, self.e1), Dual::new(self.e2, self.e12))
+    }
+
+    /// Converts a nested dual number to a hyper-dual number.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - Nested dual number $(a+b\varepsilon_1)+(c+d\varepsilon_1)\varepsilon_2$.
+    ///
+    /// # Returns
+    ///
+    /// $a+b\varepsilon_1+c\varepsilon_2+d\varepsilon_1\varepsilon_2$.
+    fn from_dual(x: Dual<Dual<T>>) -> HyperDual<T> {
+        HyperDual::new(
+            x.value.value,
+            x.value.derivative,
+            x.derivative.value,
+            x.derivative.derivative,
+        )
+    }
+}
+
+impl<T: Real> From<T> for HyperDual<T> {
+    fn from(value: T) -> HyperDual<T> {
+        HyperDual::constant(value)
+    }
+}
+
+impl<T: Real> Neg for HyperDual<T> {
+    type Output = HyperDual<T>;
+    fn neg(self) -> HyperDual<T> {
+        HyperDual::from_dual(-self.to_dual())
+    }
+}
+
+/// Implements an arithmetic operator on hyper-dual numbers in terms of nested dual numbers.
+macro_rules! operator {
+    ($trait:ident, $method:ident) => {
+        impl<T: Real> $trait for HyperDual<T> {
+            type Output = HyperDual<T>;
+            fn $method(self, other: HyperDual<T>) -> HyperDual<T> {
+                HyperDual::from_dual($trait::$method(self.to_dual(), other.to_dual()))
+            }
+        }
+    };
+}
+
+operator!(Add, add);
+operator!(Sub, sub);
+operator!(Mul, mul);
+operator!(Div, div);
+
+impl<T: Real> Real for HyperDual<T> {
+    fn from_f64(x: f64) -> HyperDual<T> {
+        HyperDual::constant(T::from_f64(x))
+    }
+    fn sqrt(&self) -> HyperDual<T> {
+        HyperDual::from_dual(self.to_dual().sqrt())
+    }
+    fn abs(&self) -> HyperDual<T> {
+        HyperDual::from_dual(self.to_dual().abs())
+    }
+}
+
+/// Implements unary [`Trig`] methods on hyper-dual numbers.
+macro_rules! unary_methods {
+    ($($name:ident),* $(,)?) => {
+        $(
+            fn $name(&self) -> HyperDual<T> {
+                HyperDual::from_dual(Trig::$name(&self.to_dual()))
+            }
+        )*
+    };
+}
+
+/// Implements pair-valued [`Trig`] methods on hyper-dual numbers.
+macro_rules! pair_methods {
+    ($($name:ident),* $(,)?) => {
+        $(
+            fn $name(&self) -> (HyperDual<T>, HyperDual<T>) {
+                let (a, b) = Trig::$name(&self.to_dual());
+                (HyperDual::from_dual(a), HyperDual::from_dual(b))
+            }
+        )*
+    };
+}
+
+/// Implements binary [`Trig`] methods on hyper-dual numbers.
+macro_rules! binary_methods {
+    ($($name:ident),* $(,)?) => {
+        $(
+            fn $name(&self, other: &HyperDual<T>) -> HyperDual<T> {
+                HyperDual::from_dual(Trig::$name(&self.to_dual(), &other.to_dual()))
+            }
+        )*
+    };
+}
+
+impl<T: Real> Trig for HyperDual<T> {
+    unary_methods!(
+        sin, cos, tan, csc, sec, cot, asin, acos, atan, acsc, asec, acot, deg2rad, rad2deg, sind,
+        cosd, tand, cscd, secd, cotd, asind, acosd, atand, acscd, asecd, acotd, sinpi, cospi,
+        tanpi, asinpi, acospi, atanpi, sinh, cosh, tanh, csch, sech, coth, asinh, acosh, atanh,
+        acsch, asech, acoth,
+    );
+    pair_methods!(sincos, sincosd, sinhcosh);
+    binary_methods!(atan2, atan2d, atan2pi);
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Asserts that the second derivatives of unary [`Trig`] methods match central finite
+    /// differences of their first derivatives (computed with [`Dual`] numbers).
+    macro_rules! check_unary {
+        ($($name:ident),* $(,)?) => {
+            $(
+                for x in [0.3, -0.45, 1.7, -2.5, 30.0, 100.0] {
+                    let f = |x: f64| Trig::$name(&Dual::variable(x)).derivative;
+                    let h = 1e-6 * f64::max(1.0, x.abs());
+                    let (lo, hi) = (f(x - h), f(x + h));
+                    if !(lo.is_finite() && hi.is_finite() && f(x).is_finite()) {
+                        continue;
+                    }
+                    let expected = (hi - lo) / (2.0 * h);
+                    let y = Trig::$name(&HyperDual::variable(x));
+                    let value = Trig::$name(&x);
+                    assert_eq!(y.value.to_bits(), value.to_bits(), "{}({x})", stringify!($name));
+                    assert_eq!(y.e1.to_bits(), f(x).to_bits(), "{}'({x})", stringify!($name));
+                    assert_eq!(y.e2.to_bits(), f(x).to_bits(), "{}'({x})", stringify!($name));
+                    assert!(
+                        (y.e12 - expected).abs() <= 1e-4 * f64::max(1.0, expected.abs()),
+                        "{}''({x}) = {}, expected {expected}",
+                        stringify!($name),
+                        y.e12
+                    );
+                }
+            )*
+        };
+    }
+
+    #[test]
+    fn test_second_derivatives() {
+        check_unary!(
+            sin, cos, tan, csc, sec, cot, asin, acos, atan, acsc, asec, acot, deg2rad, rad2deg,
+            sind, cosd, tand, cscd, secd, cotd, asind, acosd, atand, acscd, asecd, acotd, sinpi,
+            cospi, tanpi, asinpi, acospi, atanpi, sinh, cosh, tanh, csch, sech, coth, asinh, acosh,
+            atanh, acsch, asech, acoth,
+        );
+    }
+
+    #[test]
+    fn test_closed_forms() {
+        let x = 0.8_f64;
+        assert!((HyperDual::variable(x).sin().e12 + x.sin()).abs() < 1e-15);
+        assert!((HyperDual::variable(x).cosh().e12 - x.cosh()).abs() < 1e-15);
+
+        // d²/dx² tan(x) = 2 sec²(x) tan(x).
+        let expected = 2.0 * x.sec() * x.sec() * x.tan();
+        assert!((HyperDual::variable(x).tan().e12 - expected).abs() < 1e-14);
+
+        // d²/dx² acotd(x) = (180/π) 2x / (1 + x²)².
+        let expected = x.rad2deg() * 2.0 / ((1.0 + x * x) * (1.0 + x * x));
+        assert!((HyperDual::variable(x).acotd().e12 - expected).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_hessian() {
+        // f(x, y) = sin(x) sech(y) + atan2(y, x).
+        let f = |x: HyperDual<f64>, y: HyperDual<f64>| x.sin() * y.sech() + y.atan2(&x);
+        let (x, y) = (0.6_f64, -1.3_f64);
+        let r2 = x * x + y * y;
+        let hxx = -x.sin() * y.sech() + 2.0 * x * y / (r2 * r2);
+        let hyy = x.sin() * y.sech() * (2.0 * y.tanh() * y.tanh() - 1.0) - 2.0 * x * y / (r2 * r2);
+        let hxy = -x.cos() * y.sech() * y.tanh() + (y * y - x * x) / (r2 * r2);
+        let seed = |v: f64, e1: f64, e2: f64| HyperDual::new(v, e1, e2, 0.0);
+        let cases = [
+            (seed(x, 1.0, 1.0), seed(y, 0.0, 0.0), hxx),
+            (seed(x, 0.0, 0.0), seed(y, 1.0, 1.0), hyy),
+            (seed(x, 1.0, 0.0), seed(y, 0.0, 1.0), hxy),
+            (seed(x, 0.0, 1.0), seed(y, 1.0, 0.0), hxy),
+        ];
+        for (x, y, expected) in cases {
+            let h = f(x, y).e12;
+            assert!((h - expected).abs() < 1e-14, "{h} != {expected}");
+        }
+    }
+
+    #[test]
+    fn test_pairs_and_arithmetic() {
+        let x = HyperDual::variable(0.4_f64);
+        let (s, c) = x.sincos();
+        assert_eq!(s, x.sin());
+        assert_eq!(c, x.cos());
+        let (sh, ch) = x.sinhcosh();
+        for (a, b) in [(sh, x.sinh()), (ch, x.cosh())] {
+            let parts = [
+                (a.value, b.value),
+                (a.e1, b.e1),
+                (a.e2, b.e2),
+                (a.e12, b.e12),
+            ];
+            assert!(parts.iter().all(|(a, b)| (a - b).abs() < 1e-15));
+        }
+
+        // d²/dx² (x² / (1 + x)) = 2 / (1 + x)³.
+        let y = x * x / (HyperDual::from(1.0) + x);
+        assert!((y.e12 - 2.0 / (1.4_f64 * 1.4 * 1.4)).abs() < 1e-15);
+        assert_eq!((-x).e1, -1.0);
+        assert_eq!((x - x).value, 0.0);
+        assert_eq!(HyperDual::constant(2.0).sin().e12, 0.0);
+    }
+}
@@ -0,0 +1,1016 @@
+use crate::rem_pio2::rem_pio2;
+use crate::trig_trait::Trig;
+use core::f64::consts::{FRAC_PI_2, PI};
+
+/// Number of ulps by which each computed endpoint is moved outward.
+///
+/// This is four times the largest error of the [`Trig`] methods on [`f64`] that are evaluated
+/// directly at the endpoints (see the "Accuracy" section of the crate documentation), which also
+/// covers the halving of the ulp when an endpoint is moved across a power of two.
+const MARGIN: u32 = 16;
+
+/// Angular unit.
+#[derive(Clone, Copy)]
+pub(crate) enum Unit {
+    /// Radians.
+    Radians,
+
+    /// Degrees.
+    Degrees,
+
+    /// Half-turns.
+    HalfTurns,
+}
+
+impl Unit {
+    /// A quarter turn in this unit.
+    ///
+    /// # Returns
+    ///
+    /// $\frac{\pi}{2}$ (rounded), $90$, or $\frac{1}{2}$.
+    pub(crate) fn quarter_turn(self) -> f64 {
+        match self {
+            Unit::Radians => FRAC_PI_2,
+            Unit::Degrees => 90.0,
+            Unit::HalfTurns => 0.5,
+        }
+    }
+
+    /// Reduces a finite angle modulo a quarter turn.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - Angle in this unit.
+    ///
+    /// # Returns
+    ///
+    /// Quadrant `q` (in $\\{0,1,2,3\\}$) and remainder `r` (with $|r|$ at most about half of a
+    /// quarter turn) such that `x` is equal to `q` quarter turns plus `r` modulo a full turn. The
+    /// remainder is exact in degrees and half-turns, and has the exact sign in radians.
+    pub(crate) fn reduce(self, x: f64) -> (u8, f64) {
+        match self {
+            Unit::Radians => {
+                let (q, hi, _) = rem_pio2(x);
+                (q, hi)
+            }
+            Unit::Degrees | Unit::HalfTurns => {
+                // Both the remainder modulo a full turn and the subtraction of a small multiple of
+                // a quarter turn are exact.
+                let quarter_turn = self.quarter_turn();
+                let x = x % (4.0 * quarter_turn);
+                let n = nearest(x / quarter_turn);
+                (n.rem_euclid(4) as u8, x - n as f64 * quarter_turn)
+            }
+        }
+    }
+}
+
+/// Behavior of a periodic function at a multiple of a quarter turn.
+#[derive(Clone, Copy)]
+enum Critical {
+    /// Neither an extremum nor a pole.
+    Regular,
+
+    /// Extremum with the given (exact) value.
+    Extremum(f64),
+
+    /// Pole with the given limits from the left and from the right.
+    Pole(f64, f64),
+}
+
+/// Rounds to the nearest integer (with ties rounded away from zero).
+///
+/// # Arguments
+///
+/// * `x` - Value with $|x|<2^{62}$.
+///
+/// # Returns
+///
+/// Integer nearest to `x`.
+fn nearest(x: f64) -> i64 {
+    if x >= 0.0 {
+        (x + 0.5) as i64
+    } else {
+        (x - 0.5) as i64
+    }
+}
+
+/// Moves a computed value outward to a lower bound of the exact value.
+///
+/// # Arguments
+///
+/// * `y` - Value computed by a [`Trig`] method on [`f64`].
+///
+/// # Returns
+///
+/// Lower bound of the exact value (by [`MARGIN`] ulps, or by the smallest positive normal number
+/// below it, where an underflowing result has an absolute rather than a relative error).
+fn down(y: f64) -> f64 {
+    if y.is_nan() {
+        f64::NEG_INFINITY
+    } else if y.abs() < f64::MIN_POSITIVE {
+        y - f64::MIN_POSITIVE
+    } else {
+        (0..MARGIN).fold(y, |y, _| y.next_down())
+    }
+}
+
+/// Moves a computed value outward to an upper bound of the exact value.
+///
+/// # Arguments
+///
+/// * `y` - Value computed by a [`Trig`] method on [`f64`].
+///
+/// # Returns
+///
+/// Upper bound of the exact value (see [`down`]).
+fn up(y: f64) -> f64 {
+    -down(-y)
+}
+
+/// Closed interval of real numbers.
+///
+/// An interval $\[a,b\]$ represents every real number $x$ with $a\leq x\leq b$, where the endpoints
+/// may be infinite. The [`Trig`] methods on `Interval<f64>` are rigorous: each method returns an
+/// interval guaranteed to contain $f(x)$ for every $x$ in the input interval (its image), which the
+/// point-valued [`f64`] methods cannot guarantee on their own. This makes the type suitable for
+/// verified computing.
+///
+/// The image is enclosed as follows:
+///
+/// * Each endpoint is evaluated with the [`Trig`] method on [`f64`] and moved outward by a few
+///   ulps, which covers the error bounds listed in the "Accuracy" section of the crate
+///   documentation.
+/// * Extrema inside the interval are found exactly (e.g. `sin` over $\[0,4\]$ is enclosed by
+///   $\[\sin(4),1\]$, since $\frac{\pi}{2}$ lies inside), using the exact argument reduction of the
+///   crate.
+/// * An interval containing a pole (e.g. of `tan`, `sec`, or `coth`) in its interior has the whole
+///   real line as its image, while a pole at an endpoint gives a one-sided infinite image (e.g.
+///   `coth` over $\[0,1\]$ is enclosed by $\[\coth(1),\infty\]$).
+/// * The input is first clipped to the domain of the method (e.g. `asin` over
+///   $\[-2,\frac{1}{2}\]$ is enclosed by $\[-\frac{\pi}{2},\frac{\pi}{6}\]$, and `acosh` over
+///   $\[0,2\]$ by $\[0,\text{acosh}(2)\]$). An input outside the domain gives the empty interval.
+/// * An image that consists of two disjoint pieces (e.g. `csc` over $\[-1,1\]$) is enclosed by
+///   their hull.
+///
+/// # Example
+///
+/// ```
+/// use trig::{Interval, Trig};
+///
+/// let y = Interval::new(0.0, 4.0).sin();
+/// assert!(y.lo() <= 4.0_f64.sin() && y.lo() > 4.0_f64.sin() - 1e-14);
+/// assert_eq!(y.hi(), 1.0);
+///
+/// let y = Interval::new(1.0, 2.0).tan();
+/// assert_eq!((y.lo(), y.hi()), (f64::NEG_INFINITY, f64::INFINITY));
+///
+/// assert!(Interval::new(2.0, 3.0).asin().is_empty());
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Interval<T> {
+    /// Lower endpoint.
+    lo: T,
+
+    /// Upper endpoint.
+    hi: T,
+}
+
+impl<T: Copy + PartialOrd> Interval<T> {
+    /// Constructs an interval from its endpoints.
+    ///
+    /// # Arguments
+    ///
+    /// * `lo` - Lower endpoint.
+    /// * `hi` - Upper endpoint.
+    ///
+    /// # Returns
+    ///
+    /// Interval $\[\text{lo},\text{hi}\]$.
+    ///
+    /// # Panics
+    ///
+    /// If `lo` is greater than `hi`, or if either endpoint is `NaN`.
+    pub fn new(lo: T, hi: T) -> Interval<T> {
+        assert!(
+            lo <= hi,
+            "the lower endpoint must not exceed the upper endpoint"
+        );
+        Interval { lo, hi }
+    }
+
+    /// Constructs an interval containing a single number.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - Number.
+    ///
+    /// # Returns
+    ///
+    /// Interval $\[x,x\]$.
+    ///
+    /// # Panics
+    ///
+    /// If `x` is `NaN`.
+    pub fn point(x: T) -> Interval<T> {
+        Interval::new(x, x)
+    }
+
+    /// Lower endpoint of this interval.
+    ///
+    /// # Returns
+    ///
+    /// Lower endpoint (`NaN` for the empty interval).
+    pub fn lo(&self) -> T {
+        self.lo
+    }
+
+    /// Upper endpoint of this interval.
+    ///
+    /// # Returns
+    ///
+    /// Upper endpoint (`NaN` for the empty interval).
+    pub fn hi(&self) -> T {
+        self.hi
+    }
+
+    /// Determines whether this interval contains a number.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - Number.
+    ///
+    /// # Returns
+    ///
+    /// `true` if $\text{lo}\leq x\leq\text{hi}$.
+    pub fn contains(&self, x: T) -> bool {
+        self.lo <= x && x <= self.hi
+    }
+}
+
+impl Interval<f64> {
+    /// Empty interval.
+    pub const EMPTY: Interval<f64> = Interval {
+        lo: f64::NAN,
+        hi: f64::NAN,
+    };
+
+    /// Interval containing every real number.
+    pub const ENTIRE: Interval<f64> = Interval {
+        lo: f64::NEG_INFINITY,
+        hi: f64::INFINITY,
+    };
+
+    /// Determines whether this interval is empty.
+    ///
+    /// # Returns
+    ///
+    /// `true` if this interval contains no numbers.
+    pub fn is_empty(&self) -> bool {
+        self.lo.is_nan()
+    }
+
+    /// Hull of this interval and another interval.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - Other interval.
+    ///
+    /// # Returns
+    ///
+    /// Smallest interval containing both intervals.
+    pub fn hull(&self, other: &Interval<f64>) -> Interval<f64> {
+        if self.is_empty() {
+            *other
+        } else if other.is_empty() {
+            *self
+        } else {
+            Interval {
+                lo: self.lo.min(other.lo),
+                hi: self.hi.max(other.hi),
+            }
+        }
+    }
+
+    /// Intersection of this interval with another interval.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - Other interval.
+    ///
+    /// # Returns
+    ///
+    /// Largest interval contained in both intervals (possibly empty).
+    pub fn intersection(&self, other: &Interval<f64>) -> Interval<f64> {
+        let (lo, hi) = (self.lo.max(other.lo), self.hi.min(other.hi));
+        if self.is_empty() || other.is_empty() || lo > hi {
+            Interval::EMPTY
+        } else {
+            Interval { lo, hi }
+        }
+    }
+
+    /// Clips this interval to a domain.
+    ///
+    /// # Arguments
+    ///
+    /// * `lo` - Lower endpoint of the domain.
+    /// * `hi` - Upper endpoint of the domain.
+    ///
+    /// # Returns
+    ///
+    /// Intersection of this interval with $\[\text{lo},\text{hi}\]$.
+    fn clip(&self, lo: f64, hi: f64) -> Interval<f64> {
+        self.intersection(&Interval { lo, hi })
+    }
+
+    /// Excludes a pole at the boundary of the domain from a degenerate interval.
+    ///
+    /// # Arguments
+    ///
+    /// * `pole` - Pole.
+    ///
+    /// # Returns
+    ///
+    /// The empty interval if this interval only contains the pole, and this interval otherwise.
+    fn exclude_pole(&self, pole: f64) -> Interval<f64> {
+        if self.lo == pole && self.hi == pole {
+            Interval::EMPTY
+        } else {
+            *self
+        }
+    }
+
+    /// Image of this interval under an increasing function.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - Nondecreasing function.
+    ///
+    /// # Returns
+    ///
+    /// Enclosure of the image.
+    fn increasing(&self, f: fn(&f64) -> f64) -> Interval<f64> {
+        if self.is_empty() {
+            return Interval::EMPTY;
+        }
+        Interval {
+            lo: down(f(&self.lo)),
+            hi: up(f(&self.hi)),
+        }
+    }
+
+    /// Image of this interval under a decreasing function.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - Nonincreasing function.
+    ///
+    /// # Returns
+    ///
+    /// Enclosure of the image.
+    fn decreasing(&self, f: fn(&f64) -> f64) -> Interval<f64> {
+        if self.is_empty() {
+            return Interval::EMPTY;
+        }
+        Interval {
+            lo: down(f(&self.hi)),
+            hi: up(f(&self.lo)),
+        }
+    }
+
+    /// Pieces of this interval on either side of a gap around zero.
+    ///
+    /// # Arguments
+    ///
+    /// * `c` - Half-width of the gap (where $c=0$ excludes zero itself).
+    ///
+    /// # Returns
+    ///
+    /// Intersections of this interval with $(-\infty,-c\]$ and $\[c,\infty)$ (with $\mp0$ as the
+    /// endpoint at zero, so that a pole at zero evaluates to the one-sided limit).
+    fn split(&self, c: f64) -> (Interval<f64>, Interval<f64>) {
+        if self.is_empty() {
+            return (Interval::EMPTY, Interval::EMPTY);
+        }
+        let (negative, positive) = if c == 0.0 {
+            (self.lo < 0.0, self.hi > 0.0)
+        } else {
+            (self.lo <= -c, self.hi >= c)
+        };
+        let negative = if negative {
+            Interval {
+                lo: self.lo,
+                hi: self.hi.min(-c),
+            }
+        } else {
+            Interval::EMPTY
+        };
+        let positive = if positive {
+            Interval {
+                lo: self.lo.max(c),
+                hi: self.hi,
+            }
+        } else {
+            Interval::EMPTY
+        };
+        (negative, positive)
+    }
+
+    /// Image of this interval under a function that is monotonic on each side of a gap around zero.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - Function.
+    /// * `c` - Half-width of the gap (see [`split`](Interval::split)).
+    /// * `increasing` - Whether `f` is nondecreasing (rather than nonincreasing) on each side.
+    ///
+    /// # Returns
+    ///
+    /// Enclosure of the image (the hull of the images of both sides).
+    fn piecewise(&self, f: fn(&f64) -> f64, c: f64, increasing: bool) -> Interval<f64> {
+        let (negative, positive) = self.split(c);
+        if increasing {
+            negative.increasing(f).hull(&positive.increasing(f))
+        } else {
+            negative.decreasing(f).hull(&positive.decreasing(f))
+        }
+    }
+
+    /// Magnitudes of the numbers in this interval.
+    ///
+    /// # Returns
+    ///
+    /// Interval $\\{|x|\\}$.
+    fn abs(&self) -> Interval<f64> {
+        if self.is_empty() {
+            Interval::EMPTY
+        } else if self.lo >= 0.0 {
+            *self
+        } else if self.hi <= 0.0 {
+            Interval {
+                lo: -self.hi,
+                hi: -self.lo,
+            }
+        } else {
+            Interval {
+                lo: 0.0,
+                hi: self.hi.max(-self.lo),
+            }
+        }
+    }
+
+    /// Image of this interval under a periodic function.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - Function with a period of a full turn.
+    /// * `unit` - Angular unit.
+    /// * `critical` - Behavior of `f` at $0$, $1$, $2$, and $3$ quarter turns (every extremum and
+    ///   pole must lie at a multiple of a quarter turn, and `f` must be monotonic in between).
+    ///
+    /// # Returns
+    ///
+    /// Enclosure of the image.
+    fn periodic(&self, f: fn(&f64) -> f64, unit: Unit, critical: [Critical; 4]) -> Interval<f64> {
+        if self.is_empty() {
+            return Interval::EMPTY;
+        }
+        let mut image = Interval {
+            lo: f64::INFINITY,
+            hi: f64::NEG_INFINITY,
+        };
+        let mut include = |lo: f64, hi: f64| {
+            image.lo = image.lo.min(lo);
+            image.hi = image.hi.max(hi);
+        };
+
+        // An interval of two full turns or more (including an unbounded one) contains every
+        // extremum and pole.
+        let quarter_turn = unit.quarter_turn();
+        if self.lo.is_infinite() || self.hi.is_infinite() || self.hi - self.lo > 8.0 * quarter_turn
+        {
+            for c in critical {
+                match c {
+                    Critical::Regular => {}
+                    Critical::Extremum(y) => include(y, y),
+                    Critical::Pole(..) => return Interval::ENTIRE,
+                }
+            }
+            return image;
+        }
+
+        // The multiples of a quarter turn in the interval are the quadrants q_lo + k for k from
+        // k_first to k_last, where the number of quarter turns between the reductions of the
+        // endpoints is an integer that can be rounded from its approximation.
+        let (q_lo, r_lo) = unit.reduce(self.lo);
+        let (q_hi, r_hi) = unit.reduce(self.hi);
+        let turns = nearest(((self.hi - self.lo) + (r_lo - r_hi)) / quarter_turn);
+        let k_first = if r_lo > 0.0 { 1 } else { 0 };
+        let k_last = if r_hi < 0.0 { turns - 1 } else { turns };
+
+        // A pole at an endpoint contributes its one-sided limit from inside the interval.
+        match critical[q_lo as usize] {
+            Critical::Pole(_, right) if r_lo == 0.0 => include(right, right),
+            _ => {
+                let y = f(&self.lo);
+                include(down(y), up(y));
+            }
+        }
+        match critical[q_hi as usize] {
+            Critical::Pole(left, _) if r_hi == 0.0 => include(left, left),
+            _ => {
+                let y = f(&self.hi);
+                include(down(y), up(y));
+            }
+        }
+        for k in k_first..=k_last {
+            let at_endpoint = (k == 0 && r_lo == 0.0) || (k == turns && r_hi == 0.0);
+            match critical[(q_lo as i64 + k).rem_euclid(4) as usize] {
+                Critical::Regular => {}
+                Critical::Extremum(y) => include(y, y),
+                Critical::Pole(..) if at_endpoint => {}
+                Critical::Pole(..) => return Interval::ENTIRE,
+            }
+        }
+        image
+    }
+
+    /// Sine in a given unit.
+    fn sin_unit(&self, f: fn(&f64) -> f64, unit: Unit) -> Interval<f64> {
+        use Critical::{Extremum, Regular};
+        self.periodic(f, unit, [Regular, Extremum(1.0), Regular, Extremum(-1.0)])
+            .clip(-1.0, 1.0)
+    }
+
+    /// Cosine in a given unit.
+    fn cos_unit(&self, f: fn(&f64) -> f64, unit: Unit) -> Interval<f64> {
+        use Critical::{Extremum, Regular};
+        self.periodic(f, unit, [Extremum(1.0), Regular, Extremum(-1.0), Regular])
+            .clip(-1.0, 1.0)
+    }
+
+    /// Tangent in a given unit.
+    fn tan_unit(&self, f: fn(&f64) -> f64, unit: Unit) -> Interval<f64> {
+        use Critical::{Pole, Regular};
+        let pole = Pole(f64::INFINITY, f64::NEG_INFINITY);
+        self.periodic(f, unit, [Regular, pole, Regular, pole])
+    }
+
+    /// Cosecant in a given unit.
+    fn csc_unit(&self, f: fn(&f64) -> f64, unit: Unit) -> Interval<f64> {
+        use Critical::{Extremum, Pole};
+        let (inf, neg_inf) = (f64::INFINITY, f64::NEG_INFINITY);
+        self.periodic(
+            f,
+            unit,
+            [
+                Pole(neg_inf, inf),
+                Extremum(1.0),
+                Pole(inf, neg_inf),
+                Extremum(-1.0),
+            ],
+        )
+    }
+
+    /// Secant in a given unit.
+    fn sec_unit(&self, f: fn(&f64) -> f64, unit: Unit) -> Interval<f64> {
+        use Critical::{Extremum, Pole};
+        let (inf, neg_inf) = (f64::INFINITY, f64::NEG_INFINITY);
+        self.periodic(
+            f,
+            unit,
+            [
+                Extremum(1.0),
+                Pole(inf, neg_inf),
+                Extremum(-1.0),
+                Pole(neg_inf, inf),
+            ],
+        )
+    }
+
+    /// Cotangent in a given unit.
+    fn cot_unit(&self, f: fn(&f64) -> f64, unit: Unit) -> Interval<f64> {
+        use Critical::{Pole, Regular};
+        let pole = Pole(f64::NEG_INFINITY, f64::INFINITY);
+        self.periodic(f, unit, [pole, Regular, pole, Regular])
+    }
+
+    /// Two-argument inverse tangent in a given unit.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - Interval of $x$-coordinates (with this interval holding the $y$-coordinates).
+    /// * `f` - Two-argument inverse tangent on [`f64`].
+    /// * `half_turn` - A half turn in the unit of `f` (exact, or rounded down).
+    ///
+    /// # Returns
+    ///
+    /// Enclosure of the angles of the points in the box.
+    fn atan2_unit(
+        &self,
+        other: &Interval<f64>,
+        f: fn(&f64, &f64) -> f64,
+        half_turn: f64,
+    ) -> Interval<f64> {
+        if self.is_empty() || other.is_empty() {
+            return Interval::EMPTY;
+        }
+        let full = Interval {
+            lo: down(-half_turn),
+            hi: up(half_turn),
+        };
+
+        // A box crossing the negative x-axis (including the axis itself, where the angle is a half
+        // turn) has every angle in its image. Otherwise, the extreme angles are attained at its
+        // corners, where a zero y-coordinate is taken as +0 so that the angle on the negative
+        // x-axis is a positive half turn.
+        if other.lo < 0.0 && self.lo < 0.0 && self.hi >= 0.0 {
+            return full;
+        }
+        let positive_zero = |y: f64| if y == 0.0 { 0.0 } else { y };
+        let (y_lo, y_hi) = (positive_zero(self.lo), positive_zero(self.hi));
+        let mut image = Interval {
+            lo: f64::INFINITY,
+            hi: f64::NEG_INFINITY,
+        };
+        for y in [y_lo, y_hi] {
+            for x in [other.lo, other.hi] {
+                let angle = f(&y, &x);
+                image.lo = image.lo.min(down(angle));
+                image.hi = image.hi.max(up(angle));
+            }
+        }
+        image.intersection(&full)
+    }
+}
+
+impl Trig for Interval<f64> {
+    fn sin(&self) -> Interval<f64> {
+        self.sin_unit(Trig::sin, Unit::Radians)
+    }
+    fn cos(&self) -> Interval<f64> {
+        self.cos_unit(Trig::cos, Unit::Radians)
+    }
+    fn tan(&self) -> Interval<f64> {
+        self.tan_unit(Trig::tan, Unit::Radians)
+    }
+    fn csc(&self) -> Interval<f64> {
+        self.csc_unit(Trig::csc, Unit::Radians)
+    }
+    fn sec(&self) -> Interval<f64> {
+        self.sec_unit(Trig::sec, Unit::Radians)
+    }
+    fn cot(&self) -> Interval<f64> {
+        self.cot_unit(Trig::cot, Unit::Radians)
+    }
+    fn sincos(&self) -> (Interval<f64>, Interval<f64>) {
+        (self.sin(), self.cos())
+    }
+    fn asin(&self) -> Interval<f64> {
+        self.clip(-1.0, 1.0).increasing(Trig::asin)
+    }
+    fn acos(&self) -> Interval<f64> {
+        self.clip(-1.0, 1.0).decreasing(Trig::acos)
+    }
+    fn atan(&self) -> Interval<f64> {
+        self.increasing(Trig::atan)
+    }
+    fn atan2(&self, other: &Interval<f64>) -> Interval<f64> {
+        self.atan2_unit(other, Trig::atan2, PI)
+    }
+    fn acsc(&self) -> Interval<f64> {
+        self.piecewise(Trig::acsc, 1.0, false)
+    }
+    fn asec(&self) -> Interval<f64> {
+        self.piecewise(Trig::asec, 1.0, true)
+    }
+    fn acot(&self) -> Interval<f64> {
+        self.piecewise(Trig::acot, 0.0, false)
+    }
+    fn deg2rad(&self) -> Interval<f64> {
+        self.increasing(Trig::deg2rad)
+    }
+    fn rad2deg(&self) -> Interval<f64> {
+        self.increasing(Trig::rad2deg)
+    }
+    fn sind(&self) -> Interval<f64> {
+        self.sin_unit(Trig::sind, Unit::Degrees)
+    }
+    fn cosd(&self) -> Interval<f64> {
+        self.cos_unit(Trig::cosd, Unit::Degrees)
+    }
+    fn tand(&self) -> Interval<f64> {
+        self.tan_unit(Trig::tand, Unit::Degrees)
+    }
+    fn cscd(&self) -> Interval<f64> {
+        self.csc_unit(Trig::cscd, Unit::Degrees)
+    }
+    fn secd(&self) -> Interval<f64> {
+        self.sec_unit(Trig::secd, Unit::Degrees)
+    }
+    fn cotd(&self) -> Interval<f64> {
+        self.cot_unit(Trig::cotd, Unit::Degrees)
+    }
+    fn sincosd(&self) -> (Interval<f64>, Interval<f64>) {
+        (self.sind(), self.cosd())
+    }
+    fn asind(&self) -> Interval<f64> {
+        self.clip(-1.0, 1.0).increasing(Trig::asind)
+    }
+    fn acosd(&self) -> Interval<f64> {
+        self.clip(-1.0, 1.0).decreasing(Trig::acosd)
+    }
+    fn atand(&self) -> Interval<f64> {
+        self.increasing(Trig::atand)
+    }
+    fn atan2d(&self, other: &Interval<f64>) -> Interval<f64> {
+        // The radians are converted as an interval, since atan2d on f64 loses precision for angles
+        // that are subnormal in radians.
+        self.atan2(other).increasing(Trig::rad2deg)
+    }
+    fn acscd(&self) -> Interval<f64> {
+        self.piecewise(Trig::acscd, 1.0, false)
+    }
+    fn asecd(&self) -> Interval<f64> {
+        self.piecewise(Trig::asecd, 1.0, true)
+    }
+    fn acotd(&self) -> Interval<f64> {
+        self.piecewise(Trig::acotd, 0.0, false)
+    }
+    fn sinpi(&self) -> Interval<f64> {
+        self.sin_unit(Trig::sinpi, Unit::HalfTurns)
+    }
+    fn cospi(&self) -> Interval<f64> {
+        self.cos_unit(Trig::cospi, Unit::HalfTurns)
+    }
+    fn tanpi(&self) -> Interval<f64> {
+        self.tan_unit(Trig::tanpi, Unit::HalfTurns)
+    }
+    fn asinpi(&self) -> Interval<f64> {
+        self.clip(-1.0, 1.0).increasing(Trig::asinpi)
+    }
+    fn acospi(&self) -> Interval<f64> {
+        self.clip(-1.0, 1.0).decreasing(Trig::acospi)
+    }
+    fn atanpi(&self) -> Interval<f64> {
+        self.increasing(Trig::atanpi)
+    }
+    fn atan2pi(&self, other: &Interval<f64>) -> Interval<f64> {
+        self.atan2_unit(other, Trig::atan2pi, 1.0)
+    }
+    fn sinh(&self) -> Interval<f64> {
+        self.increasing(Trig::sinh)
+    }
+    fn cosh(&self) -> Interval<f64> {
+        self.abs().increasing(Trig::cosh).clip(1.0, f64::INFINITY)
+    }
+    fn tanh(&self) -> Interval<f64> {
+        self.increasing(Trig::tanh).clip(-1.0, 1.0)
+    }
+    fn csch(&self) -> Interval<f64> {
+        self.piecewise(Trig::csch, 0.0, false)
+    }
+    fn sech(&self) -> Interval<f64> {
+        self.abs().decreasing(Trig::sech).clip(0.0, 1.0)
+    }
+    fn coth(&self) -> Interval<f64> {
+        self.piecewise(Trig::coth, 0.0, false)
+    }
+    fn sinhcosh(&self) -> (Interval<f64>, Interval<f64>) {
+        (self.sinh(), self.cosh())
+    }
+    fn asinh(&self) -> Interval<f64> {
+        self.increasing(Trig::asinh)
+    }
+    fn acosh(&self) -> Interval<f64> {
+        self.clip(1.0, f64::INFINITY)
+            .increasing(Trig::acosh)
+            .clip(0.0, f64::INFINITY)
+    }
+    fn atanh(&self) -> Interval<f64> {
+        self.clip(-1.0, 1.0)
+            .exclude_pole(-1.0)
+            .exclude_pole(1.0)
+            .increasing(Trig::atanh)
+    }
+    fn acsch(&self) -> Interval<f64> {
+        self.piecewise(Trig::acsch, 0.0, false)
+    }
+    fn asech(&self) -> Interval<f64> {
+        self.clip(0.0, 1.0)
+            .exclude_pole(0.0)
+            .decreasing(Trig::asech)
+            .clip(0.0, f64::INFINITY)
+    }
+    fn acoth(&self) -> Interval<f64> {
+        let (negative, positive) = self.split(1.0);
+        let negative = negative.exclude_pole(-1.0).decreasing(Trig::acoth);
+        negative.hull(&positive.exclude_pole(1.0).decreasing(Trig::acoth))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use core::f64::consts::{FRAC_PI_6, PI};
+
+    /// Inputs of the enclosure tests.
+    const INTERVALS: [(f64, f64); 17] = [
+        (0.0, 4.0),
+        (-1.0, 1.0),
+        (0.0, 1.0),
+        (-1.0, -0.0),
+        (1.0, 2.0),
+        (-2.0, 0.5),
+        (0.5, 3.0),
+        (0.9, 1.1),
+        (-3.0, -0.25),
+        (30.0, 150.0),
+        (-90.0, 90.0),
+        (89.0, 181.0),
+        (170.0, 600.0),
+        (1e5, 1e5 + 3.0),
+        (1e22, 1e22),
+        (-0.5, 0.5),
+        (2.0, 1e300),
+    ];
+
+    /// Asserts that an enclosure contains the finite [`f64`] values of a method at points of an
+    /// interval.
+    fn assert_encloses(name: &str, x: Interval<f64>, y: Interval<f64>, f: fn(&f64) -> f64) {
+        let n = 1000;
+        for i in 0..=n {
+            let t = i as f64 / n as f64;
+            let xi = if i == n {
+                x.hi
+            } else {
+                x.lo + t * (x.hi - x.lo)
+            };
+            let yi = f(&xi);
+            if yi.is_finite() {
+                assert!(
+                    y.contains(yi),
+                    "{name}([{}, {}]) = [{}, {}] does not contain {name}({xi}) = {yi}",
+                    x.lo,
+                    x.hi,
+                    y.lo,
+                    y.hi
+                );
+            }
+        }
+    }
+
+    /// Checks unary [`Trig`] methods on the test inputs.
+    macro_rules! check_unary {
+        ($($name:ident),* $(,)?) => {
+            for (lo, hi) in INTERVALS {
+                let x = Interval::new(lo, hi);
+                $(assert_encloses(stringify!($name), x, Trig::$name(&x), Trig::$name);)*
+            }
+        };
+    }
+
+    /// Asserts that an endpoint is within a relative tolerance of a value, on the outer side.
+    fn assert_outer(endpoint: f64, value: f64, below: bool) {
+        let tolerance = 1e-14 * value.abs() + 1e-300;
+        if below {
+            assert!(
+                endpoint <= value && endpoint >= value - tolerance,
+                "{endpoint} vs {value}"
+            );
+        } else {
+            assert!(
+                endpoint >= value && endpoint <= value + tolerance,
+                "{endpoint} vs {value}"
+            );
+        }
+    }
+
+    /// Asserts that an enclosure is tight around the given bounds.
+    fn assert_tight(y: Interval<f64>, lo: f64, hi: f64) {
+        if lo.is_infinite() {
+            assert_eq!(y.lo, lo);
+        } else {
+            assert_outer(y.lo, lo, true);
+        }
+        if hi.is_infinite() {
+            assert_eq!(y.hi, hi);
+        } else {
+            assert_outer(y.hi, hi, false);
+        }
+    }
+
+    #[test]
+    fn test_enclosures() {
+        check_unary!(
+            sin, cos, tan, csc, sec, cot, asin, acos, atan, acsc, asec, acot, deg2rad, rad2deg,
+            sind, cosd, tand, cscd, secd, cotd, asind, acosd, atand, acscd, asecd, acotd, sinpi,
+            cospi, tanpi, asinpi, acospi, atanpi, sinh, cosh, tanh, csch, sech, coth, asinh, acosh,
+            atanh, acsch, asech, acoth,
+        );
+    }
+
+    #[test]
+    fn test_extrema() {
+        let i = Interval::new;
+        let inf = f64::INFINITY;
+        assert_tight(i(0.0, 4.0).sin(), 4.0_f64.sin(), 1.0);
+        assert_tight(i(-1.0, 1.0).cos(), 1.0_f64.cos(), 1.0);
+        assert_tight(i(3.0, 3.5).cos(), -1.0, 3.5_f64.cos());
+        assert_tight(i(-1.0, 1.0).sin(), -(1.0_f64.sin()), 1.0_f64.sin());
+        assert_tight(i(30.0, 150.0).sind(), 0.5, 1.0);
+        assert_tight(i(0.0, 360.0).cosd(), -1.0, 1.0);
+        assert_tight(i(0.25, 0.75).cospi(), -(0.25 * PI).sin(), (0.25 * PI).sin());
+        assert_tight(i(0.5, 3.0).csc(), 1.0, 3.0_f64.csc());
+        assert_tight(i(-1.0, 1.0).sec(), 1.0, 1.0_f64.sec());
+        assert_tight(i(179.0, 181.0).secd(), -(1.0_f64.secd()), -1.0);
+        assert_tight(i(-1.0, 2.0).cosh(), 1.0, 2.0_f64.cosh());
+        assert_tight(i(-1.0, 2.0).sech(), 2.0_f64.sech(), 1.0);
+        assert_tight(i(0.0, 1e10).sin(), -1.0, 1.0);
+        assert_tight(i(-inf, 0.0).cos(), -1.0, 1.0);
+        assert_tight(i(-inf, inf).tanh(), -1.0, 1.0);
+    }
+
+    #[test]
+    fn test_poles() {
+        let i = Interval::new;
+        let (inf, neg_inf) = (f64::INFINITY, f64::NEG_INFINITY);
+        let assert_entire = |y: Interval<f64>| assert_tight(y, neg_inf, inf);
+        assert_entire(i(1.0, 2.0).tan());
+        assert_entire(i(45.0, 135.0).tand());
+        assert_entire(i(-1.0, 1.0).cot());
+        assert_entire(i(-1.0, 1.0).csc());
+        assert_entire(i(1.0, 2.0).sec());
+        assert_entire(i(-1.0, 1.0).coth());
+        assert_entire(i(-1.0, 1.0).csch());
+        assert_entire(i(0.4, 0.6).tanpi());
+        assert_entire(i(0.0, 1e10).tan());
+        assert_tight(i(-1.0, 1.0).tan(), -(1.0_f64.tan()), 1.0_f64.tan());
+        assert_tight(i(0.0, 90.0).tand(), 0.0, inf);
+        assert_tight(i(90.0, 135.0).tand(), neg_inf, -1.0);
+        assert_tight(i(0.0, 1.0).cot(), 1.0_f64.cot(), inf);
+        assert_tight(i(-1.0, 0.0).cot(), neg_inf, -(1.0_f64.cot()));
+        assert_tight(i(0.0, 1.0).csc(), 1.0_f64.csc(), inf);
+        assert_tight(i(180.0, 270.0).cscd(), neg_inf, -1.0);
+        assert_tight(i(0.0, 90.0).secd(), 1.0, inf);
+        assert_tight(i(0.0, 1.0).coth(), 1.0_f64.coth(), inf);
+        assert_tight(i(-2.0, -0.0).coth(), neg_inf, (-2.0_f64).coth());
+        assert_tight(i(0.0, 1.0).acot(), 1.0_f64.acot(), FRAC_PI_2);
+    }
+
+    #[test]
+    fn test_domains() {
+        let i = Interval::new;
+        let inf = f64::INFINITY;
+        assert_tight(i(-2.0, 0.5).asin(), -FRAC_PI_2, FRAC_PI_6);
+        assert_tight(i(0.5, 2.0).acosd(), 0.0, 60.0);
+        assert_tight(i(0.0, 2.0).acosh(), 0.0, 2.0_f64.acosh());
+        assert_tight(i(0.5, 1.0).atanh(), 0.5_f64.atanh(), inf);
+        assert_tight(i(0.5, 2.0).acoth(), 2.0_f64.acoth(), inf);
+        assert_tight(i(-2.0, 0.5).asech(), 0.5_f64.asech(), inf);
+        assert_tight(i(-0.5, 3.0).asec(), 0.0, 3.0_f64.asec());
+        assert_tight(i(-3.0, 0.5).acsc(), -FRAC_PI_2, (-3.0_f64).acsc());
+        assert!(i(2.0, 3.0).asin().is_empty());
+        assert!(i(-0.5, 0.5).acosh().is_empty());
+        assert!(i(1.0, 2.0).atanh().is_empty());
+        assert!(i(-0.5, 0.5).acoth().is_empty());
+        assert!(i(-0.5, 0.5).asec().is_empty());
+        assert!(i(-1.0, -0.5).asech().is_empty());
+        assert!(Interval::point(0.0).coth().is_empty());
+        assert!(Interval::EMPTY.sin().is_empty());
+        assert!(Interval::EMPTY.sinh().is_empty());
+        assert!(Interval::EMPTY.atan2(&i(1.0, 2.0)).is_empty());
+    }
+
+    #[test]
+    fn test_atan2() {
+        let i = Interval::new;
+        let check = |y: Interval<f64>, x: Interval<f64>, lo: f64, hi: f64| {
+            assert_tight(y.atan2(&x), lo, hi);
+            assert_tight(y.atan2d(&x), lo.rad2deg(), hi.rad2deg());
+            assert_tight(y.atan2pi(&x), lo / PI, hi / PI);
+        };
+        check(i(1.0, 2.0), i(1.0, 2.0), 0.5_f64.atan(), 2.0_f64.atan());
+        check(i(0.0, 1.0), i(-1.0, 1.0), 0.0, PI);
+        check(i(-1.0, 1.0), i(0.0, 1.0), -FRAC_PI_2, FRAC_PI_2);
+        check(i(-1.0, 0.0), i(-2.0, -1.0), -PI, PI);
+        check(i(-1.0, 1.0), i(-1.0, 1.0), -PI, PI);
+        check(i(-2.0, -1.0), i(-1.0, 1.0), -0.75 * PI, -0.25 * PI);
+        for (ylo, yhi) in INTERVALS {
+            for (xlo, xhi) in INTERVALS {
+                let (y, x) = (i(ylo, yhi), i(xlo, xhi));
+                let image = y.atan2(&x);
+                for (yi, xi) in [(ylo, xlo), (yhi, xhi), ((ylo + yhi) / 2.0, xlo)] {
+                    assert!(image.contains(Trig::atan2(&yi, &xi)));
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_invalid_interval() {
+        Interval::new(1.0, 0.0);
+    }
+}
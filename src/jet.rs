@@ -0,0 +1,813 @@
+use crate::real::{Real, deg2rad, one_minus_square, pi, rad2deg, square_minus_one};
+use crate::trig_trait::Trig;
+use core::ops::{Add, Div, Mul, Neg, Sub};
+
+/// Truncated Taylor series (jet) for arbitrary-order derivatives.
+///
+/// A jet $\sum_{k=0}^{N-1}c_kt^k$ holds the first $N$ Taylor coefficients $c_k=\frac{f^{(k)}}{k!}$
+/// of a function of some input $t$. Arithmetic on jets is arithmetic on power series truncated after
+/// the $t^{N-1}$ term (e.g. products are Cauchy products), and every [`Trig`] method is evaluated
+/// with the standard power-series recurrences, which cost $O(N^2)$ operations:
+///
+/// * `sin`/`cos` (and `sinh`/`cosh`) are computed together from
+///   $ks_k=\sum_{j=1}^{k}ju_jc_{k-j}$ and $kc_k=-\sum_{j=1}^{k}ju_js_{k-j}$,
+/// * `tan` (and `tanh`) from $\tan'(u)=\left(1+\tan^2(u)\right)u'$,
+/// * `csc`, `sec`, and `cot` by series division, and
+/// * the inverse functions (and `atan2`) by integrating the series of their closed-form
+///   derivatives, e.g. $\text{atan}(u)=\text{atan}(u_0)+\int\frac{u'}{1+u^2}$.
+///
+/// Seeding the input as [`Jet::variable`] gives all derivatives up to order $N-1$ of an expression
+/// built from the [`Trig`] methods and the arithmetic operators, and seeding it with the Taylor
+/// coefficients of the state of an ODE gives Taylor-model integrators (see [`Jet::evaluate`]).
+///
+/// # Note
+///
+/// The degree and half-turn methods include the chain factors $\frac{\pi}{180}$ and $\pi$ (or
+/// their inverses for the inverse functions), computed in the precision of `T`, and the constant
+/// coefficient $c_0$ of every [`Trig`] method is the value of the corresponding method of `T`. At a
+/// point where a function is not analytic (e.g. `asin` at $\pm1$), the higher coefficients are
+/// infinite or `NaN`. A jet needs at least one coefficient (constructing a jet with `N = 0` fails
+/// to compile).
+///
+/// # References
+///
+/// * Griewank, A., & Walther, A. (2008). *Evaluating Derivatives: Principles and Techniques of
+///   Algorithmic Differentiation* (2nd ed.), Chapter 13. SIAM.
+///
+/// # Example
+///
+/// ```
+/// use trig::{Jet, Trig};
+///
+/// // tan(t) = t + t³/3 + 2t⁵/15 + 17t⁷/315 + ...
+/// let y = Jet::<f64, 8>::variable(0.0).tan();
+/// assert!((y.coefficients[5] - 2.0 / 15.0).abs() < 1e-16);
+/// assert!((y.coefficients[7] - 17.0 / 315.0).abs() < 1e-16);
+///
+/// // The 5th derivative of sech at 0.3.
+/// let y = Jet::<f64, 6>::variable(0.3).sech();
+/// assert!(y.derivative(5).is_finite());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+pub struct Jet<T, const N: usize> {
+    /// Taylor coefficients $c_0,\ldots,c_{N-1}$.
+    pub coefficients: [T; N],
+}
+
+impl<T: Real, const N: usize> Jet<T, N> {
+    /// Constructs a jet.
+    ///
+    /// # Arguments
+    ///
+    /// * `coefficients` - Taylor coefficients $c_0,\ldots,c_{N-1}$.
+    ///
+    /// # Returns
+    ///
+    /// Jet $\sum_{k=0}^{N-1}c_kt^k$.
+    pub fn new(coefficients: [T; N]) -> Jet<T, N> {
+        const { assert!(N > 0, "a jet needs at least one coefficient") };
+        Jet { coefficients }
+    }
+
+    /// Constructs the independent variable of a differentiation.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Value.
+    ///
+    /// # Returns
+    ///
+    /// Jet $\text{value}+t$ (just $\text{value}$ if `N = 1`).
+    pub fn variable(value: T) -> Jet<T, N> {
+        let mut x = Jet::constant(value);
+        if N > 1 {
+            x.coefficients[1] = T::from_f64(1.0);
+        }
+        x
+    }
+
+    /// Constructs a constant.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Value.
+    ///
+    /// # Returns
+    ///
+    /// Jet whose higher coefficients are zero.
+    pub fn constant(value: T) -> Jet<T, N> {
+        let mut x = Jet::new([T::from_f64(0.0); N]);
+        x.coefficients[0] = value;
+        x
+    }
+
+    /// Value.
+    ///
+    /// # Returns
+    ///
+    /// $c_0$.
+    pub fn value(&self) -> T {
+        self.coefficients[0]
+    }
+
+    /// Derivative of a given order.
+    ///
+    /// # Arguments
+    ///
+    /// * `k` - Order.
+    ///
+    /// # Returns
+    ///
+    /// $f^{(k)}=k!c_k$.
+    ///
+    /// # Panics
+    ///
+    /// If `k >= N`.
+    pub fn derivative(&self, k: usize) -> T {
+        (1..=k).fold(self.coefficients[k], |d, i| d * T::from_f64(i as f64))
+    }
+
+    /// Evaluates the truncated Taylor series.
+    ///
+    /// # Arguments
+    ///
+    /// * `h` - Step $t$.
+    ///
+    /// # Returns
+    ///
+    /// $\sum_{k=0}^{N-1}c_kh^k$ (evaluated with Horner's method).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use trig::{Jet, Trig};
+    ///
+    /// // One Taylor step of size 0.1 for y' = sin(y), y(0) = 1, with coefficients y_{k+1} =
+    /// // sin(y)_k / (k + 1) computed order by order.
+    /// let mut y = Jet::<f64, 12>::constant(1.0);
+    /// for k in 0..11 {
+    ///     y.coefficients[k + 1] = y.sin().coefficients[k] / (k + 1) as f64;
+    /// }
+    /// // The exact solution is y(t) = 2 atan(e^t tan(1/2)).
+    /// let exact = 2.0 * (0.1_f64.exp() * 0.5_f64.tan()).atan();
+    /// assert!((y.evaluate(0.1) - exact).abs() < 1e-15);
+    /// ```
+    pub fn evaluate(&self, h: T) -> T {
+        self.coefficients
+            .iter()
+            .rev()
+            .fold(T::from_f64(0.0), |sum, &c| sum * h + c)
+    }
+
+    /// Replaces the value.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - New value.
+    ///
+    /// # Returns
+    ///
+    /// This jet with $c_0=\text{value}$.
+    fn with_value(mut self, value: T) -> Jet<T, N> {
+        self.coefficients[0] = value;
+        self
+    }
+
+    /// Derivative with respect to the input.
+    ///
+    /// # Returns
+    ///
+    /// $\sum_{k=0}^{N-2}(k+1)c_{k+1}t^k$.
+    fn differentiate(&self) -> Jet<T, N> {
+        let mut d = Jet::constant(T::from_f64(0.0));
+        for k in 1..N {
+            d.coefficients[k - 1] = T::from_f64(k as f64) * self.coefficients[k];
+        }
+        d
+    }
+
+    /// Composition with a function given its value and derivative.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - $f(u_0)$ (with this jet holding $u$).
+    /// * `derivative` - $f'(u)$.
+    ///
+    /// # Returns
+    ///
+    /// $f(u)=f(u_0)+\int f'(u)u'$.
+    fn integrate(&self, value: T, derivative: Jet<T, N>) -> Jet<T, N> {
+        let g = derivative * self.differentiate();
+        let mut f = Jet::constant(value);
+        for k in 1..N {
+            f.coefficients[k] = g.coefficients[k - 1] / T::from_f64(k as f64);
+        }
+        f
+    }
+
+    /// Sine-cosine pair recurrence.
+    ///
+    /// # Arguments
+    ///
+    /// * `s` - $s(u_0)$ (with this jet holding $u$).
+    /// * `c` - $c(u_0)$.
+    /// * `scale` - Chain factor $a$.
+    /// * `sign` - $\sigma=\pm1$.
+    ///
+    /// # Returns
+    ///
+    /// $s(u)$ and $c(u)$, where $s'=acu'$ and $c'=\sigma asu'$ (so $\sigma=-1$ gives the circular and
+    /// $\sigma=1$ the hyperbolic functions).
+    fn pair(&self, s: T, c: T, scale: T, sign: T) -> (Jet<T, N>, Jet<T, N>) {
+        let u = &self.coefficients;
+        let (mut s, mut c) = (Jet::constant(s), Jet::constant(c));
+        for k in 1..N {
+            let factor = scale / T::from_f64(k as f64);
+            let ds = weighted_convolution(u, &c.coefficients, k);
+            let dc = weighted_convolution(u, &s.coefficients, k);
+            s.coefficients[k] = factor * ds;
+            c.coefficients[k] = sign * factor * dc;
+        }
+        (s, c)
+    }
+
+    /// Tangent recurrence.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - $t(u_0)$ (with this jet holding $u$).
+    /// * `slope` - $1+\sigma t(u_0)^2$, evaluated without cancellation (e.g. as
+    ///   $\text{sech}^2(u_0)$ for the hyperbolic tangent).
+    /// * `scale` - Chain factor $a$.
+    /// * `sign` - $\sigma=\pm1$.
+    ///
+    /// # Returns
+    ///
+    /// $t(u)$, where $t'=a\left(1+\sigma t^2\right)u'$ (so $\sigma=1$ gives the circular and
+    /// $\sigma=-1$ the hyperbolic tangent and cotangent).
+    fn tangent(&self, value: T, slope: T, scale: T, sign: T) -> Jet<T, N> {
+        let u = &self.coefficients;
+        let mut t = Jet::constant(value);
+        let mut w = Jet::<T, N>::constant(slope);
+        for k in 1..N {
+            // Only the constant term of 1 + σt² cancels as |t| → 1, so it is seeded instead.
+            if k > 1 {
+                let square = convolution(&t.coefficients, &t.coefficients, 0, k - 1);
+                w.coefficients[k - 1] = sign * square;
+            }
+            let sum = weighted_convolution(u, &w.coefficients, k);
+            t.coefficients[k] = scale * sum / T::from_f64(k as f64);
+        }
+        t
+    }
+
+    /// Reciprocal.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Correctly evaluated value of the reciprocal.
+    ///
+    /// # Returns
+    ///
+    /// $\frac{1}{u}$ with $c_0=\text{value}$.
+    fn reciprocal(&self, value: T) -> Jet<T, N> {
+        (Jet::constant(T::from_f64(1.0)) / *self).with_value(value)
+    }
+
+    /// Two-argument inverse tangent.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - $x$-coordinate (with this jet holding the $y$-coordinate).
+    /// * `value` - $\text{atan2}(y_0,x_0)$ in some unit.
+    /// * `scale` - A radian in that unit.
+    ///
+    /// # Returns
+    ///
+    /// $\text{atan2}(y,x)$, integrated from $s\frac{xy'-yx'}{x^2+y^2}$.
+    fn atan2_series(&self, other: &Jet<T, N>, value: T, scale: T) -> Jet<T, N> {
+        let (y, x) = (*self, *other);
+        let g = Jet::constant(scale) * (x * y.differentiate() - y * x.differentiate())
+            / (x * x + y * y);
+        let mut f = Jet::constant(value);
+        for k in 1..N {
+            f.coefficients[k] = g.coefficients[k - 1] / T::from_f64(k as f64);
+        }
+        f
+    }
+}
+
+/// Coefficient of $t^k$ in the product of two series (skipping the first terms of $a$).
+///
+/// # Arguments
+///
+/// * `a` - Coefficients $a_i$.
+/// * `b` - Coefficients $b_i$.
+/// * `lo` - First index $i$ of $a$ to include.
+/// * `k` - Order.
+///
+/// # Returns
+///
+/// $\sum_{i=\text{lo}}^{k}a_ib_{k-i}$.
+fn convolution<T: Real>(a: &[T], b: &[T], lo: usize, k: usize) -> T {
+    (lo..=k).fold(T::from_f64(0.0), |sum, i| sum + a[i] * b[k - i])
+}
+
+/// Coefficient of $t^{k-1}$ in the product of the derivative of a series with another series.
+///
+/// # Arguments
+///
+/// * `u` - Coefficients $u_j$.
+/// * `w` - Coefficients $w_j$.
+/// * `k` - Order.
+///
+/// # Returns
+///
+/// $\sum_{j=1}^{k}ju_jw_{k-j}$.
+fn weighted_convolution<T: Real>(u: &[T], w: &[T], k: usize) -> T {
+    (1..=k).fold(T::from_f64(0.0), |sum, j| {
+        sum + T::from_f64(j as f64) * u[j] * w[k - j]
+    })
+}
+
+impl<T: Real, const N: usize> From<T> for Jet<T, N> {
+    fn from(value: T) -> Jet<T, N> {
+        Jet::constant(value)
+    }
+}
+
+impl<T: Real, const N: usize> Neg for Jet<T, N> {
+    type Output = Jet<T, N>;
+    fn neg(self) -> Jet<T, N> {
+        Jet::new(self.coefficients.map(|c| -c))
+    }
+}
+
+impl<T: Real, const N: usize> Add for Jet<T, N> {
+    type Output = Jet<T, N>;
+    fn add(mut self, other: Jet<T, N>) -> Jet<T, N> {
+        for (a, &b) in self.coefficients.iter_mut().zip(other.coefficients.iter()) {
+            *a = *a + b;
+        }
+        self
+    }
+}
+
+impl<T: Real, const N: usize> Sub for Jet<T, N> {
+    type Output = Jet<T, N>;
+    fn sub(mut self, other: Jet<T, N>) -> Jet<T, N> {
+        for (a, &b) in self.coefficients.iter_mut().zip(other.coefficients.iter()) {
+            *a = *a - b;
+        }
+        self
+    }
+}
+
+impl<T: Real, const N: usize> Mul for Jet<T, N> {
+    type Output = Jet<T, N>;
+    fn mul(self, other: Jet<T, N>) -> Jet<T, N> {
+        let (a, b) = (&self.coefficients, &other.coefficients);
+        let mut p = Jet::constant(T::from_f64(0.0));
+        for k in 0..N {
+            p.coefficients[k] = convolution(a, b, 0, k);
+        }
+        p
+    }
+}
+
+impl<T: Real, const N: usize> Div for Jet<T, N> {
+    type Output = Jet<T, N>;
+    fn div(self, other: Jet<T, N>) -> Jet<T, N> {
+        let (a, b) = (&self.coefficients, &other.coefficients);
+        let mut q = Jet::constant(a[0] / b[0]);
+        for (k, &c) in a.iter().enumerate().skip(1) {
+            q.coefficients[k] = (c - convolution(b, &q.coefficients, 1, k)) / b[0];
+        }
+        q
+    }
+}
+
+impl<T: Real, const N: usize> Real for Jet<T, N> {
+    fn from_f64(x: f64) -> Jet<T, N> {
+        Jet::constant(T::from_f64(x))
+    }
+    fn sqrt(&self) -> Jet<T, N> {
+        let a = &self.coefficients;
+        let mut s = Jet::constant(a[0].sqrt());
+        let twice = T::from_f64(2.0) * s.coefficients[0];
+        for (k, &c) in a.iter().enumerate().skip(1) {
+            // s_k is still zero here, so the convolution runs over s_1, ..., s_{k-1}.
+            s.coefficients[k] = (c - convolution(&s.coefficients, &s.coefficients, 1, k)) / twice;
+        }
+        s
+    }
+    fn abs(&self) -> Jet<T, N> {
+        if self.value() < T::from_f64(0.0) {
+            -*self
+        } else {
+            *self
+        }
+    }
+}
+
+impl<T: Real, const N: usize> Trig for Jet<T, N> {
+    fn sin(&self) -> Jet<T, N> {
+        let x = self.value();
+        self.pair(x.sin(), x.cos(), T::from_f64(1.0), T::from_f64(-1.0))
+            .0
+    }
+    fn cos(&self) -> Jet<T, N> {
+        let x = self.value();
+        self.pair(x.sin(), x.cos(), T::from_f64(1.0), T::from_f64(-1.0))
+            .1
+    }
+    fn sincos(&self) -> (Jet<T, N>, Jet<T, N>) {
+        let (s, c) = self.value().sincos();
+        self.pair(s, c, T::from_f64(1.0), T::from_f64(-1.0))
+    }
+    fn tan(&self) -> Jet<T, N> {
+        let t = self.value().tan();
+        self.tangent(
+            t,
+            T::from_f64(1.0) + t * t,
+            T::from_f64(1.0),
+            T::from_f64(1.0),
+        )
+    }
+    fn csc(&self) -> Jet<T, N> {
+        self.sin().reciprocal(self.value().csc())
+    }
+    fn sec(&self) -> Jet<T, N> {
+        self.cos().reciprocal(self.value().sec())
+    }
+    fn cot(&self) -> Jet<T, N> {
+        let (s, c) = self.sincos();
+        (c / s).with_value(self.value().cot())
+    }
+    fn asin(&self) -> Jet<T, N> {
+        let d = Jet::from_f64(1.0) / one_minus_square(*self).sqrt();
+        self.integrate(self.value().asin(), d)
+    }
+    fn acos(&self) -> Jet<T, N> {
+        let d = Jet::from_f64(-1.0) / one_minus_square(*self).sqrt();
+        self.integrate(self.value().acos(), d)
+    }
+    fn atan(&self) -> Jet<T, N> {
+        let d = Jet::from_f64(1.0) / (Jet::from_f64(1.0) + *self * *self);
+        self.integrate(self.value().atan(), d)
+    }
+    fn atan2(&self, other: &Jet<T, N>) -> Jet<T, N> {
+        let value = self.value().atan2(&other.value());
+        self.atan2_series(other, value, T::from_f64(1.0))
+    }
+    fn acsc(&self) -> Jet<T, N> {
+        let d = Jet::from_f64(-1.0) / (self.abs() * square_minus_one(*self).sqrt());
+        self.integrate(self.value().acsc(), d)
+    }
+    fn asec(&self) -> Jet<T, N> {
+        let d = Jet::from_f64(1.0) / (self.abs() * square_minus_one(*self).sqrt());
+        self.integrate(self.value().asec(), d)
+    }
+    fn acot(&self) -> Jet<T, N> {
+        let d = Jet::from_f64(-1.0) / (Jet::from_f64(1.0) + *self * *self);
+        self.integrate(self.value().acot(), d)
+    }
+    fn deg2rad(&self) -> Jet<T, N> {
+        Jet::new(self.coefficients.map(|c| c.deg2rad()))
+    }
+    fn rad2deg(&self) -> Jet<T, N> {
+        Jet::new(self.coefficients.map(|c| c.rad2deg()))
+    }
+    fn sind(&self) -> Jet<T, N> {
+        let x = self.value();
+        self.pair(x.sind(), x.cosd(), deg2rad(), T::from_f64(-1.0))
+            .0
+    }
+    fn cosd(&self) -> Jet<T, N> {
+        let x = self.value();
+        self.pair(x.sind(), x.cosd(), deg2rad(), T::from_f64(-1.0))
+            .1
+    }
+    fn sincosd(&self) -> (Jet<T, N>, Jet<T, N>) {
+        let (s, c) = self.value().sincosd();
+        self.pair(s, c, deg2rad(), T::from_f64(-1.0))
+    }
+    fn tand(&self) -> Jet<T, N> {
+        let t = self.value().tand();
+        self.tangent(t, T::from_f64(1.0) + t * t, deg2rad(), T::from_f64(1.0))
+    }
+    fn cscd(&self) -> Jet<T, N> {
+        self.sind().reciprocal(self.value().cscd())
+    }
+    fn secd(&self) -> Jet<T, N> {
+        self.cosd().reciprocal(self.value().secd())
+    }
+    fn cotd(&self) -> Jet<T, N> {
+        let (s, c) = self.sincosd();
+        (c / s).with_value(self.value().cotd())
+    }
+    fn asind(&self) -> Jet<T, N> {
+        let d = Jet::constant(rad2deg()) / one_minus_square(*self).sqrt();
+        self.integrate(self.value().asind(), d)
+    }
+    fn acosd(&self) -> Jet<T, N> {
+        let d = -Jet::constant(rad2deg()) / one_minus_square(*self).sqrt();
+        self.integrate(self.value().acosd(), d)
+    }
+    fn atand(&self) -> Jet<T, N> {
+        let d = Jet::constant(rad2deg()) / (Jet::from_f64(1.0) + *self * *self);
+        self.integrate(self.value().atand(), d)
+    }
+    fn atan2d(&self, other: &Jet<T, N>) -> Jet<T, N> {
+        let value = self.value().atan2d(&other.value());
+        self.atan2_series(other, value, rad2deg())
+    }
+    fn acscd(&self) -> Jet<T, N> {
+        let d = -Jet::constant(rad2deg()) / (self.abs() * square_minus_one(*self).sqrt());
+        self.integrate(self.value().acscd(), d)
+    }
+    fn asecd(&self) -> Jet<T, N> {
+        let d = Jet::constant(rad2deg()) / (self.abs() * square_minus_one(*self).sqrt());
+        self.integrate(self.value().asecd(), d)
+    }
+    fn acotd(&self) -> Jet<T, N> {
+        let d = -Jet::constant(rad2deg()) / (Jet::from_f64(1.0) + *self * *self);
+        self.integrate(self.value().acotd(), d)
+    }
+    fn sinpi(&self) -> Jet<T, N> {
+        let x = self.value();
+        self.pair(x.sinpi(), x.cospi(), pi(), T::from_f64(-1.0)).0
+    }
+    fn cospi(&self) -> Jet<T, N> {
+        let x = self.value();
+        self.pair(x.sinpi(), x.cospi(), pi(), T::from_f64(-1.0)).1
+    }
+    fn tanpi(&self) -> Jet<T, N> {
+        let t = self.value().tanpi();
+        self.tangent(t, T::from_f64(1.0) + t * t, pi(), T::from_f64(1.0))
+    }
+    fn asinpi(&self) -> Jet<T, N> {
+        let d = Jet::constant(T::from_f64(1.0) / pi()) / one_minus_square(*self).sqrt();
+        self.integrate(self.value().asinpi(), d)
+    }
+    fn acospi(&self) -> Jet<T, N> {
+        let d = Jet::constant(T::from_f64(-1.0) / pi()) / one_minus_square(*self).sqrt();
+        self.integrate(self.value().acospi(), d)
+    }
+    fn atanpi(&self) -> Jet<T, N> {
+        let d = Jet::constant(T::from_f64(1.0) / pi()) / (Jet::from_f64(1.0) + *self * *self);
+        self.integrate(self.value().atanpi(), d)
+    }
+    fn atan2pi(&self, other: &Jet<T, N>) -> Jet<T, N> {
+        let value = self.value().atan2pi(&other.value());
+        self.atan2_series(other, value, T::from_f64(1.0) / pi())
+    }
+    fn sinh(&self) -> Jet<T, N> {
+        let x = self.value();
+        self.pair(x.sinh(), x.cosh(), T::from_f64(1.0), T::from_f64(1.0))
+            .0
+    }
+    fn cosh(&self) -> Jet<T, N> {
+        let x = self.value();
+        self.pair(x.sinh(), x.cosh(), T::from_f64(1.0), T::from_f64(1.0))
+            .1
+    }
+    fn sinhcosh(&self) -> (Jet<T, N>, Jet<T, N>) {
+        let (s, c) = self.value().sinhcosh();
+        self.pair(s, c, T::from_f64(1.0), T::from_f64(1.0))
+    }
+    fn tanh(&self) -> Jet<T, N> {
+        let (x, one) = (self.value(), T::from_f64(1.0));
+        let s = x.sech();
+        self.tangent(x.tanh(), s * s, one, -one)
+    }
+    fn csch(&self) -> Jet<T, N> {
+        self.sinh().reciprocal(self.value().csch())
+    }
+    fn sech(&self) -> Jet<T, N> {
+        self.cosh().reciprocal(self.value().sech())
+    }
+    fn coth(&self) -> Jet<T, N> {
+        // coth satisfies the same equation as tanh, with 1 - coth² = -csch².
+        let (x, one) = (self.value(), T::from_f64(1.0));
+        let s = x.csch();
+        self.tangent(x.coth(), -s * s, one, -one)
+    }
+    fn asinh(&self) -> Jet<T, N> {
+        let d = Jet::from_f64(1.0) / (*self * *self + Jet::from_f64(1.0)).sqrt();
+        self.integrate(self.value().asinh(), d)
+    }
+    fn acosh(&self) -> Jet<T, N> {
+        let d = Jet::from_f64(1.0) / square_minus_one(*self).sqrt();
+        self.integrate(self.value().acosh(), d)
+    }
+    fn atanh(&self) -> Jet<T, N> {
+        let d = Jet::from_f64(1.0) / one_minus_square(*self);
+        self.integrate(self.value().atanh(), d)
+    }
+    fn acsch(&self) -> Jet<T, N> {
+        let d = Jet::from_f64(-1.0) / (self.abs() * (Jet::from_f64(1.0) + *self * *self).sqrt());
+        self.integrate(self.value().acsch(), d)
+    }
+    fn asech(&self) -> Jet<T, N> {
+        let d = Jet::from_f64(-1.0) / (*self * one_minus_square(*self).sqrt());
+        self.integrate(self.value().asech(), d)
+    }
+    fn acoth(&self) -> Jet<T, N> {
+        let d = Jet::from_f64(1.0) / one_minus_square(*self);
+        self.integrate(self.value().acoth(), d)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{DoubleF64, Dual, HyperDual};
+
+    /// Asserts that the first three coefficients of unary [`Trig`] methods match [`HyperDual`]
+    /// numbers.
+    macro_rules! check_unary {
+        ($($name:ident),* $(,)?) => {
+            $(
+                for x in [0.3, -0.45, 1.7, -2.5, 30.0, 100.0] {
+                    let y = Trig::$name(&Jet::<f64, 3>::variable(x));
+                    let z = Trig::$name(&HyperDual::variable(x));
+                    let value = Trig::$name(&x);
+                    assert_eq!(y.value().to_bits(), value.to_bits(), "{}({x})", stringify!($name));
+                    if !value.is_finite() {
+                        continue;
+                    }
+                    for (k, expected) in [(1, z.e1), (2, z.e12)] {
+                        let d = y.derivative(k);
+                        assert!(
+                            (d - expected).abs() <= 1e-12 * f64::max(1.0, expected.abs()),
+                            "{}^({k})({x}) = {d}, expected {expected}",
+                            stringify!($name),
+                        );
+                    }
+                }
+            )*
+        };
+    }
+
+    #[test]
+    fn test_low_order() {
+        check_unary!(
+            sin, cos, tan, csc, sec, cot, asin, acos, atan, acsc, asec, acot, deg2rad, rad2deg,
+            sind, cosd, tand, cscd, secd, cotd, asind, acosd, atand, acscd, asecd, acotd, sinpi,
+            cospi, tanpi, asinpi, acospi, atanpi, sinh, cosh, tanh, csch, sech, coth, asinh, acosh,
+            atanh, acsch, asech, acoth,
+        );
+    }
+
+    #[test]
+    fn test_hyperbolic_tangents_at_large_arguments() {
+        // The derivatives decay like e^(-2|x|), far below the rounding error of 1 - tanh²(x).
+        let close = |a: f64, b: f64| (a - b).abs() <= 1e-14 * b.abs() && b != 0.0;
+        for x in [20.0, -20.0, 300.0] {
+            let (y, z) = (Jet::<f64, 3>::variable(x), HyperDual::variable(x));
+            for (jet, dual, hyper_dual) in [
+                (y.tanh(), Dual::variable(x).tanh(), z.tanh()),
+                (y.coth(), Dual::variable(x).coth(), z.coth()),
+            ] {
+                assert!(close(jet.derivative(1), dual.derivative), "{x}");
+                assert!(close(jet.derivative(2), hyper_dual.e12), "{x}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_series_at_zero() {
+        let t = Jet::<f64, 10>::variable(0.0);
+        let factorial = |k: usize| (1..=k).product::<usize>() as f64;
+        type Derivative = fn(usize) -> f64;
+        let cases: [(Jet<f64, 10>, Derivative); 5] = [
+            (t.sin(), |k| {
+                if k % 2 == 1 {
+                    (-1.0_f64).powi((k / 2) as i32)
+                } else {
+                    0.0
+                }
+            }),
+            (t.cos(), |k| {
+                if k % 2 == 0 {
+                    (-1.0_f64).powi((k / 2) as i32)
+                } else {
+                    0.0
+                }
+            }),
+            (t.sinh(), |k| (k % 2) as f64),
+            (t.cosh(), |k| ((k + 1) % 2) as f64),
+            (t.sinpi(), |k| {
+                if k % 2 == 1 {
+                    (-1.0_f64).powi((k / 2) as i32) * core::f64::consts::PI.powi(k as i32)
+                } else {
+                    0.0
+                }
+            }),
+        ];
+        for (y, derivative) in cases {
+            for k in 0..10 {
+                let expected = derivative(k) / factorial(k);
+                assert!((y.coefficients[k] - expected).abs() < 1e-15, "{k}");
+            }
+        }
+
+        // atan(t) = t - t³/3 + t⁵/5 - ..., atanh(t) = t + t³/3 + t⁵/5 + ...
+        let (atan, atanh) = (t.atan(), t.atanh());
+        for k in 0..10 {
+            let expected = if k % 2 == 1 { 1.0 / k as f64 } else { 0.0 };
+            let sign = if k % 4 == 3 { -1.0 } else { 1.0 };
+            assert!((atan.coefficients[k] - sign * expected).abs() < 1e-16);
+            assert!((atanh.coefficients[k] - expected).abs() < 1e-16);
+        }
+
+        // tanh(t) = t - t³/3 + 2t⁵/15 - 17t⁷/315 + 62t⁹/2835.
+        let expected = [
+            0.0,
+            1.0,
+            0.0,
+            -1.0 / 3.0,
+            0.0,
+            2.0 / 15.0,
+            0.0,
+            -17.0 / 315.0,
+            0.0,
+        ];
+        for (k, e) in expected.iter().enumerate() {
+            assert!((t.tanh().coefficients[k] - e).abs() < 1e-16);
+        }
+        assert!((t.tanh().coefficients[9] - 62.0 / 2835.0).abs() < 1e-16);
+    }
+
+    #[test]
+    fn test_identities() {
+        let t = Jet::<f64, 12>::variable(0.37);
+        let one = Jet::from_f64(1.0);
+        // The round-off in the coefficients of compositions grows geometrically with the order.
+        let close = |a: Jet<f64, 12>, b: Jet<f64, 12>| {
+            let (a, b) = (a.coefficients, b.coefficients);
+            let tolerance = |k: usize| 1e-14 * 3.0_f64.powi(k as i32) * f64::max(1.0, b[k].abs());
+            (0..12).all(|k| (a[k] - b[k]).abs() <= tolerance(k))
+        };
+        let (s, c) = t.sincos();
+        assert!(close(s * s + c * c, one));
+        assert!(close(t.tan(), s / c));
+        assert!(close(t.tan().atan(), t));
+        assert!(close(t.sin().asin(), t));
+        assert!(close(t.cos().acos(), t));
+        assert!(close(t.sec().asec(), t));
+        assert!(close(t.csc().acsc(), t));
+        assert!(close(t.cot().acot(), t));
+        assert!(close(t.sind().asind(), t));
+        assert!(close(t.tand().atand(), t));
+        assert!(close(t.cospi().acospi(), t));
+        let u = Jet::variable(0.1);
+        assert!(close(u.tanpi().atanpi(), u));
+        let (sh, ch) = t.sinhcosh();
+        assert!(close(ch * ch - sh * sh, one));
+        assert!(close(t.tanh(), sh / ch));
+        assert!(close(t.sinh().asinh(), t));
+        assert!(close(t.cosh().acosh(), t));
+        assert!(close(t.tanh().atanh(), t));
+        assert!(close(t.csch().acsch(), t));
+        assert!(close(t.sech().asech(), t));
+        assert!(close(t.coth().acoth(), t));
+        assert!(close(t.rad2deg().deg2rad(), t));
+
+        // atan2(y, x) = atan(y/x) for x > 0.
+        let (y, x) = (t.sin(), one + t * t);
+        assert!(close(y.atan2(&x), (y / x).atan()));
+        assert!(close(y.atan2d(&x), (y / x).atand()));
+        assert!(close(y.atan2pi(&x), (y / x).atanpi()));
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let t = Jet::<f64, 8>::variable(0.0);
+        let one = Jet::from_f64(1.0);
+
+        // 1 / (1 - t) = 1 + t + t² + ..., and sqrt(1 + t)² = 1 + t.
+        assert_eq!((one / (one - t)).coefficients, [1.0; 8]);
+        let s = (one + t).sqrt();
+        assert!(((s * s - one - t).coefficients.iter()).all(|c| c.abs() < 1e-16));
+        assert_eq!((-t - one).abs(), t + one);
+        assert_eq!(Jet::<f64, 8>::from(2.0).sin().coefficients[1..], [0.0; 7]);
+        assert_eq!(Jet::<f64, 1>::variable(0.5).sin().coefficients, [0.5.sin()]);
+        assert_eq!(Jet::<f64, 4>::variable(2.0).derivative(3), 0.0);
+        assert_eq!((t * t * t).derivative(3), 6.0);
+        assert_eq!((one + t).evaluate(2.0), 3.0);
+    }
+
+    #[test]
+    fn test_nested_and_double_f64() {
+        // Jets of jets, and jets over DoubleF64.
+        let x = Jet::<Jet<f64, 3>, 3>::variable(Jet::variable(0.5));
+        let y = x.sin();
+        assert!((y.coefficients[2].coefficients[1] + 0.5_f64.cos() / 2.0).abs() < 1e-16);
+        let y = Jet::<DoubleF64, 4>::variable(DoubleF64::from_f64(0.5)).atan();
+        // atan'''(x) = (6x² - 2) / (1 + x²)³.
+        let expected = (6.0 * 0.25 - 2.0) / (1.25_f64 * 1.25 * 1.25);
+        assert!((y.derivative(3).to_f64() - expected).abs() < 1e-16);
+    }
+}
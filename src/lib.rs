@@ -20,12 +20,14 @@
 //! their inverses for both the trigonometric and hyperbolic functions. The complete set of methods
 //! defined on the [`Trig`] trait is:
 //!
-//! * trigonometric functions (radians): `sin`, `cos`, `tan`, `csc`, `sec`, `cot`
+//! * trigonometric functions (radians): `sin`, `cos`, `tan`, `csc`, `sec`, `cot`, `sincos`
 //! * inverse trigonometric functions (radians): `asin`, `acos`, `atan`, `acsc`, `asec`, `acot`
-//! * trigonometric functions (degrees): `sind`, `cosd`, `tand`, `cscd`, `secd`, `cotd`
+//! * trigonometric functions (degrees): `sind`, `cosd`, `tand`, `cscd`, `secd`, `cotd`, `sincosd`
 //! * inverse trigonometric functions (degrees): `asind`, `acosd`, `atand`, `atan2d`, `acscd`,
 //!   `asecd`, `acotd`
-//! * hyperbolic functions: `sinh`, `cosh`, `tanh`, `csch`, `sech`, `coth`
+//! * trigonometric functions (half-turns): `sinpi`, `cospi`, `tanpi`
+//! * inverse trigonometric functions (half-turns): `asinpi`, `acospi`, `atanpi`, `atan2pi`
+//! * hyperbolic functions: `sinh`, `cosh`, `tanh`, `csch`, `sech`, `coth`, `sinhcosh`
 //! * inverse hyperbolic functions: `asinh`, `acosh`, `atanh`, `acsch`, `asech`, `acoth`
 //! * unit conversions: `deg2rad`, `rad2deg`
 //!
@@ -35,6 +37,217 @@
 //!
 //! * [`f32`]
 //! * [`f64`]
+//! * [`Tabulated`] (see [Lookup tables](#lookup-tables))
+//! * [`Q1_15`], [`Q16_16`], and [`Q1_31`] (see [Fixed-point numbers](#fixed-point-numbers))
+//! * [`DoubleF64`] (see [Double-double precision](#double-double-precision))
+//! * [`Interval<f64>`](Interval) (see [Interval arithmetic](#interval-arithmetic))
+//! * [`Dual<T>`](Dual), [`HyperDual<T>`](HyperDual), and [`Jet<T, N>`](Jet) (see
+//!   [Automatic differentiation](#automatic-differentiation))
+//! * [`Var`] (with the `alloc` feature, see [Automatic differentiation](#automatic-differentiation))
+//! * `half::f16` and `half::bf16` (with the `half` feature)
+//! * `MpFloat` (with the `arbitrary-precision` feature, see
+//!   [Arbitrary precision](#arbitrary-precision))
+//!
+//! # Fast approximations
+//!
+//! The [`TrigFast`] trait mirrors the [`Trig`] trait with `_fast` methods (e.g. `sind_fast`) that
+//! evaluate low-degree polynomial approximations instead of calling the platform's math library.
+//! Each method takes an [`Accuracy`] tier (with maximum errors of about $10^{-3}$ and $10^{-6}$,
+//! or the full accuracy of the [`Trig`] methods), trading accuracy for throughput where the full
+//! accuracy is not needed. [`TrigFast`] is implemented for [`f32`].
+//!
+//! # Lookup tables
+//!
+//! The [`Tabulated`] type wraps an [`f32`] and implements the [`Trig`] trait by interpolating
+//! `sin`, `cos`, `tan`, `atan`, and `atan2` (and their degree variants) from lookup tables with a
+//! compile-time number of entries, with [`Linear`] or [`Cubic`] interpolation. The table size
+//! trades memory for accuracy on targets without a fast math library.
+//!
+//! # CORDIC
+//!
+//! The [`Cordic`] engine computes `sin`/`cos`, `atan2`, the magnitude of a vector, `sinh`/`cosh`,
+//! and `atanh` of fixed-point or integer arguments using only integer shifts and adds in its
+//! iterations, with a configurable number of iterations trading speed for accuracy. It targets
+//! hardware without floating-point support, such as FPU-less microcontrollers and FPGAs.
+//!
+//! # Fixed-point numbers
+//!
+//! The [`Q1_15`], [`Q16_16`], and [`Q1_31`] types wrap the bits of Q1.15, Q16.16, and Q1.31
+//! fixed-point numbers and implement the [`Trig`] trait with the [`Cordic`] engine and integer
+//! arithmetic only. Results that do not fit the type saturate to its `MIN` or `MAX` (in particular
+//! at the poles, e.g. `tand` at 90 degrees), and arguments outside the domain of an inverse
+//! function are clamped to it (e.g. `asin` of 2 is `asin` of 1), since fixed-point numbers cannot
+//! represent infinities or `NaN`.
+//!
+//! # Double-double precision
+//!
+//! The [`DoubleF64`] type represents a number as the unevaluated sum of two [`f64`]s, giving about
+//! $106$ bits (roughly $32$ decimal digits) of precision for applications that need more than
+//! [`f64`], such as orbit determination or generating reference values. It supports the basic
+//! arithmetic operators, and its [`Trig`] methods (including the reciprocal, degree, and inverse
+//! hyperbolic families) have a relative error of about $2^{-100}$.
+//!
+//! # Interval arithmetic
+//!
+//! The [`Trig`] methods on [`Interval<f64>`](Interval) return rigorous enclosures: each result is
+//! an interval guaranteed to contain the image of the input interval, for verified computing. The
+//! endpoints are moved outward to cover the error of the [`f64`] methods, extrema and poles inside
+//! the interval are located with an exact argument reduction, and the input is clipped to the
+//! domain of the method (so that e.g. `asin` over $[-2,\frac{1}{2}]$ is enclosed by
+//! $[-\frac{\pi}{2},\frac{\pi}{6}]$).
+//!
+//! # Automatic differentiation
+//!
+//! The [`Dual`] type implements forward-mode automatic differentiation: each [`Trig`] method on a
+//! dual number returns the function value together with its derivative, computed from the
+//! closed-form derivative of the function (including the chain factors of the degree and half-turn
+//! methods). Dual numbers are generic over the [`Real`] trait, which is implemented for [`f32`],
+//! [`f64`], [`DoubleF64`], and dual numbers themselves, so that nesting them gives higher
+//! derivatives.
+//!
+//! The [`HyperDual`] type carries two first-derivative parts and a mixed second-derivative part,
+//! so that a single evaluation of an expression gives one exact Hessian entry (for example, for the
+//! Newton steps of an optimizer) without finite differencing.
+//!
+//! The [`Jet`] type is a truncated Taylor series with `N` coefficients, whose [`Trig`] methods are
+//! evaluated with the standard power-series recurrences. It gives derivatives of any order, and its
+//! coefficients can be propagated through an ODE right-hand side for Taylor-series integration.
+//!
+//! For the derivatives of the individual functions (e.g. in hand-written Jacobians), the
+//! [`TrigDerivative`] trait provides the closed-form first and second derivatives of every
+//! single-argument [`Trig`] function, and the gradients and Hessians of `atan2`, `atan2d`, and
+//! `atan2pi`.
+//!
+//! With the `alloc` feature, the [`Var`] type implements reverse-mode automatic differentiation:
+//! operations on variables are recorded on a [`Tape`], and a single backward sweep from an output
+//! gives its [`Gradient`] with respect to all inputs, which is much cheaper than forward mode for
+//! functions of many parameters (e.g. the cost function of a least-squares fit).
+//!
+//! # Integrals
+//!
+//! The [`TrigIntegral`] trait provides the antiderivative (`_antideriv`) of every single-argument
+//! [`Trig`] function for [`f32`] and [`f64`], along with definite integrals (`_integrate`) that check
+//! the interval of integration first. Rather than returning a finite but meaningless difference of
+//! antiderivative values, an interval containing a pole of the integrand (e.g. $\tan{x}$ on
+//! $\[0,\pi\]$) gives [`IntegralError::Pole`], and an interval leaving the domain of the integrand
+//! gives [`IntegralError::Domain`]. Integrable singularities at the ends of the interval (e.g.
+//! $\mathrm{artanh}\ x$ at $x=\pm1$) are evaluated as limits.
+//!
+//! # Arbitrary precision
+//!
+//! With the `arbitrary-precision` feature, the `MpFloat` type is a binary floating-point number
+//! with a user-chosen number of significand bits (from $2$ up to $65536$), implemented in this
+//! crate without GMP or MPFR. Its arithmetic operators and square root are correctly rounded, and
+//! its [`Trig`] methods are evaluated in multiprecision ball arithmetic (with the argument reduced
+//! against $\pi$ computed to as many bits as needed) and correctly rounded to the chosen precision.
+//! This makes it both a high-precision computation tool and an oracle for testing the [`f32`] and
+//! [`f64`] implementations.
+//!
+//! # Compile-time evaluation
+//!
+//! The [`consts_eval`] module provides `const fn` versions of `sin`, `cos`, `tan`, `atan2`,
+//! `deg2rad`, `rad2deg`, `sind`, `cosd`, `tand`, `atan2d`, `sinh`, `cosh`, and `tanh` for [`f32`]
+//! and [`f64`], for use in `const` and `static` initializers.
+//!
+//! # Features
+//!
+//! * `std` (default) - The elementary functions (`sin`, `exp`, `ln`, ...) underlying the [`Trig`]
+//!   implementations on [`f32`] and [`f64`] come from the standard library, which delegates to the
+//!   platform's math library. Without this feature, the crate is `#![no_std]` and one of `libm` or
+//!   `deterministic` must be enabled instead. This feature enables `alloc`.
+//! * `alloc` - Adds the [`Tape`]-based reverse-mode automatic differentiation types (see
+//!   [Automatic differentiation](#automatic-differentiation)), which need a global allocator.
+//! * `libm` - The elementary functions come from the pure-Rust [`libm`](https://docs.rs/libm) crate
+//!   (a port of MUSL's math library), which works without the standard library, e.g. on embedded
+//!   targets. This takes precedence over `std`.
+//! * `correctly-rounded` - Every [`Trig`] method on [`f32`] and [`f64`] returns the correctly rounded
+//!   (round-to-nearest-even) result for every input. Each function is first evaluated in
+//!   double-double ball arithmetic; in the rare case that the resulting enclosure straddles a
+//!   rounding boundary, the evaluation is repeated in multiprecision ball arithmetic at doubling
+//!   precision until the result can be rounded (Ziv's strategy). This is slower than the default
+//!   implementation, which is accurate to within a few ulps. Without `std`, this feature needs a
+//!   global allocator, since the multiprecision fallback allocates its mantissas on the heap.
+//! * `deterministic` - The [`Trig`] methods on [`f32`] and [`f64`] are computed entirely by software
+//!   routines in this crate (adapted from FDLIBM) instead of the platform's math library, so that
+//!   they return identical bits on every target and at every optimization level. The routines only
+//!   use IEEE 754 basic operations (including square root), which are correctly rounded everywhere.
+//!   This is useful for lockstep simulations and replays that must agree across platforms. Since
+//!   correctly rounded results are also platform-independent, `correctly-rounded` takes precedence
+//!   when both features are enabled. This feature works without the standard library and takes
+//!   precedence over `std` and `libm`.
+//! * `half` - Implements the [`Trig`] trait for the IEEE 754 binary16 (`f16`) and bfloat16 (`bf16`)
+//!   types of the [`half`](https://docs.rs/half) crate. Each method is evaluated on [`f32`] and the
+//!   result is rounded once, which gives the correctly rounded result; in the rare case that the
+//!   [`f32`] result is too close to a rounding boundary to decide, the method is re-evaluated on
+//!   [`f64`] instead.
+//! * `arbitrary-precision` - Adds the `MpFloat` arbitrary-precision floating-point type (see
+//!   [Arbitrary precision](#arbitrary-precision)). Without `std`, this feature needs a global
+//!   allocator.
+//!
+//! # Accuracy
+//!
+//! The table below lists the maximum error, in ulps relative to the correctly rounded result, of
+//! every [`Trig`] method on [`f32`] and [`f64`] with the `std`, `libm`, and `deterministic` backends
+//! over the normal range (an error of $n$ ulps means that the result is within $n+\frac{1}{2}$ ulps of
+//! the exact value). These bounds are enforced by an audit in the test suite that compares each
+//! method against a multiprecision reference on dense random samples and edge cases. With the
+//! `correctly-rounded` feature, every error is $0$.
+//!
+//! | method | [`f32`] | [`f64`] |
+//! | ------ | ------- | ------- |
+//! | `sin` | 1 | 1 |
+//! | `cos` | 1 | 1 |
+//! | `tan` | 1 | 2 |
+//! | `csc` | 1 | 2 |
+//! | `sec` | 1 | 2 |
+//! | `cot` | 1 | 2 |
+//! | `sincos` (sin) | 1 | 1 |
+//! | `sincos` (cos) | 1 | 1 |
+//! | `asin` | 1 | 1 |
+//! | `acos` | 1 | 1 |
+//! | `atan` | 1 | 1 |
+//! | `atan2` | 1 | 1 |
+//! | `acsc` | 2 | 1 |
+//! | `asec` | 2 | 1 |
+//! | `acot` | 1 | 1 |
+//! | `deg2rad` | 1 | 1 |
+//! | `rad2deg` | 1 | 1 |
+//! | `sind` | 1 | 2 |
+//! | `cosd` | 1 | 1 |
+//! | `tand` | 3 | 2 |
+//! | `cscd` | 4 | 3 |
+//! | `secd` | 2 | 2 |
+//! | `cotd` | 4 | 3 |
+//! | `sincosd` (sin) | 2 | 1 |
+//! | `sincosd` (cos) | 2 | 1 |
+//! | `asind` | 1 | 2 |
+//! | `acosd` | 1 | 2 |
+//! | `atand` | 1 | 2 |
+//! | `atan2d` | 1 | 1 |
+//! | `acscd` | 1 | 1 |
+//! | `asecd` | 1 | 1 |
+//! | `acotd` | 1 | 1 |
+//! | `sinpi` | 2 | 2 |
+//! | `cospi` | 2 | 2 |
+//! | `tanpi` | 3 | 3 |
+//! | `asinpi` | 1 | 2 |
+//! | `acospi` | 1 | 2 |
+//! | `atanpi` | 1 | 1 |
+//! | `atan2pi` | 1 | 2 |
+//! | `sinh` | 2 | 2 |
+//! | `cosh` | 2 | 1 |
+//! | `tanh` | 2 | 2 |
+//! | `csch` | 2 | 2 |
+//! | `sech` | 2 | 2 |
+//! | `coth` | 3 | 2 |
+//! | `sinhcosh` (sinh) | 2 | 2 |
+//! | `sinhcosh` (cosh) | 2 | 1 |
+//! | `asinh` | 1 | 1 |
+//! | `acosh` | 2 | 2 |
+//! | `atanh` | 2 | 1 |
+//! | `acsch` | 2 | 2 |
+//! | `asech` | 2 | 2 |
+//! | `acoth` | 1 | 1 |
 //!
 //! # Example
 //!
@@ -49,11 +262,85 @@
 
 // Linter setup.
 #![warn(missing_docs)]
+// The standard library is only needed for the `std` backend (and by the test harness).
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+#[cfg(any(
+    test,
+    feature = "alloc",
+    feature = "correctly-rounded",
+    feature = "arbitrary-precision"
+))]
+extern crate alloc;
 
 // Module declarations.
+#[cfg(any(test, feature = "correctly-rounded", feature = "arbitrary-precision"))]
+pub(crate) mod ball;
+#[cfg(any(test, feature = "correctly-rounded", feature = "arbitrary-precision"))]
+pub(crate) mod big_float;
+pub mod consts_eval;
+pub(crate) mod cordic;
+#[cfg(any(test, feature = "correctly-rounded"))]
+pub(crate) mod correctly_rounded;
+pub(crate) mod double_double;
+pub(crate) mod double_f64;
+pub(crate) mod dual;
+pub(crate) mod f32_fast_impl;
 pub(crate) mod f32_impl;
 pub(crate) mod f64_impl;
+pub(crate) mod fast_math;
+pub(crate) mod fixed;
+#[cfg(feature = "half")]
+pub(crate) mod half_impl;
+pub(crate) mod hyper_dual;
+pub(crate) mod interval;
+pub(crate) mod jet;
+#[cfg(all(feature = "libm", not(feature = "deterministic")))]
+pub(crate) mod libm_math;
+#[cfg(feature = "arbitrary-precision")]
+pub(crate) mod mp_float;
+pub(crate) mod real;
+pub(crate) mod rem_pio2;
+pub(crate) mod soft_math;
+#[cfg(all(feature = "std", not(any(feature = "libm", feature = "deterministic"))))]
+pub(crate) mod std_math;
+pub(crate) mod tabulated;
+#[cfg(feature = "alloc")]
+pub(crate) mod tape;
+pub(crate) mod trig_derivative;
+pub(crate) mod trig_fast_trait;
+pub(crate) mod trig_integral;
 pub(crate) mod trig_trait;
+#[cfg(test)]
+mod ulp_audit;
+
+// Backend for the elementary functions.
+#[cfg(not(any(feature = "std", feature = "libm", feature = "deterministic")))]
+compile_error!(
+    "without the `std` feature, either the `libm` or the `deterministic` feature is required"
+);
+#[cfg(all(feature = "libm", not(feature = "deterministic")))]
+pub(crate) use crate::libm_math as math;
+#[cfg(feature = "deterministic")]
+pub(crate) use crate::soft_math as math;
+#[cfg(all(feature = "std", not(any(feature = "libm", feature = "deterministic"))))]
+pub(crate) use crate::std_math as math;
 
 // Re-exports.
+pub use crate::cordic::Cordic;
+pub use crate::double_f64::DoubleF64;
+pub use crate::dual::Dual;
+pub use crate::fixed::{Q1_15, Q1_31, Q16_16};
+pub use crate::hyper_dual::HyperDual;
+pub use crate::interval::Interval;
+pub use crate::jet::Jet;
+#[cfg(feature = "arbitrary-precision")]
+pub use crate::mp_float::MpFloat;
+pub use crate::real::Real;
+pub use crate::tabulated::{Cubic, Interpolation, Linear, Tabulated};
+#[cfg(feature = "alloc")]
+pub use crate::tape::{Gradient, Tape, Var};
+pub use crate::trig_derivative::TrigDerivative;
+pub use crate::trig_fast_trait::{Accuracy, TrigFast};
+pub use crate::trig_integral::{IntegralError, TrigIntegral};
 pub use crate::trig_trait::Trig;
@@ -0,0 +1,254 @@
+//! Elementary functions provided by the [`libm`] crate.
+//!
+//! This backend is used for the [`Trig`](crate::Trig) implementations on [`f32`] and [`f64`] when
+//! the `libm` feature is enabled. [`libm`] is a pure-Rust port of MUSL's math library, so it does
+//! not need the standard library (see the `std` feature).
+
+/// Sine of an [`f64`].
+#[cfg(not(feature = "correctly-rounded"))]
+pub(crate) fn sin(x: f64) -> f64 {
+    libm::sin(x)
+}
+
+/// Cosine of an [`f64`].
+#[cfg(not(feature = "correctly-rounded"))]
+pub(crate) fn cos(x: f64) -> f64 {
+    libm::cos(x)
+}
+
+/// Tangent of an [`f64`].
+#[cfg(not(feature = "correctly-rounded"))]
+pub(crate) fn tan(x: f64) -> f64 {
+    libm::tan(x)
+}
+
+/// Inverse sine of an [`f64`].
+#[cfg(not(feature = "correctly-rounded"))]
+pub(crate) fn asin(x: f64) -> f64 {
+    libm::asin(x)
+}
+
+/// Inverse cosine of an [`f64`].
+#[cfg(not(feature = "correctly-rounded"))]
+pub(crate) fn acos(x: f64) -> f64 {
+    libm::acos(x)
+}
+
+/// Inverse tangent of an [`f64`].
+#[cfg(not(feature = "correctly-rounded"))]
+pub(crate) fn atan(x: f64) -> f64 {
+    libm::atan(x)
+}
+
+/// Four-quadrant inverse tangent of [`f64`]s.
+#[cfg(not(feature = "correctly-rounded"))]
+pub(crate) fn atan2(y: f64, x: f64) -> f64 {
+    libm::atan2(y, x)
+}
+
+/// Exponential function of an [`f64`].
+pub(crate) fn exp(x: f64) -> f64 {
+    libm::exp(x)
+}
+
+/// Exponential function minus one of an [`f64`].
+#[cfg(not(feature = "correctly-rounded"))]
+pub(crate) fn exp_m1(x: f64) -> f64 {
+    libm::expm1(x)
+}
+
+/// Natural logarithm of an [`f64`].
+pub(crate) fn ln(x: f64) -> f64 {
+    libm::log(x)
+}
+
+/// Natural logarithm of one plus an [`f64`].
+pub(crate) fn ln_1p(x: f64) -> f64 {
+    libm::log1p(x)
+}
+
+/// Hyperbolic sine of an [`f64`].
+#[cfg(not(feature = "correctly-rounded"))]
+pub(crate) fn sinh(x: f64) -> f64 {
+    libm::sinh(x)
+}
+
+/// Hyperbolic cosine of an [`f64`].
+#[cfg(not(feature = "correctly-rounded"))]
+pub(crate) fn cosh(x: f64) -> f64 {
+    libm::cosh(x)
+}
+
+/// Hyperbolic tangent of an [`f64`].
+#[cfg(not(feature = "correctly-rounded"))]
+pub(crate) fn tanh(x: f64) -> f64 {
+    libm::tanh(x)
+}
+
+/// Inverse hyperbolic sine of an [`f64`].
+#[cfg(not(feature = "correctly-rounded"))]
+pub(crate) fn asinh(x: f64) -> f64 {
+    libm::asinh(x)
+}
+
+/// Inverse hyperbolic cosine of an [`f64`].
+///
+/// # Note
+///
+/// [`libm::acosh`] only checks $|x|$ for large arguments, so it returns a finite value for some
+/// $x\leq-1$; these are mapped to `NaN` here.
+#[cfg(not(feature = "correctly-rounded"))]
+pub(crate) fn acosh(x: f64) -> f64 {
+    if x < 1.0 { f64::NAN } else { libm::acosh(x) }
+}
+
+/// Inverse hyperbolic tangent of an [`f64`].
+#[cfg(not(feature = "correctly-rounded"))]
+pub(crate) fn atanh(x: f64) -> f64 {
+    libm::atanh(x)
+}
+
+/// Euclidean norm of a two-dimensional vector of [`f64`]s.
+pub(crate) fn hypot(x: f64, y: f64) -> f64 {
+    libm::hypot(x, y)
+}
+
+/// Square root of an [`f64`].
+pub(crate) fn sqrt(x: f64) -> f64 {
+    libm::sqrt(x)
+}
+
+/// Rounds an [`f64`] to the nearest integer, with ties rounded away from zero.
+pub(crate) fn round(x: f64) -> f64 {
+    libm::round(x)
+}
+
+/// Sine of an [`f32`].
+#[cfg(not(feature = "correctly-rounded"))]
+pub(crate) fn sin_f32(x: f32) -> f32 {
+    libm::sinf(x)
+}
+
+/// Cosine of an [`f32`].
+#[cfg(not(feature = "correctly-rounded"))]
+pub(crate) fn cos_f32(x: f32) -> f32 {
+    libm::cosf(x)
+}
+
+/// Tangent of an [`f32`].
+#[cfg(not(feature = "correctly-rounded"))]
+pub(crate) fn tan_f32(x: f32) -> f32 {
+    libm::tanf(x)
+}
+
+/// Inverse sine of an [`f32`].
+///
+/// # Note
+///
+/// The inverse trigonometric functions on [`f32`] are evaluated on [`f64`] and rounded, since
+/// `libm::asinf`, `libm::acosf`, `libm::atanf`, and `libm::atan2f` round their exact endpoints
+/// (e.g. $\arccos(-1)=\pi$ and $\arctan(\infty)=\frac{\pi}{2}$) away from the nearest [`f32`].
+#[cfg(not(feature = "correctly-rounded"))]
+pub(crate) fn asin_f32(x: f32) -> f32 {
+    libm::asin(x as f64) as f32
+}
+
+/// Inverse cosine of an [`f32`] (see [`asin_f32`]).
+#[cfg(not(feature = "correctly-rounded"))]
+pub(crate) fn acos_f32(x: f32) -> f32 {
+    libm::acos(x as f64) as f32
+}
+
+/// Inverse tangent of an [`f32`] (see [`asin_f32`]).
+#[cfg(not(feature = "correctly-rounded"))]
+pub(crate) fn atan_f32(x: f32) -> f32 {
+    libm::atan(x as f64) as f32
+}
+
+/// Four-quadrant inverse tangent of [`f32`]s (see [`asin_f32`]).
+#[cfg(not(feature = "correctly-rounded"))]
+pub(crate) fn atan2_f32(y: f32, x: f32) -> f32 {
+    libm::atan2(y as f64, x as f64) as f32
+}
+
+/// Exponential function of an [`f32`].
+#[cfg(not(feature = "correctly-rounded"))]
+pub(crate) fn exp_f32(x: f32) -> f32 {
+    libm::expf(x)
+}
+
+/// Exponential function minus one of an [`f32`].
+#[cfg(not(feature = "correctly-rounded"))]
+pub(crate) fn exp_m1_f32(x: f32) -> f32 {
+    libm::expm1f(x)
+}
+
+/// Natural logarithm of an [`f32`].
+#[cfg(not(feature = "correctly-rounded"))]
+pub(crate) fn ln_f32(x: f32) -> f32 {
+    libm::logf(x)
+}
+
+/// Natural logarithm of one plus an [`f32`].
+#[cfg(not(feature = "correctly-rounded"))]
+pub(crate) fn ln_1p_f32(x: f32) -> f32 {
+    libm::log1pf(x)
+}
+
+/// Hyperbolic sine of an [`f32`].
+#[cfg(not(feature = "correctly-rounded"))]
+pub(crate) fn sinh_f32(x: f32) -> f32 {
+    libm::sinhf(x)
+}
+
+/// Hyperbolic cosine of an [`f32`].
+#[cfg(not(feature = "correctly-rounded"))]
+pub(crate) fn cosh_f32(x: f32) -> f32 {
+    libm::coshf(x)
+}
+
+/// Hyperbolic tangent of an [`f32`].
+#[cfg(not(feature = "correctly-rounded"))]
+pub(crate) fn tanh_f32(x: f32) -> f32 {
+    libm::tanhf(x)
+}
+
+/// Inverse hyperbolic sine of an [`f32`].
+#[cfg(not(feature = "correctly-rounded"))]
+pub(crate) fn asinh_f32(x: f32) -> f32 {
+    libm::asinhf(x)
+}
+
+/// Inverse hyperbolic cosine of an [`f32`].
+///
+/// # Note
+///
+/// [`libm::acoshf`] only checks $|x|$, so it returns a finite value for some $x\leq-1$; these are
+/// mapped to `NaN` here.
+#[cfg(not(feature = "correctly-rounded"))]
+pub(crate) fn acosh_f32(x: f32) -> f32 {
+    if x < 1.0 { f32::NAN } else { libm::acoshf(x) }
+}
+
+/// Inverse hyperbolic tangent of an [`f32`].
+#[cfg(not(feature = "correctly-rounded"))]
+pub(crate) fn atanh_f32(x: f32) -> f32 {
+    libm::atanhf(x)
+}
+
+/// Euclidean norm of a two-dimensional vector of [`f32`]s.
+#[cfg(not(feature = "correctly-rounded"))]
+pub(crate) fn hypot_f32(x: f32, y: f32) -> f32 {
+    libm::hypotf(x, y)
+}
+
+/// Square root of an [`f32`].
+pub(crate) fn sqrt_f32(x: f32) -> f32 {
+    libm::sqrtf(x)
+}
+
+/// Rounds an [`f32`] to the nearest integer, with ties rounded away from zero.
+#[cfg(not(feature = "correctly-rounded"))]
+pub(crate) fn round_f32(x: f32) -> f32 {
+    libm::roundf(x)
+}
@@ -0,0 +1,1466 @@
+use crate::ball::{Ball, Function, MpBall, Unit, atan2_ball};
+use crate::big_float::BigFloat;
+use crate::trig_trait::Trig;
+use core::cmp::Ordering;
+use core::ops::{Add, Div, Mul, Neg, Sub};
+
+/// Exponent range of a finite nonzero [`MpFloat`] (as $2^{-e}\leq|x|<2^{e}$).
+const MAX_EXPONENT: i64 = 1 << 30;
+
+/// Number of bits by which the first working precision of a [`Trig`] method exceeds the precision
+/// of the result.
+const GUARD_BITS: u64 = 32;
+
+/// Value of an [`MpFloat`].
+#[derive(Clone, Debug)]
+enum Value {
+    /// Not a number.
+    Nan,
+
+    /// Zero (negative if `true`).
+    Zero(bool),
+
+    /// Infinity (negative if `true`).
+    Infinite(bool),
+
+    /// Finite nonzero number.
+    Finite(BigFloat),
+}
+
+/// Arbitrary-precision binary floating-point number.
+///
+/// An `MpFloat` is a sign, a significand with a user-chosen number of bits $p$ (its precision), and
+/// an exponent in a range of about $\pm2^{30}$, together with signed zeros, infinities, and `NaN`.
+/// The arithmetic operators and [`sqrt`](MpFloat::sqrt) are correctly rounded (to nearest, with
+/// ties to even) to the larger precision of their operands.
+///
+/// The [`Trig`] methods are evaluated in multiprecision ball arithmetic (a midpoint together with a
+/// rigorous error radius), with the argument reduced against $\pi$ computed to as many bits as the
+/// argument and the precision require. The evaluation is repeated at doubling working precision
+/// until the whole ball rounds to a single value (Ziv's strategy), so each result is correctly
+/// rounded to $p$ bits. If the result cannot be decided at a working precision of $8(p+64)$ bits
+/// (which only happens if the exact result lies halfway between two representable numbers, e.g.
+/// `asind` of $\frac{1}{2}$ at a precision of $3$ bits), the midpoint of the final ball is rounded
+/// instead, which is still within one ulp of the exact result. The special values (zeros,
+/// infinities, poles, and exact results at the boundaries of the domains) follow the same
+/// conventions as the [`f64`] implementation.
+///
+/// # Note
+///
+/// Since `MpFloat` has no dependency on GMP or MPFR, it can serve as an oracle when testing the
+/// [`f32`] and [`f64`] implementations: with a precision of $53$ bits, every method returns the
+/// correctly rounded [`f64`] result (up to the exponent range, see [`to_f64`](MpFloat::to_f64)).
+/// The cost of a method grows roughly quadratically with the precision, and the reduction of an
+/// argument with a large exponent needs as many bits of $\pi$ as its exponent.
+///
+/// # References
+///
+/// * Ziv, A. (1991). Fast evaluation of elementary mathematical functions with correctly rounded
+///   last bit. *ACM Transactions on Mathematical Software*, *17*(3), 410-423.
+///
+/// # Example
+///
+/// ```
+/// use trig::{MpFloat, Trig};
+///
+/// // sin(π/6) to 200 bits.
+/// let x = MpFloat::pi(200) / MpFloat::from_f64(6.0, 200);
+/// let error = x.sin() - MpFloat::from_f64(0.5, 200);
+/// assert!(error.to_f64().abs() < 1e-59);
+///
+/// // With 53 bits, the results are the correctly rounded f64 results.
+/// assert_eq!(MpFloat::from_f64(1e22, 53).sin().to_f64(), -0.8522008497671888);
+/// ```
+#[derive(Clone, Debug)]
+pub struct MpFloat {
+    /// Value.
+    value: Value,
+
+    /// Precision (number of significant bits).
+    precision: u32,
+}
+
+impl MpFloat {
+    /// Smallest supported precision.
+    pub const MIN_PRECISION: u32 = 2;
+
+    /// Largest supported precision.
+    pub const MAX_PRECISION: u32 = 1 << 16;
+
+    /// Constructs an [`MpFloat`] from its value.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Value (with at most `precision` significant bits if finite).
+    /// * `precision` - Precision.
+    ///
+    /// # Returns
+    ///
+    /// [`MpFloat`] with the given value and precision.
+    ///
+    /// # Panics
+    ///
+    /// If the precision is outside of [`MIN_PRECISION`](MpFloat::MIN_PRECISION) to
+    /// [`MAX_PRECISION`](MpFloat::MAX_PRECISION).
+    fn new(value: Value, precision: u32) -> MpFloat {
+        assert!(
+            (MpFloat::MIN_PRECISION..=MpFloat::MAX_PRECISION).contains(&precision),
+            "the precision must be between {} and {} bits",
+            MpFloat::MIN_PRECISION,
+            MpFloat::MAX_PRECISION
+        );
+        MpFloat { value, precision }
+    }
+
+    /// Constructs an [`MpFloat`] from an exact value, rounding it to a given precision.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - Exact value.
+    /// * `precision` - Precision.
+    ///
+    /// # Returns
+    ///
+    /// `x` rounded to nearest (with overflow to $\pm\infty$ and underflow to $\pm0$ outside of the
+    /// exponent range).
+    fn rounded(x: &BigFloat, precision: u32) -> MpFloat {
+        MpFloat::finish(x.round_nearest(precision as u64), precision)
+    }
+
+    /// Constructs an [`MpFloat`] from a value that is already rounded to a given precision.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - Value with at most `precision` significant bits.
+    /// * `precision` - Precision.
+    ///
+    /// # Returns
+    ///
+    /// `x` (with overflow to $\pm\infty$ and underflow to $\pm0$ outside of the exponent range).
+    fn finish(x: BigFloat, precision: u32) -> MpFloat {
+        let value = if x.is_zero() {
+            Value::Zero(false)
+        } else if x.msb() >= MAX_EXPONENT {
+            Value::Infinite(x.is_negative())
+        } else if x.msb() < -MAX_EXPONENT {
+            Value::Zero(x.is_negative())
+        } else {
+            Value::Finite(x)
+        };
+        MpFloat::new(value, precision)
+    }
+
+    /// Converts an [`f64`] to an [`MpFloat`].
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - Value.
+    /// * `precision` - Precision (number of significant bits), between
+    ///   [`MIN_PRECISION`](MpFloat::MIN_PRECISION) and [`MAX_PRECISION`](MpFloat::MAX_PRECISION).
+    ///
+    /// # Returns
+    ///
+    /// `x` rounded to nearest to `precision` bits (exact for a precision of at least $53$ bits).
+    ///
+    /// # Panics
+    ///
+    /// If the precision is out of range.
+    pub fn from_f64(x: f64, precision: u32) -> MpFloat {
+        let value = if x.is_nan() {
+            Value::Nan
+        } else if x == 0.0 {
+            Value::Zero(x.is_sign_negative())
+        } else if x.is_infinite() {
+            Value::Infinite(x < 0.0)
+        } else {
+            return MpFloat::new(Value::Zero(false), precision).with_value(&BigFloat::from_f64(x));
+        };
+        MpFloat::new(value, precision)
+    }
+
+    /// Computes $\pi$.
+    ///
+    /// # Arguments
+    ///
+    /// * `precision` - Precision (number of significant bits), between
+    ///   [`MIN_PRECISION`](MpFloat::MIN_PRECISION) and [`MAX_PRECISION`](MpFloat::MAX_PRECISION).
+    ///
+    /// # Returns
+    ///
+    /// $\pi$ correctly rounded to `precision` bits.
+    ///
+    /// # Panics
+    ///
+    /// If the precision is out of range.
+    pub fn pi(precision: u32) -> MpFloat {
+        MpFloat::new(Value::Zero(false), precision).angle(1.0, Unit::Radians)
+    }
+
+    /// Precision of this number.
+    ///
+    /// # Returns
+    ///
+    /// Number of significant bits.
+    pub fn precision(&self) -> u32 {
+        self.precision
+    }
+
+    /// Rounds this number to a different precision.
+    ///
+    /// # Arguments
+    ///
+    /// * `precision` - Precision (number of significant bits), between
+    ///   [`MIN_PRECISION`](MpFloat::MIN_PRECISION) and [`MAX_PRECISION`](MpFloat::MAX_PRECISION).
+    ///
+    /// # Returns
+    ///
+    /// This number rounded to nearest to `precision` bits.
+    ///
+    /// # Panics
+    ///
+    /// If the precision is out of range.
+    pub fn with_precision(&self, precision: u32) -> MpFloat {
+        match &self.value {
+            Value::Finite(x) => MpFloat::rounded(x, precision),
+            value => MpFloat::new(value.clone(), precision),
+        }
+    }
+
+    /// Converts this number to an [`f64`].
+    ///
+    /// # Returns
+    ///
+    /// This number rounded to the nearest [`f64`] (with ties to even, gradual underflow, and
+    /// overflow to $\pm\infty$).
+    pub fn to_f64(&self) -> f64 {
+        match &self.value {
+            Value::Nan => f64::NAN,
+            Value::Zero(negative) => {
+                if *negative {
+                    -0.0
+                } else {
+                    0.0
+                }
+            }
+            Value::Infinite(negative) => {
+                if *negative {
+                    f64::NEG_INFINITY
+                } else {
+                    f64::INFINITY
+                }
+            }
+            Value::Finite(x) => x.round(f64::MANTISSA_DIGITS),
+        }
+    }
+
+    /// Determines whether this number is `NaN`.
+    ///
+    /// # Returns
+    ///
+    /// `true` if this number is `NaN`.
+    pub fn is_nan(&self) -> bool {
+        matches!(self.value, Value::Nan)
+    }
+
+    /// Determines whether this number is positive or negative infinity.
+    ///
+    /// # Returns
+    ///
+    /// `true` if this number is $\pm\infty$.
+    pub fn is_infinite(&self) -> bool {
+        matches!(self.value, Value::Infinite(_))
+    }
+
+    /// Determines whether this number is positive or negative zero.
+    ///
+    /// # Returns
+    ///
+    /// `true` if this number is $\pm0$.
+    pub fn is_zero(&self) -> bool {
+        matches!(self.value, Value::Zero(_))
+    }
+
+    /// Determines whether this number has a negative sign.
+    ///
+    /// # Returns
+    ///
+    /// `true` for negative numbers, $-0$, and $-\infty$ (and `false` for `NaN`).
+    pub fn is_sign_negative(&self) -> bool {
+        match &self.value {
+            Value::Nan => false,
+            Value::Zero(negative) | Value::Infinite(negative) => *negative,
+            Value::Finite(x) => x.is_negative(),
+        }
+    }
+
+    /// Computes the absolute value of this number.
+    ///
+    /// # Returns
+    ///
+    /// $|x|$ (exact).
+    pub fn abs(&self) -> MpFloat {
+        if self.is_sign_negative() {
+            -self
+        } else {
+            self.clone()
+        }
+    }
+
+    /// Computes the square root of this number.
+    ///
+    /// # Returns
+    ///
+    /// $\sqrt{x}$ correctly rounded to the precision of this number (`NaN` for $x<0$, and $-0$ for
+    /// $x=-0$).
+    pub fn sqrt(&self) -> MpFloat {
+        match &self.value {
+            Value::Finite(x) if x.is_negative() => self.with(Value::Nan),
+            Value::Finite(x) => {
+                let (root, inexact) = x.sqrt(self.precision as u64 + 2);
+                self.with_inexact(root, inexact)
+            }
+            Value::Infinite(true) => self.with(Value::Nan),
+            _ => self.clone(),
+        }
+    }
+
+    /// Constructs a number with the precision of this number.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Value (with at most as many significant bits as the precision if finite).
+    ///
+    /// # Returns
+    ///
+    /// [`MpFloat`] with the given value.
+    fn with(&self, value: Value) -> MpFloat {
+        MpFloat::new(value, self.precision)
+    }
+
+    /// Rounds an exact value to the precision of this number.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - Exact value.
+    ///
+    /// # Returns
+    ///
+    /// `x` rounded to nearest.
+    fn with_value(&self, x: &BigFloat) -> MpFloat {
+        MpFloat::rounded(x, self.precision)
+    }
+
+    /// Rounds a truncated value to the precision of this number.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - Exact value truncated toward zero to two more bits than the precision.
+    /// * `inexact` - Whether the truncation was inexact.
+    ///
+    /// # Returns
+    ///
+    /// Exact value rounded to nearest.
+    fn with_inexact(&self, x: BigFloat, inexact: bool) -> MpFloat {
+        // An inexact value lies strictly between x and the next number with two more bits than the
+        // precision, so a sticky bit below x decides the rounding of ties.
+        let x = if inexact {
+            let sticky = BigFloat::from_parts(1, x.msb() - self.precision as i64 - 3);
+            x.add(&if x.is_negative() {
+                sticky.neg()
+            } else {
+                sticky
+            })
+        } else {
+            x
+        };
+        self.with_value(&x)
+    }
+
+    /// Constructs a number with the sign of this number.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - Magnitude.
+    ///
+    /// # Returns
+    ///
+    /// $\text{sgn}(x)|f|$.
+    fn with_sign_of_self(&self, f: MpFloat) -> MpFloat {
+        if f.is_sign_negative() == self.is_sign_negative() {
+            f
+        } else {
+            -f
+        }
+    }
+
+    /// Finite nonzero value of this number.
+    ///
+    /// # Returns
+    ///
+    /// Value, or [`None`] for zeros, infinities, and `NaN`.
+    fn finite(&self) -> Option<&BigFloat> {
+        match &self.value {
+            Value::Finite(x) => Some(x),
+            _ => None,
+        }
+    }
+
+    /// Compares the magnitude of this number with a constant.
+    ///
+    /// # Arguments
+    ///
+    /// * `c` - Positive constant.
+    ///
+    /// # Returns
+    ///
+    /// Ordering of $|x|$ relative to $c$ (`None` for `NaN`).
+    fn cmp_abs(&self, c: f64) -> Option<Ordering> {
+        match &self.value {
+            Value::Nan => None,
+            Value::Zero(_) => Some(Ordering::Less),
+            Value::Infinite(_) => Some(Ordering::Greater),
+            Value::Finite(x) => Some(x.cmp_abs(&BigFloat::from_f64(c))),
+        }
+    }
+
+    /// Correctly rounds a ball computation to the precision of this number (Ziv's strategy).
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - Evaluation in ball arithmetic at a given working precision.
+    ///
+    /// # Returns
+    ///
+    /// Correctly rounded result (or the rounded midpoint of the final ball if it cannot be decided
+    /// at the largest working precision).
+    fn ziv(&self, f: impl Fn(u64) -> Option<MpBall>) -> MpFloat {
+        let precision = self.precision as u64;
+        let max_precision = 8 * (precision + 64);
+        let mut working_precision = precision + GUARD_BITS;
+        loop {
+            let ball = f(working_precision);
+            if let Some(y) = ball.as_ref().and_then(|ball| ball.round_to(precision)) {
+                return MpFloat::finish(y, self.precision);
+            }
+            if working_precision >= max_precision {
+                return match ball {
+                    Some(ball) => {
+                        MpFloat::finish(ball.round_midpoint_to(precision), self.precision)
+                    }
+                    None => self.with(Value::Nan),
+                };
+            }
+            working_precision = (2 * working_precision).min(max_precision);
+        }
+    }
+
+    /// Correctly rounds a function of one argument to the precision of this number.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - Function.
+    /// * `x` - Argument.
+    ///
+    /// # Returns
+    ///
+    /// $f(x)$ rounded to nearest.
+    fn round(&self, f: Function, x: &BigFloat) -> MpFloat {
+        self.ziv(|w| f.eval(&MpBall::from_big_float(x.clone(), w)))
+    }
+
+    /// Correctly rounds a fraction of a half turn to the precision of this number.
+    ///
+    /// # Arguments
+    ///
+    /// * `fraction` - Fraction of a half turn.
+    /// * `unit` - Angular unit of the result.
+    ///
+    /// # Returns
+    ///
+    /// `fraction` half-turns in `unit`, rounded to nearest.
+    fn angle(&self, fraction: f64, unit: Unit) -> MpFloat {
+        match unit {
+            Unit::Radians => self.ziv(|w| {
+                let ball = MpBall::from_big_float(BigFloat::from_f64(fraction), w);
+                ball.mul(&ball.pi()?)
+            }),
+            Unit::Degrees => self.with_value(&BigFloat::from_f64(180.0 * fraction)),
+            Unit::HalfTurns => self.with_value(&BigFloat::from_f64(fraction)),
+        }
+    }
+
+    /// Signed fraction of a half turn.
+    ///
+    /// # Arguments
+    ///
+    /// * `fraction` - Fraction of a half turn.
+    /// * `negative` - Whether the angle is negative.
+    /// * `unit` - Angular unit of the result.
+    ///
+    /// # Returns
+    ///
+    /// $\pm$`fraction` half-turns in `unit`, rounded to nearest.
+    fn signed_angle(&self, fraction: f64, negative: bool, unit: Unit) -> MpFloat {
+        let angle = self.angle(fraction, unit);
+        if negative { -angle } else { angle }
+    }
+
+    /// Sine of an angle in units with an exact quarter turn.
+    fn sin_turn(&self, unit: Unit) -> MpFloat {
+        let Some(x) = self.finite() else {
+            return if self.is_zero() {
+                self.clone()
+            } else {
+                self.with(Value::Nan)
+            };
+        };
+        let (q, r) = reduce_turn(x, unit.quarter_turn());
+        let f = match q {
+            0 => self.sin_reduced(&r, unit),
+            1 => self.round(Function::Cos(unit), &r),
+            2 => -self.sin_reduced(&r, unit),
+            _ => -self.round(Function::Cos(unit), &r),
+        };
+        if f.is_zero() {
+            self.with(Value::Zero(self.is_sign_negative()))
+        } else {
+            f
+        }
+    }
+
+    /// Cosine of an angle in units with an exact quarter turn.
+    fn cos_turn(&self, unit: Unit) -> MpFloat {
+        let Some(x) = self.finite() else {
+            return if self.is_zero() {
+                self.with_value(&BigFloat::from_i64(1))
+            } else {
+                self.with(Value::Nan)
+            };
+        };
+        let (q, r) = reduce_turn(x, unit.quarter_turn());
+        let f = match q {
+            0 => self.round(Function::Cos(unit), &r),
+            1 => -self.sin_reduced(&r, unit),
+            2 => -self.round(Function::Cos(unit), &r),
+            _ => self.sin_reduced(&r, unit),
+        };
+        if f.is_zero() {
+            self.with(Value::Zero(false))
+        } else {
+            f
+        }
+    }
+
+    /// Tangent of an angle in units with an exact quarter turn.
+    fn tan_turn(&self, unit: Unit) -> MpFloat {
+        let Some(x) = self.finite() else {
+            return if self.is_zero() {
+                self.clone()
+            } else {
+                self.with(Value::Nan)
+            };
+        };
+        let (q, r) = reduce_turn(x, unit.quarter_turn());
+        if r.is_zero() {
+            match q {
+                1 => self.with(Value::Infinite(false)),
+                3 => self.with(Value::Infinite(true)),
+                _ => self.with(Value::Zero(self.is_sign_negative())),
+            }
+        } else if q % 2 == 0 {
+            self.round(Function::Tan(unit), &r)
+        } else {
+            -self.round(Function::Cot(unit), &r)
+        }
+    }
+
+    /// Cosecant of an angle in units with an exact quarter turn.
+    fn csc_turn(&self, unit: Unit) -> MpFloat {
+        let Some(x) = self.finite() else {
+            return self.one() / self.sin_turn(unit);
+        };
+        let (q, r) = reduce_turn(x, unit.quarter_turn());
+        if r.is_zero() && q % 2 == 0 {
+            return self.one() / self.sin_turn(unit);
+        }
+        match q {
+            0 => self.round(Function::Csc(unit), &r),
+            1 => self.round(Function::Sec(unit), &r),
+            2 => -self.round(Function::Csc(unit), &r),
+            _ => -self.round(Function::Sec(unit), &r),
+        }
+    }
+
+    /// Secant of an angle in units with an exact quarter turn.
+    fn sec_turn(&self, unit: Unit) -> MpFloat {
+        let Some(x) = self.finite() else {
+            return self.one() / self.cos_turn(unit);
+        };
+        let (q, r) = reduce_turn(x, unit.quarter_turn());
+        if r.is_zero() && q % 2 == 1 {
+            return self.one() / self.cos_turn(unit);
+        }
+        match q {
+            0 => self.round(Function::Sec(unit), &r),
+            1 => -self.round(Function::Csc(unit), &r),
+            2 => -self.round(Function::Sec(unit), &r),
+            _ => self.round(Function::Csc(unit), &r),
+        }
+    }
+
+    /// Cotangent of an angle in units with an exact quarter turn.
+    fn cot_turn(&self, unit: Unit) -> MpFloat {
+        let Some(x) = self.finite() else {
+            return self.one() / self.tan_turn(unit);
+        };
+        let (q, r) = reduce_turn(x, unit.quarter_turn());
+        if r.is_zero() {
+            self.one() / self.tan_turn(unit)
+        } else if q % 2 == 0 {
+            self.round(Function::Cot(unit), &r)
+        } else {
+            -self.round(Function::Tan(unit), &r)
+        }
+    }
+
+    /// Sine of a reduced angle, which is an exact zero for a zero angle.
+    ///
+    /// # Arguments
+    ///
+    /// * `r` - Reduced angle.
+    /// * `unit` - Angular unit of `r`.
+    ///
+    /// # Returns
+    ///
+    /// $\sin(r)$.
+    fn sin_reduced(&self, r: &BigFloat, unit: Unit) -> MpFloat {
+        if r.is_zero() {
+            self.with(Value::Zero(false))
+        } else {
+            self.round(Function::Sin(unit), r)
+        }
+    }
+
+    /// Inverse sine in a given unit.
+    fn asin_unit(&self, unit: Unit) -> MpFloat {
+        match self.cmp_abs(1.0) {
+            None | Some(Ordering::Greater) => self.with(Value::Nan),
+            Some(Ordering::Equal) => self.signed_angle(0.5, self.is_sign_negative(), unit),
+            Some(Ordering::Less) => match self.finite() {
+                Some(x) => self.round(Function::Asin(unit), x),
+                None => self.clone(),
+            },
+        }
+    }
+
+    /// Inverse cosine in a given unit.
+    fn acos_unit(&self, unit: Unit) -> MpFloat {
+        match self.cmp_abs(1.0) {
+            None | Some(Ordering::Greater) => self.with(Value::Nan),
+            Some(Ordering::Equal) if self.is_sign_negative() => self.angle(1.0, unit),
+            Some(Ordering::Equal) => self.with(Value::Zero(false)),
+            Some(Ordering::Less) => match self.finite() {
+                Some(x) => self.round(Function::Acos(unit), x),
+                None => self.angle(0.5, unit),
+            },
+        }
+    }
+
+    /// Inverse tangent in a given unit.
+    fn atan_unit(&self, unit: Unit) -> MpFloat {
+        match &self.value {
+            Value::Finite(x) => self.round(Function::Atan(unit), x),
+            Value::Infinite(negative) => self.signed_angle(0.5, *negative, unit),
+            _ => self.clone(),
+        }
+    }
+
+    /// Two-argument inverse tangent in a given unit.
+    fn atan2_unit(&self, other: &MpFloat, unit: Unit) -> MpFloat {
+        let result = MpFloat::new(Value::Nan, self.precision.max(other.precision));
+        let negative = self.is_sign_negative();
+        match (&self.value, &other.value) {
+            (Value::Nan, _) | (_, Value::Nan) => result,
+            (Value::Zero(_), _) => {
+                if other.is_sign_negative() {
+                    result.signed_angle(1.0, negative, unit)
+                } else {
+                    result.with(self.value.clone())
+                }
+            }
+            (Value::Infinite(_), Value::Infinite(false)) => {
+                result.signed_angle(0.25, negative, unit)
+            }
+            (Value::Infinite(_), Value::Infinite(true)) => {
+                result.signed_angle(0.75, negative, unit)
+            }
+            (Value::Infinite(_), _) | (_, Value::Zero(_)) => {
+                result.signed_angle(0.5, negative, unit)
+            }
+            (_, Value::Infinite(false)) => result.with(Value::Zero(negative)),
+            (_, Value::Infinite(true)) => result.signed_angle(1.0, negative, unit),
+            (Value::Finite(y), Value::Finite(x)) => result.ziv(|w| {
+                let y = MpBall::from_big_float(y.clone(), w);
+                let x = MpBall::from_big_float(x.clone(), w);
+                unit.radians_to(&atan2_ball(&y, &x)?)
+            }),
+        }
+    }
+
+    /// Inverse cosecant in a given unit.
+    fn acsc_unit(&self, unit: Unit) -> MpFloat {
+        match self.cmp_abs(1.0) {
+            None | Some(Ordering::Less) => self.with(Value::Nan),
+            Some(Ordering::Equal) => self.signed_angle(0.5, self.is_sign_negative(), unit),
+            Some(Ordering::Greater) => match self.finite() {
+                Some(x) => self.round(Function::Acsc(unit), x),
+                None => self.with(Value::Zero(self.is_sign_negative())),
+            },
+        }
+    }
+
+    /// Inverse secant in a given unit.
+    fn asec_unit(&self, unit: Unit) -> MpFloat {
+        match self.cmp_abs(1.0) {
+            None | Some(Ordering::Less) => self.with(Value::Nan),
+            Some(Ordering::Equal) if self.is_sign_negative() => self.angle(1.0, unit),
+            Some(Ordering::Equal) => self.with(Value::Zero(false)),
+            Some(Ordering::Greater) => match self.finite() {
+                Some(x) => self.round(Function::Asec(unit), x),
+                None => self.angle(0.5, unit),
+            },
+        }
+    }
+
+    /// Inverse cotangent in a given unit.
+    fn acot_unit(&self, unit: Unit) -> MpFloat {
+        match &self.value {
+            Value::Finite(x) => self.round(Function::Acot(unit), x),
+            Value::Zero(negative) => self.signed_angle(0.5, *negative, unit),
+            Value::Infinite(negative) => self.with(Value::Zero(*negative)),
+            Value::Nan => self.clone(),
+        }
+    }
+
+    /// One.
+    ///
+    /// # Returns
+    ///
+    /// $1$ with the precision of this number.
+    fn one(&self) -> MpFloat {
+        self.with_value(&BigFloat::from_i64(1))
+    }
+
+    /// Evaluates an odd hyperbolic function on the magnitude of this number.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - Function (of a positive argument).
+    /// * `x` - Finite nonzero value of this number.
+    ///
+    /// # Returns
+    ///
+    /// $\text{sgn}(x)f(|x|)$.
+    fn odd(&self, f: Function, x: &BigFloat) -> MpFloat {
+        self.with_sign_of_self(self.round(f, &x.abs()))
+    }
+
+    /// Determines whether $e^{-2|x|}$ is negligible next to $1$ at the precision of this number.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - Finite nonzero value of this number.
+    ///
+    /// # Returns
+    ///
+    /// `true` if $|x|>p$, in which case $\tanh(x)$ and $\coth(x)$ round to $\pm1$.
+    fn saturates(&self, x: &BigFloat) -> bool {
+        x.cmp_abs(&BigFloat::from_i64(self.precision as i64)) == Ordering::Greater
+    }
+
+    /// Determines whether $e^{|x|}$ is outside of the exponent range.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - Finite nonzero value of this number.
+    ///
+    /// # Returns
+    ///
+    /// `true` if $|x|\geq2^{30}$, in which case $\sinh(x)$ and $\cosh(x)$ overflow and
+    /// $\text{csch}(x)$ and $\text{sech}(x)$ underflow.
+    fn overflows(x: &BigFloat) -> bool {
+        x.msb() >= 30
+    }
+}
+
+/// Exactly reduces an angle modulo a quarter turn.
+///
+/// # Arguments
+///
+/// * `x` - Finite nonzero angle.
+/// * `quarter_turn` - A quarter turn in the units of `x` (e.g. `90.0` for degrees).
+///
+/// # Returns
+///
+/// Quadrant `q` (in $\\{0,1,2,3\\}$) and remainder `r` (with $|r|$ at most about half of
+/// `quarter_turn`) such that `x` is exactly equal to `q * quarter_turn + r` modulo a full turn.
+fn reduce_turn(x: &BigFloat, quarter_turn: f64) -> (u8, BigFloat) {
+    let quarter_turn = BigFloat::from_f64(quarter_turn);
+    if x.msb() < quarter_turn.msb() - 1 {
+        return (0, x.clone());
+    }
+
+    // The quotient only needs to be accurate enough to round to the nearest integer (or a neighbor
+    // of it), since the remainder is computed exactly.
+    let bits = (x.msb() - quarter_turn.msb() + 64) as u64;
+    let (n, q) = x.div(&quarter_turn, bits).0.round_integer();
+    (q, x.sub(&n.mul(&quarter_turn)))
+}
+
+impl Neg for MpFloat {
+    type Output = MpFloat;
+    fn neg(self) -> MpFloat {
+        -&self
+    }
+}
+
+impl Neg for &MpFloat {
+    type Output = MpFloat;
+    fn neg(self) -> MpFloat {
+        self.with(match &self.value {
+            Value::Nan => Value::Nan,
+            Value::Zero(negative) => Value::Zero(!negative),
+            Value::Infinite(negative) => Value::Infinite(!negative),
+            Value::Finite(x) => Value::Finite(x.neg()),
+        })
+    }
+}
+
+impl Add for &MpFloat {
+    type Output = MpFloat;
+    fn add(self, other: &MpFloat) -> MpFloat {
+        let result = MpFloat::new(Value::Nan, self.precision.max(other.precision));
+        match (&self.value, &other.value) {
+            (Value::Nan, _) | (_, Value::Nan) => result,
+            (Value::Infinite(a), Value::Infinite(b)) if a != b => result,
+            (Value::Infinite(_), _) => result.with(self.value.clone()),
+            (_, Value::Infinite(_)) => result.with(other.value.clone()),
+            (Value::Zero(a), Value::Zero(b)) => result.with(Value::Zero(*a && *b)),
+            (Value::Zero(_), Value::Finite(x)) | (Value::Finite(x), Value::Zero(_)) => {
+                result.with_value(x)
+            }
+            (Value::Finite(x), Value::Finite(y)) => {
+                // If the exponents are far apart, the smaller summand is below a quarter ulp of the
+                // larger one and cannot affect the rounding (which also avoids aligning the
+                // mantissas).
+                let gap = result.precision as i64 + 2;
+                if x.msb() > y.msb() + gap {
+                    result.with_value(x)
+                } else if y.msb() > x.msb() + gap {
+                    result.with_value(y)
+                } else {
+                    result.with_value(&x.add(y))
+                }
+            }
+        }
+    }
+}
+
+impl Sub for &MpFloat {
+    type Output = MpFloat;
+    fn sub(self, other: &MpFloat) -> MpFloat {
+        self + &-other
+    }
+}
+
+impl Mul for &MpFloat {
+    type Output = MpFloat;
+    fn mul(self, other: &MpFloat) -> MpFloat {
+        let result = MpFloat::new(Value::Nan, self.precision.max(other.precision));
+        let negative = self.is_sign_negative() != other.is_sign_negative();
+        match (&self.value, &other.value) {
+            (Value::Nan, _) | (_, Value::Nan) => result,
+            (Value::Infinite(_), Value::Zero(_)) | (Value::Zero(_), Value::Infinite(_)) => result,
+            (Value::Infinite(_), _) | (_, Value::Infinite(_)) => {
+                result.with(Value::Infinite(negative))
+            }
+            (Value::Zero(_), _) | (_, Value::Zero(_)) => result.with(Value::Zero(negative)),
+            (Value::Finite(x), Value::Finite(y)) => result.with_value(&x.mul(y)),
+        }
+    }
+}
+
+impl Div for &MpFloat {
+    type Output = MpFloat;
+    fn div(self, other: &MpFloat) -> MpFloat {
+        let result = MpFloat::new(Value::Nan, self.precision.max(other.precision));
+        let negative = self.is_sign_negative() != other.is_sign_negative();
+        match (&self.value, &other.value) {
+            (Value::Nan, _) | (_, Value::Nan) => result,
+            (Value::Infinite(_), Value::Infinite(_)) | (Value::Zero(_), Value::Zero(_)) => result,
+            (Value::Infinite(_), _) | (_, Value::Zero(_)) => result.with(Value::Infinite(negative)),
+            (Value::Zero(_), _) | (_, Value::Infinite(_)) => result.with(Value::Zero(negative)),
+            (Value::Finite(x), Value::Finite(y)) => {
+                let (quotient, inexact) = x.div(y, result.precision as u64 + 2);
+                result.with_inexact(quotient, inexact)
+            }
+        }
+    }
+}
+
+/// Implements an arithmetic operator on owned [`MpFloat`]s in terms of references.
+macro_rules! owned_operator {
+    ($trait:ident, $method:ident) => {
+        impl $trait for MpFloat {
+            type Output = MpFloat;
+            fn $method(self, other: MpFloat) -> MpFloat {
+                $trait::$method(&self, &other)
+            }
+        }
+    };
+}
+
+owned_operator!(Add, add);
+owned_operator!(Sub, sub);
+owned_operator!(Mul, mul);
+owned_operator!(Div, div);
+
+impl PartialEq for MpFloat {
+    fn eq(&self, other: &MpFloat) -> bool {
+        self.partial_cmp(other) == Some(Ordering::Equal)
+    }
+}
+
+impl PartialOrd for MpFloat {
+    fn partial_cmp(&self, other: &MpFloat) -> Option<Ordering> {
+        if self.is_nan() || other.is_nan() {
+            return None;
+        }
+        let negative = self.is_sign_negative();
+        if self.is_zero() && other.is_zero() {
+            Some(Ordering::Equal)
+        } else if self.is_zero() {
+            Some(if other.is_sign_negative() {
+                Ordering::Greater
+            } else {
+                Ordering::Less
+            })
+        } else if other.is_zero() || negative != other.is_sign_negative() {
+            Some(if negative {
+                Ordering::Less
+            } else {
+                Ordering::Greater
+            })
+        } else {
+            let magnitude = match (&self.value, &other.value) {
+                (Value::Infinite(_), Value::Infinite(_)) => Ordering::Equal,
+                (Value::Infinite(_), _) => Ordering::Greater,
+                (_, Value::Infinite(_)) => Ordering::Less,
+                (Value::Finite(x), Value::Finite(y)) => x.cmp_abs(y),
+                _ => unreachable!(),
+            };
+            Some(if negative {
+                magnitude.reverse()
+            } else {
+                magnitude
+            })
+        }
+    }
+}
+
+impl Trig for MpFloat {
+    fn sin(&self) -> MpFloat {
+        match &self.value {
+            Value::Finite(x) => self.round(Function::Sin(Unit::Radians), x),
+            Value::Zero(_) => self.clone(),
+            _ => self.with(Value::Nan),
+        }
+    }
+    fn cos(&self) -> MpFloat {
+        match &self.value {
+            Value::Finite(x) => self.round(Function::Cos(Unit::Radians), x),
+            Value::Zero(_) => self.one(),
+            _ => self.with(Value::Nan),
+        }
+    }
+    fn tan(&self) -> MpFloat {
+        match &self.value {
+            Value::Finite(x) => self.round(Function::Tan(Unit::Radians), x),
+            Value::Zero(_) => self.clone(),
+            _ => self.with(Value::Nan),
+        }
+    }
+    fn csc(&self) -> MpFloat {
+        match &self.value {
+            Value::Finite(x) => self.round(Function::Csc(Unit::Radians), x),
+            Value::Zero(_) => self.one() / self.clone(),
+            _ => self.with(Value::Nan),
+        }
+    }
+    fn sec(&self) -> MpFloat {
+        match &self.value {
+            Value::Finite(x) => self.round(Function::Sec(Unit::Radians), x),
+            Value::Zero(_) => self.one(),
+            _ => self.with(Value::Nan),
+        }
+    }
+    fn cot(&self) -> MpFloat {
+        match &self.value {
+            Value::Finite(x) => self.round(Function::Cot(Unit::Radians), x),
+            Value::Zero(_) => self.one() / self.clone(),
+            _ => self.with(Value::Nan),
+        }
+    }
+    fn sincos(&self) -> (MpFloat, MpFloat) {
+        (self.sin(), self.cos())
+    }
+    fn asin(&self) -> MpFloat {
+        self.asin_unit(Unit::Radians)
+    }
+    fn acos(&self) -> MpFloat {
+        self.acos_unit(Unit::Radians)
+    }
+    fn atan(&self) -> MpFloat {
+        self.atan_unit(Unit::Radians)
+    }
+    fn atan2(&self, other: &MpFloat) -> MpFloat {
+        self.atan2_unit(other, Unit::Radians)
+    }
+    fn acsc(&self) -> MpFloat {
+        self.acsc_unit(Unit::Radians)
+    }
+    fn asec(&self) -> MpFloat {
+        self.asec_unit(Unit::Radians)
+    }
+    fn acot(&self) -> MpFloat {
+        self.acot_unit(Unit::Radians)
+    }
+    fn deg2rad(&self) -> MpFloat {
+        match &self.value {
+            Value::Finite(x) => self.round(Function::ToRadians(Unit::Degrees), x),
+            _ => self.clone(),
+        }
+    }
+    fn rad2deg(&self) -> MpFloat {
+        match &self.value {
+            Value::Finite(x) => self.round(Function::FromRadians(Unit::Degrees), x),
+            _ => self.clone(),
+        }
+    }
+    fn sind(&self) -> MpFloat {
+        self.sin_turn(Unit::Degrees)
+    }
+    fn cosd(&self) -> MpFloat {
+        self.cos_turn(Unit::Degrees)
+    }
+    fn tand(&self) -> MpFloat {
+        self.tan_turn(Unit::Degrees)
+    }
+    fn cscd(&self) -> MpFloat {
+        self.csc_turn(Unit::Degrees)
+    }
+    fn secd(&self) -> MpFloat {
+        self.sec_turn(Unit::Degrees)
+    }
+    fn cotd(&self) -> MpFloat {
+        self.cot_turn(Unit::Degrees)
+    }
+    fn sincosd(&self) -> (MpFloat, MpFloat) {
+        (self.sind(), self.cosd())
+    }
+    fn asind(&self) -> MpFloat {
+        self.asin_unit(Unit::Degrees)
+    }
+    fn acosd(&self) -> MpFloat {
+        self.acos_unit(Unit::Degrees)
+    }
+    fn atand(&self) -> MpFloat {
+        self.atan_unit(Unit::Degrees)
+    }
+    fn atan2d(&self, other: &MpFloat) -> MpFloat {
+        self.atan2_unit(other, Unit::Degrees)
+    }
+    fn acscd(&self) -> MpFloat {
+        self.acsc_unit(Unit::Degrees)
+    }
+    fn asecd(&self) -> MpFloat {
+        self.asec_unit(Unit::Degrees)
+    }
+    fn acotd(&self) -> MpFloat {
+        self.acot_unit(Unit::Degrees)
+    }
+    fn sinpi(&self) -> MpFloat {
+        self.sin_turn(Unit::HalfTurns)
+    }
+    fn cospi(&self) -> MpFloat {
+        self.cos_turn(Unit::HalfTurns)
+    }
+    fn tanpi(&self) -> MpFloat {
+        self.tan_turn(Unit::HalfTurns)
+    }
+    fn asinpi(&self) -> MpFloat {
+        self.asin_unit(Unit::HalfTurns)
+    }
+    fn acospi(&self) -> MpFloat {
+        self.acos_unit(Unit::HalfTurns)
+    }
+    fn atanpi(&self) -> MpFloat {
+        self.atan_unit(Unit::HalfTurns)
+    }
+    fn atan2pi(&self, other: &MpFloat) -> MpFloat {
+        self.atan2_unit(other, Unit::HalfTurns)
+    }
+    fn sinh(&self) -> MpFloat {
+        match &self.value {
+            Value::Finite(x) if MpFloat::overflows(x) => {
+                self.with(Value::Infinite(x.is_negative()))
+            }
+            Value::Finite(x) => self.odd(Function::Sinh, x),
+            _ => self.clone(),
+        }
+    }
+    fn cosh(&self) -> MpFloat {
+        match &self.value {
+            Value::Finite(x) if MpFloat::overflows(x) => self.with(Value::Infinite(false)),
+            Value::Finite(x) => self.round(Function::Cosh, &x.abs()),
+            Value::Zero(_) => self.one(),
+            Value::Infinite(_) => self.with(Value::Infinite(false)),
+            Value::Nan => self.clone(),
+        }
+    }
+    fn tanh(&self) -> MpFloat {
+        match &self.value {
+            Value::Finite(x) if self.saturates(x) => self.with_sign_of_self(self.one()),
+            Value::Finite(x) => self.odd(Function::Tanh, x),
+            Value::Infinite(_) => self.with_sign_of_self(self.one()),
+            _ => self.clone(),
+        }
+    }
+    fn csch(&self) -> MpFloat {
+        match &self.value {
+            Value::Finite(x) if MpFloat::overflows(x) => self.with(Value::Zero(x.is_negative())),
+            Value::Finite(x) => self.odd(Function::Csch, x),
+            _ => self.one() / self.clone(),
+        }
+    }
+    fn sech(&self) -> MpFloat {
+        match &self.value {
+            Value::Finite(x) if MpFloat::overflows(x) => self.with(Value::Zero(false)),
+            Value::Finite(x) => self.round(Function::Sech, &x.abs()),
+            Value::Zero(_) => self.one(),
+            Value::Infinite(_) => self.with(Value::Zero(false)),
+            Value::Nan => self.clone(),
+        }
+    }
+    fn coth(&self) -> MpFloat {
+        match &self.value {
+            Value::Finite(x) if self.saturates(x) => self.with_sign_of_self(self.one()),
+            Value::Finite(x) => self.odd(Function::Coth, x),
+            Value::Infinite(_) => self.with_sign_of_self(self.one()),
+            _ => self.one() / self.clone(),
+        }
+    }
+    fn sinhcosh(&self) -> (MpFloat, MpFloat) {
+        (self.sinh(), self.cosh())
+    }
+    fn asinh(&self) -> MpFloat {
+        match &self.value {
+            Value::Finite(x) => self.odd(Function::Asinh, x),
+            _ => self.clone(),
+        }
+    }
+    fn acosh(&self) -> MpFloat {
+        if self.is_sign_negative() {
+            return self.with(Value::Nan);
+        }
+        match (&self.value, self.cmp_abs(1.0)) {
+            (Value::Finite(x), Some(Ordering::Greater)) => self.round(Function::Acosh, x),
+            (_, Some(Ordering::Equal)) => self.with(Value::Zero(false)),
+            (Value::Infinite(_), _) => self.clone(),
+            _ => self.with(Value::Nan),
+        }
+    }
+    fn atanh(&self) -> MpFloat {
+        match (&self.value, self.cmp_abs(1.0)) {
+            (_, None | Some(Ordering::Greater)) => self.with(Value::Nan),
+            (_, Some(Ordering::Equal)) => self.with(Value::Infinite(self.is_sign_negative())),
+            (Value::Finite(x), _) => self.odd(Function::Atanh, x),
+            _ => self.clone(),
+        }
+    }
+    fn acsch(&self) -> MpFloat {
+        match &self.value {
+            Value::Finite(x) => self.odd(Function::Acsch, x),
+            _ => self.one() / self.clone(),
+        }
+    }
+    fn asech(&self) -> MpFloat {
+        if self.is_nan() || (self.is_sign_negative() && !self.is_zero()) {
+            return self.with(Value::Nan);
+        }
+        match (&self.value, self.cmp_abs(1.0)) {
+            (_, Some(Ordering::Greater)) => self.with(Value::Nan),
+            (_, Some(Ordering::Equal)) => self.with(Value::Zero(false)),
+            (Value::Finite(x), _) => self.round(Function::Asech, x),
+            _ => self.with(Value::Infinite(false)),
+        }
+    }
+    fn acoth(&self) -> MpFloat {
+        match (&self.value, self.cmp_abs(1.0)) {
+            (_, None | Some(Ordering::Less)) => self.with(Value::Nan),
+            (_, Some(Ordering::Equal)) => self.with(Value::Infinite(self.is_sign_negative())),
+            (Value::Finite(x), _) => self.odd(Function::Acoth, x),
+            _ => self.with(Value::Zero(self.is_sign_negative())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::correctly_rounded;
+    use core::f64::consts::PI;
+
+    /// Arguments of the oracle tests (including special values, exact angles, and boundaries of the
+    /// domains).
+    const ARGUMENTS: [f64; 25] = [
+        0.0,
+        -0.0,
+        1e-300,
+        -1e-5,
+        0.5,
+        -0.75,
+        0.999,
+        1.0,
+        -1.0,
+        1.5,
+        2.0,
+        -3.0,
+        30.0,
+        45.0,
+        90.0,
+        -180.0,
+        270.0,
+        100.5,
+        -720.25,
+        1000.0,
+        1e22,
+        -1e300,
+        f64::INFINITY,
+        f64::NEG_INFINITY,
+        f64::NAN,
+    ];
+
+    /// Asserts that two results are identical (or both `NaN`) once rounded to [`f32`] for a
+    /// precision of $24$ bits.
+    ///
+    /// Subnormal [`f32`] results are skipped, since they have fewer than $24$ bits.
+    fn assert_same(name: &str, x: f64, precision: u32, actual: f64, expected: f64) {
+        let (actual, expected) = if precision == f32::MANTISSA_DIGITS {
+            if (expected as f32).is_subnormal() {
+                return;
+            }
+            (actual as f32 as f64, expected as f32 as f64)
+        } else {
+            (actual, expected)
+        };
+        assert!(
+            actual.to_bits() == expected.to_bits() || (actual.is_nan() && expected.is_nan()),
+            "{name}({x}): {actual} != {expected}"
+        );
+    }
+
+    /// Checks the unary [`Trig`] methods against the correctly rounded [`f64`] functions.
+    macro_rules! check_unary {
+        ($x:expr, $precision:expr; $($name:ident),* $(,)?) => {
+            $(
+                let x: f64 = $x;
+                let actual = Trig::$name(&MpFloat::from_f64(x, $precision)).to_f64();
+                let expected = correctly_rounded::$name(x, $precision);
+                assert_same(stringify!($name), x, $precision, actual, expected);
+            )*
+        };
+    }
+
+    #[test]
+    fn test_oracle() {
+        for precision in [f64::MANTISSA_DIGITS, f32::MANTISSA_DIGITS] {
+            for x in ARGUMENTS {
+                if precision == f32::MANTISSA_DIGITS && x as f32 as f64 != x && !x.is_nan() {
+                    continue;
+                }
+                check_unary!(
+                    x, precision;
+                    sin, cos, tan, csc, sec, cot,
+                    asin, acos, atan, acsc, asec, acot,
+                    deg2rad, rad2deg,
+                    sind, cosd, tand, cscd, secd, cotd,
+                    asind, acosd, atand, acscd, asecd, acotd,
+                    sinpi, cospi, tanpi,
+                    asinpi, acospi, atanpi,
+                    sinh, cosh, tanh, csch, sech, coth,
+                    asinh, acosh, atanh, acsch, asech, acoth,
+                );
+                for y in [0.0, -0.0, 0.5, -2.0, f64::INFINITY, f64::NEG_INFINITY] {
+                    let (yp, xp) = (
+                        MpFloat::from_f64(x, precision),
+                        MpFloat::from_f64(y, precision),
+                    );
+                    let name = "atan2";
+                    let actual = yp.atan2(&xp).to_f64();
+                    assert_same(
+                        name,
+                        x,
+                        precision,
+                        actual,
+                        correctly_rounded::atan2(x, y, precision),
+                    );
+                    let actual = yp.atan2d(&xp).to_f64();
+                    assert_same(
+                        name,
+                        x,
+                        precision,
+                        actual,
+                        correctly_rounded::atan2d(x, y, precision),
+                    );
+                    let actual = yp.atan2pi(&xp).to_f64();
+                    assert_same(
+                        name,
+                        x,
+                        precision,
+                        actual,
+                        correctly_rounded::atan2pi(x, y, precision),
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_pi() {
+        assert_eq!(MpFloat::pi(53).to_f64(), PI);
+        assert_eq!(MpFloat::pi(24).to_f64(), core::f32::consts::PI as f64);
+        let error = MpFloat::pi(106) - MpFloat::from_f64(PI, 106);
+        assert_eq!(error.to_f64(), 1.224646799147353e-16);
+        assert_eq!(MpFloat::pi(2).to_f64(), 3.0);
+    }
+
+    #[test]
+    fn test_high_precision() {
+        // sin(1) to 150 bits, written as a sum of three f64s.
+        let f = MpFloat::from_f64(1.0, 150).sin();
+        let hi = f.to_f64();
+        let r = &f - &MpFloat::from_f64(hi, 150);
+        let mid = r.to_f64();
+        let lo = (&r - &MpFloat::from_f64(mid, 150)).to_f64();
+        assert_eq!(hi, 0.8414709848078965);
+        assert_eq!(mid, 1.776845092935536e-18);
+        assert!((lo - -1.4730549161871722e-34).abs() < 1e-44);
+
+        // The reduction of a large argument uses as many bits of π as its exponent.
+        let f = MpFloat::from_f64(1e300, 120).cos();
+        let hi = f.to_f64();
+        assert_eq!(hi, -0.5753861119575491);
+        assert!(((f - MpFloat::from_f64(hi, 120)).to_f64() - 2.6770761918787068e-17).abs() < 1e-32);
+    }
+
+    #[test]
+    fn test_exact_angles() {
+        let p = 100;
+        let exact = |x: f64| MpFloat::from_f64(x, p);
+        assert_eq!(exact(30.0).sind(), exact(0.5));
+        assert_eq!(exact(-150.0).cosd(), -exact(0.75).sqrt());
+        assert_eq!(exact(225.0).tand(), exact(1.0));
+        assert_eq!(exact(0.5).asind(), exact(30.0));
+        assert_eq!(exact(-1.0).acosd(), exact(180.0));
+        assert_eq!(exact(1.0).atanpi(), exact(0.25));
+        assert_eq!(exact(-1.0).atan2d(&exact(-1.0)), exact(-135.0));
+        assert!(exact(180.0).sind().is_zero());
+        assert!(exact(-180.0).sind().is_sign_negative());
+        assert!(exact(90.0).cosd().is_zero());
+        assert_eq!(exact(90.0).tand().to_f64(), f64::INFINITY);
+        assert_eq!(exact(270.0).tand().to_f64(), f64::NEG_INFINITY);
+        assert_eq!(exact(1.5).sinpi(), exact(-1.0));
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let p = 64;
+        let x = |x: f64| MpFloat::from_f64(x, p);
+
+        // 1 + 2^-70 rounds to 1, 1 + 2^-63 is exact, and halfway cases round to even.
+        assert_eq!(x(1.0) + x(2f64.powi(-70)), x(1.0));
+        assert_eq!(
+            (x(1.0) + x(2f64.powi(-63)) - x(1.0)).to_f64(),
+            2f64.powi(-63)
+        );
+        assert_eq!(x(1.0) + x(2f64.powi(-64)), x(1.0));
+        assert_eq!(
+            (x(1.0) + x(2f64.powi(-63)) + x(2f64.powi(-64)) - x(1.0)).to_f64(),
+            2f64.powi(-62)
+        );
+
+        // 1/3 and sqrt(2) are correctly rounded.
+        let third = x(1.0) / x(3.0);
+        assert_eq!(third.with_precision(53).to_f64(), 1.0 / 3.0);
+        let residual = &(&third * &x(3.0)) - &x(1.0);
+        assert!(residual.abs() < x(2f64.powi(-64)));
+        assert_eq!(
+            x(2.0).sqrt().with_precision(53).to_f64(),
+            core::f64::consts::SQRT_2
+        );
+        assert_eq!(x(4.0).sqrt(), x(2.0));
+
+        // Precision of mixed operations.
+        assert_eq!((MpFloat::from_f64(1.0, 10) + x(1.0)).precision(), p);
+        assert_eq!(MpFloat::from_f64(1.0 + 2f64.powi(-20), 10).to_f64(), 1.0);
+
+        // Special values.
+        assert!((x(f64::INFINITY) - x(f64::INFINITY)).is_nan());
+        assert!((x(0.0) / x(0.0)).is_nan());
+        assert!((x(0.0) * x(f64::INFINITY)).is_nan());
+        assert!(x(-1.0).sqrt().is_nan());
+        assert_eq!((x(1.0) / x(-0.0)).to_f64(), f64::NEG_INFINITY);
+        assert!((x(-0.0) + x(-0.0)).is_sign_negative());
+        assert!(!(x(-0.0) + x(0.0)).is_sign_negative());
+        assert!(x(-0.0).sqrt().is_sign_negative());
+
+        // Comparisons.
+        assert!(x(-1.0) < x(-0.5));
+        assert!(x(-0.0) == x(0.0));
+        assert!(x(f64::NEG_INFINITY) < x(-1e300));
+        assert!(x(2.0) > x(1.0));
+        assert!(x(f64::NAN).partial_cmp(&x(1.0)).is_none());
+
+        // Overflow and underflow of the exponent range.
+        let mut y = x(2.0);
+        for _ in 0..31 {
+            y = &y * &y;
+        }
+        assert!(y.is_infinite());
+        assert!((x(1.0) / y).is_zero());
+    }
+
+    #[test]
+    fn test_hyperbolic_limits() {
+        let p = 80;
+        let x = |x: f64| MpFloat::from_f64(x, p);
+        assert!(x(2e9).sinh().is_infinite());
+        assert!(x(-2e9).sinh().is_sign_negative());
+        assert!(x(-2e9).csch().is_zero());
+        assert!(x(-2e9).csch().is_sign_negative());
+        assert_eq!(x(81.0).tanh(), x(1.0));
+        assert_eq!(x(-81.0).coth(), x(-1.0));
+        assert!(x(20.0).tanh() < x(1.0));
+        let f = x(1e5).cosh();
+        assert!(!f.is_infinite());
+        assert!(f.to_f64().is_infinite());
+        assert!((f.acosh() - x(1e5)).abs() < x(1e-15));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_precision_out_of_range() {
+        MpFloat::from_f64(1.0, 1);
+    }
+}
@@ -0,0 +1,108 @@
+use crate::double_f64::DoubleF64;
+use crate::trig_trait::Trig;
+use core::ops::{Add, Div, Mul, Neg, Sub};
+
+/// Real number type underlying the automatic differentiation types.
+///
+/// This trait collects the arithmetic that the derivatives of the [`Trig`] methods need on top of
+/// the [`Trig`] methods themselves. It is implemented for [`f32`], [`f64`], and [`DoubleF64`], and
+/// for [`Dual`](crate::Dual), [`HyperDual`](crate::HyperDual), and [`Jet`](crate::Jet) numbers over
+/// any of them (so that they can be nested).
+pub trait Real:
+    Trig
+    + Copy
+    + PartialOrd
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+{
+    /// Converts an [`f64`] to this type.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - Value.
+    ///
+    /// # Returns
+    ///
+    /// `x` (rounded to the precision of this type).
+    fn from_f64(x: f64) -> Self;
+
+    /// Square root.
+    ///
+    /// # Returns
+    ///
+    /// $\sqrt{x}$ (`NaN` for $x<0$).
+    fn sqrt(&self) -> Self;
+
+    /// Absolute value.
+    ///
+    /// # Returns
+    ///
+    /// $|x|$.
+    fn abs(&self) -> Self;
+}
+
+/// A degree in radians.
+pub(crate) fn deg2rad<T: Real>() -> T {
+    T::from_f64(1.0).deg2rad()
+}
+
+/// A radian in degrees.
+pub(crate) fn rad2deg<T: Real>() -> T {
+    T::from_f64(1.0).rad2deg()
+}
+
+/// $\pi$.
+pub(crate) fn pi<T: Real>() -> T {
+    T::from_f64(180.0).deg2rad()
+}
+
+/// $1-x^2$, evaluated as $(1-x)(1+x)$ for accuracy near $|x|=1$.
+pub(crate) fn one_minus_square<T: Real>(x: T) -> T {
+    let one = T::from_f64(1.0);
+    (one - x) * (one + x)
+}
+
+/// $x^2-1$, evaluated as $(x-1)(x+1)$ for accuracy near $|x|=1$.
+pub(crate) fn square_minus_one<T: Real>(x: T) -> T {
+    let one = T::from_f64(1.0);
+    (x - one) * (x + one)
+}
+
+impl Real for f32 {
+    fn from_f64(x: f64) -> f32 {
+        x as f32
+    }
+    fn sqrt(&self) -> f32 {
+        crate::math::sqrt_f32(*self)
+    }
+    fn abs(&self) -> f32 {
+        f32::abs(*self)
+    }
+}
+
+impl Real for f64 {
+    fn from_f64(x: f64) -> f64 {
+        x
+    }
+    fn sqrt(&self) -> f64 {
+        crate::math::sqrt(*self)
+    }
+    fn abs(&self) -> f64 {
+        f64::abs(*self)
+    }
+}
+
+impl Real for DoubleF64 {
+    fn from_f64(x: f64) -> DoubleF64 {
+        DoubleF64::from_f64(x)
+    }
+    fn sqrt(&self) -> DoubleF64 {
+        DoubleF64::sqrt(*self)
+    }
+    fn abs(&self) -> DoubleF64 {
+        DoubleF64::abs(*self)
+    }
+}
@@ -0,0 +1,704 @@
+//! Reduction of radian arguments modulo $\frac{\pi}{2}$.
+//!
+//! Arguments of moderate magnitude are reduced with a Cody–Waite reduction, while larger arguments
+//! are reduced with a Payne–Hanek reduction that multiplies the argument by the required window of
+//! the binary expansion of $\frac{2}{\pi}$ using exact integer arithmetic. Together, these give a
+//! remainder that is accurate to well beyond [`f64`] precision for every finite argument.
+
+use crate::double_double::two_product;
+#[cfg(not(feature = "correctly-rounded"))]
+use crate::math;
+use crate::soft_math;
+
+/// First 33 bits of $\frac{\pi}{2}$.
+pub(crate) const PIO2_1: f64 = 1.570_796_326_734_125_6;
+
+/// $\frac{\pi}{2}-$ `PIO2_1`.
+const PIO2_1T: f64 = 6.077_100_506_506_192e-11;
+
+/// Second 33 bits of $\frac{\pi}{2}$.
+pub(crate) const PIO2_2: f64 = 6.077_100_506_303_966e-11;
+
+/// $\frac{\pi}{2}-$ (`PIO2_1` + `PIO2_2`).
+const PIO2_2T: f64 = 2.022_266_248_795_950_6e-21;
+
+/// Third 33 bits of $\frac{\pi}{2}$.
+pub(crate) const PIO2_3: f64 = 2.022_266_248_711_166_5e-21;
+
+/// $\frac{\pi}{2}-$ (`PIO2_1` + `PIO2_2` + `PIO2_3`).
+pub(crate) const PIO2_3T: f64 = 8.478_427_660_368_9e-32;
+
+/// First 25 bits of $\frac{\pi}{2}$ (used for [`f32`] arguments).
+const PIO2_1_F32: f64 = 1.570_796_310_901_641_8;
+
+/// $\frac{\pi}{2}-$ `PIO2_1_F32`.
+const PIO2_1T_F32: f64 = 1.589_325_477_352_819_7e-8;
+
+/// $\frac{\pi}{2}$ rounded to [`f64`].
+const PIO2_HI: f64 = core::f64::consts::FRAC_PI_2;
+
+/// $\frac{\pi}{2}-$ `PIO2_HI`.
+const PIO2_LO: f64 = 6.123_233_995_736_766e-17;
+
+/// Binary expansion of $\frac{2}{\pi}$, preceded by one word of zeros so that windows starting
+/// slightly before the binary point can be extracted without special cases.
+///
+/// Bit $63-j$ of word $k$ is the bit of $\frac{2}{\pi}$ with weight $2^{-(64k+j-63)}$.
+const TWO_OVER_PI: [u64; 24] = [
+    0x0000000000000000,
+    0xa2f9836e4e441529,
+    0xfc2757d1f534ddc0,
+    0xdb6295993c439041,
+    0xfe5163abdebbc561,
+    0xb7246e3a424dd2e0,
+    0x06492eea09d1921c,
+    0xfe1deb1cb129a73e,
+    0xe88235f52ebb4484,
+    0xe99c7026b45f7e41,
+    0x3991d639835339f4,
+    0x9c845f8bbdf9283b,
+    0x1ff897ffde05980f,
+    0xef2f118b5a0a6d1f,
+    0x6d367ecf27cb09b7,
+    0x4f463f669e5fea2d,
+    0x7527bac7ebe5f17b,
+    0x3d0739f78a5292ea,
+    0x6bfb5fb11f8d5d08,
+    0x56033046fc7b6bab,
+    0xf0cfbc209af4361d,
+    0xa9e391615ee61b08,
+    0x6599855f14a06840,
+    0x8dffd8804d732731,
+];
+
+/// Largest magnitude of an [`f64`] argument handled by the Cody–Waite reduction
+/// ($2^{20}\cdot\frac{\pi}{2}$).
+const MEDIUM_F64: f64 = 1_647_099.329_165_285_5;
+
+/// Largest magnitude of an [`f32`] argument handled by the Cody–Waite reduction
+/// ($2^{28}\cdot\frac{\pi}{2}$).
+const MEDIUM_F32: f32 = 421_657_440.0;
+
+/// Reduces an [`f64`] argument modulo $\frac{\pi}{2}$.
+///
+/// # Arguments
+///
+/// * `x` - Angle \[rad\].
+///
+/// # Returns
+///
+/// The quadrant `q` (in $\\{0,1,2,3\\}$) and the remainder `hi + lo` (with
+/// $|\text{hi}+\text{lo}|\lesssim\frac{\pi}{4}$) such that $x=q\frac{\pi}{2}+\text{hi}+\text{lo}$
+/// modulo $2\pi$. The remainder is `NaN` if `x` is not finite.
+pub(crate) const fn rem_pio2(x: f64) -> (u8, f64, f64) {
+    if let Some(reduced) = rem_pio2_medium(x) {
+        reduced
+    } else if !x.is_finite() {
+        (0, f64::NAN, 0.0)
+    } else {
+        rem_pio2_large(x)
+    }
+}
+
+/// Reduces an [`f32`] argument modulo $\frac{\pi}{2}$.
+///
+/// # Arguments
+///
+/// * `x` - Angle \[rad\].
+///
+/// # Returns
+///
+/// The quadrant `q` (in $\\{0,1,2,3\\}$) and the remainder `r` (with $|r|\lesssim\frac{\pi}{4}$)
+/// such that $x=q\frac{\pi}{2}+r$ modulo $2\pi$. The remainder is `NaN` if `x` is not finite.
+pub(crate) const fn rem_pio2_f32(x: f32) -> (u8, f64) {
+    match rem_pio2_medium_f32(x) {
+        Some(reduced) => reduced,
+        None => {
+            let (q, hi, _) = rem_pio2(x as f64);
+            (q, hi)
+        }
+    }
+}
+
+/// Reduces an [`f64`] argument modulo $\frac{\pi}{2}$ using a three-stage Cody–Waite reduction.
+///
+/// # Arguments
+///
+/// * `x` - Angle \[rad\].
+///
+/// # Returns
+///
+/// `None` if $|x|>2^{20}\cdot\frac{\pi}{2}$ (or `x` is not finite). Otherwise, the quadrant `q`
+/// (in $\\{0,1,2,3\\}$) and the remainder `hi + lo` (with
+/// $|\text{hi}+\text{lo}|\lesssim\frac{\pi}{4}$) such that $x=q\frac{\pi}{2}+\text{hi}+\text{lo}$
+/// modulo $2\pi$, where the remainder is accurate to well beyond [`f64`] precision.
+const fn rem_pio2_medium(x: f64) -> Option<(u8, f64, f64)> {
+    if x.abs() <= core::f64::consts::FRAC_PI_4 {
+        return Some((0, x, 0.0));
+    } else if x.is_nan() || x.abs() > MEDIUM_F64 {
+        return None;
+    }
+
+    let n = soft_math::round(x * core::f64::consts::FRAC_2_PI);
+    let ex = exponent(x);
+
+    // First round, good to 85 bits.
+    let mut r = x - n * PIO2_1;
+    let mut w = n * PIO2_1T;
+    let mut hi = r - w;
+
+    // Second and third rounds, only needed when the first round cancels many bits.
+    if ex - exponent(hi) > 16 {
+        let t = r;
+        w = n * PIO2_2;
+        r = t - w;
+        w = n * PIO2_2T - ((t - r) - w);
+        hi = r - w;
+        if ex - exponent(hi) > 49 {
+            let t = r;
+            w = n * PIO2_3;
+            r = t - w;
+            w = n * PIO2_3T - ((t - r) - w);
+            hi = r - w;
+        }
+    }
+    let lo = (r - hi) - w;
+
+    Some(((n as i32).rem_euclid(4) as u8, hi, lo))
+}
+
+/// Reduces an [`f32`] argument modulo $\frac{\pi}{2}$ using a Cody–Waite reduction carried out in
+/// [`f64`] arithmetic.
+///
+/// # Arguments
+///
+/// * `x` - Angle \[rad\].
+///
+/// # Returns
+///
+/// `None` if $|x|>2^{28}\cdot\frac{\pi}{2}$ (or `x` is not finite). Otherwise, the quadrant `q`
+/// (in $\\{0,1,2,3\\}$) and the remainder `r` (with $|r|\lesssim\frac{\pi}{4}$) such that
+/// $x=q\frac{\pi}{2}+r$ modulo $2\pi$, where the remainder is accurate to well beyond [`f32`]
+/// precision.
+const fn rem_pio2_medium_f32(x: f32) -> Option<(u8, f64)> {
+    if x.abs() <= core::f32::consts::FRAC_PI_4 {
+        return Some((0, x as f64));
+    } else if x.is_nan() || x.abs() > MEDIUM_F32 {
+        return None;
+    }
+    let x = x as f64;
+    let n = soft_math::round(x * core::f64::consts::FRAC_2_PI);
+    let r = x - n * PIO2_1_F32 - n * PIO2_1T_F32;
+    Some(((n as i32).rem_euclid(4) as u8, r))
+}
+
+/// Reduces a finite [`f64`] argument modulo $\frac{\pi}{2}$ using a Payne–Hanek reduction.
+///
+/// # Arguments
+///
+/// * `x` - Finite angle, $|x|\geq1$ \[rad\].
+///
+/// # Returns
+///
+/// The quadrant `q` (in $\\{0,1,2,3\\}$) and the remainder `hi + lo` (with
+/// $|\text{hi}+\text{lo}|\leq\frac{\pi}{4}$) such that $x=q\frac{\pi}{2}+\text{hi}+\text{lo}$
+/// modulo $2\pi$.
+///
+/// # Note
+///
+/// Writing $|x|=m\cdot2^{e}$ with an integer mantissa $m<2^{53}$, only the bits of $\frac{2}{\pi}$
+/// with weights between $2^{1-e}$ and $2^{-254-e}$ matter: more significant bits contribute
+/// multiples of $4$ to $\frac{2}{\pi}|x|$ (i.e. whole turns), while less significant bits
+/// contribute less than $2^{-200}$. The product of $m$ and this $256$-bit window is computed
+/// exactly, so the only error left is the rounding of the $254$-bit fraction to a double-double.
+const fn rem_pio2_large(x: f64) -> (u8, f64, f64) {
+    let bits = x.to_bits();
+    let e = exponent(x) - 1075;
+    let m = (bits & 0x000f_ffff_ffff_ffff) | 0x0010_0000_0000_0000;
+
+    // Extract the 256-bit window of 2/π starting at the bit with weight 2^(1 - e).
+    let t = (e + 62) as usize;
+    let (k, shift) = (t / 64, t % 64);
+    let mut window = [0u64; 4];
+    let mut i = 0;
+    while i < 4 {
+        window[i] = if shift == 0 {
+            TWO_OVER_PI[k + i]
+        } else {
+            (TWO_OVER_PI[k + i] << shift) | (TWO_OVER_PI[k + i + 1] >> (64 - shift))
+        };
+        i += 1;
+    }
+
+    // Multiply the mantissa by the window, keeping all 320 bits of the product. The product is
+    // 2/π · |x| scaled by 2^254.
+    let mut product = [0u64; 5];
+    let mut carry = 0u128;
+    let mut i = 4;
+    while i > 0 {
+        i -= 1;
+        let p = (window[i] as u128) * (m as u128) + carry;
+        product[i + 1] = p as u64;
+        carry = p >> 64;
+    }
+    product[0] = carry as u64;
+
+    // The two bits above the binary point give the quadrant, and the 254 bits below it give the
+    // fraction (left-aligned in four words).
+    let mut q = (product[1] >> 62) as u8;
+    let mut fraction = [
+        (product[1] << 2) | (product[2] >> 62),
+        (product[2] << 2) | (product[3] >> 62),
+        (product[3] << 2) | (product[4] >> 62),
+        product[4] << 2,
+    ];
+
+    // Round to the nearest quadrant, so that the fraction lies in [-1/2, 1/2].
+    let negative = fraction[0] >> 63 == 1;
+    if negative {
+        q = (q + 1) % 4;
+        negate(&mut fraction);
+    }
+
+    // Normalize the magnitude of the fraction so that its leading bit is the third bit of a 128-bit
+    // integer, then split that integer into a double-double.
+    let (mut lz, mut i) = (0, 0);
+    while i < 4 {
+        lz += fraction[i].leading_zeros();
+        if fraction[i] != 0 {
+            break;
+        }
+        i += 1;
+    }
+    let shift = lz.saturating_sub(2);
+    let top = shifted_u128(&fraction, shift);
+    let f_hi = top as f64;
+    let f_lo = if f_hi as u128 > top {
+        -((f_hi as u128 - top) as f64)
+    } else {
+        (top - f_hi as u128) as f64
+    };
+    let scale = f64::from_bits(((1023 - 128 - shift as i64) as u64) << 52);
+    let (f_hi, f_lo) = (f_hi * scale, f_lo * scale);
+
+    // Multiply the fraction by π/2 in double-double arithmetic.
+    let (p_hi, p_lo) = two_product(f_hi, PIO2_HI);
+    let p_lo = p_lo + (f_hi * PIO2_LO + f_lo * PIO2_HI);
+    let hi = p_hi + p_lo;
+    let lo = p_lo - (hi - p_hi);
+
+    let (hi, lo) = if negative { (-hi, -lo) } else { (hi, lo) };
+    if x.is_sign_negative() {
+        ((4 - q) % 4, -hi, -lo)
+    } else {
+        (q, hi, lo)
+    }
+}
+
+/// Reduces a double-double argument modulo $\frac{\pi}{2}$.
+///
+/// # Arguments
+///
+/// * `hi` - Leading part of the angle \[rad\].
+/// * `lo` - Trailing part of the angle, $|\text{lo}|\leq\frac{1}{2}\text{ulp}(\text{hi})$ \[rad\].
+///
+/// # Returns
+///
+/// The quadrant `q` (in $\\{0,1,2,3\\}$) and the remainder `r_hi + r_lo` (with
+/// $|\text{r\\_hi}+\text{r\\_lo}|\leq\frac{\pi}{4}$) such that
+/// $\text{hi}+\text{lo}=q\frac{\pi}{2}+\text{r\\_hi}+\text{r\\_lo}$ modulo $2\pi$, where the
+/// remainder is accurate to double-double precision. The remainder is `NaN` if `hi` is not finite.
+///
+/// # Note
+///
+/// This is the Payne–Hanek reduction of [`rem_pio2_large`] with a $384$-bit window of
+/// $\frac{2}{\pi}$, applied to both parts of the argument. The two products are summed modulo $4$
+/// in fixed-point arithmetic, so that the cancellation between them is exact and the fraction keeps
+/// well over $106$ significant bits even for arguments extremely close to a multiple of
+/// $\frac{\pi}{2}$.
+pub(crate) const fn rem_pio2_dd(hi: f64, lo: f64) -> (u8, f64, f64) {
+    if !hi.is_finite() {
+        return (0, f64::NAN, 0.0);
+    } else if hi.abs() <= core::f64::consts::FRAC_PI_4 {
+        return (0, hi, lo);
+    }
+
+    // 2/π · (hi + lo) modulo 4, with two bits above the binary point and 382 bits below it.
+    let mut sum = [0u64; 6];
+    add_product_2_over_pi(&mut sum, hi);
+    add_product_2_over_pi(&mut sum, lo);
+    let mut q = (sum[0] >> 62) as u8;
+    let mut fraction = [0u64; 6];
+    let mut i = 0;
+    while i < 6 {
+        fraction[i] = sum[i] << 2;
+        if i < 5 {
+            fraction[i] |= sum[i + 1] >> 62;
+        }
+        i += 1;
+    }
+
+    // Round to the nearest quadrant, so that the fraction lies in [-1/2, 1/2].
+    let negative = fraction[0] >> 63 == 1;
+    if negative {
+        q = (q + 1) % 4;
+        negate(&mut fraction);
+    }
+
+    // Convert the magnitude of the fraction to a double-double and multiply it by π/2.
+    let (mut lz, mut i) = (0, 0);
+    while i < 6 {
+        lz += fraction[i].leading_zeros();
+        if fraction[i] != 0 {
+            break;
+        }
+        i += 1;
+    }
+    if lz == 384 {
+        return (q, 0.0, 0.0);
+    }
+    let shift = lz.saturating_sub(2);
+    let top = shifted_u128(&fraction, shift);
+    let f_hi = top as f64;
+    let f_lo = if f_hi as u128 > top {
+        -((f_hi as u128 - top) as f64)
+    } else {
+        (top - f_hi as u128) as f64
+    };
+    let scale = f64::from_bits(((1023 - 128 - shift as i64) as u64) << 52);
+    let (f_hi, f_lo) = (f_hi * scale, f_lo * scale);
+    let (p_hi, p_lo) = two_product(f_hi, PIO2_HI);
+    let p_lo = p_lo + (f_hi * PIO2_LO + f_lo * PIO2_HI);
+    let r_hi = p_hi + p_lo;
+    let r_lo = p_lo - (r_hi - p_hi);
+
+    if negative {
+        (q, -r_hi, -r_lo)
+    } else {
+        (q, r_hi, r_lo)
+    }
+}
+
+/// Adds $\frac{2}{\pi}x$ modulo $4$ to a fixed-point accumulator.
+///
+/// # Arguments
+///
+/// * `sum` - Accumulator, a $384$-bit two's complement integer (most significant word first)
+///   representing a multiple of $2^{-382}$ modulo $4$.
+/// * `x` - Finite value.
+const fn add_product_2_over_pi(sum: &mut [u64; 6], x: f64) {
+    if x == 0.0 {
+        return;
+    }
+
+    // Write |x| = m·2^e with an integer mantissa m < 2^53.
+    let bits = x.to_bits();
+    let (m, e) = if exponent(x) == 0 {
+        (bits & 0x000f_ffff_ffff_ffff, -1074)
+    } else {
+        (
+            (bits & 0x000f_ffff_ffff_ffff) | 0x0010_0000_0000_0000,
+            exponent(x) - 1075,
+        )
+    };
+
+    // Multiply the mantissa by the 384-bit window of 2/π starting at the bit with weight 2^(1 - e),
+    // keeping the 384 least significant bits of the product (the rest are multiples of 4).
+    let start = e as i64 + 62;
+    let mut product = [0u64; 6];
+    let mut carry = 0u128;
+    let mut i = 6;
+    while i > 0 {
+        i -= 1;
+        let p = (two_over_pi_bits(start + 64 * i as i64) as u128) * (m as u128) + carry;
+        product[i] = p as u64;
+        carry = p >> 64;
+    }
+    if x < 0.0 {
+        negate(&mut product);
+    }
+
+    let mut carry = false;
+    let mut i = 6;
+    while i > 0 {
+        i -= 1;
+        let (s, c1) = sum[i].overflowing_add(product[i]);
+        let (s, c2) = s.overflowing_add(carry as u64);
+        sum[i] = s;
+        carry = c1 || c2;
+    }
+}
+
+/// Extracts $64$ consecutive bits of the binary expansion of $\frac{2}{\pi}$.
+///
+/// # Arguments
+///
+/// * `start` - Index of the first bit in [`TWO_OVER_PI`] (counted from the most significant bit of
+///   its first word, and possibly negative).
+///
+/// # Returns
+///
+/// The $64$ bits starting at `start`, with zeros before the binary point and past the end of the
+/// table.
+const fn two_over_pi_bits(start: i64) -> u64 {
+    let (k, s) = (start.div_euclid(64), start.rem_euclid(64));
+    if s == 0 {
+        two_over_pi_word(k)
+    } else {
+        (two_over_pi_word(k) << s) | (two_over_pi_word(k + 1) >> (64 - s))
+    }
+}
+
+/// Extracts a word of the binary expansion of $\frac{2}{\pi}$.
+///
+/// # Arguments
+///
+/// * `k` - Index of the word in [`TWO_OVER_PI`] (possibly out of bounds).
+///
+/// # Returns
+///
+/// Word `k` of [`TWO_OVER_PI`], or $0$ if `k` is out of bounds.
+const fn two_over_pi_word(k: i64) -> u64 {
+    if k >= 0 && (k as usize) < TWO_OVER_PI.len() {
+        TWO_OVER_PI[k as usize]
+    } else {
+        0
+    }
+}
+
+/// Negates a multi-word two's complement integer in place.
+///
+/// # Arguments
+///
+/// * `words` - Integer, most significant word first.
+const fn negate(words: &mut [u64]) {
+    let mut borrow = true;
+    let mut i = words.len();
+    while i > 0 {
+        i -= 1;
+        words[i] = !words[i];
+        if borrow {
+            words[i] = words[i].wrapping_add(1);
+            borrow = words[i] == 0;
+        }
+    }
+}
+
+/// Extracts the 128 bits starting `shift` bits into a left-aligned multi-word integer.
+///
+/// # Arguments
+///
+/// * `words` - Integer, most significant word first.
+/// * `shift` - Number of leading bits to skip.
+///
+/// # Returns
+///
+/// The 128 bits of `words` starting at bit `shift` (counted from the most significant bit), with
+/// zeros shifted in past the end of `words`.
+const fn shifted_u128(words: &[u64], shift: u32) -> u128 {
+    let (k, s) = ((shift / 64) as usize, shift % 64);
+    let n = words.len();
+    let mut parts = [0u64; 2];
+    let mut i = 0;
+    while i < 2 {
+        let word = if k + i < n { words[k + i] } else { 0 };
+        let next = if k + i + 1 < n { words[k + i + 1] } else { 0 };
+        parts[i] = if s == 0 {
+            word
+        } else {
+            (word << s) | (next >> (64 - s))
+        };
+        i += 1;
+    }
+    ((parts[0] as u128) << 64) | (parts[1] as u128)
+}
+
+/// Computes the sine of a reduced argument given as an unevaluated sum.
+///
+/// # Arguments
+///
+/// * `hi` - Leading part of the reduced argument, $|\text{hi}|\lesssim\frac{\pi}{4}$ \[rad\].
+/// * `lo` - Trailing part of the reduced argument, $|\text{lo}|\leq\frac{1}{2}\text{ulp}(\text{hi})$
+///   \[rad\].
+///
+/// # Returns
+///
+/// Sine of `hi + lo`.
+///
+/// # Note
+///
+/// Since `lo` is tiny, $\sin(\text{hi}+\text{lo})\approx\sin(\text{hi})+\text{lo}\cos(\text{hi})$,
+/// and $\cos(\text{hi})$ can be replaced by $1-\frac{\text{hi}^{2}}{2}$ with an error well below
+/// one ulp of the result. The same reasoning applies to [`cos_reduced`] and [`tan_reduced`].
+#[cfg(not(feature = "correctly-rounded"))]
+pub(crate) fn sin_reduced(hi: f64, lo: f64) -> f64 {
+    if lo == 0.0 {
+        math::sin(hi)
+    } else {
+        math::sin(hi) + lo * (1.0 - 0.5 * hi * hi)
+    }
+}
+
+/// Computes the cosine of a reduced argument given as an unevaluated sum.
+///
+/// # Arguments
+///
+/// * `hi` - Leading part of the reduced argument, $|\text{hi}|\lesssim\frac{\pi}{4}$ \[rad\].
+/// * `lo` - Trailing part of the reduced argument, $|\text{lo}|\leq\frac{1}{2}\text{ulp}(\text{hi})$
+///   \[rad\].
+///
+/// # Returns
+///
+/// Cosine of `hi + lo`.
+#[cfg(not(feature = "correctly-rounded"))]
+pub(crate) fn cos_reduced(hi: f64, lo: f64) -> f64 {
+    math::cos(hi) - lo * hi
+}
+
+/// Computes the tangent of a reduced argument given as an unevaluated sum.
+///
+/// # Arguments
+///
+/// * `hi` - Leading part of the reduced argument, $|\text{hi}|\lesssim\frac{\pi}{4}$ \[rad\].
+/// * `lo` - Trailing part of the reduced argument, $|\text{lo}|\leq\frac{1}{2}\text{ulp}(\text{hi})$
+///   \[rad\].
+///
+/// # Returns
+///
+/// Tangent of `hi + lo`.
+#[cfg(not(feature = "correctly-rounded"))]
+pub(crate) fn tan_reduced(hi: f64, lo: f64) -> f64 {
+    let t = math::tan(hi);
+    if lo == 0.0 { t } else { t + lo * (1.0 + t * t) }
+}
+
+/// Computes the sine and cosine of a reduced argument given as an unevaluated sum.
+///
+/// # Arguments
+///
+/// * `hi` - Leading part of the reduced argument, $|\text{hi}|\lesssim\frac{\pi}{4}$ \[rad\].
+/// * `lo` - Trailing part of the reduced argument, $|\text{lo}|\leq\frac{1}{2}\text{ulp}(\text{hi})$
+///   \[rad\].
+///
+/// # Returns
+///
+/// Sine and cosine of `hi + lo`.
+#[cfg(not(feature = "correctly-rounded"))]
+pub(crate) fn sincos_reduced(hi: f64, lo: f64) -> (f64, f64) {
+    (sin_reduced(hi, lo), cos_reduced(hi, lo))
+}
+
+/// Biased exponent field of an [`f64`].
+const fn exponent(x: f64) -> i32 {
+    ((x.to_bits() >> 52) & 0x7ff) as i32
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_rem_pio2_medium() {
+        // Reference remainders computed with 300-bit arithmetic.
+        for (x, q_ref, hi_ref, lo_ref) in [
+            (3.0, 2, -0.14159265358979323, -1.1442377452219664e-17),
+            (-5.5, 0, 0.7831853071795865, 2.2884754904439327e-17),
+            (1e6, 0, -0.357564167085735, -2.5512230752599545e-17),
+            (1647099.0, 0, -0.3291652855174071, -1.1076107309665409e-17),
+        ] {
+            let (q, hi, lo) = rem_pio2_medium(x).unwrap();
+            assert_eq!(q, q_ref);
+            assert_eq!(hi, hi_ref);
+            assert!((lo - lo_ref).abs() < 1e-25);
+        }
+        assert_eq!(rem_pio2_medium(0.5), Some((0, 0.5, 0.0)));
+        assert_eq!(rem_pio2_medium(1e7), None);
+        assert_eq!(rem_pio2_medium(f64::NAN), None);
+    }
+
+    #[test]
+    fn test_rem_pio2_large() {
+        // Reference remainders computed with 3000-bit arithmetic. The fourth argument is the
+        // worst case for f64 argument reduction (the closest f64 to a multiple of π/2).
+        for (x, q_ref, hi_ref, lo_ref) in [
+            (1e7, 2, -0.4340490172675572, -2.1635325148744475e-17),
+            (5e15, 3, -0.44708370221371335, 6.967046892366922e-18),
+            (1e22, 3, 0.5506189342358097, -7.985621383147488e-18),
+            (-3.4e100, 0, -0.3428200771843381, 1.5406739722264822e-17),
+            (1e300, 3, -0.613076157357336, 1.2100878101071674e-17),
+            (
+                6381956970095103.0 * 2f64.powi(797),
+                1,
+                4.687165924254628e-19,
+                -4.3720557429382733e-36,
+            ),
+            (
+                8.98846567431158e307,
+                2,
+                -0.5981659040720844,
+                1.7488698628038262e-18,
+            ),
+            (f64::MAX, 2, -0.004961975150787273, -3.656438180407946e-19),
+        ] {
+            let (q, hi, lo) = rem_pio2(x);
+            assert_eq!(q, q_ref);
+            assert_eq!(hi, hi_ref);
+            assert!((lo - lo_ref).abs() <= 1e-30 * hi_ref.abs());
+        }
+    }
+
+    #[test]
+    fn test_rem_pio2_dd() {
+        // Reference remainders computed with 6000-bit arithmetic. The second argument is π to
+        // double-double precision, and the fifth is the worst case for f64 argument reduction.
+        for (hi, lo, q_ref, hi_ref, lo_ref) in [
+            (0.5, 1e-20, 0, 0.5, 1e-20),
+            (
+                core::f64::consts::PI,
+                1.2246467991473532e-16,
+                2,
+                2.9947698097183397e-33,
+                -1.1124542208633653e-49,
+            ),
+            (3.0, 1e-16, 2, -0.14159265358979314, 5.290895700893594e-18),
+            (-1e22, -1.0, 0, 0.020177392559086973, -1.709776985571371e-19),
+            (
+                6381956970095103.0 * 2f64.powi(797),
+                0.0,
+                1,
+                4.687165924254628e-19,
+                -4.3720557429382733e-36,
+            ),
+            (
+                1e300,
+                1e280,
+                1,
+                -0.1422079195323811,
+                -4.3438048248111555e-19,
+            ),
+        ] {
+            let (q, r_hi, r_lo) = rem_pio2_dd(hi, lo);
+            assert_eq!(q, q_ref);
+            assert_eq!(r_hi, hi_ref);
+            assert!((r_lo - lo_ref).abs() < hi_ref.abs() * 1e-30);
+        }
+        assert_eq!(rem_pio2_dd(0.0, 0.0), (0, 0.0, 0.0));
+        assert!(rem_pio2_dd(f64::INFINITY, 0.0).1.is_nan());
+    }
+
+    #[test]
+    fn test_rem_pio2_non_finite() {
+        assert!(rem_pio2(f64::INFINITY).1.is_nan());
+        assert!(rem_pio2(f64::NEG_INFINITY).1.is_nan());
+        assert!(rem_pio2(f64::NAN).1.is_nan());
+    }
+
+    #[test]
+    fn test_rem_pio2_medium_f32() {
+        let (q, r) = rem_pio2_medium_f32(3.0).unwrap();
+        assert_eq!(q, 2);
+        assert!((r + 0.14159265358979323).abs() < 1e-15);
+        assert_eq!(rem_pio2_medium_f32(1e9), None);
+        assert!(rem_pio2_f32(-0.0).1.is_sign_negative());
+    }
+}
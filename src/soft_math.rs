@@ -0,0 +1,1385 @@
+//! Software implementations of the elementary functions.
+//!
+//! Every function in this module is computed using only IEEE 754 basic operations (addition,
+//! subtraction, multiplication, division, and square root, all of which are correctly rounded) and
+//! exact bit manipulations, so the results are identical on every target and at every
+//! optimization level. The [`f64`] algorithms and polynomial coefficients are those of FDLIBM,
+//! with the radian trigonometric functions using this crate's own argument reduction. The [`f32`]
+//! functions evaluate the corresponding [`f64`] function and round the result.
+//!
+//! The functions that do not need a square root are `const fn`, so that they can also back the
+//! compile-time evaluation in [`consts_eval`](crate::consts_eval).
+//!
+//! # References
+//!
+//! * Sun Microsystems, "FDLIBM 5.3", 1993. <https://www.netlib.org/fdlibm/>
+
+use crate::double_double::pow2;
+#[cfg(any(test, feature = "deterministic"))]
+use crate::double_double::two_product;
+use crate::rem_pio2::rem_pio2;
+#[cfg(any(
+    test,
+    all(feature = "deterministic", not(feature = "correctly-rounded"))
+))]
+use core::f64::consts::LN_2;
+use core::f64::consts::{FRAC_PI_2, FRAC_PI_4, LOG2_E, PI};
+
+/// Coefficients of the polynomial approximating $\sin(x)$ on $\left[-\frac{\pi}{4},\frac{\pi}{4}\right]$.
+const S: [f64; 6] = [
+    -0.16666666666666632,
+    0.00833333333332249,
+    -0.0001984126982985795,
+    2.7557313707070068e-6,
+    -2.5050760253406863e-8,
+    1.58969099521155e-10,
+];
+
+/// Coefficients of the polynomial approximating $\cos(x)$ on $\left[-\frac{\pi}{4},\frac{\pi}{4}\right]$.
+const C: [f64; 6] = [
+    0.0416666666666666,
+    -0.001388888888887411,
+    2.480158728947673e-5,
+    -2.7557314351390663e-7,
+    2.087572321298175e-9,
+    -1.1359647557788195e-11,
+];
+
+/// Coefficients of the polynomial approximating $\tan(x)$ on $\left[0,0.6744\right]$.
+const T: [f64; 13] = [
+    0.3333333333333341,
+    0.13333333333320124,
+    0.05396825397622605,
+    0.021869488294859542,
+    0.0088632398235993,
+    0.0035920791075913124,
+    0.0014562094543252903,
+    0.0005880412408202641,
+    0.0002464631348184699,
+    7.817944429395571e-5,
+    7.140724913826082e-5,
+    -1.8558637485527546e-5,
+    2.590730518636337e-5,
+];
+
+/// $\frac{\pi}{4}-$ `FRAC_PI_4`.
+const PIO4_LO: f64 = 3.061616997868383e-17;
+
+/// $\frac{\pi}{2}-$ `FRAC_PI_2`.
+const PIO2_LO: f64 = 6.123233995736766e-17;
+
+/// $\pi-$ `PI`.
+const PI_LO: f64 = 1.2246467991473532e-16;
+
+/// Numerator coefficients of the rational approximation used by [`asin`] and [`acos`].
+#[cfg(any(
+    test,
+    all(feature = "deterministic", not(feature = "correctly-rounded"))
+))]
+const P_ASIN: [f64; 6] = [
+    0.16666666666666666,
+    -0.3255658186224009,
+    0.20121253213486293,
+    -0.04005553450067941,
+    0.0007915349942898145,
+    3.479331075960212e-5,
+];
+
+/// Denominator coefficients of the rational approximation used by [`asin`] and [`acos`].
+#[cfg(any(
+    test,
+    all(feature = "deterministic", not(feature = "correctly-rounded"))
+))]
+const Q_ASIN: [f64; 4] = [
+    -2.403394911734414,
+    2.0209457602335057,
+    -0.6882839716054533,
+    0.07703815055590194,
+];
+
+/// $\arctan(x)$ at the breakpoints $x=\frac{1}{2},1,\frac{3}{2},\infty$ (leading parts).
+const ATAN_HI: [f64; 4] = [0.4636476090008061, FRAC_PI_4, 0.982793723247329, FRAC_PI_2];
+
+/// $\arctan(x)$ at the breakpoints $x=\frac{1}{2},1,\frac{3}{2},\infty$ (trailing parts).
+const ATAN_LO: [f64; 4] = [
+    2.2698777452961687e-17,
+    PIO4_LO,
+    1.3903311031230998e-17,
+    PIO2_LO,
+];
+
+/// Coefficients of the polynomial approximating $\arctan(x)$ on $\left[-\frac{7}{16},\frac{7}{16}\right]$.
+const AT: [f64; 11] = [
+    0.3333333333333293,
+    -0.19999999999876483,
+    0.14285714272503466,
+    -0.11111110405462356,
+    0.09090887133436507,
+    -0.0769187620504483,
+    0.06661073137387531,
+    -0.058335701337905735,
+    0.049768779946159324,
+    -0.036531572744216916,
+    0.016285820115365782,
+];
+
+/// Leading 32 bits of $\ln(2)$.
+const LN2_HI: f64 = 0.6931471803691238;
+
+/// $\ln(2)-$ `LN2_HI`.
+const LN2_LO: f64 = 1.9082149292705877e-10;
+
+/// Coefficients of the polynomial used by [`exp`].
+const P_EXP: [f64; 5] = [
+    0.16666666666666602,
+    -0.0027777777777015593,
+    6.613756321437934e-5,
+    -1.6533902205465252e-6,
+    4.1381367970572385e-8,
+];
+
+/// Coefficients of the polynomial used by [`exp_m1`].
+const Q_EXP_M1: [f64; 5] = [
+    -0.03333333333333313,
+    0.0015873015872548146,
+    -7.93650757867488e-5,
+    4.008217827329362e-6,
+    -2.0109921818362437e-7,
+];
+
+/// Coefficients of the polynomial used by [`ln`] and [`ln_1p`].
+#[cfg(any(test, feature = "deterministic"))]
+const LG: [f64; 7] = [
+    0.6666666666666735,
+    0.3999999999940942,
+    0.2857142874366239,
+    0.22222198432149784,
+    0.1818357216161805,
+    0.15313837699209373,
+    0.14798198605116586,
+];
+
+/// Largest argument for which $e^{x}$ does not overflow.
+const EXP_OVERFLOW: f64 = 709.782712893384;
+
+/// Smallest argument for which $e^{x}$ does not underflow to zero.
+const EXP_UNDERFLOW: f64 = -745.1332191019411;
+
+/// Sine.
+///
+/// # Arguments
+///
+/// * `x` - Angle \[rad\].
+///
+/// # Returns
+///
+/// $\sin(x)$.
+pub(crate) const fn sin(x: f64) -> f64 {
+    if x.abs() < 1.4901161193847656e-8 {
+        // |x| < 2⁻²⁶ (this also preserves the sign of zero).
+        return x;
+    }
+    let (q, hi, lo) = rem_pio2(x);
+    match q {
+        0 => sin_kernel(hi, lo),
+        1 => cos_kernel(hi, lo),
+        2 => -sin_kernel(hi, lo),
+        _ => -cos_kernel(hi, lo),
+    }
+}
+
+/// Cosine.
+///
+/// # Arguments
+///
+/// * `x` - Angle \[rad\].
+///
+/// # Returns
+///
+/// $\cos(x)$.
+pub(crate) const fn cos(x: f64) -> f64 {
+    let (q, hi, lo) = rem_pio2(x);
+    match q {
+        0 => cos_kernel(hi, lo),
+        1 => -sin_kernel(hi, lo),
+        2 => -cos_kernel(hi, lo),
+        _ => sin_kernel(hi, lo),
+    }
+}
+
+/// Tangent.
+///
+/// # Arguments
+///
+/// * `x` - Angle \[rad\].
+///
+/// # Returns
+///
+/// $\tan(x)$.
+pub(crate) const fn tan(x: f64) -> f64 {
+    if x.abs() < 7.450580596923828e-9 {
+        // |x| < 2⁻²⁷ (this also preserves the sign of zero).
+        return x;
+    }
+    let (q, hi, lo) = rem_pio2(x);
+    tan_kernel(hi, lo, q % 2 == 1)
+}
+
+/// Inverse sine.
+///
+/// # Arguments
+///
+/// * `x` - Value, $x\in[-1,1]$.
+///
+/// # Returns
+///
+/// $\arcsin(x)$ \[rad\] (`NaN` for $|x|>1$).
+#[cfg(any(
+    test,
+    all(feature = "deterministic", not(feature = "correctly-rounded"))
+))]
+pub(crate) fn asin(x: f64) -> f64 {
+    let a = x.abs();
+    let f = if a.is_nan() || a > 1.0 {
+        return f64::NAN;
+    } else if a == 1.0 {
+        FRAC_PI_2
+    } else if a < 1.4901161193847656e-8 {
+        // |x| < 2⁻²⁶, where x³/6 is below half an ulp of x.
+        a
+    } else if a < 0.5 {
+        a + a * asin_rational(a * a)
+    } else {
+        // asin(x) = π/2 - 2asin(sqrt((1 - x)/2)).
+        let z = (1.0 - a) * 0.5;
+        let s = sqrt(z);
+        let r = asin_rational(z);
+        if a >= 0.9749999046325684 {
+            FRAC_PI_2 - (2.0 * (s + s * r) - PIO2_LO)
+        } else {
+            // Split s = f + c exactly so that 2f can be subtracted from π/4 without error.
+            let f = clear_low_word(s);
+            let c = (z - f * f) / (s + f);
+            0.5 * FRAC_PI_2 - (2.0 * s * r - (PIO2_LO - 2.0 * c) - (0.5 * FRAC_PI_2 - 2.0 * f))
+        }
+    };
+    f.copysign(x)
+}
+
+/// Inverse cosine.
+///
+/// # Arguments
+///
+/// * `x` - Value, $x\in[-1,1]$.
+///
+/// # Returns
+///
+/// $\arccos(x)$ \[rad\] (`NaN` for $|x|>1$).
+#[cfg(any(
+    test,
+    all(feature = "deterministic", not(feature = "correctly-rounded"))
+))]
+pub(crate) fn acos(x: f64) -> f64 {
+    let a = x.abs();
+    if a.is_nan() || a > 1.0 {
+        f64::NAN
+    } else if a == 1.0 {
+        if x > 0.0 { 0.0 } else { PI }
+    } else if a < 0.5 {
+        if a <= 6.938893903907228e-18 {
+            // |x| ≤ 2⁻⁵⁷.
+            FRAC_PI_2
+        } else {
+            FRAC_PI_2 - (x - (PIO2_LO - x * asin_rational(x * x)))
+        }
+    } else if x < 0.0 {
+        // acos(x) = π - 2asin(sqrt((1 + x)/2)).
+        let z = (1.0 + x) * 0.5;
+        let s = sqrt(z);
+        let w = asin_rational(z) * s - PIO2_LO;
+        2.0 * (FRAC_PI_2 - (s + w))
+    } else {
+        // acos(x) = 2asin(sqrt((1 - x)/2)).
+        let z = (1.0 - x) * 0.5;
+        let s = sqrt(z);
+        let f = clear_low_word(s);
+        let c = (z - f * f) / (s + f);
+        let w = asin_rational(z) * s + c;
+        2.0 * (f + w)
+    }
+}
+
+/// Inverse tangent.
+///
+/// # Arguments
+///
+/// * `x` - Value.
+///
+/// # Returns
+///
+/// $\arctan(x)$ \[rad\].
+pub(crate) const fn atan(x: f64) -> f64 {
+    let a = x.abs();
+    if a.is_nan() {
+        return x;
+    } else if a >= 7.378697629483821e19 {
+        // |x| ≥ 2⁶⁶, where atan(x) rounds to ±π/2.
+        return FRAC_PI_2.copysign(x);
+    } else if a < 0.4375 {
+        if a < 7.450580596923828e-9 {
+            // |x| < 2⁻²⁷.
+            return x;
+        }
+        return x - x * atan_series(x);
+    }
+
+    // Reduce to |t| < 7/16 using atan(x) = atan(c) + atan((x - c)/(1 + cx)) at a nearby breakpoint c.
+    let (i, t) = if a < 0.6875 {
+        (0, (2.0 * a - 1.0) / (2.0 + a))
+    } else if a < 1.1875 {
+        (1, (a - 1.0) / (a + 1.0))
+    } else if a < 2.4375 {
+        (2, (a - 1.5) / (1.0 + 1.5 * a))
+    } else {
+        (3, -1.0 / a)
+    };
+    let f = ATAN_HI[i] - (t * atan_series(t) - ATAN_LO[i] - t);
+    f.copysign(x)
+}
+
+/// Four-quadrant inverse tangent.
+///
+/// # Arguments
+///
+/// * `y` - $y$-coordinate.
+/// * `x` - $x$-coordinate.
+///
+/// # Returns
+///
+/// Angle \[rad\] of the point $(x,y)$, in $[-\pi,\pi]$.
+pub(crate) const fn atan2(y: f64, x: f64) -> f64 {
+    if x.is_nan() || y.is_nan() {
+        return x + y;
+    } else if x == 1.0 {
+        return atan(y);
+    }
+    let negative_x = x.is_sign_negative();
+    let (ax, ay) = (x.abs(), y.abs());
+    let z = if ay == 0.0 {
+        if negative_x { PI } else { 0.0 }
+    } else if ax == 0.0 {
+        FRAC_PI_2
+    } else if ax == f64::INFINITY {
+        match (ay == f64::INFINITY, negative_x) {
+            (true, false) => FRAC_PI_4,
+            (true, true) => 3.0 * FRAC_PI_4,
+            (false, false) => 0.0,
+            (false, true) => PI,
+        }
+    } else if ay == f64::INFINITY || exponent(ay) - exponent(ax) > 64 {
+        // |y/x| > 2⁶⁴.
+        FRAC_PI_2
+    } else {
+        let z = if negative_x && exponent(ax) - exponent(ay) > 64 {
+            // |y/x| < 2⁻⁶⁴ with x < 0, so the result is ±π.
+            0.0
+        } else {
+            atan((y / x).abs())
+        };
+        if negative_x { PI - (z - PI_LO) } else { z }
+    };
+    z.copysign(y)
+}
+
+/// Exponential function.
+///
+/// # Arguments
+///
+/// * `x` - Value.
+///
+/// # Returns
+///
+/// $e^{x}$.
+pub(crate) const fn exp(x: f64) -> f64 {
+    let a = x.abs();
+    if x.is_nan() {
+        return x;
+    } else if x > EXP_OVERFLOW {
+        return f64::INFINITY;
+    } else if x < EXP_UNDERFLOW {
+        return 0.0;
+    } else if a < 3.725290298461914e-9 {
+        // |x| < 2⁻²⁸.
+        return 1.0 + x;
+    }
+
+    // Reduce to |r| ≤ ln(2)/2 with x = k·ln(2) + r, where r = hi - lo.
+    let (k, hi, lo) = if a > 0.3465733528137207 {
+        let k = if a < 1.0397205352783203 {
+            if x > 0.0 { 1 } else { -1 }
+        } else {
+            (LOG2_E * x + 0.5_f64.copysign(x)) as i32
+        };
+        (k, x - k as f64 * LN2_HI, k as f64 * LN2_LO)
+    } else {
+        (0, x, 0.0)
+    };
+    let r = hi - lo;
+
+    // e^r = 1 + 2r/(2 - c), where c = r - r²(P1 + r²(P2 + ...)).
+    let rr = r * r;
+    let c = r - rr * horner(rr, &P_EXP);
+    let y = 1.0 + (r * c / (2.0 - c) - lo + hi);
+    if k == 0 { y } else { scalbn(y, k) }
+}
+
+/// Exponential function minus one.
+///
+/// # Arguments
+///
+/// * `x` - Value.
+///
+/// # Returns
+///
+/// $e^{x}-1$, accurate for small $|x|$.
+pub(crate) const fn exp_m1(x: f64) -> f64 {
+    let a = x.abs();
+    if x.is_nan() {
+        return x;
+    } else if a >= 38.81622314453125 {
+        // |x| ≥ 56ln(2).
+        if x < 0.0 {
+            return -1.0;
+        } else if x > EXP_OVERFLOW {
+            return f64::INFINITY;
+        }
+    } else if a < 5.551115123125783e-17 {
+        // |x| < 2⁻⁵⁴.
+        return x;
+    }
+
+    // Reduce to |r| ≤ ln(2)/2 with x = k·ln(2) + r, where the rounding error of r is c.
+    let (k, r, c) = if a > 0.3465733528137207 {
+        let (k, hi, lo) = if a < 1.0397205352783203 {
+            if x > 0.0 {
+                (1, x - LN2_HI, LN2_LO)
+            } else {
+                (-1, x + LN2_HI, -LN2_LO)
+            }
+        } else {
+            let k = (LOG2_E * x + 0.5_f64.copysign(x)) as i32;
+            (k, x - k as f64 * LN2_HI, k as f64 * LN2_LO)
+        };
+        let r = hi - lo;
+        (k, r, (hi - r) - lo)
+    } else {
+        (0, x, 0.0)
+    };
+
+    // e^r - 1 = r + r²/2 + r³/6·(3 - r1·r/2)/(6 - r·(3 - r1·r/2)) with a rational correction r1.
+    let hfx = 0.5 * r;
+    let hxs = r * hfx;
+    let r1 = 1.0 + hxs * horner(hxs, &Q_EXP_M1);
+    let t = 3.0 - r1 * hfx;
+    let e = hxs * ((r1 - t) / (6.0 - r * t));
+    if k == 0 {
+        return r - (r * e - hxs);
+    }
+    let e = r * (e - c) - c - hxs;
+
+    // e^x - 1 = 2^k·(e^r - 1) + 2^k - 1.
+    match k {
+        -1 => 0.5 * (r - e) - 0.5,
+        1 if r < -0.25 => -2.0 * (e - (r + 0.5)),
+        1 => 1.0 + 2.0 * (r - e),
+        _ if k < 0 || k > 56 => {
+            let y = r - e + 1.0;
+            let y = if k == 1024 {
+                y * 2.0 * pow2(1023)
+            } else {
+                y * pow2(k)
+            };
+            y - 1.0
+        }
+        _ if k < 20 => (r - e + (1.0 - pow2(-k))) * pow2(k),
+        _ => (r - (e + pow2(-k)) + 1.0) * pow2(k),
+    }
+}
+
+/// Natural logarithm.
+///
+/// # Arguments
+///
+/// * `x` - Value, $x>0$.
+///
+/// # Returns
+///
+/// $\ln(x)$ (`NaN` for $x<0$).
+#[cfg(any(test, feature = "deterministic"))]
+pub(crate) fn ln(x: f64) -> f64 {
+    if x.is_nan() || x < 0.0 {
+        return f64::NAN;
+    } else if x == 0.0 {
+        return f64::NEG_INFINITY;
+    } else if x == f64::INFINITY {
+        return x;
+    } else if x == 1.0 {
+        return 0.0;
+    }
+
+    // Scale subnormals into the normal range.
+    let (x, k) = if x < f64::MIN_POSITIVE {
+        (x * pow2(54), -54)
+    } else {
+        (x, 0)
+    };
+
+    // Write x = 2^k·(1 + f) with 1 + f in [sqrt(2)/2, sqrt(2)).
+    let (k, f) = split_exponent(x, k);
+    ln_1p_reduced(f, k as f64, 0.0)
+}
+
+/// Natural logarithm of one plus a value.
+///
+/// # Arguments
+///
+/// * `x` - Value, $x>-1$.
+///
+/// # Returns
+///
+/// $\ln(1+x)$, accurate for small $|x|$ (`NaN` for $x<-1$).
+#[cfg(any(test, feature = "deterministic"))]
+pub(crate) fn ln_1p(x: f64) -> f64 {
+    if x.is_nan() || x < -1.0 {
+        return f64::NAN;
+    } else if x == -1.0 {
+        return f64::NEG_INFINITY;
+    } else if x == f64::INFINITY {
+        return x;
+    } else if x.abs() < 1.1102230246251565e-16 {
+        // |x| < 2⁻⁵³.
+        return x;
+    } else if (-0.2928934097290039..0.4142136573791504).contains(&x) {
+        // 1 + x is already in [sqrt(2)/2, sqrt(2)).
+        return ln_1p_reduced(x, 0.0, 0.0);
+    }
+
+    // Write 1 + x = 2^k·(1 + f), carrying the rounding error of 1 + x in the correction c.
+    let u = 1.0 + x;
+    let (k, f) = split_exponent(u, 0);
+    let c = if k < 54 {
+        let c = if k >= 2 { 1.0 - (u - x) } else { x - (u - 1.0) };
+        c / u
+    } else {
+        0.0
+    };
+    ln_1p_reduced(f, k as f64, c)
+}
+
+/// Hyperbolic sine.
+///
+/// # Arguments
+///
+/// * `x` - Value.
+///
+/// # Returns
+///
+/// $\sinh(x)$.
+pub(crate) const fn sinh(x: f64) -> f64 {
+    let a = x.abs();
+    let h = 0.5_f64.copysign(x);
+    if a < 709.7822265625 {
+        // |x| < ln(f64::MAX).
+        let t = exp_m1(a);
+        if a < 1.0 {
+            if a < 1.4901161193847656e-8 {
+                // |x| < 2⁻²⁶.
+                return x;
+            }
+            return h * (2.0 * t - t * t / (t + 1.0));
+        }
+        return h * (t + t / (t + 1.0));
+    }
+    exp_halved(a, 2.0 * h)
+}
+
+/// Hyperbolic cosine.
+///
+/// # Arguments
+///
+/// * `x` - Value.
+///
+/// # Returns
+///
+/// $\cosh(x)$.
+pub(crate) const fn cosh(x: f64) -> f64 {
+    let a = x.abs();
+    if a < 0.6931467056274414 {
+        // |x| < ln(2).
+        if a < 1.4901161193847656e-8 {
+            return 1.0;
+        }
+        let t = exp_m1(a);
+        return 1.0 + t * t / (2.0 * (1.0 + t));
+    } else if a < 709.7822265625 {
+        let t = exp(a);
+        return 0.5 * (t + 1.0 / t);
+    }
+    exp_halved(a, 1.0)
+}
+
+/// Hyperbolic tangent.
+///
+/// # Arguments
+///
+/// * `x` - Value.
+///
+/// # Returns
+///
+/// $\tanh(x)$.
+pub(crate) const fn tanh(x: f64) -> f64 {
+    let a = x.abs();
+    let t = if a.is_nan() {
+        return x;
+    } else if a > 0.5493059158325195 {
+        // |x| > ln(3)/2.
+        if a > 20.0 {
+            1.0
+        } else {
+            1.0 - 2.0 / (exp_m1(2.0 * a) + 2.0)
+        }
+    } else if a > 0.25541257858276367 {
+        // |x| > ln(5/3)/2.
+        let t = exp_m1(2.0 * a);
+        t / (t + 2.0)
+    } else if a >= f64::MIN_POSITIVE {
+        let t = exp_m1(-2.0 * a);
+        -t / (t + 2.0)
+    } else {
+        a
+    };
+    t.copysign(x)
+}
+
+/// Inverse hyperbolic sine.
+///
+/// # Arguments
+///
+/// * `x` - Value.
+///
+/// # Returns
+///
+/// $\sinh^{-1}(x)$.
+#[cfg(any(
+    test,
+    all(feature = "deterministic", not(feature = "correctly-rounded"))
+))]
+pub(crate) fn asinh(x: f64) -> f64 {
+    let a = x.abs();
+    let f = if a >= 67108864.0 {
+        // |x| ≥ 2²⁶, where sqrt(x² + 1) rounds to |x|.
+        ln(a) + LN_2
+    } else if a >= 2.0 {
+        ln(2.0 * a + 1.0 / (sqrt(a * a + 1.0) + a))
+    } else if a >= 1.4901161193847656e-8 {
+        ln_1p(a + a * a / (sqrt(a * a + 1.0) + 1.0))
+    } else {
+        a
+    };
+    f.copysign(x)
+}
+
+/// Inverse hyperbolic cosine.
+///
+/// # Arguments
+///
+/// * `x` - Value, $x\geq1$.
+///
+/// # Returns
+///
+/// $\cosh^{-1}(x)$ (`NaN` for $x<1$).
+#[cfg(any(
+    test,
+    all(feature = "deterministic", not(feature = "correctly-rounded"))
+))]
+pub(crate) fn acosh(x: f64) -> f64 {
+    if x.is_nan() || x < 1.0 {
+        f64::NAN
+    } else if x < 2.0 {
+        let t = x - 1.0;
+        ln_1p(t + sqrt(t * t + 2.0 * t))
+    } else if x < 67108864.0 {
+        ln(2.0 * x - 1.0 / (x + sqrt(x * x - 1.0)))
+    } else {
+        ln(x) + LN_2
+    }
+}
+
+/// Inverse hyperbolic tangent.
+///
+/// # Arguments
+///
+/// * `x` - Value, $x\in[-1,1]$.
+///
+/// # Returns
+///
+/// $\tanh^{-1}(x)$ (`NaN` for $|x|>1$).
+#[cfg(any(
+    test,
+    all(feature = "deterministic", not(feature = "correctly-rounded"))
+))]
+pub(crate) fn atanh(x: f64) -> f64 {
+    let a = x.abs();
+    let f = if a.is_nan() || a > 1.0 {
+        return f64::NAN;
+    } else if a < 2.3283064365386963e-10 {
+        // |x| < 2⁻³².
+        a
+    } else if a < 0.5 {
+        0.5 * ln_1p(2.0 * a + 2.0 * a * a / (1.0 - a))
+    } else {
+        0.5 * ln_1p(2.0 * (a / (1.0 - a)))
+    };
+    f.copysign(x)
+}
+
+/// Euclidean norm of a two-dimensional vector.
+///
+/// # Arguments
+///
+/// * `x` - First component.
+/// * `y` - Second component.
+///
+/// # Returns
+///
+/// $\sqrt{x^{2}+y^{2}}$, computed without undue overflow or underflow.
+#[cfg(any(test, feature = "deterministic"))]
+pub(crate) fn hypot(x: f64, y: f64) -> f64 {
+    let (x, y) = if x.abs().to_bits() < y.abs().to_bits() {
+        (y.abs(), x.abs())
+    } else {
+        (x.abs(), y.abs())
+    };
+    if y.is_infinite() {
+        return y;
+    } else if !x.is_finite() || y == 0.0 {
+        return x;
+    } else if exponent(x) - exponent(y) > 64 {
+        return x + y;
+    }
+
+    // Scale so that the squares neither overflow nor underflow.
+    let (z, x, y) = if exponent(x) > 0x3ff + 510 {
+        (pow2(700), x * pow2(-700), y * pow2(-700))
+    } else if exponent(y) < 0x3ff - 450 {
+        (pow2(-700), x * pow2(700), y * pow2(700))
+    } else {
+        (1.0, x, y)
+    };
+    let (hx, lx) = two_product(x, x);
+    let (hy, ly) = two_product(y, y);
+    z * sqrt(ly + lx + hy + hx)
+}
+
+/// Square root.
+///
+/// # Arguments
+///
+/// * `x` - Value, $x\geq0$.
+///
+/// # Returns
+///
+/// $\sqrt{x}$ (`NaN` for $x<0$).
+///
+/// # Note
+///
+/// Square root is an IEEE 754 basic operation that is correctly rounded on every target, so, like
+/// division, it is deterministic. Without the standard library, it is computed by an integer square
+/// root, which gives the same correctly rounded result.
+#[cfg(any(test, feature = "deterministic"))]
+pub(crate) fn sqrt(x: f64) -> f64 {
+    #[cfg(feature = "std")]
+    {
+        x.sqrt()
+    }
+    #[cfg(not(feature = "std"))]
+    {
+        sqrt_integer(x)
+    }
+}
+
+/// Rounds to the nearest integer, with ties rounded away from zero.
+///
+/// # Arguments
+///
+/// * `x` - Value.
+///
+/// # Returns
+///
+/// Nearest integer to $x$.
+pub(crate) const fn round(x: f64) -> f64 {
+    let e = exponent(x) - 0x3ff;
+    if e >= 52 {
+        // Integers, infinities, and NaN.
+        x
+    } else if e < -1 {
+        0.0_f64.copysign(x)
+    } else if e == -1 {
+        1.0_f64.copysign(x)
+    } else {
+        // Adding half a unit to the magnitude carries into the integer part exactly when rounding
+        // away from zero; the fraction bits are then cleared.
+        let fraction = (1_u64 << (52 - e)) - 1;
+        f64::from_bits((x.to_bits() + (1 << (51 - e))) & !fraction)
+    }
+}
+
+/// Sine of an [`f32`] (see [`sin`]).
+pub(crate) const fn sin_f32(x: f32) -> f32 {
+    sin(x as f64) as f32
+}
+
+/// Cosine of an [`f32`] (see [`cos`]).
+pub(crate) const fn cos_f32(x: f32) -> f32 {
+    cos(x as f64) as f32
+}
+
+/// Tangent of an [`f32`] (see [`tan`]).
+pub(crate) const fn tan_f32(x: f32) -> f32 {
+    tan(x as f64) as f32
+}
+
+/// Inverse sine of an [`f32`] (see [`asin`]).
+#[cfg(all(feature = "deterministic", not(feature = "correctly-rounded")))]
+pub(crate) fn asin_f32(x: f32) -> f32 {
+    asin(x as f64) as f32
+}
+
+/// Inverse cosine of an [`f32`] (see [`acos`]).
+#[cfg(all(feature = "deterministic", not(feature = "correctly-rounded")))]
+pub(crate) fn acos_f32(x: f32) -> f32 {
+    acos(x as f64) as f32
+}
+
+/// Inverse tangent of an [`f32`] (see [`atan`]).
+#[cfg(all(feature = "deterministic", not(feature = "correctly-rounded")))]
+pub(crate) const fn atan_f32(x: f32) -> f32 {
+    atan(x as f64) as f32
+}
+
+/// Four-quadrant inverse tangent of [`f32`]s (see [`atan2`]).
+pub(crate) const fn atan2_f32(y: f32, x: f32) -> f32 {
+    atan2(y as f64, x as f64) as f32
+}
+
+/// Exponential function of an [`f32`] (see [`exp`]).
+#[cfg(all(feature = "deterministic", not(feature = "correctly-rounded")))]
+pub(crate) const fn exp_f32(x: f32) -> f32 {
+    exp(x as f64) as f32
+}
+
+/// Exponential function minus one of an [`f32`] (see [`exp_m1`]).
+#[cfg(all(feature = "deterministic", not(feature = "correctly-rounded")))]
+pub(crate) const fn exp_m1_f32(x: f32) -> f32 {
+    exp_m1(x as f64) as f32
+}
+
+/// Natural logarithm of an [`f32`] (see [`ln`]).
+#[cfg(all(feature = "deterministic", not(feature = "correctly-rounded")))]
+pub(crate) fn ln_f32(x: f32) -> f32 {
+    ln(x as f64) as f32
+}
+
+/// Natural logarithm of one plus an [`f32`] (see [`ln_1p`]).
+#[cfg(all(feature = "deterministic", not(feature = "correctly-rounded")))]
+pub(crate) fn ln_1p_f32(x: f32) -> f32 {
+    ln_1p(x as f64) as f32
+}
+
+/// Hyperbolic sine of an [`f32`] (see [`sinh`]).
+pub(crate) const fn sinh_f32(x: f32) -> f32 {
+    sinh(x as f64) as f32
+}
+
+/// Hyperbolic cosine of an [`f32`] (see [`cosh`]).
+pub(crate) const fn cosh_f32(x: f32) -> f32 {
+    cosh(x as f64) as f32
+}
+
+/// Hyperbolic tangent of an [`f32`] (see [`tanh`]).
+pub(crate) const fn tanh_f32(x: f32) -> f32 {
+    tanh(x as f64) as f32
+}
+
+/// Inverse hyperbolic sine of an [`f32`] (see [`asinh`]).
+#[cfg(all(feature = "deterministic", not(feature = "correctly-rounded")))]
+pub(crate) fn asinh_f32(x: f32) -> f32 {
+    asinh(x as f64) as f32
+}
+
+/// Inverse hyperbolic cosine of an [`f32`] (see [`acosh`]).
+#[cfg(all(feature = "deterministic", not(feature = "correctly-rounded")))]
+pub(crate) fn acosh_f32(x: f32) -> f32 {
+    acosh(x as f64) as f32
+}
+
+/// Inverse hyperbolic tangent of an [`f32`] (see [`atanh`]).
+#[cfg(all(feature = "deterministic", not(feature = "correctly-rounded")))]
+pub(crate) fn atanh_f32(x: f32) -> f32 {
+    atanh(x as f64) as f32
+}
+
+/// Euclidean norm of a two-dimensional vector of [`f32`]s (see [`hypot`]).
+#[cfg(all(feature = "deterministic", not(feature = "correctly-rounded")))]
+pub(crate) fn hypot_f32(x: f32, y: f32) -> f32 {
+    // The squares are exact in f64 and cannot overflow, so a single rounding suffices.
+    let (x, y) = (x as f64, y as f64);
+    if x.is_infinite() || y.is_infinite() {
+        f32::INFINITY
+    } else {
+        sqrt(x * x + y * y) as f32
+    }
+}
+
+/// Square root of an [`f32`] (see [`sqrt`]).
+#[cfg(feature = "deterministic")]
+pub(crate) fn sqrt_f32(x: f32) -> f32 {
+    #[cfg(feature = "std")]
+    {
+        x.sqrt()
+    }
+    #[cfg(not(feature = "std"))]
+    {
+        // The f64 square root has enough extra precision that rounding it again is harmless.
+        sqrt_integer(x as f64) as f32
+    }
+}
+
+/// Rounds an [`f32`] to the nearest integer, with ties rounded away from zero (see [`round`]).
+pub(crate) const fn round_f32(x: f32) -> f32 {
+    round(x as f64) as f32
+}
+
+/// Computes a correctly rounded square root with integer arithmetic.
+///
+/// # Arguments
+///
+/// * `x` - Value, $x\geq0$.
+///
+/// # Returns
+///
+/// $\sqrt{x}$ (`NaN` for $x<0$).
+#[cfg(any(test, all(feature = "deterministic", not(feature = "std"))))]
+fn sqrt_integer(x: f64) -> f64 {
+    if x.is_nan() || x < 0.0 {
+        return f64::NAN;
+    } else if x == 0.0 || x.is_infinite() {
+        return x;
+    }
+
+    // Write x = m·2ᵉ with an integer significand 2⁵² ≤ m < 2⁵³ (normalizing subnormals).
+    let bits = x.to_bits();
+    let (mut m, mut e) = if exponent(x) == 0 {
+        let shift = (bits << 11).leading_zeros() as i32;
+        (bits << shift, -1074 - shift)
+    } else {
+        (
+            (bits & 0x000f_ffff_ffff_ffff) | (1 << 52),
+            exponent(x) - 1075,
+        )
+    };
+
+    // Make the exponent even, so that √x = √(m·2⁵⁴)·2^((e - 54)/2).
+    if e % 2 != 0 {
+        m <<= 1;
+        e -= 1;
+    }
+    let n = (m as u128) << 54;
+    let r = n.isqrt();
+
+    // 2⁵³ ≤ r < 2⁵⁴, so drop one bit, rounding to nearest (the square root of a 53-bit integer
+    // can never lie exactly halfway, but ties are rounded to even all the same).
+    let round_bit = r & 1 == 1;
+    let sticky = r * r != n;
+    let mut q = (r >> 1) as u64;
+    if round_bit && (sticky || q & 1 == 1) {
+        q += 1;
+    }
+    scalbn(q as f64, (e - 54) / 2 + 1)
+}
+
+/// Computes the sine of a reduced argument given as an unevaluated sum.
+///
+/// # Arguments
+///
+/// * `x` - Leading part of the reduced argument, $|x|\lesssim\frac{\pi}{4}$ \[rad\].
+/// * `y` - Trailing part of the reduced argument \[rad\].
+///
+/// # Returns
+///
+/// Sine of `x + y`.
+const fn sin_kernel(x: f64, y: f64) -> f64 {
+    let z = x * x;
+    let w = z * z;
+    let r = S[1] + z * (S[2] + z * S[3]) + z * w * (S[4] + z * S[5]);
+    let v = z * x;
+    if y == 0.0 {
+        x + v * (S[0] + z * r)
+    } else {
+        x - ((z * (0.5 * y - v * r) - y) - v * S[0])
+    }
+}
+
+/// Computes the cosine of a reduced argument given as an unevaluated sum.
+///
+/// # Arguments
+///
+/// * `x` - Leading part of the reduced argument, $|x|\lesssim\frac{\pi}{4}$ \[rad\].
+/// * `y` - Trailing part of the reduced argument \[rad\].
+///
+/// # Returns
+///
+/// Cosine of `x + y`.
+const fn cos_kernel(x: f64, y: f64) -> f64 {
+    let z = x * x;
+    let w = z * z;
+    let r = z * (C[0] + z * (C[1] + z * C[2])) + w * w * (C[3] + z * (C[4] + z * C[5]));
+    let hz = 0.5 * z;
+    let w = 1.0 - hz;
+    w + (((1.0 - w) - hz) + (z * r - x * y))
+}
+
+/// Computes the tangent (or negative cotangent) of a reduced argument given as an unevaluated sum.
+///
+/// # Arguments
+///
+/// * `x` - Leading part of the reduced argument, $|x|\lesssim\frac{\pi}{4}$ \[rad\].
+/// * `y` - Trailing part of the reduced argument \[rad\].
+/// * `odd` - `true` to compute $-\cot(x+y)$ instead of $\tan(x+y)$.
+///
+/// # Returns
+///
+/// $\tan(x+y)$, or $-\cot(x+y)$ if `odd` is `true`.
+const fn tan_kernel(x: f64, y: f64, odd: bool) -> f64 {
+    if x.is_nan() {
+        return x;
+    }
+
+    // Near ±π/4, use tan(π/4 - t) = (1 - tan(t))/(1 + tan(t)) with the small t = π/4 - |x|.
+    let negative = x < 0.0;
+    let big = x.abs() >= 0.6743354797363281;
+    let (x, y) = if big {
+        let (x, y) = if negative { (-x, -y) } else { (x, y) };
+        ((FRAC_PI_4 - x) + (PIO4_LO - y), 0.0)
+    } else {
+        (x, y)
+    };
+    let z = x * x;
+    let w = z * z;
+    let r = T[1] + w * (T[3] + w * (T[5] + w * (T[7] + w * (T[9] + w * T[11]))));
+    let v = z * (T[2] + w * (T[4] + w * (T[6] + w * (T[8] + w * (T[10] + w * T[12])))));
+    let s = z * x;
+    let r = y + z * (s * (r + v) + y) + s * T[0];
+    let w = x + r;
+    if big {
+        let s = if odd { -1.0 } else { 1.0 };
+        let v = s - 2.0 * (x + (r - w * w / (w + s)));
+        return if negative { -v } else { v };
+    }
+    if !odd {
+        return w;
+    }
+
+    // Compute -1/(x + r) accurately by splitting both w and -1/w.
+    let w0 = clear_low_word(w);
+    let v = r - (w0 - x);
+    let a = -1.0 / w;
+    let a0 = clear_low_word(a);
+    a0 + a * (1.0 + a0 * w0 + a0 * v)
+}
+
+/// Evaluates the rational approximation $R(z)$ with $\arcsin(x)=x+xR(x^{2})$.
+///
+/// # Arguments
+///
+/// * `z` - Value, $0\leq z\leq\frac{1}{4}$.
+///
+/// # Returns
+///
+/// $R(z)$.
+#[cfg(any(
+    test,
+    all(feature = "deterministic", not(feature = "correctly-rounded"))
+))]
+fn asin_rational(z: f64) -> f64 {
+    z * horner(z, &P_ASIN) / (1.0 + z * horner(z, &Q_ASIN))
+}
+
+/// Evaluates the odd part of the polynomial approximating $\arctan(x)$, so that
+/// $\arctan(x)\approx x-x\cdot$ `atan_series(x)`.
+///
+/// # Arguments
+///
+/// * `x` - Value, $|x|\leq\frac{7}{16}$.
+///
+/// # Returns
+///
+/// Correction term.
+const fn atan_series(x: f64) -> f64 {
+    let z = x * x;
+    let w = z * z;
+    let s1 = z * (AT[0] + w * (AT[2] + w * (AT[4] + w * (AT[6] + w * (AT[8] + w * AT[10])))));
+    let s2 = w * (AT[1] + w * (AT[3] + w * (AT[5] + w * (AT[7] + w * AT[9]))));
+    s1 + s2
+}
+
+/// Computes $\ln(1+f)+k\ln(2)$ for a reduced argument.
+///
+/// # Arguments
+///
+/// * `f` - Reduced argument, $1+f\in\left[\frac{\sqrt{2}}{2},\sqrt{2}\right)$.
+/// * `k` - Power of two.
+/// * `c` - Correction for the rounding error in `f`.
+///
+/// # Returns
+///
+/// $\ln(1+f)+k\ln(2)+c$.
+#[cfg(any(test, feature = "deterministic"))]
+fn ln_1p_reduced(f: f64, k: f64, c: f64) -> f64 {
+    let hfsq = 0.5 * f * f;
+    let s = f / (2.0 + f);
+    let z = s * s;
+    let w = z * z;
+    let t1 = w * (LG[1] + w * (LG[3] + w * LG[5]));
+    let t2 = z * (LG[0] + w * (LG[2] + w * (LG[4] + w * LG[6])));
+    s * (hfsq + t1 + t2) + (k * LN2_LO + c) - hfsq + f + k * LN2_HI
+}
+
+/// Splits a positive normal number as $x=2^{k}(1+f)$ with $1+f\in\left[\frac{\sqrt{2}}{2},\sqrt{2}\right)$.
+///
+/// # Arguments
+///
+/// * `x` - Positive normal number.
+/// * `k` - Power of two already factored out of `x`.
+///
+/// # Returns
+///
+/// The total power of two $k$ and the reduced argument $f$ (exact).
+#[cfg(any(test, feature = "deterministic"))]
+fn split_exponent(x: f64, k: i32) -> (i32, f64) {
+    let bits = x.to_bits();
+    let hx = (bits >> 32) as u32 + (0x3ff0_0000 - 0x3fe6_a09e);
+    let k = k + (hx >> 20) as i32 - 0x3ff;
+    let hx = (hx & 0x000f_ffff) + 0x3fe6_a09e;
+    let x = f64::from_bits(((hx as u64) << 32) | (bits & 0xffff_ffff));
+    (k, x - 1.0)
+}
+
+/// Computes $\frac{s}{2}e^{x}$ for arguments where $e^{x}$ overflows but the result may not.
+///
+/// # Arguments
+///
+/// * `x` - Value, $x\geq\ln(\text{f64::MAX})$.
+/// * `s` - Scale factor (typically $\pm1$).
+///
+/// # Returns
+///
+/// $\frac{s}{2}e^{x}$.
+const fn exp_halved(x: f64, s: f64) -> f64 {
+    // e^x/2 = e^(x - 2043ln(2))·2^1021·2^1021, where the first factor does not overflow.
+    const K_LN_2: f64 = 1416.0996898839683;
+    exp(x - K_LN_2) * (s * pow2(1021)) * pow2(1021)
+}
+
+/// Multiplies a value by an integer power of two.
+///
+/// # Arguments
+///
+/// * `x` - Value.
+/// * `n` - Exponent.
+///
+/// # Returns
+///
+/// $x\cdot2^{n}$, rounded once.
+const fn scalbn(x: f64, n: i32) -> f64 {
+    let (mut y, mut n) = (x, n);
+    if n > 1023 {
+        y *= pow2(1023);
+        n -= 1023;
+        if n > 1023 {
+            y *= pow2(1023);
+            n -= 1023;
+            if n > 1023 {
+                n = 1023;
+            }
+        }
+    } else if n < -1022 {
+        // Scale in steps that keep the final multiplication out of the subnormal range until the
+        // last step, so that the result is rounded only once.
+        y *= pow2(-969);
+        n += 969;
+        if n < -1022 {
+            y *= pow2(-969);
+            n += 969;
+            if n < -1022 {
+                n = -1022;
+            }
+        }
+    }
+    y * pow2(n)
+}
+
+/// Evaluates a polynomial with Horner's scheme.
+///
+/// # Arguments
+///
+/// * `x` - Value.
+/// * `coefficients` - Coefficients, starting with the constant term.
+///
+/// # Returns
+///
+/// Value of the polynomial at `x`.
+const fn horner(x: f64, coefficients: &[f64]) -> f64 {
+    let (mut p, mut i) = (0.0, coefficients.len());
+    while i > 0 {
+        i -= 1;
+        p = p * x + coefficients[i];
+    }
+    p
+}
+
+/// Clears the low 32 bits of an [`f64`].
+///
+/// # Arguments
+///
+/// * `x` - Value.
+///
+/// # Returns
+///
+/// `x` with its 32 lowest-order significand bits set to zero.
+const fn clear_low_word(x: f64) -> f64 {
+    f64::from_bits(x.to_bits() & 0xffff_ffff_0000_0000)
+}
+
+/// Biased exponent field of an [`f64`].
+const fn exponent(x: f64) -> i32 {
+    ((x.to_bits() >> 52) & 0x7ff) as i32
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::double_double::DoubleDouble;
+
+    /// Error of an approximation in units of the last place of a double-double reference value.
+    fn ulp_error(approximation: f64, reference: DoubleDouble) -> f64 {
+        let ulp = reference.hi.abs().next_up() - reference.hi.abs();
+        ((approximation - reference.hi) - reference.lo).abs() / ulp
+    }
+
+    #[test]
+    fn test_golden_values() {
+        // Replays recorded with this backend depend on these exact bit patterns.
+        assert_eq!(sin(0.5), 0.479_425_538_604_203);
+        assert_eq!(sin(1e22), -0.852_200_849_767_188_8);
+        assert_eq!(cos(2.0), -0.416_146_836_547_142_4);
+        assert_eq!(tan(1.5), 14.101_419_947_171_719);
+        assert_eq!(asin(0.3), 0.304_692_654_015_397_5);
+        assert_eq!(acos(-0.7), 2.346_193_823_405_65);
+        assert_eq!(atan(3.0), 1.249_045_772_398_254_4);
+        assert_eq!(atan2(2.0, -3.0), 2.553_590_050_042_225_7);
+        assert_eq!(sinh(0.3), 0.304_520_293_447_142_6);
+        assert_eq!(cosh(5.0), 74.209_948_524_787_85);
+        assert_eq!(tanh(0.4), 0.379_948_962_255_224_9);
+        assert_eq!(asinh(10.0), 2.998_222_950_297_97);
+        assert_eq!(acosh(1.5), 0.962_423_650_119_206_9);
+        assert_eq!(atanh(0.9), 1.472_219_489_583_220_4);
+    }
+
+    #[test]
+    fn test_accuracy() {
+        for i in 1..=1000 {
+            let x = i as f64 / 1000.0;
+            let (s, c) = DoubleDouble::from_f64(x).sin_cos();
+            assert!(ulp_error(sin(x), s) < 1.0);
+            assert!(ulp_error(cos(x), c) < 1.0);
+            for x in [x, 8.0 * x, 1e3 * x] {
+                let atan_ref = DoubleDouble::from_f64(x).atan();
+                assert!(ulp_error(atan(x), atan_ref) < 1.0);
+                assert!(ulp_error(ln(x), DoubleDouble::from_f64(x).ln()) < 1.0);
+                assert!(ulp_error(ln_1p(x), DoubleDouble::from_f64(x).ln_1p()) < 1.0);
+            }
+            for x in [x, -x, 8.0 * x, -8.0 * x] {
+                let exp_m1_ref = DoubleDouble::from_f64(x).exp_m1();
+                assert!(ulp_error(exp(x), exp_m1_ref.add_f64(1.0)) < 1.0);
+                assert!(ulp_error(exp_m1(x), exp_m1_ref) < 1.0);
+            }
+        }
+    }
+
+    #[test]
+    fn test_special_values() {
+        for f in [sin, tan, asin, atan, sinh, tanh, asinh, atanh] {
+            assert!(f(-0.0).is_sign_negative());
+            assert!(f(f64::NAN).is_nan());
+        }
+        assert!(sin(f64::INFINITY).is_nan());
+        assert!(cos(f64::NEG_INFINITY).is_nan());
+        assert!(asin(1.5).is_nan());
+        assert!(acosh(0.5).is_nan());
+        assert_eq!(acos(-1.0), PI);
+        assert_eq!(atan(f64::INFINITY), FRAC_PI_2);
+        assert_eq!(atan2(0.0, -0.0), PI);
+        assert_eq!(atan2(-0.0, -1.0), -PI);
+        assert_eq!(atan2(f64::INFINITY, f64::NEG_INFINITY), 3.0 * FRAC_PI_4);
+        assert_eq!(exp(-800.0), 0.0);
+        assert_eq!(exp(710.0), f64::INFINITY);
+        assert_eq!(exp(-740.0), 4.2e-322);
+        assert_eq!(exp_m1(-40.0), -1.0);
+        assert_eq!(ln(0.0), f64::NEG_INFINITY);
+        assert_eq!(ln(5e-324), -744.440_071_921_381_2);
+        assert_eq!(ln_1p(-1.0), f64::NEG_INFINITY);
+        assert_eq!(sinh(710.0), 1.116_997_383_080_855_5e308);
+        assert_eq!(cosh(-710.0), 1.116_997_383_080_855_5e308);
+        assert_eq!(tanh(-30.0), -1.0);
+        assert_eq!(asinh(f64::MAX), 710.475_860_073_943_9);
+        assert_eq!(atanh(1.0), f64::INFINITY);
+        assert_eq!(hypot(3.0, 4.0), 5.0);
+        assert_eq!(hypot(f64::NAN, f64::INFINITY), f64::INFINITY);
+        assert_eq!(hypot(1e300, 1e300), 1.414_213_562_373_095_1e300);
+        assert_eq!(hypot(3e-320, 4e-320), 5e-320);
+    }
+
+    #[test]
+    fn test_sqrt_integer() {
+        let mut x = 5e-324_f64;
+        while x.is_finite() {
+            for y in [x, x.next_up(), x * 1.37, x * 1.999_999_999_999_999_8] {
+                assert_eq!(sqrt_integer(y).to_bits(), y.sqrt().to_bits());
+            }
+            x *= 1.9;
+        }
+        assert_eq!(sqrt_integer(-0.0).to_bits(), (-0.0_f64).to_bits());
+        assert_eq!(sqrt_integer(f64::INFINITY), f64::INFINITY);
+        assert!(sqrt_integer(-1.0).is_nan());
+    }
+
+    #[test]
+    fn test_round() {
+        for x in [
+            0.0,
+            0.49,
+            0.5,
+            1.5,
+            2.5,
+            3.499_999_999_999_999_6,
+            1e15 + 0.5,
+            4503599627370497.0,
+        ] {
+            assert_eq!(round(x), x.round());
+            assert_eq!(round(-x).to_bits(), (-x).round().to_bits());
+        }
+        assert_eq!(round(f64::INFINITY), f64::INFINITY);
+        assert!(round(f64::NAN).is_nan());
+    }
+}
@@ -0,0 +1,280 @@
+//! Elementary functions provided by the standard library.
+//!
+//! This is the default backend for the [`Trig`](crate::Trig) implementations on [`f32`] and
+//! [`f64`]. The standard library delegates to the platform's math library, so results may differ
+//! in the last bit between platforms (see the `deterministic` feature).
+
+#[cfg(not(feature = "correctly-rounded"))]
+use std::f64::consts::LN_2;
+
+/// Sine of an [`f64`].
+#[cfg(not(feature = "correctly-rounded"))]
+pub(crate) fn sin(x: f64) -> f64 {
+    x.sin()
+}
+
+/// Cosine of an [`f64`].
+#[cfg(not(feature = "correctly-rounded"))]
+pub(crate) fn cos(x: f64) -> f64 {
+    x.cos()
+}
+
+/// Tangent of an [`f64`].
+#[cfg(not(feature = "correctly-rounded"))]
+pub(crate) fn tan(x: f64) -> f64 {
+    x.tan()
+}
+
+/// Inverse sine of an [`f64`].
+#[cfg(not(feature = "correctly-rounded"))]
+pub(crate) fn asin(x: f64) -> f64 {
+    x.asin()
+}
+
+/// Inverse cosine of an [`f64`].
+#[cfg(not(feature = "correctly-rounded"))]
+pub(crate) fn acos(x: f64) -> f64 {
+    x.acos()
+}
+
+/// Inverse tangent of an [`f64`].
+#[cfg(not(feature = "correctly-rounded"))]
+pub(crate) fn atan(x: f64) -> f64 {
+    x.atan()
+}
+
+/// Four-quadrant inverse tangent of [`f64`]s.
+#[cfg(not(feature = "correctly-rounded"))]
+pub(crate) fn atan2(y: f64, x: f64) -> f64 {
+    y.atan2(x)
+}
+
+/// Exponential function of an [`f64`].
+pub(crate) fn exp(x: f64) -> f64 {
+    x.exp()
+}
+
+/// Exponential function minus one of an [`f64`].
+#[cfg(not(feature = "correctly-rounded"))]
+pub(crate) fn exp_m1(x: f64) -> f64 {
+    x.exp_m1()
+}
+
+/// Natural logarithm of an [`f64`].
+pub(crate) fn ln(x: f64) -> f64 {
+    x.ln()
+}
+
+/// Natural logarithm of one plus an [`f64`].
+pub(crate) fn ln_1p(x: f64) -> f64 {
+    x.ln_1p()
+}
+
+/// Hyperbolic sine of an [`f64`].
+#[cfg(not(feature = "correctly-rounded"))]
+pub(crate) fn sinh(x: f64) -> f64 {
+    x.sinh()
+}
+
+/// Hyperbolic cosine of an [`f64`].
+#[cfg(not(feature = "correctly-rounded"))]
+pub(crate) fn cosh(x: f64) -> f64 {
+    x.cosh()
+}
+
+/// Hyperbolic tangent of an [`f64`].
+#[cfg(not(feature = "correctly-rounded"))]
+pub(crate) fn tanh(x: f64) -> f64 {
+    x.tanh()
+}
+
+/// Inverse hyperbolic sine of an [`f64`].
+///
+/// # Note
+///
+/// The standard library's `asinh` overflows for $|x|$ close to [`f64::MAX`], so this uses the
+/// FDLIBM formulation on top of the standard library's `ln` and `ln_1p`.
+#[cfg(not(feature = "correctly-rounded"))]
+pub(crate) fn asinh(x: f64) -> f64 {
+    let a = x.abs();
+    let f = if a >= 67108864.0 {
+        // |x| ≥ 2²⁶, where sqrt(x² + 1) rounds to |x|.
+        a.ln() + LN_2
+    } else if a >= 2.0 {
+        (2.0 * a + 1.0 / ((a * a + 1.0).sqrt() + a)).ln()
+    } else {
+        (a + a * a / ((a * a + 1.0).sqrt() + 1.0)).ln_1p()
+    };
+    f.copysign(x)
+}
+
+/// Inverse hyperbolic cosine of an [`f64`].
+///
+/// # Note
+///
+/// The standard library's `acosh` overflows for $x$ close to [`f64::MAX`], so this uses the
+/// FDLIBM formulation on top of the standard library's `ln` and `ln_1p`.
+#[cfg(not(feature = "correctly-rounded"))]
+pub(crate) fn acosh(x: f64) -> f64 {
+    if x.is_nan() || x < 1.0 {
+        f64::NAN
+    } else if x < 2.0 {
+        let t = x - 1.0;
+        (t + (t * t + 2.0 * t).sqrt()).ln_1p()
+    } else if x < 67108864.0 {
+        (2.0 * x - 1.0 / (x + (x * x - 1.0).sqrt())).ln()
+    } else {
+        x.ln() + LN_2
+    }
+}
+
+/// Inverse hyperbolic tangent of an [`f64`].
+///
+/// # Note
+///
+/// The standard library's `atanh` computes $1-x$ for negative $x$, which loses all accuracy as
+/// $x\to-1$, so this evaluates the odd function at $|x|$ instead.
+#[cfg(not(feature = "correctly-rounded"))]
+pub(crate) fn atanh(x: f64) -> f64 {
+    let a = x.abs();
+    let f = if a < 0.5 {
+        0.5 * (2.0 * a + 2.0 * a * a / (1.0 - a)).ln_1p()
+    } else {
+        0.5 * (2.0 * (a / (1.0 - a))).ln_1p()
+    };
+    f.copysign(x)
+}
+
+/// Euclidean norm of a two-dimensional vector of [`f64`]s.
+pub(crate) fn hypot(x: f64, y: f64) -> f64 {
+    x.hypot(y)
+}
+
+/// Square root of an [`f64`].
+pub(crate) fn sqrt(x: f64) -> f64 {
+    x.sqrt()
+}
+
+/// Rounds an [`f64`] to the nearest integer, with ties rounded away from zero.
+pub(crate) fn round(x: f64) -> f64 {
+    x.round()
+}
+
+/// Sine of an [`f32`].
+#[cfg(not(feature = "correctly-rounded"))]
+pub(crate) fn sin_f32(x: f32) -> f32 {
+    x.sin()
+}
+
+/// Cosine of an [`f32`].
+#[cfg(not(feature = "correctly-rounded"))]
+pub(crate) fn cos_f32(x: f32) -> f32 {
+    x.cos()
+}
+
+/// Tangent of an [`f32`].
+#[cfg(not(feature = "correctly-rounded"))]
+pub(crate) fn tan_f32(x: f32) -> f32 {
+    x.tan()
+}
+
+/// Inverse sine of an [`f32`].
+#[cfg(not(feature = "correctly-rounded"))]
+pub(crate) fn asin_f32(x: f32) -> f32 {
+    x.asin()
+}
+
+/// Inverse cosine of an [`f32`].
+#[cfg(not(feature = "correctly-rounded"))]
+pub(crate) fn acos_f32(x: f32) -> f32 {
+    x.acos()
+}
+
+/// Inverse tangent of an [`f32`].
+#[cfg(not(feature = "correctly-rounded"))]
+pub(crate) fn atan_f32(x: f32) -> f32 {
+    x.atan()
+}
+
+/// Four-quadrant inverse tangent of [`f32`]s.
+#[cfg(not(feature = "correctly-rounded"))]
+pub(crate) fn atan2_f32(y: f32, x: f32) -> f32 {
+    y.atan2(x)
+}
+
+/// Exponential function of an [`f32`].
+#[cfg(not(feature = "correctly-rounded"))]
+pub(crate) fn exp_f32(x: f32) -> f32 {
+    x.exp()
+}
+
+/// Exponential function minus one of an [`f32`].
+#[cfg(not(feature = "correctly-rounded"))]
+pub(crate) fn exp_m1_f32(x: f32) -> f32 {
+    x.exp_m1()
+}
+
+/// Natural logarithm of an [`f32`].
+#[cfg(not(feature = "correctly-rounded"))]
+pub(crate) fn ln_f32(x: f32) -> f32 {
+    x.ln()
+}
+
+/// Natural logarithm of one plus an [`f32`].
+#[cfg(not(feature = "correctly-rounded"))]
+pub(crate) fn ln_1p_f32(x: f32) -> f32 {
+    x.ln_1p()
+}
+
+/// Hyperbolic sine of an [`f32`].
+#[cfg(not(feature = "correctly-rounded"))]
+pub(crate) fn sinh_f32(x: f32) -> f32 {
+    x.sinh()
+}
+
+/// Hyperbolic cosine of an [`f32`].
+#[cfg(not(feature = "correctly-rounded"))]
+pub(crate) fn cosh_f32(x: f32) -> f32 {
+    x.cosh()
+}
+
+/// Hyperbolic tangent of an [`f32`].
+#[cfg(not(feature = "correctly-rounded"))]
+pub(crate) fn tanh_f32(x: f32) -> f32 {
+    x.tanh()
+}
+
+/// Inverse hyperbolic sine of an [`f32`] (see [`asinh`]).
+#[cfg(not(feature = "correctly-rounded"))]
+pub(crate) fn asinh_f32(x: f32) -> f32 {
+    asinh(x as f64) as f32
+}
+
+/// Inverse hyperbolic cosine of an [`f32`] (see [`acosh`]).
+#[cfg(not(feature = "correctly-rounded"))]
+pub(crate) fn acosh_f32(x: f32) -> f32 {
+    acosh(x as f64) as f32
+}
+
+/// Inverse hyperbolic tangent of an [`f32`] (see [`atanh`]).
+#[cfg(not(feature = "correctly-rounded"))]
+pub(crate) fn atanh_f32(x: f32) -> f32 {
+    atanh(x as f64) as f32
+}
+
+/// Euclidean norm of a two-dimensional vector of [`f32`]s.
+#[cfg(not(feature = "correctly-rounded"))]
+pub(crate) fn hypot_f32(x: f32, y: f32) -> f32 {
+    x.hypot(y)
+}
+
+/// Square root of an [`f32`].
+pub(crate) fn sqrt_f32(x: f32) -> f32 {
+    x.sqrt()
+}
+
+/// Rounds an [`f32`] to the nearest integer, with ties rounded away from zero.
+#[cfg(not(feature = "correctly-rounded"))]
+pub(crate) fn round_f32(x: f32) -> f32 {
+    x.round()
+}
@@ -0,0 +1,601 @@
+use crate::consts_eval;
+use crate::fast_math;
+use crate::math;
+use crate::trig_trait::Trig;
+use core::f32::consts::{FRAC_1_PI, FRAC_2_PI, FRAC_PI_2, FRAC_PI_4, PI};
+use core::marker::PhantomData;
+
+/// $\frac{180}{\pi}$.
+const RAD2DEG: f32 = 180.0 / PI;
+
+/// Interpolation between the entries of the lookup tables of a [`Tabulated`].
+pub trait Interpolation {
+    /// Whether the interpolation is cubic (`true`) or linear (`false`).
+    const CUBIC: bool;
+}
+
+/// Linear interpolation between adjacent table entries.
+///
+/// # Note
+///
+/// Only the values of the functions are tabulated, and each evaluation costs one multiply-add
+/// after the table lookup.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Linear;
+
+impl Interpolation for Linear {
+    const CUBIC: bool = false;
+}
+
+/// Cubic Hermite interpolation between adjacent table entries, using the exact derivatives at the
+/// table entries.
+///
+/// # Note
+///
+/// The derivatives of sine and cosine are read from the same table as their values, while the
+/// derivatives of the inverse tangent are stored in a second table of the same size.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Cubic;
+
+impl Interpolation for Cubic {
+    const CUBIC: bool = true;
+}
+
+/// An [`f32`] whose trigonometric functions are interpolated from lookup tables with `N` entries.
+///
+/// # Type parameters
+///
+/// * `N` - Number of entries of each lookup table (at least 2).
+/// * `I` - Interpolation between table entries, [`Linear`] (default) or [`Cubic`].
+///
+/// # Note
+///
+/// `sin`, `cos`, `tan`, `atan`, and `atan2` and their reciprocal, degree, and half-turn variants
+/// (as well as `asin`, `acos`, `acsc`, and `asec`, computed with `atan2`) are interpolated from a
+/// table of $\sin$ over $\left[0,\frac{\pi}{2}\right]$ and a table of $\arctan$ over
+/// $\left[0,1\right]$. Both tables are built at compile time and only the tables that are used are
+/// kept in the binary, taking `4 * N` bytes each (plus another `4 * N` bytes for the derivatives of
+/// $\arctan$ with [`Cubic`] interpolation). The hyperbolic functions and the unit conversions are
+/// computed as for [`f32`].
+///
+/// The maximum error of `sin` and `cos` is about $\frac{\pi^{2}}{32(N-1)^{2}}$ with [`Linear`]
+/// interpolation and $\frac{\pi^{4}}{6144(N-1)^{4}}$ with [`Cubic`] interpolation (e.g.
+/// $4.8\times10^{-6}$ and $1.5\times10^{-11}$ for `N = 256`), plus the rounding error of [`f32`].
+/// The error of `atan` is smaller, while `tan` and the reciprocal functions have relative errors
+/// that grow near their poles.
+///
+/// # Example
+///
+/// ```
+/// use trig::{Cubic, Linear, Tabulated, Trig};
+///
+/// let x: Tabulated<256> = Tabulated::new(30.0);
+/// assert!((x.sind().value - 0.5).abs() < 1e-5);
+///
+/// let x: Tabulated<64, Cubic> = Tabulated::new(0.5);
+/// assert!((x.atan().value - 0.5_f32.atan()).abs() < 1e-6);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+pub struct Tabulated<const N: usize, I = Linear> {
+    /// Value.
+    pub value: f32,
+    interpolation: PhantomData<I>,
+}
+
+impl<const N: usize, I> Tabulated<N, I> {
+    /// $\sin\left(\frac{\pi}{2}\frac{i}{N-1}\right)$ for $i=0,\dots,N-1$.
+    const SIN: [f32; N] = sin_table();
+
+    /// $\arctan\left(\frac{i}{N-1}\right)$ for $i=0,\dots,N-1$.
+    const ATAN: [f32; N] = atan_table();
+
+    /// Derivatives of the entries of [`Self::ATAN`] with respect to the table index.
+    const ATAN_SLOPE: [f32; N] = atan_slope_table();
+
+    /// Creates a value whose trigonometric functions are interpolated from lookup tables.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Value.
+    ///
+    /// # Returns
+    ///
+    /// Tabulated value.
+    pub const fn new(value: f32) -> Self {
+        Self {
+            value,
+            interpolation: PhantomData,
+        }
+    }
+}
+
+impl<const N: usize, I> From<f32> for Tabulated<N, I> {
+    fn from(value: f32) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<const N: usize, I> From<Tabulated<N, I>> for f32 {
+    fn from(x: Tabulated<N, I>) -> f32 {
+        x.value
+    }
+}
+
+/// Builds the table of $\sin$ over $\left[0,\frac{\pi}{2}\right]$.
+///
+/// # Returns
+///
+/// $\sin\left(\frac{\pi}{2}\frac{i}{N-1}\right)$ for $i=0,\dots,N-1$.
+const fn sin_table<const N: usize>() -> [f32; N] {
+    assert!(N >= 2, "a lookup table needs at least two entries");
+    let mut table = [0.0; N];
+    let mut i = 0;
+    while i < N {
+        let x = core::f64::consts::FRAC_PI_2 * (i as f64 / (N - 1) as f64);
+        table[i] = consts_eval::sin(x) as f32;
+        i += 1;
+    }
+    table
+}
+
+/// Builds the table of $\arctan$ over $[0,1]$.
+///
+/// # Returns
+///
+/// $\arctan\left(\frac{i}{N-1}\right)$ for $i=0,\dots,N-1$.
+const fn atan_table<const N: usize>() -> [f32; N] {
+    assert!(N >= 2, "a lookup table needs at least two entries");
+    let mut table = [0.0; N];
+    let mut i = 0;
+    while i < N {
+        let t = i as f64 / (N - 1) as f64;
+        table[i] = consts_eval::atan2(t, 1.0) as f32;
+        i += 1;
+    }
+    table
+}
+
+/// Builds the table of the derivatives of the entries of the table of $\arctan$ over $[0,1]$ with
+/// respect to the table index.
+///
+/// # Returns
+///
+/// $\frac{1}{(N-1)(1+t_{i}^{2})}$ with $t_{i}=\frac{i}{N-1}$ for $i=0,\dots,N-1$.
+const fn atan_slope_table<const N: usize>() -> [f32; N] {
+    assert!(N >= 2, "a lookup table needs at least two entries");
+    let mut table = [0.0; N];
+    let mut i = 0;
+    while i < N {
+        let t = i as f64 / (N - 1) as f64;
+        table[i] = (1.0 / ((N - 1) as f64 * (1.0 + t * t))) as f32;
+        i += 1;
+    }
+    table
+}
+
+/// Interpolates a function from its table over $[0,1]$.
+///
+/// # Arguments
+///
+/// * `values` - Values of the function at $\frac{i}{N-1}$ for $i=0,\dots,N-1$.
+/// * `slope` - Derivative of the function at the `i`th entry with respect to the table index (only
+///   used by [`Cubic`] interpolation).
+/// * `s` - Point to interpolate at, $s\in[0,1]$.
+///
+/// # Returns
+///
+/// Interpolated value of the function at `s`.
+fn interpolate<const N: usize, I: Interpolation>(
+    values: &[f32; N],
+    slope: impl Fn(usize) -> f32,
+    s: f32,
+) -> f32 {
+    let p = s * (N - 1) as f32;
+    let i = (p as usize).min(N - 2);
+    let f = p - i as f32;
+    let (y0, y1) = (values[i], values[i + 1]);
+    if I::CUBIC {
+        let (m0, m1) = (slope(i), slope(i + 1));
+        let d = y1 - y0;
+        y0 + f * (m0 + f * ((3.0 * d - 2.0 * m0 - m1) + f * (m0 + m1 - 2.0 * d)))
+    } else {
+        y0 + f * (y1 - y0)
+    }
+}
+
+impl<const N: usize, I: Interpolation> Tabulated<N, I> {
+    /// Interpolates the sine of an angle in quarter turns.
+    ///
+    /// # Arguments
+    ///
+    /// * `s` - Angle, $s\in[0,1]$ \[quarter turns\].
+    ///
+    /// # Returns
+    ///
+    /// $\sin\left(\frac{\pi}{2}s\right)$.
+    fn sin_quarter(s: f32) -> f32 {
+        // The derivative of sin is cos, which is the table read backwards.
+        let slope = |i: usize| (FRAC_PI_2 / (N - 1) as f32) * Self::SIN[N - 1 - i];
+        interpolate::<N, I>(&Self::SIN, slope, s)
+    }
+
+    /// Sine and cosine of an angle given as a quadrant and a reduced angle.
+    ///
+    /// # Arguments
+    ///
+    /// * `q` - Quadrant, in $\\{0,1,2,3\\}$.
+    /// * `t` - Reduced angle, $|t|\lesssim\frac{1}{2}$ \[quarter turns\].
+    ///
+    /// # Returns
+    ///
+    /// $\sin\left(\frac{\pi}{2}(q+t)\right)$ and $\cos\left(\frac{\pi}{2}(q+t)\right)$.
+    fn sincos_reduced(q: u8, t: f32) -> (f32, f32) {
+        let a = t.abs();
+        let s = Self::sin_quarter(a).copysign(t);
+        let c = Self::sin_quarter(1.0 - a);
+        match q {
+            0 => (s, c),
+            1 => (c, -s),
+            2 => (-s, -c),
+            _ => (-c, s),
+        }
+    }
+
+    /// Tangent of an angle given as a quadrant and a reduced angle.
+    ///
+    /// # Arguments
+    ///
+    /// * `q` - Quadrant, in $\\{0,1,2,3\\}$.
+    /// * `t` - Reduced angle, $|t|\lesssim\frac{1}{2}$ \[quarter turns\].
+    ///
+    /// # Returns
+    ///
+    /// $\tan\left(\frac{\pi}{2}(q+t)\right)$.
+    fn tan_reduced(q: u8, t: f32) -> f32 {
+        let a = t.abs();
+        let s = Self::sin_quarter(a).copysign(t);
+        let c = Self::sin_quarter(1.0 - a);
+        if q.is_multiple_of(2) { s / c } else { -c / s }
+    }
+
+    /// Reduces an angle in radians to a quadrant and an angle in quarter turns.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - Angle \[rad\].
+    ///
+    /// # Returns
+    ///
+    /// Quadrant `q` (in $\\{0,1,2,3\\}$) and reduced angle `t` (with $|t|\lesssim\frac{1}{2}$)
+    /// \[quarter turns\] such that $x=\frac{\pi}{2}(q+t)$ modulo $2\pi$.
+    fn reduce(x: f32) -> (u8, f32) {
+        let (q, r) = fast_math::rem_pio2(x);
+        (q, r * FRAC_2_PI)
+    }
+
+    /// Interpolates the inverse tangent of a value in $[0,1]$.
+    ///
+    /// # Arguments
+    ///
+    /// * `t` - Value, $t\in[0,1]$.
+    ///
+    /// # Returns
+    ///
+    /// $\arctan(t)$ \[rad\].
+    fn atan_unit(t: f32) -> f32 {
+        interpolate::<N, I>(&Self::ATAN, |i| Self::ATAN_SLOPE[i], t)
+    }
+
+    /// Interpolates the four-quadrant inverse tangent.
+    ///
+    /// # Arguments
+    ///
+    /// * `y` - $y$-coordinate.
+    /// * `x` - $x$-coordinate.
+    ///
+    /// # Returns
+    ///
+    /// Angle \[rad\] of the point $(x,y)$, in $[-\pi,\pi]$.
+    fn atan2_value(y: f32, x: f32) -> f32 {
+        let (ax, ay) = (x.abs(), y.abs());
+        let f = if ax == ay {
+            // Covers both zeros (angle 0) and both infinities (angle π/4), where the ratio is NaN.
+            if ax == 0.0 { 0.0 } else { FRAC_PI_4 }
+        } else if ay > ax {
+            FRAC_PI_2 - Self::atan_unit(ax / ay)
+        } else {
+            Self::atan_unit(ay / ax)
+        };
+        let f = if x.is_sign_negative() { PI - f } else { f };
+        f.copysign(y)
+    }
+}
+
+impl<const N: usize, I: Interpolation> Trig for Tabulated<N, I> {
+    fn sin(&self) -> Self {
+        let (q, t) = Self::reduce(self.value);
+        Self::new(Self::sincos_reduced(q, t).0)
+    }
+    fn cos(&self) -> Self {
+        let (q, t) = Self::reduce(self.value);
+        Self::new(Self::sincos_reduced(q, t).1)
+    }
+    fn tan(&self) -> Self {
+        let (q, t) = Self::reduce(self.value);
+        Self::new(Self::tan_reduced(q, t))
+    }
+    fn csc(&self) -> Self {
+        Self::new(1.0 / self.sin().value)
+    }
+    fn sec(&self) -> Self {
+        Self::new(1.0 / self.cos().value)
+    }
+    fn cot(&self) -> Self {
+        Self::new(1.0 / self.tan().value)
+    }
+    fn sincos(&self) -> (Self, Self) {
+        let (q, t) = Self::reduce(self.value);
+        let (s, c) = Self::sincos_reduced(q, t);
+        (Self::new(s), Self::new(c))
+    }
+    fn asin(&self) -> Self {
+        let x = self.value;
+        Self::new(Self::atan2_value(x, math::sqrt_f32((1.0 - x) * (1.0 + x))))
+    }
+    fn acos(&self) -> Self {
+        let x = self.value;
+        Self::new(Self::atan2_value(math::sqrt_f32((1.0 - x) * (1.0 + x)), x))
+    }
+    fn atan(&self) -> Self {
+        let a = self.value.abs();
+        let f = if a > 1.0 {
+            FRAC_PI_2 - Self::atan_unit(1.0 / a)
+        } else {
+            Self::atan_unit(a)
+        };
+        Self::new(f.copysign(self.value))
+    }
+    fn atan2(&self, other: &Self) -> Self {
+        Self::new(Self::atan2_value(self.value, other.value))
+    }
+    fn acsc(&self) -> Self {
+        let x = self.value;
+        Self::new(Self::atan2_value(
+            1.0_f32.copysign(x),
+            sqrt_x2_minus_1(x.abs()),
+        ))
+    }
+    fn asec(&self) -> Self {
+        let x = self.value;
+        Self::new(Self::atan2_value(
+            sqrt_x2_minus_1(x.abs()),
+            1.0_f32.copysign(x),
+        ))
+    }
+    fn acot(&self) -> Self {
+        let x = self.value;
+        Self::new(Self::atan2_value(1.0_f32.copysign(x), x.abs()))
+    }
+    fn deg2rad(&self) -> Self {
+        Self::new(Trig::deg2rad(&self.value))
+    }
+    fn rad2deg(&self) -> Self {
+        Self::new(Trig::rad2deg(&self.value))
+    }
+    fn sind(&self) -> Self {
+        let (q, r) = fast_math::reduce_turn(self.value, 90.0);
+        Self::new(Self::sincos_reduced(q, r / 90.0).0)
+    }
+    fn cosd(&self) -> Self {
+        let (q, r) = fast_math::reduce_turn(self.value, 90.0);
+        Self::new(Self::sincos_reduced(q, r / 90.0).1)
+    }
+    fn tand(&self) -> Self {
+        let (q, r) = fast_math::reduce_turn(self.value, 90.0);
+        Self::new(Self::tan_reduced(q, r / 90.0))
+    }
+    fn cscd(&self) -> Self {
+        Self::new(1.0 / self.sind().value)
+    }
+    fn secd(&self) -> Self {
+        Self::new(1.0 / self.cosd().value)
+    }
+    fn cotd(&self) -> Self {
+        Self::new(1.0 / self.tand().value)
+    }
+    fn sincosd(&self) -> (Self, Self) {
+        let (q, r) = fast_math::reduce_turn(self.value, 90.0);
+        let (s, c) = Self::sincos_reduced(q, r / 90.0);
+        (Self::new(s), Self::new(c))
+    }
+    fn asind(&self) -> Self {
+        Self::new(self.asin().value * RAD2DEG)
+    }
+    fn acosd(&self) -> Self {
+        Self::new(self.acos().value * RAD2DEG)
+    }
+    fn atand(&self) -> Self {
+        Self::new(self.atan().value * RAD2DEG)
+    }
+    fn atan2d(&self, other: &Self) -> Self {
+        Self::new(self.atan2(other).value * RAD2DEG)
+    }
+    fn acscd(&self) -> Self {
+        Self::new(self.acsc().value * RAD2DEG)
+    }
+    fn asecd(&self) -> Self {
+        Self::new(self.asec().value * RAD2DEG)
+    }
+    fn acotd(&self) -> Self {
+        Self::new(self.acot().value * RAD2DEG)
+    }
+    fn sinpi(&self) -> Self {
+        let (q, r) = fast_math::reduce_turn(self.value, 0.5);
+        Self::new(Self::sincos_reduced(q, r * 2.0).0)
+    }
+    fn cospi(&self) -> Self {
+        let (q, r) = fast_math::reduce_turn(self.value, 0.5);
+        Self::new(Self::sincos_reduced(q, r * 2.0).1)
+    }
+    fn tanpi(&self) -> Self {
+        let (q, r) = fast_math::reduce_turn(self.value, 0.5);
+        Self::new(Self::tan_reduced(q, r * 2.0))
+    }
+    fn asinpi(&self) -> Self {
+        Self::new(self.asin().value * FRAC_1_PI)
+    }
+    fn acospi(&self) -> Self {
+        Self::new(self.acos().value * FRAC_1_PI)
+    }
+    fn atanpi(&self) -> Self {
+        Self::new(self.atan().value * FRAC_1_PI)
+    }
+    fn atan2pi(&self, other: &Self) -> Self {
+        Self::new(self.atan2(other).value * FRAC_1_PI)
+    }
+    fn sinh(&self) -> Self {
+        Self::new(Trig::sinh(&self.value))
+    }
+    fn cosh(&self) -> Self {
+        Self::new(Trig::cosh(&self.value))
+    }
+    fn tanh(&self) -> Self {
+        Self::new(Trig::tanh(&self.value))
+    }
+    fn csch(&self) -> Self {
+        Self::new(Trig::csch(&self.value))
+    }
+    fn sech(&self) -> Self {
+        Self::new(Trig::sech(&self.value))
+    }
+    fn coth(&self) -> Self {
+        Self::new(Trig::coth(&self.value))
+    }
+    fn sinhcosh(&self) -> (Self, Self) {
+        let (s, c) = Trig::sinhcosh(&self.value);
+        (Self::new(s), Self::new(c))
+    }
+    fn asinh(&self) -> Self {
+        Self::new(Trig::asinh(&self.value))
+    }
+    fn acosh(&self) -> Self {
+        Self::new(Trig::acosh(&self.value))
+    }
+    fn atanh(&self) -> Self {
+        Self::new(Trig::atanh(&self.value))
+    }
+    fn acsch(&self) -> Self {
+        Self::new(Trig::acsch(&self.value))
+    }
+    fn asech(&self) -> Self {
+        Self::new(Trig::asech(&self.value))
+    }
+    fn acoth(&self) -> Self {
+        Self::new(Trig::acoth(&self.value))
+    }
+}
+
+/// Computes $\sqrt{x^{2}-1}$ for $x\geq1$ without overflow or cancellation.
+///
+/// # Arguments
+///
+/// * `x` - Value, $x\geq1$.
+///
+/// # Returns
+///
+/// $\sqrt{x^{2}-1}$ (`NaN` for $x<1$).
+fn sqrt_x2_minus_1(x: f32) -> f32 {
+    if x < 1e18 {
+        // x - 1 is exact near x = 1, so there is no cancellation in the product.
+        math::sqrt_f32((x - 1.0) * (x + 1.0))
+    } else {
+        x
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Maximum error of each function of a [`Tabulated`] over a sweep of its domain.
+    ///
+    /// # Arguments
+    ///
+    /// * `bound` - Maximum allowed error.
+    fn check<const N: usize, I: Interpolation>(bound: f64) {
+        let t = |x: f64| Tabulated::<N, I>::new(x as f32);
+        let mut max = 0.0_f64;
+        let mut update = |f: f32, g: f64, name: &str, x: f64| {
+            let e = if g.abs() <= 1.0 {
+                (f as f64 - g).abs()
+            } else {
+                ((f as f64 - g) / g).abs()
+            };
+            assert!(e <= bound, "{name}({x}): {f} vs {g} (error {e:e})");
+            max = max.max(e);
+        };
+        for k in -2000..=2000 {
+            // Angles stay away from the poles of tan, where relative errors are amplified.
+            let x = k as f64 * 0.0051;
+            if (x.rem_euclid(core::f64::consts::PI) - core::f64::consts::FRAC_PI_2).abs() > 0.2 {
+                update(t(x).tan().value, (x as f32 as f64).tan(), "tan", x);
+            }
+            update(t(x).sin().value, (x as f32 as f64).sin(), "sin", x);
+            update(t(x).cos().value, (x as f32 as f64).cos(), "cos", x);
+            let d = k as f64 * 0.173;
+            let (s, c) = t(d).sincosd();
+            let r = (d as f32 as f64).to_radians();
+            update(s.value, r.sin(), "sind", d);
+            update(c.value, r.cos(), "cosd", d);
+            let u = k as f64 * 0.00137;
+            update(
+                t(u).sinpi().value,
+                (u as f32 as f64 * core::f64::consts::PI).sin(),
+                "sinpi",
+                u,
+            );
+            let y = k as f64 * 0.011;
+            update(t(y).atan().value, (y as f32 as f64).atan(), "atan", y);
+            let a = (k as f64 * 0.0031).sin();
+            update(
+                t(a).atan2(&t(1.0 - a)).value,
+                (a as f32 as f64).atan2((1.0 - a) as f32 as f64),
+                "atan2",
+                a,
+            );
+            update(t(a).asin().value, (a as f32 as f64).asin(), "asin", a);
+        }
+        assert!(max > 0.0);
+    }
+
+    #[test]
+    fn test_accuracy() {
+        // Bounds from the errors documented on Tabulated, plus the rounding error of f32.
+        let linear = |n: f64| core::f64::consts::PI.powi(2) / (32.0 * (n - 1.0).powi(2)) + 1e-6;
+        check::<16, Linear>(linear(16.0));
+        check::<256, Linear>(linear(256.0));
+        check::<16, Cubic>(1e-6);
+        check::<256, Cubic>(1e-6);
+    }
+
+    #[test]
+    fn test_special_values() {
+        let t = Tabulated::<32, Linear>::new;
+        assert_eq!(t(0.0).sin().value, 0.0);
+        assert_eq!(t(0.0).cos().value, 1.0);
+        assert_eq!(t(90.0).sind().value, 1.0);
+        assert_eq!(t(180.0).cosd().value, -1.0);
+        assert_eq!(t(-270.0).sind().value, 1.0);
+        assert_eq!(t(0.5).cospi().value, 0.0);
+        assert_eq!(t(1.0).atan().value, FRAC_PI_4);
+        assert_eq!(t(1.0).atan2(&t(-1.0)).value, 3.0 * FRAC_PI_4);
+        assert_eq!(t(0.0).atan2(&t(-1.0)).value, PI);
+        assert_eq!(t(f32::INFINITY).atan().value, FRAC_PI_2);
+        assert_eq!(t(1.0).asind().value, 90.0);
+        assert!(t(f32::NAN).sin().value.is_nan());
+        assert!(t(f32::INFINITY).cos().value.is_nan());
+        assert!(t(2.0).asin().value.is_nan());
+        assert_eq!(f32::from(t(2.0).sinh()), Trig::sinh(&2.0_f32));
+        assert_eq!(Tabulated::<32, Cubic>::new(90.0).sind().value, 1.0);
+    }
+}
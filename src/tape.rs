@@ -0,0 +1,462 @@
+use crate::dual::Dual;
+use crate::real::Real;
+use crate::trig_trait::Trig;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cell::RefCell;
+use core::cmp::Ordering;
+use core::ops::{Add, Div, Mul, Neg, Sub};
+
+/// Operation recorded on a [`Tape`].
+#[derive(Clone, Copy, Debug)]
+struct Node<T> {
+    /// Indices of the operands and the partial derivatives of the result with respect to them
+    /// (`None` for unused operand slots).
+    parents: [Option<(usize, T)>; 2],
+}
+
+/// Tape for reverse-mode automatic differentiation.
+///
+/// A tape records every operation performed on its [`Var`]s, together with the local partial
+/// derivatives of the result with respect to the operands. [`Var::gradient`] then sweeps the tape
+/// backwards once, accumulating adjoints, which gives the derivatives of one output with respect to
+/// all inputs at a cost proportional to the number of recorded operations (independent of the
+/// number of inputs). This makes reverse mode the better choice over [`Dual`] numbers when a scalar
+/// (e.g. a sum of squared residuals) depends on many parameters.
+///
+/// # Note
+///
+/// The local derivatives of the [`Trig`] methods are the closed-form derivatives used by [`Dual`]
+/// numbers (including the chain factors of the degree and half-turn methods), and the values are
+/// those of the corresponding methods of `T`.
+///
+/// # Example
+///
+/// ```
+/// use trig::{Tape, Trig};
+///
+/// // f(x, y) = cosd(x) asin(y).
+/// let tape = Tape::new();
+/// let (x, y) = (tape.variable(60.0_f64), tape.variable(0.5));
+/// let f = x.cosd() * y.asin();
+/// let gradient = f.gradient();
+///
+/// // ∂f/∂x = -(π/180) sind(x) asin(y), ∂f/∂y = cosd(x) / sqrt(1 - y²).
+/// let pi = std::f64::consts::PI;
+/// assert!((gradient.wrt(&x) + pi / 180.0 * 60.0_f64.sind() * 0.5_f64.asin()).abs() < 1e-16);
+/// assert!((gradient.wrt(&y) - 0.5 / 0.75_f64.sqrt()).abs() < 1e-15);
+/// ```
+#[derive(Debug, Default)]
+pub struct Tape<T> {
+    /// Recorded operations, in evaluation order.
+    nodes: RefCell<Vec<Node<T>>>,
+}
+
+impl<T: Real> Tape<T> {
+    /// Constructs an empty tape.
+    ///
+    /// # Returns
+    ///
+    /// Tape without any recorded operations.
+    pub fn new() -> Tape<T> {
+        Tape {
+            nodes: RefCell::new(Vec::new()),
+        }
+    }
+
+    /// Constructs an input of a differentiation.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Value.
+    ///
+    /// # Returns
+    ///
+    /// Variable recorded on this tape. Constants are also created this way (their derivatives are
+    /// simply never queried).
+    pub fn variable(&self, value: T) -> Var<'_, T> {
+        self.push(value, [None, None])
+    }
+
+    /// Number of recorded operations.
+    ///
+    /// # Returns
+    ///
+    /// Number of variables created on this tape (inputs and intermediate results).
+    pub fn len(&self) -> usize {
+        self.nodes.borrow().len()
+    }
+
+    /// Determines if the tape is empty.
+    ///
+    /// # Returns
+    ///
+    /// `true` if no variables were created on this tape, `false` otherwise.
+    pub fn is_empty(&self) -> bool {
+        self.nodes.borrow().is_empty()
+    }
+
+    /// Removes all recorded operations (keeping the allocated memory for reuse).
+    ///
+    /// # Note
+    ///
+    /// Since every [`Var`] borrows its tape, this can only be called once all of them are dropped.
+    pub fn clear(&mut self) {
+        self.nodes.get_mut().clear();
+    }
+
+    /// Records an operation.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Value of the result.
+    /// * `parents` - Operands and the partial derivatives of the result with respect to them.
+    ///
+    /// # Returns
+    ///
+    /// Result of the operation.
+    fn push(&self, value: T, parents: [Option<(usize, T)>; 2]) -> Var<'_, T> {
+        let mut nodes = self.nodes.borrow_mut();
+        nodes.push(Node { parents });
+        Var {
+            tape: self,
+            index: nodes.len() - 1,
+            value,
+        }
+    }
+}
+
+/// Variable recorded on a [`Tape`].
+///
+/// Arithmetic and [`Trig`] methods on variables compute their values eagerly and record the
+/// operation on the tape (see [`Tape`] for an example).
+///
+/// # Panics
+///
+/// Combining variables recorded on different tapes panics.
+#[derive(Clone, Copy, Debug)]
+pub struct Var<'a, T> {
+    /// Tape this variable is recorded on.
+    tape: &'a Tape<T>,
+
+    /// Index of this variable on the tape.
+    index: usize,
+
+    /// Value.
+    value: T,
+}
+
+impl<'a, T: Real> Var<'a, T> {
+    /// Value.
+    ///
+    /// # Returns
+    ///
+    /// Value of this variable.
+    pub fn value(&self) -> T {
+        self.value
+    }
+
+    /// Derivatives of this variable with respect to every variable recorded before it.
+    ///
+    /// # Returns
+    ///
+    /// Gradient, queried with [`Gradient::wrt`].
+    pub fn gradient(&self) -> Gradient<T> {
+        let nodes = self.tape.nodes.borrow();
+        let mut adjoints = vec![T::from_f64(0.0); self.index + 1];
+        adjoints[self.index] = T::from_f64(1.0);
+        for i in (0..=self.index).rev() {
+            let adjoint = adjoints[i];
+            for &(j, partial) in nodes[i].parents.iter().flatten() {
+                adjoints[j] = adjoints[j] + partial * adjoint;
+            }
+        }
+        Gradient { adjoints }
+    }
+
+    /// Records a unary operation.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - $f(x)$.
+    /// * `derivative` - $f'(x)$.
+    ///
+    /// # Returns
+    ///
+    /// $f(x)$.
+    fn unary(&self, value: T, derivative: T) -> Var<'a, T> {
+        self.tape
+            .push(value, [Some((self.index, derivative)), None])
+    }
+
+    /// Records a binary operation.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - Second operand $y$.
+    /// * `value` - $f(x,y)$.
+    /// * `dx` - $\frac{\partial f}{\partial x}$.
+    /// * `dy` - $\frac{\partial f}{\partial y}$.
+    ///
+    /// # Returns
+    ///
+    /// $f(x,y)$.
+    ///
+    /// # Panics
+    ///
+    /// If `other` is recorded on a different tape.
+    fn binary(&self, other: &Var<'a, T>, value: T, dx: T, dy: T) -> Var<'a, T> {
+        assert!(
+            core::ptr::eq(self.tape, other.tape),
+            "variables are recorded on different tapes"
+        );
+        let parents = [Some((self.index, dx)), Some((other.index, dy))];
+        self.tape.push(value, parents)
+    }
+}
+
+/// Gradient computed by a reverse sweep of a [`Tape`].
+#[derive(Clone, Debug)]
+pub struct Gradient<T> {
+    /// Adjoint of every variable recorded up to the output.
+    adjoints: Vec<T>,
+}
+
+impl<T: Real> Gradient<T> {
+    /// Derivative with respect to a variable.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - Variable.
+    ///
+    /// # Returns
+    ///
+    /// Derivative of the output with respect to `x` ($0$ if `x` was recorded after the output).
+    pub fn wrt(&self, x: &Var<'_, T>) -> T {
+        self.adjoints
+            .get(x.index)
+            .copied()
+            .unwrap_or(T::from_f64(0.0))
+    }
+}
+
+impl<T: Real> PartialEq for Var<'_, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<T: Real> PartialOrd for Var<'_, T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.value.partial_cmp(&other.value)
+    }
+}
+
+impl<'a, T: Real> Neg for Var<'a, T> {
+    type Output = Var<'a, T>;
+    fn neg(self) -> Var<'a, T> {
+        self.unary(-self.value, T::from_f64(-1.0))
+    }
+}
+
+impl<'a, T: Real> Add for Var<'a, T> {
+    type Output = Var<'a, T>;
+    fn add(self, other: Var<'a, T>) -> Var<'a, T> {
+        let one = T::from_f64(1.0);
+        self.binary(&other, self.value + other.value, one, one)
+    }
+}
+
+impl<'a, T: Real> Sub for Var<'a, T> {
+    type Output = Var<'a, T>;
+    fn sub(self, other: Var<'a, T>) -> Var<'a, T> {
+        let (one, minus_one) = (T::from_f64(1.0), T::from_f64(-1.0));
+        self.binary(&other, self.value - other.value, one, minus_one)
+    }
+}
+
+impl<'a, T: Real> Mul for Var<'a, T> {
+    type Output = Var<'a, T>;
+    fn mul(self, other: Var<'a, T>) -> Var<'a, T> {
+        self.binary(&other, self.value * other.value, other.value, self.value)
+    }
+}
+
+impl<'a, T: Real> Div for Var<'a, T> {
+    type Output = Var<'a, T>;
+    fn div(self, other: Var<'a, T>) -> Var<'a, T> {
+        let value = self.value / other.value;
+        let dx = T::from_f64(1.0) / other.value;
+        self.binary(&other, value, dx, -value * dx)
+    }
+}
+
+/// Implements unary [`Trig`] methods on variables with the derivatives of [`Dual`] numbers.
+macro_rules! unary_methods {
+    ($($name:ident),* $(,)?) => {
+        $(
+            fn $name(&self) -> Var<'a, T> {
+                let y = Trig::$name(&Dual::variable(self.value));
+                self.unary(y.value, y.derivative)
+            }
+        )*
+    };
+}
+
+/// Implements pair-valued [`Trig`] methods on variables with the derivatives of [`Dual`] numbers.
+macro_rules! pair_methods {
+    ($($name:ident),* $(,)?) => {
+        $(
+            fn $name(&self) -> (Var<'a, T>, Var<'a, T>) {
+                let (a, b) = Trig::$name(&Dual::variable(self.value));
+                (self.unary(a.value, a.derivative), self.unary(b.value, b.derivative))
+            }
+        )*
+    };
+}
+
+/// Implements binary [`Trig`] methods on variables with the partial derivatives of [`Dual`]
+/// numbers.
+macro_rules! binary_methods {
+    ($($name:ident),* $(,)?) => {
+        $(
+            fn $name(&self, other: &Var<'a, T>) -> Var<'a, T> {
+                let (y, x) = (self.value, other.value);
+                let dy = Trig::$name(&Dual::variable(y), &Dual::constant(x));
+                let dx = Trig::$name(&Dual::constant(y), &Dual::variable(x));
+                self.binary(other, dy.value, dy.derivative, dx.derivative)
+            }
+        )*
+    };
+}
+
+impl<'a, T: Real> Trig for Var<'a, T> {
+    unary_methods!(
+        sin, cos, tan, csc, sec, cot, asin, acos, atan, acsc, asec, acot, deg2rad, rad2deg, sind,
+        cosd, tand, cscd, secd, cotd, asind, acosd, atand, acscd, asecd, acotd, sinpi, cospi,
+        tanpi, asinpi, acospi, atanpi, sinh, cosh, tanh, csch, sech, coth, asinh, acosh, atanh,
+        acsch, asech, acoth,
+    );
+    pair_methods!(sincos, sincosd, sinhcosh);
+    binary_methods!(atan2, atan2d, atan2pi);
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Asserts that the gradients of unary [`Trig`] methods match [`Dual`] numbers.
+    macro_rules! check_unary {
+        ($($name:ident),* $(,)?) => {
+            $(
+                for x in [0.3_f64, -0.45, 1.7, -2.5, 30.0, 100.0] {
+                    let tape = Tape::new();
+                    let v = tape.variable(x);
+                    let y = Trig::$name(&v);
+                    let expected = Trig::$name(&Dual::variable(x));
+                    let derivative = y.gradient().wrt(&v);
+                    let name = stringify!($name);
+                    assert_eq!(y.value().to_bits(), Trig::$name(&x).to_bits(), "{name}({x})");
+                    // The adjoint is accumulated onto zero, so a derivative of -0 comes out as +0.
+                    let d = expected.derivative;
+                    assert!(
+                        derivative == d || (derivative.is_nan() && d.is_nan()),
+                        "{name}'({x}) = {derivative}, expected {d}"
+                    );
+                }
+            )*
+        };
+    }
+
+    #[test]
+    fn test_unary() {
+        check_unary!(
+            sin, cos, tan, csc, sec, cot, asin, acos, atan, acsc, asec, acot, deg2rad, rad2deg,
+            sind, cosd, tand, cscd, secd, cotd, asind, acosd, atand, acscd, asecd, acotd, sinpi,
+            cospi, tanpi, asinpi, acospi, atanpi, sinh, cosh, tanh, csch, sech, coth, asinh, acosh,
+            atanh, acsch, asech, acoth,
+        );
+    }
+
+    #[test]
+    fn test_atan2() {
+        for (y, x) in [(1.0, 2.0), (-0.5, -3.0), (2.0, -1e-3), (0.0, 1.0)] {
+            let tape = Tape::new();
+            let (vy, vx) = (tape.variable(y), tape.variable(x));
+            let r2 = x * x + y * y;
+            for (f, scale) in [
+                (vy.atan2(&vx), 1.0),
+                (vy.atan2d(&vx), 180.0 / core::f64::consts::PI),
+                (vy.atan2pi(&vx), 1.0 / core::f64::consts::PI),
+            ] {
+                let gradient = f.gradient();
+                assert!((gradient.wrt(&vy) - scale * x / r2).abs() < 1e-13 * scale / r2.sqrt());
+                assert!((gradient.wrt(&vx) + scale * y / r2).abs() < 1e-13 * scale / r2.sqrt());
+            }
+            assert_eq!(vy.atan2d(&vx).value(), y.atan2d(&x));
+        }
+    }
+
+    #[test]
+    fn test_many_parameters() {
+        // Sum of squared residuals of the model a_i cosd(θ_i) + asin(s_i) with one constant
+        // observation per term, compared against one forward pass per parameter.
+        let n = 200;
+        let parameters: Vec<f64> = (0..3 * n).map(|i| 0.3 + 0.1 * (i % 7) as f64).collect();
+        let residual = |a: Dual<f64>, t: Dual<f64>, s: Dual<f64>, i: usize| {
+            a * (t * Dual::constant(10.0)).cosd() + s.asin() - Dual::constant(i as f64 / n as f64)
+        };
+        let tape = Tape::new();
+        let vars: Vec<Var<'_, f64>> = parameters.iter().map(|&p| tape.variable(p)).collect();
+        let mut cost = tape.variable(0.0);
+        for i in 0..n {
+            let (a, t, s) = (vars[3 * i], vars[3 * i + 1], vars[3 * i + 2]);
+            let observation = tape.variable(i as f64 / n as f64);
+            let r = a * (t * tape.variable(10.0)).cosd() + s.asin() - observation;
+            cost = cost + r * r;
+        }
+        let gradient = cost.gradient();
+        for (k, var) in vars.iter().enumerate() {
+            let i = k / 3;
+            let mut p: Vec<Dual<f64>> = parameters[3 * i..3 * i + 3]
+                .iter()
+                .map(|&p| Dual::constant(p))
+                .collect();
+            p[k % 3] = Dual::variable(parameters[k]);
+            let r = residual(p[0], p[1], p[2], i);
+            let expected = 2.0 * r.value * r.derivative;
+            assert!((gradient.wrt(var) - expected).abs() < 1e-13, "{k}");
+        }
+    }
+
+    #[test]
+    fn test_arithmetic_and_reuse() {
+        let mut tape = Tape::new();
+        {
+            let (x, y) = (tape.variable(3.0), tape.variable(-2.0));
+            let f = (x * y - y) / (x + y) + (-x);
+            let gradient = f.gradient();
+
+            // f = (xy - y)/(x + y) - x, so ∂f/∂x = (y(x + y) - (xy - y))/(x + y)² - 1.
+            assert_eq!(f.value(), -4.0 - 3.0);
+            assert_eq!(gradient.wrt(&x), (-2.0 - (-6.0 + 2.0)) - 1.0);
+            assert_eq!(gradient.wrt(&y), ((3.0 - 1.0) - (-6.0 + 2.0)) / 1.0);
+            assert_eq!(f.gradient().wrt(&f), 1.0);
+            assert_eq!(x.gradient().wrt(&f), 0.0);
+            assert!(x > y && x == tape.variable(3.0));
+        }
+        assert_eq!(tape.len(), 9);
+        tape.clear();
+        assert!(tape.is_empty());
+        let x = tape.variable(0.5);
+        let (s, c) = x.sincos();
+        assert_eq!((s * s + c * c).gradient().wrt(&x), 0.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "variables are recorded on different tapes")]
+    fn test_different_tapes() {
+        let (a, b) = (Tape::new(), Tape::new());
+        let _ = a.variable(1.0) + b.variable(2.0);
+    }
+}
@@ -0,0 +1,726 @@
+use crate::hyper_dual::HyperDual;
+use crate::real::Real;
+use crate::trig_trait::Trig;
+
+/// Closed-form derivatives of the trigonometric and hyperbolic functions.
+///
+/// This trait mirrors the [`Trig`] trait: every single-valued [`Trig`] method has a counterpart
+/// with a `_deriv` suffix that returns its first and second derivatives at this value, and `atan2`,
+/// `atan2d`, and `atan2pi` have `_grad` and `_hess` counterparts that return their gradients and
+/// Hessians (e.g. for the Jacobians of an extended Kalman filter). The derivatives of the degree
+/// and half-turn functions include the chain factors $\frac{\pi}{180}$ and $\pi$ (or their
+/// inverses for the inverse functions). At a point where a function is not differentiable (e.g. `asin` at
+/// $\pm1$), the derivatives are infinite or `NaN`.
+///
+/// The trait is implemented for every [`Real`] type. Each method evaluates the closed-form first
+/// derivative of the function (the one used by [`Dual`](crate::Dual) numbers) on a
+/// [`HyperDual`] number, so that the second derivative is the exact derivative of that expression.
+///
+/// # Example
+///
+/// ```
+/// use trig::{Trig, TrigDerivative};
+///
+/// // d/dx sind(x) = (π/180) cosd(x).
+/// let (first, second) = 60.0_f64.sind_deriv();
+/// assert!((first - std::f64::consts::PI / 360.0).abs() < 1e-16);
+/// assert!((second + (std::f64::consts::PI / 180.0).powi(2) * 60.0_f64.sind()).abs() < 1e-16);
+///
+/// // The gradient of atan2(y, x) at (1, 1) is (1/2, -1/2).
+/// assert_eq!(1.0_f64.atan2_grad(&1.0), (0.5, -0.5));
+/// ```
+pub trait TrigDerivative: Sized {
+    /// First and second derivatives of the sine function $f(x)=\sin(x)$ ($x$ in radians).
+    ///
+    /// # Returns
+    ///
+    /// $f'(x)=\cos(x)$ and $f''(x)=-\sin(x)$, as the tuple `(first, second)`.
+    fn sin_deriv(&self) -> (Self, Self);
+
+    /// First and second derivatives of the cosine function $f(x)=\cos(x)$ ($x$ in radians).
+    ///
+    /// # Returns
+    ///
+    /// $f'(x)=-\sin(x)$ and $f''(x)=-\cos(x)$, as the tuple `(first, second)`.
+    fn cos_deriv(&self) -> (Self, Self);
+
+    /// First and second derivatives of the tangent function $f(x)=\tan(x)$ ($x$ in radians).
+    ///
+    /// # Returns
+    ///
+    /// $f'(x)=\sec^2(x)$ and $f''(x)=2\sec^2(x)\tan(x)$, as the tuple `(first, second)`.
+    fn tan_deriv(&self) -> (Self, Self);
+
+    /// First and second derivatives of the cosecant function $f(x)=\csc(x)$ ($x$ in radians).
+    ///
+    /// # Returns
+    ///
+    /// $f'(x)=-\csc(x)\cot(x)$ and $f''(x)=\csc(x)\left(\csc^2(x)+\cot^2(x)\right)$, as the tuple
+    /// `(first, second)`.
+    fn csc_deriv(&self) -> (Self, Self);
+
+    /// First and second derivatives of the secant function $f(x)=\sec(x)$ ($x$ in radians).
+    ///
+    /// # Returns
+    ///
+    /// $f'(x)=\sec(x)\tan(x)$ and $f''(x)=\sec(x)\left(\sec^2(x)+\tan^2(x)\right)$, as the tuple
+    /// `(first, second)`.
+    fn sec_deriv(&self) -> (Self, Self);
+
+    /// First and second derivatives of the cotangent function $f(x)=\cot(x)$ ($x$ in radians).
+    ///
+    /// # Returns
+    ///
+    /// $f'(x)=-\csc^2(x)$ and $f''(x)=2\csc^2(x)\cot(x)$, as the tuple `(first, second)`.
+    fn cot_deriv(&self) -> (Self, Self);
+
+    /// First and second derivatives of the inverse sine function $f(x)=\arcsin(x)$ (in radians).
+    ///
+    /// # Returns
+    ///
+    /// $f'(x)=\frac{1}{\sqrt{1-x^2}}$ and $f''(x)=\frac{x}{\left(1-x^2\right)^{3/2}}$, as the tuple
+    /// `(first, second)`.
+    fn asin_deriv(&self) -> (Self, Self);
+
+    /// First and second derivatives of the inverse cosine function $f(x)=\arccos(x)$ (in radians).
+    ///
+    /// # Returns
+    ///
+    /// $f'(x)=-\frac{1}{\sqrt{1-x^2}}$ and $f''(x)=-\frac{x}{\left(1-x^2\right)^{3/2}}$, as the
+    /// tuple `(first, second)`.
+    fn acos_deriv(&self) -> (Self, Self);
+
+    /// First and second derivatives of the inverse tangent function $f(x)=\arctan(x)$ (in radians).
+    ///
+    /// # Returns
+    ///
+    /// $f'(x)=\frac{1}{1+x^2}$ and $f''(x)=-\frac{2x}{\left(1+x^2\right)^2}$, as the tuple `(first,
+    /// second)`.
+    fn atan_deriv(&self) -> (Self, Self);
+
+    /// First and second derivatives of the inverse cosecant function $f(x)=\text{arccsc}(x)$ (in
+    /// radians).
+    ///
+    /// # Returns
+    ///
+    /// $f'(x)=-\frac{1}{|x|\sqrt{x^2-1}}$ and $f''(x)=\frac{2x^2-1}{x|x|\left(x^2-1\right)^{3/2}}$,
+    /// as the tuple `(first, second)`.
+    fn acsc_deriv(&self) -> (Self, Self);
+
+    /// First and second derivatives of the inverse secant function $f(x)=\text{arcsec}(x)$ (in
+    /// radians).
+    ///
+    /// # Returns
+    ///
+    /// $f'(x)=\frac{1}{|x|\sqrt{x^2-1}}$ and $f''(x)=-\frac{2x^2-1}{x|x|\left(x^2-1\right)^{3/2}}$,
+    /// as the tuple `(first, second)`.
+    fn asec_deriv(&self) -> (Self, Self);
+
+    /// First and second derivatives of the inverse cotangent function $f(x)=\text{arccot}(x)$ (in
+    /// radians).
+    ///
+    /// # Returns
+    ///
+    /// $f'(x)=-\frac{1}{1+x^2}$ and $f''(x)=\frac{2x}{\left(1+x^2\right)^2}$, as the tuple `(first,
+    /// second)`.
+    fn acot_deriv(&self) -> (Self, Self);
+
+    /// First and second derivatives of the conversion from degrees to radians
+    /// $f(x)=\frac{\pi}{180}x$.
+    ///
+    /// # Returns
+    ///
+    /// $f'(x)=\frac{\pi}{180}$ and $f''(x)=0$, as the tuple `(first, second)`.
+    fn deg2rad_deriv(&self) -> (Self, Self);
+
+    /// First and second derivatives of the conversion from radians to degrees
+    /// $f(x)=\frac{180}{\pi}x$.
+    ///
+    /// # Returns
+    ///
+    /// $f'(x)=\frac{180}{\pi}$ and $f''(x)=0$, as the tuple `(first, second)`.
+    fn rad2deg_deriv(&self) -> (Self, Self);
+
+    /// First and second derivatives of the sine function $f(x)=\text{sind}(x)$ ($x$ in degrees).
+    ///
+    /// # Returns
+    ///
+    /// $f'(x)=\frac{\pi}{180}\text{cosd}(x)$ and
+    /// $f''(x)=-\left(\frac{\pi}{180}\right)^2\text{sind}(x)$, as the tuple `(first, second)`.
+    fn sind_deriv(&self) -> (Self, Self);
+
+    /// First and second derivatives of the cosine function $f(x)=\text{cosd}(x)$ ($x$ in degrees).
+    ///
+    /// # Returns
+    ///
+    /// $f'(x)=-\frac{\pi}{180}\text{sind}(x)$ and
+    /// $f''(x)=-\left(\frac{\pi}{180}\right)^2\text{cosd}(x)$, as the tuple `(first, second)`.
+    fn cosd_deriv(&self) -> (Self, Self);
+
+    /// First and second derivatives of the tangent function $f(x)=\text{tand}(x)$ ($x$ in degrees).
+    ///
+    /// # Returns
+    ///
+    /// $f'(x)=\frac{\pi}{180}\text{secd}^2(x)$ and
+    /// $f''(x)=2\left(\frac{\pi}{180}\right)^2\text{secd}^2(x)\text{tand}(x)$, as the tuple
+    /// `(first, second)`.
+    fn tand_deriv(&self) -> (Self, Self);
+
+    /// First and second derivatives of the cosecant function $f(x)=\text{cscd}(x)$ ($x$ in
+    /// degrees).
+    ///
+    /// # Returns
+    ///
+    /// $f'(x)=-\frac{\pi}{180}\text{cscd}(x)\text{cotd}(x)$ and
+    /// $f''(x)=\left(\frac{\pi}{180}\right)^2\text{cscd}(x)\left(\text{cscd}^2(x)+\text{cotd}^2(x)\right)$,
+    /// as the tuple `(first, second)`.
+    fn cscd_deriv(&self) -> (Self, Self);
+
+    /// First and second derivatives of the secant function $f(x)=\text{secd}(x)$ ($x$ in degrees).
+    ///
+    /// # Returns
+    ///
+    /// $f'(x)=\frac{\pi}{180}\text{secd}(x)\text{tand}(x)$ and
+    /// $f''(x)=\left(\frac{\pi}{180}\right)^2\text{secd}(x)\left(\text{secd}^2(x)+\text{tand}^2(x)\right)$,
+    /// as the tuple `(first, second)`.
+    fn secd_deriv(&self) -> (Self, Self);
+
+    /// First and second derivatives of the cotangent function $f(x)=\text{cotd}(x)$ ($x$ in
+    /// degrees).
+    ///
+    /// # Returns
+    ///
+    /// $f'(x)=-\frac{\pi}{180}\text{cscd}^2(x)$ and
+    /// $f''(x)=2\left(\frac{\pi}{180}\right)^2\text{cscd}^2(x)\text{cotd}(x)$, as the tuple
+    /// `(first, second)`.
+    fn cotd_deriv(&self) -> (Self, Self);
+
+    /// First and second derivatives of the inverse sine function $f(x)=\text{asind}(x)$ (in
+    /// degrees).
+    ///
+    /// # Returns
+    ///
+    /// $f'(x)=\frac{180}{\pi}\frac{1}{\sqrt{1-x^2}}$ and
+    /// $f''(x)=\frac{180}{\pi}\frac{x}{\left(1-x^2\right)^{3/2}}$, as the tuple `(first, second)`.
+    fn asind_deriv(&self) -> (Self, Self);
+
+    /// First and second derivatives of the inverse cosine function $f(x)=\text{acosd}(x)$ (in
+    /// degrees).
+    ///
+    /// # Returns
+    ///
+    /// $f'(x)=-\frac{180}{\pi}\frac{1}{\sqrt{1-x^2}}$ and
+    /// $f''(x)=-\frac{180}{\pi}\frac{x}{\left(1-x^2\right)^{3/2}}$, as the tuple `(first, second)`.
+    fn acosd_deriv(&self) -> (Self, Self);
+
+    /// First and second derivatives of the inverse tangent function $f(x)=\text{atand}(x)$ (in
+    /// degrees).
+    ///
+    /// # Returns
+    ///
+    /// $f'(x)=\frac{180}{\pi}\frac{1}{1+x^2}$ and
+    /// $f''(x)=-\frac{180}{\pi}\frac{2x}{\left(1+x^2\right)^2}$, as the tuple `(first, second)`.
+    fn atand_deriv(&self) -> (Self, Self);
+
+    /// First and second derivatives of the inverse cosecant function $f(x)=\text{acscd}(x)$ (in
+    /// degrees).
+    ///
+    /// # Returns
+    ///
+    /// $f'(x)=-\frac{180}{\pi}\frac{1}{|x|\sqrt{x^2-1}}$ and
+    /// $f''(x)=\frac{180}{\pi}\frac{2x^2-1}{x|x|\left(x^2-1\right)^{3/2}}$, as the tuple `(first,
+    /// second)`.
+    fn acscd_deriv(&self) -> (Self, Self);
+
+    /// First and second derivatives of the inverse secant function $f(x)=\text{asecd}(x)$ (in
+    /// degrees).
+    ///
+    /// # Returns
+    ///
+    /// $f'(x)=\frac{180}{\pi}\frac{1}{|x|\sqrt{x^2-1}}$ and
+    /// $f''(x)=-\frac{180}{\pi}\frac{2x^2-1}{x|x|\left(x^2-1\right)^{3/2}}$, as the tuple `(first,
+    /// second)`.
+    fn asecd_deriv(&self) -> (Self, Self);
+
+    /// First and second derivatives of the inverse cotangent function $f(x)=\text{acotd}(x)$ (in
+    /// degrees).
+    ///
+    /// # Returns
+    ///
+    /// $f'(x)=-\frac{180}{\pi}\frac{1}{1+x^2}$ and
+    /// $f''(x)=\frac{180}{\pi}\frac{2x}{\left(1+x^2\right)^2}$, as the tuple `(first, second)`.
+    fn acotd_deriv(&self) -> (Self, Self);
+
+    /// First and second derivatives of the function $f(x)=\sin(\pi x)$.
+    ///
+    /// # Returns
+    ///
+    /// $f'(x)=\pi\cos(\pi x)$ and $f''(x)=-\pi^2\sin(\pi x)$, as the tuple `(first, second)`.
+    fn sinpi_deriv(&self) -> (Self, Self);
+
+    /// First and second derivatives of the function $f(x)=\cos(\pi x)$.
+    ///
+    /// # Returns
+    ///
+    /// $f'(x)=-\pi\sin(\pi x)$ and $f''(x)=-\pi^2\cos(\pi x)$, as the tuple `(first, second)`.
+    fn cospi_deriv(&self) -> (Self, Self);
+
+    /// First and second derivatives of the function $f(x)=\tan(\pi x)$.
+    ///
+    /// # Returns
+    ///
+    /// $f'(x)=\pi\sec^2(\pi x)$ and $f''(x)=2\pi^2\sec^2(\pi x)\tan(\pi x)$, as the tuple `(first,
+    /// second)`.
+    fn tanpi_deriv(&self) -> (Self, Self);
+
+    /// First and second derivatives of the function $f(x)=\frac{\arcsin(x)}{\pi}$.
+    ///
+    /// # Returns
+    ///
+    /// $f'(x)=\frac{1}{\pi\sqrt{1-x^2}}$ and $f''(x)=\frac{x}{\pi\left(1-x^2\right)^{3/2}}$, as the
+    /// tuple `(first, second)`.
+    fn asinpi_deriv(&self) -> (Self, Self);
+
+    /// First and second derivatives of the function $f(x)=\frac{\arccos(x)}{\pi}$.
+    ///
+    /// # Returns
+    ///
+    /// $f'(x)=-\frac{1}{\pi\sqrt{1-x^2}}$ and $f''(x)=-\frac{x}{\pi\left(1-x^2\right)^{3/2}}$, as
+    /// the tuple `(first, second)`.
+    fn acospi_deriv(&self) -> (Self, Self);
+
+    /// First and second derivatives of the function $f(x)=\frac{\arctan(x)}{\pi}$.
+    ///
+    /// # Returns
+    ///
+    /// $f'(x)=\frac{1}{\pi\left(1+x^2\right)}$ and $f''(x)=-\frac{2x}{\pi\left(1+x^2\right)^2}$, as
+    /// the tuple `(first, second)`.
+    fn atanpi_deriv(&self) -> (Self, Self);
+
+    /// First and second derivatives of the hyperbolic sine function $f(x)=\sinh(x)$.
+    ///
+    /// # Returns
+    ///
+    /// $f'(x)=\cosh(x)$ and $f''(x)=\sinh(x)$, as the tuple `(first, second)`.
+    fn sinh_deriv(&self) -> (Self, Self);
+
+    /// First and second derivatives of the hyperbolic cosine function $f(x)=\cosh(x)$.
+    ///
+    /// # Returns
+    ///
+    /// $f'(x)=\sinh(x)$ and $f''(x)=\cosh(x)$, as the tuple `(first, second)`.
+    fn cosh_deriv(&self) -> (Self, Self);
+
+    /// First and second derivatives of the hyperbolic tangent function $f(x)=\tanh(x)$.
+    ///
+    /// # Returns
+    ///
+    /// $f'(x)=\text{sech}^2(x)$ and $f''(x)=-2\,\text{sech}^2(x)\tanh(x)$, as the tuple `(first,
+    /// second)`.
+    fn tanh_deriv(&self) -> (Self, Self);
+
+    /// First and second derivatives of the hyperbolic cosecant function $f(x)=\text{csch}(x)$.
+    ///
+    /// # Returns
+    ///
+    /// $f'(x)=-\text{csch}(x)\coth(x)$ and
+    /// $f''(x)=\text{csch}(x)\left(\text{csch}^2(x)+\coth^2(x)\right)$, as the tuple `(first,
+    /// second)`.
+    fn csch_deriv(&self) -> (Self, Self);
+
+    /// First and second derivatives of the hyperbolic secant function $f(x)=\text{sech}(x)$.
+    ///
+    /// # Returns
+    ///
+    /// $f'(x)=-\text{sech}(x)\tanh(x)$ and
+    /// $f''(x)=\text{sech}(x)\left(\tanh^2(x)-\text{sech}^2(x)\right)$, as the tuple `(first,
+    /// second)`.
+    fn sech_deriv(&self) -> (Self, Self);
+
+    /// First and second derivatives of the hyperbolic cotangent function $f(x)=\coth(x)$.
+    ///
+    /// # Returns
+    ///
+    /// $f'(x)=-\text{csch}^2(x)$ and $f''(x)=2\,\text{csch}^2(x)\coth(x)$, as the tuple `(first,
+    /// second)`.
+    fn coth_deriv(&self) -> (Self, Self);
+
+    /// First and second derivatives of the inverse hyperbolic sine function
+    /// $f(x)=\text{arcsinh}(x)$.
+    ///
+    /// # Returns
+    ///
+    /// $f'(x)=\frac{1}{\sqrt{x^2+1}}$ and $f''(x)=-\frac{x}{\left(x^2+1\right)^{3/2}}$, as the
+    /// tuple `(first, second)`.
+    fn asinh_deriv(&self) -> (Self, Self);
+
+    /// First and second derivatives of the inverse hyperbolic cosine function
+    /// $f(x)=\text{arccosh}(x)$.
+    ///
+    /// # Returns
+    ///
+    /// $f'(x)=\frac{1}{\sqrt{x^2-1}}$ and $f''(x)=-\frac{x}{\left(x^2-1\right)^{3/2}}$, as the
+    /// tuple `(first, second)`.
+    fn acosh_deriv(&self) -> (Self, Self);
+
+    /// First and second derivatives of the inverse hyperbolic tangent function
+    /// $f(x)=\text{arctanh}(x)$.
+    ///
+    /// # Returns
+    ///
+    /// $f'(x)=\frac{1}{1-x^2}$ and $f''(x)=\frac{2x}{\left(1-x^2\right)^2}$, as the tuple `(first,
+    /// second)`.
+    fn atanh_deriv(&self) -> (Self, Self);
+
+    /// First and second derivatives of the inverse hyperbolic cosecant function
+    /// $f(x)=\text{arccsch}(x)$.
+    ///
+    /// # Returns
+    ///
+    /// $f'(x)=-\frac{1}{|x|\sqrt{1+x^2}}$ and $f''(x)=\frac{2x^2+1}{x|x|\left(1+x^2\right)^{3/2}}$,
+    /// as the tuple `(first, second)`.
+    fn acsch_deriv(&self) -> (Self, Self);
+
+    /// First and second derivatives of the inverse hyperbolic secant function
+    /// $f(x)=\text{arcsech}(x)$.
+    ///
+    /// # Returns
+    ///
+    /// $f'(x)=-\frac{1}{x\sqrt{1-x^2}}$ and $f''(x)=\frac{1-2x^2}{x^2\left(1-x^2\right)^{3/2}}$, as
+    /// the tuple `(first, second)`.
+    fn asech_deriv(&self) -> (Self, Self);
+
+    /// First and second derivatives of the inverse hyperbolic cotangent function
+    /// $f(x)=\text{arccoth}(x)$.
+    ///
+    /// # Returns
+    ///
+    /// $f'(x)=\frac{1}{1-x^2}$ and $f''(x)=\frac{2x}{\left(1-x^2\right)^2}$, as the tuple `(first,
+    /// second)`.
+    fn acoth_deriv(&self) -> (Self, Self);
+
+    /// Gradient of the two-argument inverse tangent function $f(y,x)=\text{atan2}(y,x)$ (in
+    /// radians), where this value is $y$.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - $x$.
+    ///
+    /// # Returns
+    ///
+    /// $\frac{\partial f}{\partial y}=\frac{x}{x^2+y^2}$ and
+    /// $\frac{\partial f}{\partial x}=-\frac{y}{x^2+y^2}$, as the tuple `(df_dy, df_dx)`.
+    fn atan2_grad(&self, other: &Self) -> (Self, Self);
+
+    /// Hessian of the two-argument inverse tangent function $f(y,x)=\text{atan2}(y,x)$ (in
+    /// radians), where this value is $y$.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - $x$.
+    ///
+    /// # Returns
+    ///
+    /// $\frac{\partial^2f}{\partial y^2}=-\frac{2xy}{\left(x^2+y^2\right)^2}$,
+    /// $\frac{\partial^2f}{\partial y\partial x}=\frac{y^2-x^2}{\left(x^2+y^2\right)^2}$, and
+    /// $\frac{\partial^2f}{\partial x^2}=\frac{2xy}{\left(x^2+y^2\right)^2}$, as the tuple
+    /// `(d2f_dy2, d2f_dydx, d2f_dx2)`.
+    fn atan2_hess(&self, other: &Self) -> (Self, Self, Self);
+
+    /// Gradient of the two-argument inverse tangent function $f(y,x)=\text{atan2d}(y,x)$ (in
+    /// degrees), where this value is $y$.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - $x$.
+    ///
+    /// # Returns
+    ///
+    /// $\frac{\partial f}{\partial y}=\frac{180}{\pi}\frac{x}{x^2+y^2}$ and
+    /// $\frac{\partial f}{\partial x}=-\frac{180}{\pi}\frac{y}{x^2+y^2}$, as the tuple `(df_dy,
+    /// df_dx)`.
+    fn atan2d_grad(&self, other: &Self) -> (Self, Self);
+
+    /// Hessian of the two-argument inverse tangent function $f(y,x)=\text{atan2d}(y,x)$ (in
+    /// degrees), where this value is $y$.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - $x$.
+    ///
+    /// # Returns
+    ///
+    /// $\frac{\partial^2f}{\partial y^2}=-\frac{180}{\pi}\frac{2xy}{\left(x^2+y^2\right)^2}$,
+    /// $\frac{\partial^2f}{\partial y\partial x}=\frac{180}{\pi}\frac{y^2-x^2}{\left(x^2+y^2\right)^2}$,
+    /// and $\frac{\partial^2f}{\partial x^2}=\frac{180}{\pi}\frac{2xy}{\left(x^2+y^2\right)^2}$, as
+    /// the tuple `(d2f_dy2, d2f_dydx, d2f_dx2)`.
+    fn atan2d_hess(&self, other: &Self) -> (Self, Self, Self);
+
+    /// Gradient of the two-argument inverse tangent function $f(y,x)=\text{atan2pi}(y,x)$ (in
+    /// half-turns), where this value is $y$.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - $x$.
+    ///
+    /// # Returns
+    ///
+    /// $\frac{\partial f}{\partial y}=\frac{1}{\pi}\frac{x}{x^2+y^2}$ and
+    /// $\frac{\partial f}{\partial x}=-\frac{1}{\pi}\frac{y}{x^2+y^2}$, as the tuple `(df_dy,
+    /// df_dx)`.
+    fn atan2pi_grad(&self, other: &Self) -> (Self, Self);
+
+    /// Hessian of the two-argument inverse tangent function $f(y,x)=\text{atan2pi}(y,x)$ (in
+    /// half-turns), where this value is $y$.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - $x$.
+    ///
+    /// # Returns
+    ///
+    /// $\frac{\partial^2f}{\partial y^2}=-\frac{1}{\pi}\frac{2xy}{\left(x^2+y^2\right)^2}$,
+    /// $\frac{\partial^2f}{\partial y\partial x}=\frac{1}{\pi}\frac{y^2-x^2}{\left(x^2+y^2\right)^2}$,
+    /// and $\frac{\partial^2f}{\partial x^2}=\frac{1}{\pi}\frac{2xy}{\left(x^2+y^2\right)^2}$, as
+    /// the tuple `(d2f_dy2, d2f_dydx, d2f_dx2)`.
+    fn atan2pi_hess(&self, other: &Self) -> (Self, Self, Self);
+}
+
+/// Implements the `_deriv` methods with [`HyperDual`] numbers.
+macro_rules! deriv_methods {
+    ($($name:ident => $method:ident),* $(,)?) => {
+        $(
+            fn $name(&self) -> (T, T) {
+                let y = Trig::$method(&HyperDual::variable(*self));
+                (y.e1, y.e12)
+            }
+        )*
+    };
+}
+
+/// Implements the `_grad` and `_hess` methods with [`HyperDual`] numbers.
+macro_rules! binary_methods {
+    ($($grad:ident, $hess:ident => $method:ident),* $(,)?) => {
+        $(
+            fn $grad(&self, other: &T) -> (T, T) {
+                let zero = T::from_f64(0.0);
+                let y = HyperDual::new(*self, T::from_f64(1.0), zero, zero);
+                let x = HyperDual::new(*other, zero, T::from_f64(1.0), zero);
+                let f = Trig::$method(&y, &x);
+                (f.e1, f.e2)
+            }
+            fn $hess(&self, other: &T) -> (T, T, T) {
+                let (zero, one) = (T::from_f64(0.0), T::from_f64(1.0));
+                let seed = |y1: T, y2: T, x1: T, x2: T| {
+                    let y = HyperDual::new(*self, y1, y2, zero);
+                    let x = HyperDual::new(*other, x1, x2, zero);
+                    Trig::$method(&y, &x).e12
+                };
+                (seed(one, one, zero, zero), seed(one, zero, zero, one), seed(zero, zero, one, one))
+            }
+        )*
+    };
+}
+
+impl<T: Real> TrigDerivative for T {
+    deriv_methods!(
+        sin_deriv => sin,
+        cos_deriv => cos,
+        tan_deriv => tan,
+        csc_deriv => csc,
+        sec_deriv => sec,
+        cot_deriv => cot,
+        asin_deriv => asin,
+        acos_deriv => acos,
+        atan_deriv => atan,
+        acsc_deriv => acsc,
+        asec_deriv => asec,
+        acot_deriv => acot,
+        deg2rad_deriv => deg2rad,
+        rad2deg_deriv => rad2deg,
+        sind_deriv => sind,
+        cosd_deriv => cosd,
+        tand_deriv => tand,
+        cscd_deriv => cscd,
+        secd_deriv => secd,
+        cotd_deriv => cotd,
+        asind_deriv => asind,
+        acosd_deriv => acosd,
+        atand_deriv => atand,
+        acscd_deriv => acscd,
+        asecd_deriv => asecd,
+        acotd_deriv => acotd,
+        sinpi_deriv => sinpi,
+        cospi_deriv => cospi,
+        tanpi_deriv => tanpi,
+        asinpi_deriv => asinpi,
+        acospi_deriv => acospi,
+        atanpi_deriv => atanpi,
+        sinh_deriv => sinh,
+        cosh_deriv => cosh,
+        tanh_deriv => tanh,
+        csch_deriv => csch,
+        sech_deriv => sech,
+        coth_deriv => coth,
+        asinh_deriv => asinh,
+        acosh_deriv => acosh,
+        atanh_deriv => atanh,
+        acsch_deriv => acsch,
+        asech_deriv => asech,
+        acoth_deriv => acoth,
+    );
+    binary_methods!(
+        atan2_grad, atan2_hess => atan2,
+        atan2d_grad, atan2d_hess => atan2d,
+        atan2pi_grad, atan2pi_hess => atan2pi,
+    );
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use core::f64::consts::PI;
+
+    /// Asserts that the `_deriv` methods match the closed forms in their documentation.
+    macro_rules! check_deriv {
+        ($($name:ident => |$x:ident| ($first:expr, $second:expr)),* $(,)?) => {
+            $(
+                for $x in [0.3_f64, -0.45, 1.7, -2.5, 30.0, 100.0] {
+                    let expected: (f64, f64) = ($first, $second);
+                    let actual = $x.$name();
+                    for (a, e) in [(actual.0, expected.0), (actual.1, expected.1)] {
+                        if !e.is_finite() {
+                            assert!(!a.is_finite(), "{}({}) = {a}", stringify!($name), $x);
+                            continue;
+                        }
+                        assert!(
+                            (a - e).abs() <= 1e-13 * f64::max(1.0, e.abs()),
+                            "{}({}) = {a}, expected {e}",
+                            stringify!($name),
+                            $x
+                        );
+                    }
+                }
+            )*
+        };
+    }
+
+    #[test]
+    fn test_deriv() {
+        let (d, r) = (PI / 180.0, 180.0 / PI);
+        let cube = |x: f64| x * x * x;
+        check_deriv!(
+            sin_deriv => |x| (x.cos(), -x.sin()),
+            cos_deriv => |x| (-x.sin(), -x.cos()),
+            tan_deriv => |x| (x.sec().powi(2), 2.0 * x.sec().powi(2) * x.tan()),
+            csc_deriv => |x| (-x.csc() * x.cot(), x.csc() * (x.csc().powi(2) + x.cot().powi(2))),
+            sec_deriv => |x| (x.sec() * x.tan(), x.sec() * (x.sec().powi(2) + x.tan().powi(2))),
+            cot_deriv => |x| (-x.csc().powi(2), 2.0 * x.csc().powi(2) * x.cot()),
+            asin_deriv => |x| (1.0 / (1.0 - x * x).sqrt(), x / cube((1.0 - x * x).sqrt())),
+            acos_deriv => |x| (-1.0 / (1.0 - x * x).sqrt(), -x / cube((1.0 - x * x).sqrt())),
+            atan_deriv => |x| (1.0 / (1.0 + x * x), -2.0 * x / (1.0 + x * x).powi(2)),
+            acsc_deriv => |x| (
+                -1.0 / (x.abs() * (x * x - 1.0).sqrt()),
+                (2.0 * x * x - 1.0) / (x * x.abs() * cube((x * x - 1.0).sqrt()))
+            ),
+            asec_deriv => |x| (
+                1.0 / (x.abs() * (x * x - 1.0).sqrt()),
+                -(2.0 * x * x - 1.0) / (x * x.abs() * cube((x * x - 1.0).sqrt()))
+            ),
+            acot_deriv => |x| (-1.0 / (1.0 + x * x), 2.0 * x / (1.0 + x * x).powi(2)),
+            deg2rad_deriv => |x| (d, 0.0),
+            rad2deg_deriv => |x| (r, 0.0),
+            sind_deriv => |x| (d * x.cosd(), -d * d * x.sind()),
+            cosd_deriv => |x| (-d * x.sind(), -d * d * x.cosd()),
+            tand_deriv => |x| (d * x.secd().powi(2), 2.0 * d * d * x.secd().powi(2) * x.tand()),
+            cscd_deriv => |x| (
+                -d * x.cscd() * x.cotd(),
+                d * d * x.cscd() * (x.cscd().powi(2) + x.cotd().powi(2))
+            ),
+            secd_deriv => |x| (
+                d * x.secd() * x.tand(),
+                d * d * x.secd() * (x.secd().powi(2) + x.tand().powi(2))
+            ),
+            cotd_deriv => |x| (-d * x.cscd().powi(2), 2.0 * d * d * x.cscd().powi(2) * x.cotd()),
+            asind_deriv => |x| (r / (1.0 - x * x).sqrt(), r * x / cube((1.0 - x * x).sqrt())),
+            acosd_deriv => |x| (-r / (1.0 - x * x).sqrt(), -r * x / cube((1.0 - x * x).sqrt())),
+            atand_deriv => |x| (r / (1.0 + x * x), -r * 2.0 * x / (1.0 + x * x).powi(2)),
+            acscd_deriv => |x| (
+                -r / (x.abs() * (x * x - 1.0).sqrt()),
+                r * (2.0 * x * x - 1.0) / (x * x.abs() * cube((x * x - 1.0).sqrt()))
+            ),
+            asecd_deriv => |x| (
+                r / (x.abs() * (x * x - 1.0).sqrt()),
+                -r * (2.0 * x * x - 1.0) / (x * x.abs() * cube((x * x - 1.0).sqrt()))
+            ),
+            acotd_deriv => |x| (-r / (1.0 + x * x), r * 2.0 * x / (1.0 + x * x).powi(2)),
+            sinpi_deriv => |x| (PI * x.cospi(), -PI * PI * x.sinpi()),
+            cospi_deriv => |x| (-PI * x.sinpi(), -PI * PI * x.cospi()),
+            tanpi_deriv => |x| (
+                PI / x.cospi().powi(2),
+                2.0 * PI * PI * x.tanpi() / x.cospi().powi(2)
+            ),
+            asinpi_deriv => |x| (
+                1.0 / (PI * (1.0 - x * x).sqrt()),
+                x / (PI * cube((1.0 - x * x).sqrt()))
+            ),
+            acospi_deriv => |x| (
+                -1.0 / (PI * (1.0 - x * x).sqrt()),
+                -x / (PI * cube((1.0 - x * x).sqrt()))
+            ),
+            atanpi_deriv => |x| (1.0 / (PI * (1.0 + x * x)), -2.0 * x / (PI * (1.0 + x * x).powi(2))),
+            sinh_deriv => |x| (x.cosh(), x.sinh()),
+            cosh_deriv => |x| (x.sinh(), x.cosh()),
+            tanh_deriv => |x| (x.sech().powi(2), -2.0 * x.sech().powi(2) * x.tanh()),
+            csch_deriv => |x| (
+                -x.csch() * x.coth(),
+                x.csch() * (x.csch().powi(2) + x.coth().powi(2))
+            ),
+            sech_deriv => |x| (
+                -x.sech() * x.tanh(),
+                x.sech() * (x.tanh().powi(2) - x.sech().powi(2))
+            ),
+            coth_deriv => |x| (-x.csch().powi(2), 2.0 * x.csch().powi(2) * x.coth()),
+            asinh_deriv => |x| (1.0 / (x * x + 1.0).sqrt(), -x / cube((x * x + 1.0).sqrt())),
+            acosh_deriv => |x| (1.0 / (x * x - 1.0).sqrt(), -x / cube((x * x - 1.0).sqrt())),
+            atanh_deriv => |x| (1.0 / (1.0 - x * x), 2.0 * x / (1.0 - x * x).powi(2)),
+            acsch_deriv => |x| (
+                -1.0 / (x.abs() * (1.0 + x * x).sqrt()),
+                (2.0 * x * x + 1.0) / (x * x.abs() * cube((1.0 + x * x).sqrt()))
+            ),
+            asech_deriv => |x| (
+                -1.0 / (x * (1.0 - x * x).sqrt()),
+                (1.0 - 2.0 * x * x) / (x * x * cube((1.0 - x * x).sqrt()))
+            ),
+            acoth_deriv => |x| (1.0 / (1.0 - x * x), 2.0 * x / (1.0 - x * x).powi(2)),
+        );
+    }
+
+    #[test]
+    fn test_atan2() {
+        for (y, x) in [(1.0_f64, 2.0_f64), (-0.5, -3.0), (2.0, -1e-3), (0.0, 1.0)] {
+            let r2 = x * x + y * y;
+            for (grad, hess, scale) in [
+                (y.atan2_grad(&x), y.atan2_hess(&x), 1.0),
+                (y.atan2d_grad(&x), y.atan2d_hess(&x), 180.0 / PI),
+                (y.atan2pi_grad(&x), y.atan2pi_hess(&x), 1.0 / PI),
+            ] {
+                let tolerance = 1e-13 * scale / r2;
+                assert!((grad.0 - scale * x / r2).abs() < tolerance);
+                assert!((grad.1 + scale * y / r2).abs() < tolerance);
+                let tolerance = tolerance / r2;
+                assert!((hess.0 + scale * 2.0 * x * y / (r2 * r2)).abs() < tolerance);
+                assert!((hess.1 - scale * (y * y - x * x) / (r2 * r2)).abs() < tolerance);
+                assert!((hess.2 - scale * 2.0 * x * y / (r2 * r2)).abs() < tolerance);
+            }
+        }
+    }
+
+    #[test]
+    fn test_f32() {
+        let (first, second) = 0.5_f32.acoth_deriv();
+        assert!((first - 1.0 / 0.75).abs() < 1e-6);
+        assert!((second - 1.0 / (0.75 * 0.75)).abs() < 1e-6);
+        assert_eq!(1.0_f32.asin_deriv().0, f32::INFINITY);
+    }
+}
@@ -0,0 +1,625 @@
+/// Accuracy tier of the approximations computed by the [`TrigFast`] methods.
+///
+/// The [`Accuracy::Low`] and [`Accuracy::Medium`] tiers select the degree of the polynomials
+/// approximating the underlying elementary functions, so that the lower tier needs fewer
+/// operations. Their bounds below are the maximum errors of every [`TrigFast`] method on [`f32`],
+/// measured as the absolute error for results of magnitude at most $1$ and as the relative error
+/// for larger results.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Accuracy {
+    /// Maximum error of $10^{-3}$.
+    Low,
+
+    /// Maximum error of $10^{-6}$.
+    Medium,
+
+    /// Full [`f32`] accuracy: the methods return the result of the corresponding
+    /// [`Trig`](crate::Trig) method, which is within a few ulps of the exact result (see the
+    /// "Accuracy" section of the crate documentation).
+    Full,
+}
+
+/// Fast approximations of the trigonometric and hyperbolic functions.
+///
+/// This trait mirrors the [`Trig`](crate::Trig) trait: every [`Trig`](crate::Trig) method has a
+/// counterpart with a `_fast` suffix that takes an [`Accuracy`] tier as its last argument. Instead
+/// of calling the platform's math library, the methods evaluate low-degree polynomial
+/// approximations, trading the accuracy of the [`Trig`](crate::Trig) methods (which are within a
+/// few ulps of the exact result) for throughput, e.g. in game and graphics code, unless the
+/// [`Accuracy::Full`] tier asks for that accuracy. Passing the tier as a constant lets the compiler
+/// drop the code for the other tiers.
+///
+/// Like the [`Trig`](crate::Trig) methods, the `_fast` methods reduce their arguments over the
+/// whole range of [`f32`] (exactly for degrees and half-turns), return `NaN` outside the domain of
+/// the function, and propagate `NaN`s and infinities. The accuracy of each tier is listed on
+/// [`Accuracy`].
+///
+/// # Example
+///
+/// ```
+/// use trig::{Accuracy, TrigFast};
+///
+/// let x = 30.0_f32;
+///
+/// assert!((x.sind_fast(Accuracy::Low) - 0.5).abs() < 1e-3);
+/// assert!((x.sind_fast(Accuracy::Medium) - 0.5).abs() < 1e-6);
+/// ```
+pub trait TrigFast {
+    /// Approximates the sine of this value, where this value is in radians.
+    ///
+    /// # Arguments
+    ///
+    /// * `accuracy` - Accuracy tier.
+    ///
+    /// # Returns
+    ///
+    /// Sine of this value (see [`Trig::sin`](crate::Trig::sin)).
+    fn sin_fast(&self, accuracy: Accuracy) -> Self;
+
+    /// Approximates the cosine of this value, where this value is in radians.
+    ///
+    /// # Arguments
+    ///
+    /// * `accuracy` - Accuracy tier.
+    ///
+    /// # Returns
+    ///
+    /// Cosine of this value (see [`Trig::cos`](crate::Trig::cos)).
+    fn cos_fast(&self, accuracy: Accuracy) -> Self;
+
+    /// Approximates the tangent of this value, where this value is in radians.
+    ///
+    /// # Arguments
+    ///
+    /// * `accuracy` - Accuracy tier.
+    ///
+    /// # Returns
+    ///
+    /// Tangent of this value (see [`Trig::tan`](crate::Trig::tan)).
+    fn tan_fast(&self, accuracy: Accuracy) -> Self;
+
+    /// Approximates the cosecant of this value, where this value is in radians.
+    ///
+    /// # Arguments
+    ///
+    /// * `accuracy` - Accuracy tier.
+    ///
+    /// # Returns
+    ///
+    /// Cosecant of this value (see [`Trig::csc`](crate::Trig::csc)).
+    fn csc_fast(&self, accuracy: Accuracy) -> Self;
+
+    /// Approximates the secant of this value, where this value is in radians.
+    ///
+    /// # Arguments
+    ///
+    /// * `accuracy` - Accuracy tier.
+    ///
+    /// # Returns
+    ///
+    /// Secant of this value (see [`Trig::sec`](crate::Trig::sec)).
+    fn sec_fast(&self, accuracy: Accuracy) -> Self;
+
+    /// Approximates the cotangent of this value, where this value is in radians.
+    ///
+    /// # Arguments
+    ///
+    /// * `accuracy` - Accuracy tier.
+    ///
+    /// # Returns
+    ///
+    /// Cotangent of this value (see [`Trig::cot`](crate::Trig::cot)).
+    fn cot_fast(&self, accuracy: Accuracy) -> Self;
+
+    /// Simultaneously approximates the sine and cosine of this value, where this value is in
+    /// radians.
+    ///
+    /// # Arguments
+    ///
+    /// * `accuracy` - Accuracy tier.
+    ///
+    /// # Returns
+    ///
+    /// Sine and cosine of this value, as the tuple `(sin, cos)` (see
+    /// [`Trig::sincos`](crate::Trig::sincos)).
+    fn sincos_fast(&self, accuracy: Accuracy) -> (Self, Self)
+    where
+        Self: Sized;
+
+    /// Approximates the inverse sine of this value, returning the result in radians.
+    ///
+    /// # Arguments
+    ///
+    /// * `accuracy` - Accuracy tier.
+    ///
+    /// # Returns
+    ///
+    /// Inverse sine of this value \[rad\] (see [`Trig::asin`](crate::Trig::asin)).
+    fn asin_fast(&self, accuracy: Accuracy) -> Self;
+
+    /// Approximates the inverse cosine of this value, returning the result in radians.
+    ///
+    /// # Arguments
+    ///
+    /// * `accuracy` - Accuracy tier.
+    ///
+    /// # Returns
+    ///
+    /// Inverse cosine of this value \[rad\] (see [`Trig::acos`](crate::Trig::acos)).
+    fn acos_fast(&self, accuracy: Accuracy) -> Self;
+
+    /// Approximates the inverse tangent of this value, returning the result in radians.
+    ///
+    /// # Arguments
+    ///
+    /// * `accuracy` - Accuracy tier.
+    ///
+    /// # Returns
+    ///
+    /// Inverse tangent of this value \[rad\] (see [`Trig::atan`](crate::Trig::atan)).
+    fn atan_fast(&self, accuracy: Accuracy) -> Self;
+
+    /// Approximates the four-quadrant inverse tangent of `self` (`y`) and `other` (`x`), returning
+    /// the
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - Other value (`x`) for computing the four-quadrant inverse with `self` (`y`).
+    /// * `accuracy` - Accuracy tier.
+    ///
+    /// # Returns
+    ///
+    /// Four-quadrant inverse tangent of `self` (`y`) and `other` (`x`) \[rad\] (see
+    /// [`Trig::atan2`](crate::Trig::atan2)).
+    fn atan2_fast(&self, other: &Self, accuracy: Accuracy) -> Self;
+
+    /// Approximates the inverse cosecant of this value, returning the result in radians.
+    ///
+    /// # Arguments
+    ///
+    /// * `accuracy` - Accuracy tier.
+    ///
+    /// # Returns
+    ///
+    /// Inverse cosecant of this value \[rad\] (see [`Trig::acsc`](crate::Trig::acsc)).
+    fn acsc_fast(&self, accuracy: Accuracy) -> Self;
+
+    /// Approximates the inverse secant of this value, returning the result in radians.
+    ///
+    /// # Arguments
+    ///
+    /// * `accuracy` - Accuracy tier.
+    ///
+    /// # Returns
+    ///
+    /// Inverse secant of this value \[rad\] (see [`Trig::asec`](crate::Trig::asec)).
+    fn asec_fast(&self, accuracy: Accuracy) -> Self;
+
+    /// Approximates the inverse cotangent of this value, returning the result in radians.
+    ///
+    /// # Arguments
+    ///
+    /// * `accuracy` - Accuracy tier.
+    ///
+    /// # Returns
+    ///
+    /// Inverse cotangent of this value \[rad\] (see [`Trig::acot`](crate::Trig::acot)).
+    fn acot_fast(&self, accuracy: Accuracy) -> Self;
+
+    /// Converts this value from degrees to radians.
+    ///
+    /// The conversion is a single multiplication, so `accuracy` has no effect.
+    ///
+    /// # Arguments
+    ///
+    /// * `accuracy` - Accuracy tier.
+    ///
+    /// # Returns
+    ///
+    /// This value (originally in degrees) in radians (see [`Trig::deg2rad`](crate::Trig::deg2rad)).
+    fn deg2rad_fast(&self, accuracy: Accuracy) -> Self;
+
+    /// Converts this value from radians to degrees.
+    ///
+    /// The conversion is a single multiplication, so `accuracy` has no effect.
+    ///
+    /// # Arguments
+    ///
+    /// * `accuracy` - Accuracy tier.
+    ///
+    /// # Returns
+    ///
+    /// This value (originally in radians) in degrees (see [`Trig::rad2deg`](crate::Trig::rad2deg)).
+    fn rad2deg_fast(&self, accuracy: Accuracy) -> Self;
+
+    /// Approximates the sine of this value, where this value is in degrees.
+    ///
+    /// # Arguments
+    ///
+    /// * `accuracy` - Accuracy tier.
+    ///
+    /// # Returns
+    ///
+    /// Sine of this value (see [`Trig::sind`](crate::Trig::sind)).
+    fn sind_fast(&self, accuracy: Accuracy) -> Self;
+
+    /// Approximates the cosine of this value, where this value is in degrees.
+    ///
+    /// # Arguments
+    ///
+    /// * `accuracy` - Accuracy tier.
+    ///
+    /// # Returns
+    ///
+    /// Cosine of this value (see [`Trig::cosd`](crate::Trig::cosd)).
+    fn cosd_fast(&self, accuracy: Accuracy) -> Self;
+
+    /// Approximates the tangent of this value, where this value is in degrees.
+    ///
+    /// # Arguments
+    ///
+    /// * `accuracy` - Accuracy tier.
+    ///
+    /// # Returns
+    ///
+    /// Tangent of this value (see [`Trig::tand`](crate::Trig::tand)).
+    fn tand_fast(&self, accuracy: Accuracy) -> Self;
+
+    /// Approximates the cosecant of this value, where this value is in degrees.
+    ///
+    /// # Arguments
+    ///
+    /// * `accuracy` - Accuracy tier.
+    ///
+    /// # Returns
+    ///
+    /// Cosecant of this value (see [`Trig::cscd`](crate::Trig::cscd)).
+    fn cscd_fast(&self, accuracy: Accuracy) -> Self;
+
+    /// Approximates the secant of this value, where this value is in degrees.
+    ///
+    /// # Arguments
+    ///
+    /// * `accuracy` - Accuracy tier.
+    ///
+    /// # Returns
+    ///
+    /// Secant of this value (see [`Trig::secd`](crate::Trig::secd)).
+    fn secd_fast(&self, accuracy: Accuracy) -> Self;
+
+    /// Approximates the cotangent of this value, where this value is in degrees.
+    ///
+    /// # Arguments
+    ///
+    /// * `accuracy` - Accuracy tier.
+    ///
+    /// # Returns
+    ///
+    /// Cotangent of this value (see [`Trig::cotd`](crate::Trig::cotd)).
+    fn cotd_fast(&self, accuracy: Accuracy) -> Self;
+
+    /// Simultaneously approximates the sine and cosine of this value, where this value is in
+    /// degrees.
+    ///
+    /// # Arguments
+    ///
+    /// * `accuracy` - Accuracy tier.
+    ///
+    /// # Returns
+    ///
+    /// Sine and cosine of this value, as the tuple `(sind, cosd)` (see
+    /// [`Trig::sincosd`](crate::Trig::sincosd)).
+    fn sincosd_fast(&self, accuracy: Accuracy) -> (Self, Self)
+    where
+        Self: Sized;
+
+    /// Approximates the inverse sine of this value, returning the result in degrees.
+    ///
+    /// # Arguments
+    ///
+    /// * `accuracy` - Accuracy tier.
+    ///
+    /// # Returns
+    ///
+    /// Inverse sine of this value \[deg\] (see [`Trig::asind`](crate::Trig::asind)).
+    fn asind_fast(&self, accuracy: Accuracy) -> Self;
+
+    /// Approximates the inverse cosine of this value, returning the result in degrees.
+    ///
+    /// # Arguments
+    ///
+    /// * `accuracy` - Accuracy tier.
+    ///
+    /// # Returns
+    ///
+    /// Inverse cosine of this value \[deg\] (see [`Trig::acosd`](crate::Trig::acosd)).
+    fn acosd_fast(&self, accuracy: Accuracy) -> Self;
+
+    /// Approximates the inverse tangent of this value, returning the result in degrees.
+    ///
+    /// # Arguments
+    ///
+    /// * `accuracy` - Accuracy tier.
+    ///
+    /// # Returns
+    ///
+    /// Inverse tangent of this value \[deg\] (see [`Trig::atand`](crate::Trig::atand)).
+    fn atand_fast(&self, accuracy: Accuracy) -> Self;
+
+    /// Approximates the four-quadrant inverse tangent of `self` (`y`) and `other` (`x`), returning
+    /// the
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - Other value (`x`) for computing the four-quadrant inverse with `self` (`y`).
+    /// * `accuracy` - Accuracy tier.
+    ///
+    /// # Returns
+    ///
+    /// Four-quadrant inverse tangent of `self` (`y`) and `other` (`x`) \[deg\] (see
+    /// [`Trig::atan2d`](crate::Trig::atan2d)).
+    fn atan2d_fast(&self, other: &Self, accuracy: Accuracy) -> Self;
+
+    /// Approximates the inverse cosecant of this value, returning the result in degrees.
+    ///
+    /// # Arguments
+    ///
+    /// * `accuracy` - Accuracy tier.
+    ///
+    /// # Returns
+    ///
+    /// Inverse cosecant of this value \[deg\] (see [`Trig::acscd`](crate::Trig::acscd)).
+    fn acscd_fast(&self, accuracy: Accuracy) -> Self;
+
+    /// Approximates the inverse secant of this value, returning the result in degrees.
+    ///
+    /// # Arguments
+    ///
+    /// * `accuracy` - Accuracy tier.
+    ///
+    /// # Returns
+    ///
+    /// Inverse secant of this value \[deg\] (see [`Trig::asecd`](crate::Trig::asecd)).
+    fn asecd_fast(&self, accuracy: Accuracy) -> Self;
+
+    /// Approximates the inverse cotangent of this value, returning the result in degrees.
+    ///
+    /// # Arguments
+    ///
+    /// * `accuracy` - Accuracy tier.
+    ///
+    /// # Returns
+    ///
+    /// Inverse cotangent of this value \[deg\] (see [`Trig::acotd`](crate::Trig::acotd)).
+    fn acotd_fast(&self, accuracy: Accuracy) -> Self;
+
+    /// Approximates the sine of $\pi$ times this value, where this value is in half-turns.
+    ///
+    /// # Arguments
+    ///
+    /// * `accuracy` - Accuracy tier.
+    ///
+    /// # Returns
+    ///
+    /// Sine of $\pi$ times this value (see [`Trig::sinpi`](crate::Trig::sinpi)).
+    fn sinpi_fast(&self, accuracy: Accuracy) -> Self;
+
+    /// Approximates the cosine of $\pi$ times this value, where this value is in half-turns.
+    ///
+    /// # Arguments
+    ///
+    /// * `accuracy` - Accuracy tier.
+    ///
+    /// # Returns
+    ///
+    /// Cosine of $\pi$ times this value (see [`Trig::cospi`](crate::Trig::cospi)).
+    fn cospi_fast(&self, accuracy: Accuracy) -> Self;
+
+    /// Approximates the tangent of $\pi$ times this value, where this value is in half-turns.
+    ///
+    /// # Arguments
+    ///
+    /// * `accuracy` - Accuracy tier.
+    ///
+    /// # Returns
+    ///
+    /// Tangent of $\pi$ times this value (see [`Trig::tanpi`](crate::Trig::tanpi)).
+    fn tanpi_fast(&self, accuracy: Accuracy) -> Self;
+
+    /// Approximates the inverse sine of this value, returning the result in half-turns (i.e. as a
+    ///
+    /// # Arguments
+    ///
+    /// * `accuracy` - Accuracy tier.
+    ///
+    /// # Returns
+    ///
+    /// Inverse sine of this value divided by $\pi$ \[half-turns\] (see
+    /// [`Trig::asinpi`](crate::Trig::asinpi)).
+    fn asinpi_fast(&self, accuracy: Accuracy) -> Self;
+
+    /// Approximates the inverse cosine of this value, returning the result in half-turns (i.e. as a
+    ///
+    /// # Arguments
+    ///
+    /// * `accuracy` - Accuracy tier.
+    ///
+    /// # Returns
+    ///
+    /// Inverse cosine of this value divided by $\pi$ \[half-turns\] (see
+    /// [`Trig::acospi`](crate::Trig::acospi)).
+    fn acospi_fast(&self, accuracy: Accuracy) -> Self;
+
+    /// Approximates the inverse tangent of this value, returning the result in half-turns (i.e. as
+    /// a
+    ///
+    /// # Arguments
+    ///
+    /// * `accuracy` - Accuracy tier.
+    ///
+    /// # Returns
+    ///
+    /// Inverse tangent of this value divided by $\pi$ \[half-turns\] (see
+    /// [`Trig::atanpi`](crate::Trig::atanpi)).
+    fn atanpi_fast(&self, accuracy: Accuracy) -> Self;
+
+    /// Approximates the four-quadrant inverse tangent of `self` (`y`) and `other` (`x`), returning
+    /// the
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - Other value (`x`) for computing the four-quadrant inverse with `self` (`y`).
+    /// * `accuracy` - Accuracy tier.
+    ///
+    /// # Returns
+    ///
+    /// Four-quadrant inverse tangent of `self` (`y`) and `other` (`x`) divided by $\pi$
+    /// \[half-turns\] (see [`Trig::atan2pi`](crate::Trig::atan2pi)).
+    fn atan2pi_fast(&self, other: &Self, accuracy: Accuracy) -> Self;
+
+    /// Approximates the hyperbolic sine of this value.
+    ///
+    /// # Arguments
+    ///
+    /// * `accuracy` - Accuracy tier.
+    ///
+    /// # Returns
+    ///
+    /// Hyperbolic sine of this value (see [`Trig::sinh`](crate::Trig::sinh)).
+    fn sinh_fast(&self, accuracy: Accuracy) -> Self;
+
+    /// Approximates the hyperbolic cosine of this value.
+    ///
+    /// # Arguments
+    ///
+    /// * `accuracy` - Accuracy tier.
+    ///
+    /// # Returns
+    ///
+    /// Hyperbolic cosine of this value (see [`Trig::cosh`](crate::Trig::cosh)).
+    fn cosh_fast(&self, accuracy: Accuracy) -> Self;
+
+    /// Approximates the hyperbolic tangent of this value.
+    ///
+    /// # Arguments
+    ///
+    /// * `accuracy` - Accuracy tier.
+    ///
+    /// # Returns
+    ///
+    /// Hyperbolic tangent of this value (see [`Trig::tanh`](crate::Trig::tanh)).
+    fn tanh_fast(&self, accuracy: Accuracy) -> Self;
+
+    /// Approximates the hyperbolic cosecant of this value.
+    ///
+    /// # Arguments
+    ///
+    /// * `accuracy` - Accuracy tier.
+    ///
+    /// # Returns
+    ///
+    /// Hyperbolic cosecant of this value (see [`Trig::csch`](crate::Trig::csch)).
+    fn csch_fast(&self, accuracy: Accuracy) -> Self;
+
+    /// Approximates the hyperbolic secant of this value.
+    ///
+    /// # Arguments
+    ///
+    /// * `accuracy` - Accuracy tier.
+    ///
+    /// # Returns
+    ///
+    /// Hyperbolic secant of this value (see [`Trig::sech`](crate::Trig::sech)).
+    fn sech_fast(&self, accuracy: Accuracy) -> Self;
+
+    /// Approximates the hyperbolic cotangent of this value.
+    ///
+    /// # Arguments
+    ///
+    /// * `accuracy` - Accuracy tier.
+    ///
+    /// # Returns
+    ///
+    /// Hyperbolic cotangent of this value (see [`Trig::coth`](crate::Trig::coth)).
+    fn coth_fast(&self, accuracy: Accuracy) -> Self;
+
+    /// Simultaneously approximates the hyperbolic sine and hyperbolic cosine of this value.
+    ///
+    /// # Arguments
+    ///
+    /// * `accuracy` - Accuracy tier.
+    ///
+    /// # Returns
+    ///
+    /// Hyperbolic sine and hyperbolic cosine of this value, as the tuple `(sinh, cosh)` (see
+    /// [`Trig::sinhcosh`](crate::Trig::sinhcosh)).
+    fn sinhcosh_fast(&self, accuracy: Accuracy) -> (Self, Self)
+    where
+        Self: Sized;
+
+    /// Approximates the inverse hyperbolic sine of this value.
+    ///
+    /// # Arguments
+    ///
+    /// * `accuracy` - Accuracy tier.
+    ///
+    /// # Returns
+    ///
+    /// Inverse hyperbolic sine of this value (see [`Trig::asinh`](crate::Trig::asinh)).
+    fn asinh_fast(&self, accuracy: Accuracy) -> Self;
+
+    /// Approximates the inverse hyperbolic cosine of this value.
+    ///
+    /// # Arguments
+    ///
+    /// * `accuracy` - Accuracy tier.
+    ///
+    /// # Returns
+    ///
+    /// Inverse hyperbolic cosine of this value (see [`Trig::acosh`](crate::Trig::acosh)).
+    fn acosh_fast(&self, accuracy: Accuracy) -> Self;
+
+    /// Approximates the inverse hyperbolic tangent of this value.
+    ///
+    /// # Arguments
+    ///
+    /// * `accuracy` - Accuracy tier.
+    ///
+    /// # Returns
+    ///
+    /// Inverse hyperbolic tangent of this value (see [`Trig::atanh`](crate::Trig::atanh)).
+    fn atanh_fast(&self, accuracy: Accuracy) -> Self;
+
+    /// Approximates the inverse hyperbolic cosecant of this value.
+    ///
+    /// # Arguments
+    ///
+    /// * `accuracy` - Accuracy tier.
+    ///
+    /// # Returns
+    ///
+    /// Inverse hyperbolic cosecant of this value (see [`Trig::acsch`](crate::Trig::acsch)).
+    fn acsch_fast(&self, accuracy: Accuracy) -> Self;
+
+    /// Approximates the inverse hyperbolic secant of this value.
+    ///
+    /// # Arguments
+    ///
+    /// * `accuracy` - Accuracy tier.
+    ///
+    /// # Returns
+    ///
+    /// Inverse hyperbolic secant of this value (see [`Trig::asech`](crate::Trig::asech)).
+    fn asech_fast(&self, accuracy: Accuracy) -> Self;
+
+    /// Approximates the inverse hyperbolic cotangent of this value.
+    ///
+    /// # Arguments
+    ///
+    /// * `accuracy` - Accuracy tier.
+    ///
+    /// # Returns
+    ///
+    /// Inverse hyperbolic cotangent of this value (see [`Trig::acoth`](crate::Trig::acoth)).
+    fn acoth_fast(&self, accuracy: Accuracy) -> Self;
+}
@@ -0,0 +1,1748 @@
+use crate::interval::Unit;
+use crate::math::{self, ln};
+use crate::trig_trait::Trig;
+use core::f64::consts::{FRAC_1_PI, LN_2, PI};
+use core::fmt;
+
+/// A radian in degrees.
+const DEGREES: f64 = 180.0 / PI;
+
+/// Error returned by the `_integrate` methods of [`TrigIntegral`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum IntegralError<T> {
+    /// The interval of integration contains a pole of the integrand (at the given point), so the
+    /// integral diverges.
+    Pole(T),
+
+    /// A limit of integration is `NaN`, or the interval of integration is not contained in the
+    /// domain of the integrand.
+    Domain,
+
+    /// The integral diverges (e.g. over an infinite interval) or its value overflows.
+    NotFinite,
+}
+
+impl<T: fmt::Display> fmt::Display for IntegralError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IntegralError::Pole(x) => write!(f, "the integrand has a pole at {x}"),
+            IntegralError::Domain => {
+                write!(f, "the interval is outside the domain of the integrand")
+            }
+            IntegralError::NotFinite => write!(f, "the integral diverges or overflows"),
+        }
+    }
+}
+
+impl<T: fmt::Debug + fmt::Display> core::error::Error for IntegralError<T> {}
+
+/// Antiderivatives and definite integrals of the trigonometric and hyperbolic functions.
+///
+/// This trait mirrors the [`Trig`] trait: every single-argument [`Trig`] method has a counterpart
+/// with an `_antideriv` suffix that evaluates its antiderivative $F$ (with the formulas of standard
+/// tables, e.g. $\int\sec(x)\,dx=\ln|\sec(x)+\tan(x)|$) and one with an `_integrate` suffix that
+/// evaluates the definite integral $\int_a^bf(x)\,dx=F(b)-F(a)$ from this value $a$ to an upper
+/// limit $b$ (which may be smaller than $a$).
+///
+/// Before evaluating $F(b)-F(a)$, the `_integrate` methods check the interval of integration, so
+/// that they return an [`IntegralError`] instead of a meaningless number when the interval contains
+/// a pole of the integrand (e.g. $\int_0^\pi\tan(x)\,dx$, where $F(b)-F(a)=0$) or leaves the domain
+/// of an inverse function. The poles are located exactly (the limits are reduced exactly modulo a
+/// quarter turn), so an interval that ends at the rounded position of a pole in radians (e.g.
+/// [`FRAC_PI_2`](core::f64::consts::FRAC_PI_2), just below $\frac{\pi}{2}$) does not contain it,
+/// and its integral is large but finite. An empty interval ($a=b$) has an integral of $0$, and
+/// singularities that are integrable (e.g. of `atanh` at $\pm1$ or `acsch` at $0$) are not errors.
+///
+/// # Note
+///
+/// On [`f32`], the antiderivatives and integrals are evaluated on [`f64`] and rounded.
+///
+/// # Example
+///
+/// ```
+/// use trig::{IntegralError, TrigIntegral};
+///
+/// // ∫ sec(x) dx from 0 to π/4 = ln(1 + √2).
+/// let integral = 0.0_f64.sec_integrate(&std::f64::consts::FRAC_PI_4).unwrap();
+/// assert!((integral - 2.0_f64.sqrt().ln_1p()).abs() < 1e-15);
+///
+/// // tand has a pole at 90°.
+/// assert_eq!(45.0_f64.tand_integrate(&135.0), Err(IntegralError::Pole(90.0)));
+///
+/// // acoth is only defined outside of [-1, 1].
+/// assert_eq!(0.5_f64.acoth_integrate(&2.0), Err(IntegralError::Domain));
+/// ```
+pub trait TrigIntegral: Sized {
+    /// Antiderivative of the sine function $f(x)=\sin(x)$ ($x$ in radians).
+    ///
+    /// # Returns
+    ///
+    /// $F(x)=-\cos(x)$.
+    fn sin_antideriv(&self) -> Self;
+
+    /// Definite integral of the sine function $f(x)=\sin(x)$ ($x$ in radians) from this value to
+    /// `upper`.
+    ///
+    /// # Arguments
+    ///
+    /// * `upper` - Upper limit of integration $b$ (with this value as the lower limit $a$).
+    ///
+    /// # Returns
+    ///
+    /// $\int_a^bf(x)\,dx=F(b)-F(a)$, where $F$ is [`TrigIntegral::sin_antideriv`].
+    ///
+    /// # Errors
+    ///
+    /// * [`IntegralError::NotFinite`] if the integral diverges or overflows.
+    fn sin_integrate(&self, upper: &Self) -> Result<Self, IntegralError<Self>>;
+
+    /// Antiderivative of the cosine function $f(x)=\cos(x)$ ($x$ in radians).
+    ///
+    /// # Returns
+    ///
+    /// $F(x)=\sin(x)$.
+    fn cos_antideriv(&self) -> Self;
+
+    /// Definite integral of the cosine function $f(x)=\cos(x)$ ($x$ in radians) from this value to
+    /// `upper`.
+    ///
+    /// # Arguments
+    ///
+    /// * `upper` - Upper limit of integration $b$ (with this value as the lower limit $a$).
+    ///
+    /// # Returns
+    ///
+    /// $\int_a^bf(x)\,dx=F(b)-F(a)$, where $F$ is [`TrigIntegral::cos_antideriv`].
+    ///
+    /// # Errors
+    ///
+    /// * [`IntegralError::NotFinite`] if the integral diverges or overflows.
+    fn cos_integrate(&self, upper: &Self) -> Result<Self, IntegralError<Self>>;
+
+    /// Antiderivative of the tangent function $f(x)=\tan(x)$ ($x$ in radians).
+    ///
+    /// # Returns
+    ///
+    /// $F(x)=-\ln|\cos(x)|$.
+    fn tan_antideriv(&self) -> Self;
+
+    /// Definite integral of the tangent function $f(x)=\tan(x)$ ($x$ in radians) from this value to
+    /// `upper`.
+    ///
+    /// # Arguments
+    ///
+    /// * `upper` - Upper limit of integration $b$ (with this value as the lower limit $a$).
+    ///
+    /// # Returns
+    ///
+    /// $\int_a^bf(x)\,dx=F(b)-F(a)$, where $F$ is [`TrigIntegral::tan_antideriv`].
+    ///
+    /// # Errors
+    ///
+    /// * [`IntegralError::Pole`] if the interval between $a$ and $b$ contains a pole
+    ///   $x=\frac{\pi}{2}+k\pi$.
+    /// * [`IntegralError::NotFinite`] if the integral diverges or overflows.
+    fn tan_integrate(&self, upper: &Self) -> Result<Self, IntegralError<Self>>;
+
+    /// Antiderivative of the cosecant function $f(x)=\csc(x)$ ($x$ in radians).
+    ///
+    /// # Returns
+    ///
+    /// $F(x)=\ln\left|\tan\left(\frac{x}{2}\right)\right|$.
+    fn csc_antideriv(&self) -> Self;
+
+    /// Definite integral of the cosecant function $f(x)=\csc(x)$ ($x$ in radians) from this value
+    /// to `upper`.
+    ///
+    /// # Arguments
+    ///
+    /// * `upper` - Upper limit of integration $b$ (with this value as the lower limit $a$).
+    ///
+    /// # Returns
+    ///
+    /// $\int_a^bf(x)\,dx=F(b)-F(a)$, where $F$ is [`TrigIntegral::csc_antideriv`].
+    ///
+    /// # Errors
+    ///
+    /// * [`IntegralError::Pole`] if the interval between $a$ and $b$ contains a pole $x=k\pi$.
+    /// * [`IntegralError::NotFinite`] if the integral diverges or overflows.
+    fn csc_integrate(&self, upper: &Self) -> Result<Self, IntegralError<Self>>;
+
+    /// Antiderivative of the secant function $f(x)=\sec(x)$ ($x$ in radians).
+    ///
+    /// # Returns
+    ///
+    /// $F(x)=\ln|\sec(x)+\tan(x)|$.
+    fn sec_antideriv(&self) -> Self;
+
+    /// Definite integral of the secant function $f(x)=\sec(x)$ ($x$ in radians) from this value to
+    /// `upper`.
+    ///
+    /// # Arguments
+    ///
+    /// * `upper` - Upper limit of integration $b$ (with this value as the lower limit $a$).
+    ///
+    /// # Returns
+    ///
+    /// $\int_a^bf(x)\,dx=F(b)-F(a)$, where $F$ is [`TrigIntegral::sec_antideriv`].
+    ///
+    /// # Errors
+    ///
+    /// * [`IntegralError::Pole`] if the interval between $a$ and $b$ contains a pole
+    ///   $x=\frac{\pi}{2}+k\pi$.
+    /// * [`IntegralError::NotFinite`] if the integral diverges or overflows.
+    fn sec_integrate(&self, upper: &Self) -> Result<Self, IntegralError<Self>>;
+
+    /// Antiderivative of the cotangent function $f(x)=\cot(x)$ ($x$ in radians).
+    ///
+    /// # Returns
+    ///
+    /// $F(x)=\ln|\sin(x)|$.
+    fn cot_antideriv(&self) -> Self;
+
+    /// Definite integral of the cotangent function $f(x)=\cot(x)$ ($x$ in radians) from this value
+    /// to `upper`.
+    ///
+    /// # Arguments
+    ///
+    /// * `upper` - Upper limit of integration $b$ (with this value as the lower limit $a$).
+    ///
+    /// # Returns
+    ///
+    /// $\int_a^bf(x)\,dx=F(b)-F(a)$, where $F$ is [`TrigIntegral::cot_antideriv`].
+    ///
+    /// # Errors
+    ///
+    /// * [`IntegralError::Pole`] if the interval between $a$ and $b$ contains a pole $x=k\pi$.
+    /// * [`IntegralError::NotFinite`] if the integral diverges or overflows.
+    fn cot_integrate(&self, upper: &Self) -> Result<Self, IntegralError<Self>>;
+
+    /// Antiderivative of the inverse sine function $f(x)=\arcsin(x)$ (in radians).
+    ///
+    /// # Returns
+    ///
+    /// $F(x)=x\arcsin(x)+\sqrt{1-x^2}$.
+    fn asin_antideriv(&self) -> Self;
+
+    /// Definite integral of the inverse sine function $f(x)=\arcsin(x)$ (in radians) from this
+    /// value to `upper`.
+    ///
+    /// # Arguments
+    ///
+    /// * `upper` - Upper limit of integration $b$ (with this value as the lower limit $a$).
+    ///
+    /// # Returns
+    ///
+    /// $\int_a^bf(x)\,dx=F(b)-F(a)$, where $F$ is [`TrigIntegral::asin_antideriv`].
+    ///
+    /// # Errors
+    ///
+    /// * [`IntegralError::Domain`] if the interval between $a$ and $b$ is not contained in
+    ///   $\[-1,1\]$.
+    /// * [`IntegralError::NotFinite`] if the integral diverges or overflows.
+    fn asin_integrate(&self, upper: &Self) -> Result<Self, IntegralError<Self>>;
+
+    /// Antiderivative of the inverse cosine function $f(x)=\arccos(x)$ (in radians).
+    ///
+    /// # Returns
+    ///
+    /// $F(x)=x\arccos(x)-\sqrt{1-x^2}$.
+    fn acos_antideriv(&self) -> Self;
+
+    /// Definite integral of the inverse cosine function $f(x)=\arccos(x)$ (in radians) from this
+    /// value to `upper`.
+    ///
+    /// # Arguments
+    ///
+    /// * `upper` - Upper limit of integration $b$ (with this value as the lower limit $a$).
+    ///
+    /// # Returns
+    ///
+    /// $\int_a^bf(x)\,dx=F(b)-F(a)$, where $F$ is [`TrigIntegral::acos_antideriv`].
+    ///
+    /// # Errors
+    ///
+    /// * [`IntegralError::Domain`] if the interval between $a$ and $b$ is not contained in
+    ///   $\[-1,1\]$.
+    /// * [`IntegralError::NotFinite`] if the integral diverges or overflows.
+    fn acos_integrate(&self, upper: &Self) -> Result<Self, IntegralError<Self>>;
+
+    /// Antiderivative of the inverse tangent function $f(x)=\arctan(x)$ (in radians).
+    ///
+    /// # Returns
+    ///
+    /// $F(x)=x\arctan(x)-\frac{1}{2}\ln\left(1+x^2\right)$.
+    fn atan_antideriv(&self) -> Self;
+
+    /// Definite integral of the inverse tangent function $f(x)=\arctan(x)$ (in radians) from this
+    /// value to `upper`.
+    ///
+    /// # Arguments
+    ///
+    /// * `upper` - Upper limit of integration $b$ (with this value as the lower limit $a$).
+    ///
+    /// # Returns
+    ///
+    /// $\int_a^bf(x)\,dx=F(b)-F(a)$, where $F$ is [`TrigIntegral::atan_antideriv`].
+    ///
+    /// # Errors
+    ///
+    /// * [`IntegralError::NotFinite`] if the integral diverges or overflows.
+    fn atan_integrate(&self, upper: &Self) -> Result<Self, IntegralError<Self>>;
+
+    /// Antiderivative of the inverse cosecant function $f(x)=\text{arccsc}(x)$ (in radians).
+    ///
+    /// # Returns
+    ///
+    /// $F(x)=x\,\text{arccsc}(x)+\text{arccosh}|x|$.
+    fn acsc_antideriv(&self) -> Self;
+
+    /// Definite integral of the inverse cosecant function $f(x)=\text{arccsc}(x)$ (in radians) from
+    /// this value to `upper`.
+    ///
+    /// # Arguments
+    ///
+    /// * `upper` - Upper limit of integration $b$ (with this value as the lower limit $a$).
+    ///
+    /// # Returns
+    ///
+    /// $\int_a^bf(x)\,dx=F(b)-F(a)$, where $F$ is [`TrigIntegral::acsc_antideriv`].
+    ///
+    /// # Errors
+    ///
+    /// * [`IntegralError::Domain`] if the interval between $a$ and $b$ is not contained in
+    ///   $(-\infty,-1\]\cup\[1,\infty)$.
+    /// * [`IntegralError::NotFinite`] if the integral diverges or overflows.
+    fn acsc_integrate(&self, upper: &Self) -> Result<Self, IntegralError<Self>>;
+
+    /// Antiderivative of the inverse secant function $f(x)=\text{arcsec}(x)$ (in radians).
+    ///
+    /// # Returns
+    ///
+    /// $F(x)=x\,\text{arcsec}(x)-\text{arccosh}|x|$.
+    fn asec_antideriv(&self) -> Self;
+
+    /// Definite integral of the inverse secant function $f(x)=\text{arcsec}(x)$ (in radians) from
+    /// this value to `upper`.
+    ///
+    /// # Arguments
+    ///
+    /// * `upper` - Upper limit of integration $b$ (with this value as the lower limit $a$).
+    ///
+    /// # Returns
+    ///
+    /// $\int_a^bf(x)\,dx=F(b)-F(a)$, where $F$ is [`TrigIntegral::asec_antideriv`].
+    ///
+    /// # Errors
+    ///
+    /// * [`IntegralError::Domain`] if the interval between $a$ and $b$ is not contained in
+    ///   $(-\infty,-1\]\cup\[1,\infty)$.
+    /// * [`IntegralError::NotFinite`] if the integral diverges or overflows.
+    fn asec_integrate(&self, upper: &Self) -> Result<Self, IntegralError<Self>>;
+
+    /// Antiderivative of the inverse cotangent function $f(x)=\text{arccot}(x)$ (in radians).
+    ///
+    /// # Returns
+    ///
+    /// $F(x)=x\,\text{arccot}(x)+\frac{1}{2}\ln\left(1+x^2\right)$.
+    fn acot_antideriv(&self) -> Self;
+
+    /// Definite integral of the inverse cotangent function $f(x)=\text{arccot}(x)$ (in radians)
+    /// from this value to `upper`.
+    ///
+    /// # Arguments
+    ///
+    /// * `upper` - Upper limit of integration $b$ (with this value as the lower limit $a$).
+    ///
+    /// # Returns
+    ///
+    /// $\int_a^bf(x)\,dx=F(b)-F(a)$, where $F$ is [`TrigIntegral::acot_antideriv`].
+    ///
+    /// # Errors
+    ///
+    /// * [`IntegralError::NotFinite`] if the integral diverges or overflows.
+    fn acot_integrate(&self, upper: &Self) -> Result<Self, IntegralError<Self>>;
+
+    /// Antiderivative of the conversion from degrees to radians $f(x)=\frac{\pi}{180}x$.
+    ///
+    /// # Returns
+    ///
+    /// $F(x)=\frac{\pi}{360}x^2$.
+    fn deg2rad_antideriv(&self) -> Self;
+
+    /// Definite integral of the conversion from degrees to radians $f(x)=\frac{\pi}{180}x$ from
+    /// this value to `upper`.
+    ///
+    /// # Arguments
+    ///
+    /// * `upper` - Upper limit of integration $b$ (with this value as the lower limit $a$).
+    ///
+    /// # Returns
+    ///
+    /// $\int_a^bf(x)\,dx=F(b)-F(a)$, where $F$ is [`TrigIntegral::deg2rad_antideriv`].
+    ///
+    /// # Errors
+    ///
+    /// * [`IntegralError::NotFinite`] if the integral diverges or overflows.
+    fn deg2rad_integrate(&self, upper: &Self) -> Result<Self, IntegralError<Self>>;
+
+    /// Antiderivative of the conversion from radians to degrees $f(x)=\frac{180}{\pi}x$.
+    ///
+    /// # Returns
+    ///
+    /// $F(x)=\frac{90}{\pi}x^2$.
+    fn rad2deg_antideriv(&self) -> Self;
+
+    /// Definite integral of the conversion from radians to degrees $f(x)=\frac{180}{\pi}x$ from
+    /// this value to `upper`.
+    ///
+    /// # Arguments
+    ///
+    /// * `upper` - Upper limit of integration $b$ (with this value as the lower limit $a$).
+    ///
+    /// # Returns
+    ///
+    /// $\int_a^bf(x)\,dx=F(b)-F(a)$, where $F$ is [`TrigIntegral::rad2deg_antideriv`].
+    ///
+    /// # Errors
+    ///
+    /// * [`IntegralError::NotFinite`] if the integral diverges or overflows.
+    fn rad2deg_integrate(&self, upper: &Self) -> Result<Self, IntegralError<Self>>;
+
+    /// Antiderivative of the sine function $f(x)=\text{sind}(x)$ ($x$ in degrees).
+    ///
+    /// # Returns
+    ///
+    /// $F(x)=-\frac{180}{\pi}\text{cosd}(x)$.
+    fn sind_antideriv(&self) -> Self;
+
+    /// Definite integral of the sine function $f(x)=\text{sind}(x)$ ($x$ in degrees) from this
+    /// value to `upper`.
+    ///
+    /// # Arguments
+    ///
+    /// * `upper` - Upper limit of integration $b$ (with this value as the lower limit $a$).
+    ///
+    /// # Returns
+    ///
+    /// $\int_a^bf(x)\,dx=F(b)-F(a)$, where $F$ is [`TrigIntegral::sind_antideriv`].
+    ///
+    /// # Errors
+    ///
+    /// * [`IntegralError::NotFinite`] if the integral diverges or overflows.
+    fn sind_integrate(&self, upper: &Self) -> Result<Self, IntegralError<Self>>;
+
+    /// Antiderivative of the cosine function $f(x)=\text{cosd}(x)$ ($x$ in degrees).
+    ///
+    /// # Returns
+    ///
+    /// $F(x)=\frac{180}{\pi}\text{sind}(x)$.
+    fn cosd_antideriv(&self) -> Self;
+
+    /// Definite integral of the cosine function $f(x)=\text{cosd}(x)$ ($x$ in degrees) from this
+    /// value to `upper`.
+    ///
+    /// # Arguments
+    ///
+    /// * `upper` - Upper limit of integration $b$ (with this value as the lower limit $a$).
+    ///
+    /// # Returns
+    ///
+    /// $\int_a^bf(x)\,dx=F(b)-F(a)$, where $F$ is [`TrigIntegral::cosd_antideriv`].
+    ///
+    /// # Errors
+    ///
+    /// * [`IntegralError::NotFinite`] if the integral diverges or overflows.
+    fn cosd_integrate(&self, upper: &Self) -> Result<Self, IntegralError<Self>>;
+
+    /// Antiderivative of the tangent function $f(x)=\text{tand}(x)$ ($x$ in degrees).
+    ///
+    /// # Returns
+    ///
+    /// $F(x)=-\frac{180}{\pi}\ln|\text{cosd}(x)|$.
+    fn tand_antideriv(&self) -> Self;
+
+    /// Definite integral of the tangent function $f(x)=\text{tand}(x)$ ($x$ in degrees) from this
+    /// value to `upper`.
+    ///
+    /// # Arguments
+    ///
+    /// * `upper` - Upper limit of integration $b$ (with this value as the lower limit $a$).
+    ///
+    /// # Returns
+    ///
+    /// $\int_a^bf(x)\,dx=F(b)-F(a)$, where $F$ is [`TrigIntegral::tand_antideriv`].
+    ///
+    /// # Errors
+    ///
+    /// * [`IntegralError::Pole`] if the interval between $a$ and $b$ contains a pole $x=90+180k$.
+    /// * [`IntegralError::NotFinite`] if the integral diverges or overflows.
+    fn tand_integrate(&self, upper: &Self) -> Result<Self, IntegralError<Self>>;
+
+    /// Antiderivative of the cosecant function $f(x)=\text{cscd}(x)$ ($x$ in degrees).
+    ///
+    /// # Returns
+    ///
+    /// $F(x)=\frac{180}{\pi}\ln\left|\text{tand}\left(\frac{x}{2}\right)\right|$.
+    fn cscd_antideriv(&self) -> Self;
+
+    /// Definite integral of the cosecant function $f(x)=\text{cscd}(x)$ ($x$ in degrees) from this
+    /// value to `upper`.
+    ///
+    /// # Arguments
+    ///
+    /// * `upper` - Upper limit of integration $b$ (with this value as the lower limit $a$).
+    ///
+    /// # Returns
+    ///
+    /// $\int_a^bf(x)\,dx=F(b)-F(a)$, where $F$ is [`TrigIntegral::cscd_antideriv`].
+    ///
+    /// # Errors
+    ///
+    /// * [`IntegralError::Pole`] if the interval between $a$ and $b$ contains a pole $x=180k$.
+    /// * [`IntegralError::NotFinite`] if the integral diverges or overflows.
+    fn cscd_integrate(&self, upper: &Self) -> Result<Self, IntegralError<Self>>;
+
+    /// Antiderivative of the secant function $f(x)=\text{secd}(x)$ ($x$ in degrees).
+    ///
+    /// # Returns
+    ///
+    /// $F(x)=\frac{180}{\pi}\ln|\text{secd}(x)+\text{tand}(x)|$.
+    fn secd_antideriv(&self) -> Self;
+
+    /// Definite integral of the secant function $f(x)=\text{secd}(x)$ ($x$ in degrees) from this
+    /// value to `upper`.
+    ///
+    /// # Arguments
+    ///
+    /// * `upper` - Upper limit of integration $b$ (with this value as the lower limit $a$).
+    ///
+    /// # Returns
+    ///
+    /// $\int_a^bf(x)\,dx=F(b)-F(a)$, where $F$ is [`TrigIntegral::secd_antideriv`].
+    ///
+    /// # Errors
+    ///
+    /// * [`IntegralError::Pole`] if the interval between $a$ and $b$ contains a pole $x=90+180k$.
+    /// * [`IntegralError::NotFinite`] if the integral diverges or overflows.
+    fn secd_integrate(&self, upper: &Self) -> Result<Self, IntegralError<Self>>;
+
+    /// Antiderivative of the cotangent function $f(x)=\text{cotd}(x)$ ($x$ in degrees).
+    ///
+    /// # Returns
+    ///
+    /// $F(x)=\frac{180}{\pi}\ln|\text{sind}(x)|$.
+    fn cotd_antideriv(&self) -> Self;
+
+    /// Definite integral of the cotangent function $f(x)=\text{cotd}(x)$ ($x$ in degrees) from this
+    /// value to `upper`.
+    ///
+    /// # Arguments
+    ///
+    /// * `upper` - Upper limit of integration $b$ (with this value as the lower limit $a$).
+    ///
+    /// # Returns
+    ///
+    /// $\int_a^bf(x)\,dx=F(b)-F(a)$, where $F$ is [`TrigIntegral::cotd_antideriv`].
+    ///
+    /// # Errors
+    ///
+    /// * [`IntegralError::Pole`] if the interval between $a$ and $b$ contains a pole $x=180k$.
+    /// * [`IntegralError::NotFinite`] if the integral diverges or overflows.
+    fn cotd_integrate(&self, upper: &Self) -> Result<Self, IntegralError<Self>>;
+
+    /// Antiderivative of the inverse sine function $f(x)=\text{asind}(x)$ (in degrees).
+    ///
+    /// # Returns
+    ///
+    /// $F(x)=x\,\text{asind}(x)+\frac{180}{\pi}\sqrt{1-x^2}$.
+    fn asind_antideriv(&self) -> Self;
+
+    /// Definite integral of the inverse sine function $f(x)=\text{asind}(x)$ (in degrees) from this
+    /// value to `upper`.
+    ///
+    /// # Arguments
+    ///
+    /// * `upper` - Upper limit of integration $b$ (with this value as the lower limit $a$).
+    ///
+    /// # Returns
+    ///
+    /// $\int_a^bf(x)\,dx=F(b)-F(a)$, where $F$ is [`TrigIntegral::asind_antideriv`].
+    ///
+    /// # Errors
+    ///
+    /// * [`IntegralError::Domain`] if the interval between $a$ and $b$ is not contained in
+    ///   $\[-1,1\]$.
+    /// * [`IntegralError::NotFinite`] if the integral diverges or overflows.
+    fn asind_integrate(&self, upper: &Self) -> Result<Self, IntegralError<Self>>;
+
+    /// Antiderivative of the inverse cosine function $f(x)=\text{acosd}(x)$ (in degrees).
+    ///
+    /// # Returns
+    ///
+    /// $F(x)=x\,\text{acosd}(x)-\frac{180}{\pi}\sqrt{1-x^2}$.
+    fn acosd_antideriv(&self) -> Self;
+
+    /// Definite integral of the inverse cosine function $f(x)=\text{acosd}(x)$ (in degrees) from
+    /// this value to `upper`.
+    ///
+    /// # Arguments
+    ///
+    /// * `upper` - Upper limit of integration $b$ (with this value as the lower limit $a$).
+    ///
+    /// # Returns
+    ///
+    /// $\int_a^bf(x)\,dx=F(b)-F(a)$, where $F$ is [`TrigIntegral::acosd_antideriv`].
+    ///
+    /// # Errors
+    ///
+    /// * [`IntegralError::Domain`] if the interval between $a$ and $b$ is not contained in
+    ///   $\[-1,1\]$.
+    /// * [`IntegralError::NotFinite`] if the integral diverges or overflows.
+    fn acosd_integrate(&self, upper: &Self) -> Result<Self, IntegralError<Self>>;
+
+    /// Antiderivative of the inverse tangent function $f(x)=\text{atand}(x)$ (in degrees).
+    ///
+    /// # Returns
+    ///
+    /// $F(x)=x\,\text{atand}(x)-\frac{90}{\pi}\ln\left(1+x^2\right)$.
+    fn atand_antideriv(&self) -> Self;
+
+    /// Definite integral of the inverse tangent function $f(x)=\text{atand}(x)$ (in degrees) from
+    /// this value to `upper`.
+    ///
+    /// # Arguments
+    ///
+    /// * `upper` - Upper limit of integration $b$ (with this value as the lower limit $a$).
+    ///
+    /// # Returns
+    ///
+    /// $\int_a^bf(x)\,dx=F(b)-F(a)$, where $F$ is [`TrigIntegral::atand_antideriv`].
+    ///
+    /// # Errors
+    ///
+    /// * [`IntegralError::NotFinite`] if the integral diverges or overflows.
+    fn atand_integrate(&self, upper: &Self) -> Result<Self, IntegralError<Self>>;
+
+    /// Antiderivative of the inverse cosecant function $f(x)=\text{acscd}(x)$ (in degrees).
+    ///
+    /// # Returns
+    ///
+    /// $F(x)=x\,\text{acscd}(x)+\frac{180}{\pi}\text{arccosh}|x|$.
+    fn acscd_antideriv(&self) -> Self;
+
+    /// Definite integral of the inverse cosecant function $f(x)=\text{acscd}(x)$ (in degrees) from
+    /// this value to `upper`.
+    ///
+    /// # Arguments
+    ///
+    /// * `upper` - Upper limit of integration $b$ (with this value as the lower limit $a$).
+    ///
+    /// # Returns
+    ///
+    /// $\int_a^bf(x)\,dx=F(b)-F(a)$, where $F$ is [`TrigIntegral::acscd_antideriv`].
+    ///
+    /// # Errors
+    ///
+    /// * [`IntegralError::Domain`] if the interval between $a$ and $b$ is not contained in
+    ///   $(-\infty,-1\]\cup\[1,\infty)$.
+    /// * [`IntegralError::NotFinite`] if the integral diverges or overflows.
+    fn acscd_integrate(&self, upper: &Self) -> Result<Self, IntegralError<Self>>;
+
+    /// Antiderivative of the inverse secant function $f(x)=\text{asecd}(x)$ (in degrees).
+    ///
+    /// # Returns
+    ///
+    /// $F(x)=x\,\text{asecd}(x)-\frac{180}{\pi}\text{arccosh}|x|$.
+    fn asecd_antideriv(&self) -> Self;
+
+    /// Definite integral of the inverse secant function $f(x)=\text{asecd}(x)$ (in degrees) from
+    /// this value to `upper`.
+    ///
+    /// # Arguments
+    ///
+    /// * `upper` - Upper limit of integration $b$ (with this value as the lower limit $a$).
+    ///
+    /// # Returns
+    ///
+    /// $\int_a^bf(x)\,dx=F(b)-F(a)$, where $F$ is [`TrigIntegral::asecd_antideriv`].
+    ///
+    /// # Errors
+    ///
+    /// * [`IntegralError::Domain`] if the interval between $a$ and $b$ is not contained in
+    ///   $(-\infty,-1\]\cup\[1,\infty)$.
+    /// * [`IntegralError::NotFinite`] if the integral diverges or overflows.
+    fn asecd_integrate(&self, upper: &Self) -> Result<Self, IntegralError<Self>>;
+
+    /// Antiderivative of the inverse cotangent function $f(x)=\text{acotd}(x)$ (in degrees).
+    ///
+    /// # Returns
+    ///
+    /// $F(x)=x\,\text{acotd}(x)+\frac{90}{\pi}\ln\left(1+x^2\right)$.
+    fn acotd_antideriv(&self) -> Self;
+
+    /// Definite integral of the inverse cotangent function $f(x)=\text{acotd}(x)$ (in degrees) from
+    /// this value to `upper`.
+    ///
+    /// # Arguments
+    ///
+    /// * `upper` - Upper limit of integration $b$ (with this value as the lower limit $a$).
+    ///
+    /// # Returns
+    ///
+    /// $\int_a^bf(x)\,dx=F(b)-F(a)$, where $F$ is [`TrigIntegral::acotd_antideriv`].
+    ///
+    /// # Errors
+    ///
+    /// * [`IntegralError::NotFinite`] if the integral diverges or overflows.
+    fn acotd_integrate(&self, upper: &Self) -> Result<Self, IntegralError<Self>>;
+
+    /// Antiderivative of the function $f(x)=\sin(\pi x)$.
+    ///
+    /// # Returns
+    ///
+    /// $F(x)=-\frac{\cos(\pi x)}{\pi}$.
+    fn sinpi_antideriv(&self) -> Self;
+
+    /// Definite integral of the function $f(x)=\sin(\pi x)$ from this value to `upper`.
+    ///
+    /// # Arguments
+    ///
+    /// * `upper` - Upper limit of integration $b$ (with this value as the lower limit $a$).
+    ///
+    /// # Returns
+    ///
+    /// $\int_a^bf(x)\,dx=F(b)-F(a)$, where $F$ is [`TrigIntegral::sinpi_antideriv`].
+    ///
+    /// # Errors
+    ///
+    /// * [`IntegralError::NotFinite`] if the integral diverges or overflows.
+    fn sinpi_integrate(&self, upper: &Self) -> Result<Self, IntegralError<Self>>;
+
+    /// Antiderivative of the function $f(x)=\cos(\pi x)$.
+    ///
+    /// # Returns
+    ///
+    /// $F(x)=\frac{\sin(\pi x)}{\pi}$.
+    fn cospi_antideriv(&self) -> Self;
+
+    /// Definite integral of the function $f(x)=\cos(\pi x)$ from this value to `upper`.
+    ///
+    /// # Arguments
+    ///
+    /// * `upper` - Upper limit of integration $b$ (with this value as the lower limit $a$).
+    ///
+    /// # Returns
+    ///
+    /// $\int_a^bf(x)\,dx=F(b)-F(a)$, where $F$ is [`TrigIntegral::cospi_antideriv`].
+    ///
+    /// # Errors
+    ///
+    /// * [`IntegralError::NotFinite`] if the integral diverges or overflows.
+    fn cospi_integrate(&self, upper: &Self) -> Result<Self, IntegralError<Self>>;
+
+    /// Antiderivative of the function $f(x)=\tan(\pi x)$.
+    ///
+    /// # Returns
+    ///
+    /// $F(x)=-\frac{\ln|\cos(\pi x)|}{\pi}$.
+    fn tanpi_antideriv(&self) -> Self;
+
+    /// Definite integral of the function $f(x)=\tan(\pi x)$ from this value to `upper`.
+    ///
+    /// # Arguments
+    ///
+    /// * `upper` - Upper limit of integration $b$ (with this value as the lower limit $a$).
+    ///
+    /// # Returns
+    ///
+    /// $\int_a^bf(x)\,dx=F(b)-F(a)$, where $F$ is [`TrigIntegral::tanpi_antideriv`].
+    ///
+    /// # Errors
+    ///
+    /// * [`IntegralError::Pole`] if the interval between $a$ and $b$ contains a pole
+    ///   $x=\frac{1}{2}+k$.
+    /// * [`IntegralError::NotFinite`] if the integral diverges or overflows.
+    fn tanpi_integrate(&self, upper: &Self) -> Result<Self, IntegralError<Self>>;
+
+    /// Antiderivative of the function $f(x)=\frac{\arcsin(x)}{\pi}$.
+    ///
+    /// # Returns
+    ///
+    /// $F(x)=x\frac{\arcsin(x)}{\pi}+\frac{\sqrt{1-x^2}}{\pi}$.
+    fn asinpi_antideriv(&self) -> Self;
+
+    /// Definite integral of the function $f(x)=\frac{\arcsin(x)}{\pi}$ from this value to `upper`.
+    ///
+    /// # Arguments
+    ///
+    /// * `upper` - Upper limit of integration $b$ (with this value as the lower limit $a$).
+    ///
+    /// # Returns
+    ///
+    /// $\int_a^bf(x)\,dx=F(b)-F(a)$, where $F$ is [`TrigIntegral::asinpi_antideriv`].
+    ///
+    /// # Errors
+    ///
+    /// * [`IntegralError::Domain`] if the interval between $a$ and $b$ is not contained in
+    ///   $\[-1,1\]$.
+    /// * [`IntegralError::NotFinite`] if the integral diverges or overflows.
+    fn asinpi_integrate(&self, upper: &Self) -> Result<Self, IntegralError<Self>>;
+
+    /// Antiderivative of the function $f(x)=\frac{\arccos(x)}{\pi}$.
+    ///
+    /// # Returns
+    ///
+    /// $F(x)=x\frac{\arccos(x)}{\pi}-\frac{\sqrt{1-x^2}}{\pi}$.
+    fn acospi_antideriv(&self) -> Self;
+
+    /// Definite integral of the function $f(x)=\frac{\arccos(x)}{\pi}$ from this value to `upper`.
+    ///
+    /// # Arguments
+    ///
+    /// * `upper` - Upper limit of integration $b$ (with this value as the lower limit $a$).
+    ///
+    /// # Returns
+    ///
+    /// $\int_a^bf(x)\,dx=F(b)-F(a)$, where $F$ is [`TrigIntegral::acospi_antideriv`].
+    ///
+    /// # Errors
+    ///
+    /// * [`IntegralError::Domain`] if the interval between $a$ and $b$ is not contained in
+    ///   $\[-1,1\]$.
+    /// * [`IntegralError::NotFinite`] if the integral diverges or overflows.
+    fn acospi_integrate(&self, upper: &Self) -> Result<Self, IntegralError<Self>>;
+
+    /// Antiderivative of the function $f(x)=\frac{\arctan(x)}{\pi}$.
+    ///
+    /// # Returns
+    ///
+    /// $F(x)=x\frac{\arctan(x)}{\pi}-\frac{\ln\left(1+x^2\right)}{2\pi}$.
+    fn atanpi_antideriv(&self) -> Self;
+
+    /// Definite integral of the function $f(x)=\frac{\arctan(x)}{\pi}$ from this value to `upper`.
+    ///
+    /// # Arguments
+    ///
+    /// * `upper` - Upper limit of integration $b$ (with this value as the lower limit $a$).
+    ///
+    /// # Returns
+    ///
+    /// $\int_a^bf(x)\,dx=F(b)-F(a)$, where $F$ is [`TrigIntegral::atanpi_antideriv`].
+    ///
+    /// # Errors
+    ///
+    /// * [`IntegralError::NotFinite`] if the integral diverges or overflows.
+    fn atanpi_integrate(&self, upper: &Self) -> Result<Self, IntegralError<Self>>;
+
+    /// Antiderivative of the hyperbolic sine function $f(x)=\sinh(x)$.
+    ///
+    /// # Returns
+    ///
+    /// $F(x)=\cosh(x)$.
+    fn sinh_antideriv(&self) -> Self;
+
+    /// Definite integral of the hyperbolic sine function $f(x)=\sinh(x)$ from this value to
+    /// `upper`.
+    ///
+    /// # Arguments
+    ///
+    /// * `upper` - Upper limit of integration $b$ (with this value as the lower limit $a$).
+    ///
+    /// # Returns
+    ///
+    /// $\int_a^bf(x)\,dx=F(b)-F(a)$, where $F$ is [`TrigIntegral::sinh_antideriv`].
+    ///
+    /// # Errors
+    ///
+    /// * [`IntegralError::NotFinite`] if the integral diverges or overflows.
+    fn sinh_integrate(&self, upper: &Self) -> Result<Self, IntegralError<Self>>;
+
+    /// Antiderivative of the hyperbolic cosine function $f(x)=\cosh(x)$.
+    ///
+    /// # Returns
+    ///
+    /// $F(x)=\sinh(x)$.
+    fn cosh_antideriv(&self) -> Self;
+
+    /// Definite integral of the hyperbolic cosine function $f(x)=\cosh(x)$ from this value to
+    /// `upper`.
+    ///
+    /// # Arguments
+    ///
+    /// * `upper` - Upper limit of integration $b$ (with this value as the lower limit $a$).
+    ///
+    /// # Returns
+    ///
+    /// $\int_a^bf(x)\,dx=F(b)-F(a)$, where $F$ is [`TrigIntegral::cosh_antideriv`].
+    ///
+    /// # Errors
+    ///
+    /// * [`IntegralError::NotFinite`] if the integral diverges or overflows.
+    fn cosh_integrate(&self, upper: &Self) -> Result<Self, IntegralError<Self>>;
+
+    /// Antiderivative of the hyperbolic tangent function $f(x)=\tanh(x)$.
+    ///
+    /// # Returns
+    ///
+    /// $F(x)=\ln(\cosh(x))$.
+    fn tanh_antideriv(&self) -> Self;
+
+    /// Definite integral of the hyperbolic tangent function $f(x)=\tanh(x)$ from this value to
+    /// `upper`.
+    ///
+    /// # Arguments
+    ///
+    /// * `upper` - Upper limit of integration $b$ (with this value as the lower limit $a$).
+    ///
+    /// # Returns
+    ///
+    /// $\int_a^bf(x)\,dx=F(b)-F(a)$, where $F$ is [`TrigIntegral::tanh_antideriv`].
+    ///
+    /// # Errors
+    ///
+    /// * [`IntegralError::NotFinite`] if the integral diverges or overflows.
+    fn tanh_integrate(&self, upper: &Self) -> Result<Self, IntegralError<Self>>;
+
+    /// Antiderivative of the hyperbolic cosecant function $f(x)=\text{csch}(x)$.
+    ///
+    /// # Returns
+    ///
+    /// $F(x)=\ln\left|\tanh\left(\frac{x}{2}\right)\right|$.
+    fn csch_antideriv(&self) -> Self;
+
+    /// Definite integral of the hyperbolic cosecant function $f(x)=\text{csch}(x)$ from this value
+    /// to `upper`.
+    ///
+    /// # Arguments
+    ///
+    /// * `upper` - Upper limit of integration $b$ (with this value as the lower limit $a$).
+    ///
+    /// # Returns
+    ///
+    /// $\int_a^bf(x)\,dx=F(b)-F(a)$, where $F$ is [`TrigIntegral::csch_antideriv`].
+    ///
+    /// # Errors
+    ///
+    /// * [`IntegralError::Pole`] if the interval between $a$ and $b$ contains a pole $x=0$.
+    /// * [`IntegralError::NotFinite`] if the integral diverges or overflows.
+    fn csch_integrate(&self, upper: &Self) -> Result<Self, IntegralError<Self>>;
+
+    /// Antiderivative of the hyperbolic secant function $f(x)=\text{sech}(x)$.
+    ///
+    /// # Returns
+    ///
+    /// $F(x)=2\arctan\left(\tanh\left(\frac{x}{2}\right)\right)$.
+    fn sech_antideriv(&self) -> Self;
+
+    /// Definite integral of the hyperbolic secant function $f(x)=\text{sech}(x)$ from this value to
+    /// `upper`.
+    ///
+    /// # Arguments
+    ///
+    /// * `upper` - Upper limit of integration $b$ (with this value as the lower limit $a$).
+    ///
+    /// # Returns
+    ///
+    /// $\int_a^bf(x)\,dx=F(b)-F(a)$, where $F$ is [`TrigIntegral::sech_antideriv`].
+    ///
+    /// # Errors
+    ///
+    /// * [`IntegralError::NotFinite`] if the integral diverges or overflows.
+    fn sech_integrate(&self, upper: &Self) -> Result<Self, IntegralError<Self>>;
+
+    /// Antiderivative of the hyperbolic cotangent function $f(x)=\coth(x)$.
+    ///
+    /// # Returns
+    ///
+    /// $F(x)=\ln|\sinh(x)|$.
+    fn coth_antideriv(&self) -> Self;
+
+    /// Definite integral of the hyperbolic cotangent function $f(x)=\coth(x)$ from this value to
+    /// `upper`.
+    ///
+    /// # Arguments
+    ///
+    /// * `upper` - Upper limit of integration $b$ (with this value as the lower limit $a$).
+    ///
+    /// # Returns
+    ///
+    /// $\int_a^bf(x)\,dx=F(b)-F(a)$, where $F$ is [`TrigIntegral::coth_antideriv`].
+    ///
+    /// # Errors
+    ///
+    /// * [`IntegralError::Pole`] if the interval between $a$ and $b$ contains a pole $x=0$.
+    /// * [`IntegralError::NotFinite`] if the integral diverges or overflows.
+    fn coth_integrate(&self, upper: &Self) -> Result<Self, IntegralError<Self>>;
+
+    /// Antiderivative of the inverse hyperbolic sine function $f(x)=\text{arcsinh}(x)$.
+    ///
+    /// # Returns
+    ///
+    /// $F(x)=x\,\text{arcsinh}(x)-\sqrt{x^2+1}$.
+    fn asinh_antideriv(&self) -> Self;
+
+    /// Definite integral of the inverse hyperbolic sine function $f(x)=\text{arcsinh}(x)$ from this
+    /// value to `upper`.
+    ///
+    /// # Arguments
+    ///
+    /// * `upper` - Upper limit of integration $b$ (with this value as the lower limit $a$).
+    ///
+    /// # Returns
+    ///
+    /// $\int_a^bf(x)\,dx=F(b)-F(a)$, where $F$ is [`TrigIntegral::asinh_antideriv`].
+    ///
+    /// # Errors
+    ///
+    /// * [`IntegralError::NotFinite`] if the integral diverges or overflows.
+    fn asinh_integrate(&self, upper: &Self) -> Result<Self, IntegralError<Self>>;
+
+    /// Antiderivative of the inverse hyperbolic cosine function $f(x)=\text{arccosh}(x)$.
+    ///
+    /// # Returns
+    ///
+    /// $F(x)=x\,\text{arccosh}(x)-\sqrt{x^2-1}$.
+    fn acosh_antideriv(&self) -> Self;
+
+    /// Definite integral of the inverse hyperbolic cosine function $f(x)=\text{arccosh}(x)$ from
+    /// this value to `upper`.
+    ///
+    /// # Arguments
+    ///
+    /// * `upper` - Upper limit of integration $b$ (with this value as the lower limit $a$).
+    ///
+    /// # Returns
+    ///
+    /// $\int_a^bf(x)\,dx=F(b)-F(a)$, where $F$ is [`TrigIntegral::acosh_antideriv`].
+    ///
+    /// # Errors
+    ///
+    /// * [`IntegralError::Domain`] if the interval between $a$ and $b$ is not contained in
+    ///   $\[1,\infty)$.
+    /// * [`IntegralError::NotFinite`] if the integral diverges or overflows.
+    fn acosh_integrate(&self, upper: &Self) -> Result<Self, IntegralError<Self>>;
+
+    /// Antiderivative of the inverse hyperbolic tangent function $f(x)=\text{arctanh}(x)$.
+    ///
+    /// # Returns
+    ///
+    /// $F(x)=x\,\text{arctanh}(x)+\frac{1}{2}\ln\left(1-x^2\right)$.
+    ///
+    /// # Note
+    ///
+    /// The limits $F(\pm1)=\ln(2)$ are returned at $x=\pm1$.
+    fn atanh_antideriv(&self) -> Self;
+
+    /// Definite integral of the inverse hyperbolic tangent function $f(x)=\text{arctanh}(x)$ from
+    /// this value to `upper`.
+    ///
+    /// # Arguments
+    ///
+    /// * `upper` - Upper limit of integration $b$ (with this value as the lower limit $a$).
+    ///
+    /// # Returns
+    ///
+    /// $\int_a^bf(x)\,dx=F(b)-F(a)$, where $F$ is [`TrigIntegral::atanh_antideriv`].
+    ///
+    /// # Errors
+    ///
+    /// * [`IntegralError::Domain`] if the interval between $a$ and $b$ is not contained in
+    ///   $\[-1,1\]$.
+    /// * [`IntegralError::NotFinite`] if the integral diverges or overflows.
+    fn atanh_integrate(&self, upper: &Self) -> Result<Self, IntegralError<Self>>;
+
+    /// Antiderivative of the inverse hyperbolic cosecant function $f(x)=\text{arccsch}(x)$.
+    ///
+    /// # Returns
+    ///
+    /// $F(x)=x\,\text{arccsch}(x)+\text{arcsinh}|x|$.
+    ///
+    /// # Note
+    ///
+    /// The limit $F(0)=0$ is returned at $x=0$.
+    fn acsch_antideriv(&self) -> Self;
+
+    /// Definite integral of the inverse hyperbolic cosecant function $f(x)=\text{arccsch}(x)$ from
+    /// this value to `upper`.
+    ///
+    /// # Arguments
+    ///
+    /// * `upper` - Upper limit of integration $b$ (with this value as the lower limit $a$).
+    ///
+    /// # Returns
+    ///
+    /// $\int_a^bf(x)\,dx=F(b)-F(a)$, where $F$ is [`TrigIntegral::acsch_antideriv`].
+    ///
+    /// # Errors
+    ///
+    /// * [`IntegralError::NotFinite`] if the integral diverges or overflows.
+    fn acsch_integrate(&self, upper: &Self) -> Result<Self, IntegralError<Self>>;
+
+    /// Antiderivative of the inverse hyperbolic secant function $f(x)=\text{arcsech}(x)$.
+    ///
+    /// # Returns
+    ///
+    /// $F(x)=x\,\text{arcsech}(x)+\arcsin(x)$.
+    ///
+    /// # Note
+    ///
+    /// The limit $F(0)=0$ is returned at $x=0$.
+    fn asech_antideriv(&self) -> Self;
+
+    /// Definite integral of the inverse hyperbolic secant function $f(x)=\text{arcsech}(x)$ from
+    /// this value to `upper`.
+    ///
+    /// # Arguments
+    ///
+    /// * `upper` - Upper limit of integration $b$ (with this value as the lower limit $a$).
+    ///
+    /// # Returns
+    ///
+    /// $\int_a^bf(x)\,dx=F(b)-F(a)$, where $F$ is [`TrigIntegral::asech_antideriv`].
+    ///
+    /// # Errors
+    ///
+    /// * [`IntegralError::Domain`] if the interval between $a$ and $b$ is not contained in
+    ///   $\[0,1\]$.
+    /// * [`IntegralError::NotFinite`] if the integral diverges or overflows.
+    fn asech_integrate(&self, upper: &Self) -> Result<Self, IntegralError<Self>>;
+
+    /// Antiderivative of the inverse hyperbolic cotangent function $f(x)=\text{arccoth}(x)$.
+    ///
+    /// # Returns
+    ///
+    /// $F(x)=x\,\text{arccoth}(x)+\frac{1}{2}\ln\left(x^2-1\right)$.
+    ///
+    /// # Note
+    ///
+    /// The limits $F(\pm1)=\ln(2)$ are returned at $x=\pm1$.
+    fn acoth_antideriv(&self) -> Self;
+
+    /// Definite integral of the inverse hyperbolic cotangent function $f(x)=\text{arccoth}(x)$ from
+    /// this value to `upper`.
+    ///
+    /// # Arguments
+    ///
+    /// * `upper` - Upper limit of integration $b$ (with this value as the lower limit $a$).
+    ///
+    /// # Returns
+    ///
+    /// $\int_a^bf(x)\,dx=F(b)-F(a)$, where $F$ is [`TrigIntegral::acoth_antideriv`].
+    ///
+    /// # Errors
+    ///
+    /// * [`IntegralError::Domain`] if the interval between $a$ and $b$ is not contained in
+    ///   $(-\infty,-1\]\cup\[1,\infty)$.
+    /// * [`IntegralError::NotFinite`] if the integral diverges or overflows.
+    fn acoth_integrate(&self, upper: &Self) -> Result<Self, IntegralError<Self>>;
+}
+
+/// Domain of an integrand.
+#[derive(Clone, Copy)]
+enum Domain {
+    /// All real numbers.
+    Real,
+
+    /// $\[-1,1\]$.
+    Unit,
+
+    /// $(-\infty,-1\]\cup\[1,\infty)$.
+    OutsideUnit,
+
+    /// $\[1,\infty)$.
+    AtLeastOne,
+
+    /// $\[0,1\]$.
+    ZeroToOne,
+}
+
+impl Domain {
+    /// Determines if an interval is contained in this domain.
+    ///
+    /// # Arguments
+    ///
+    /// * `lo` - Lower end of the interval.
+    /// * `hi` - Upper end of the interval.
+    ///
+    /// # Returns
+    ///
+    /// `true` if $\[\text{lo},\text{hi}\]$ is contained in this domain, `false` otherwise.
+    fn contains(self, lo: f64, hi: f64) -> bool {
+        match self {
+            Domain::Real => true,
+            Domain::Unit => -1.0 <= lo && hi <= 1.0,
+            Domain::OutsideUnit => lo >= 1.0 || hi <= -1.0,
+            Domain::AtLeastOne => lo >= 1.0,
+            Domain::ZeroToOne => 0.0 <= lo && hi <= 1.0,
+        }
+    }
+}
+
+/// Poles of an integrand.
+#[derive(Clone, Copy)]
+enum Poles {
+    /// No poles.
+    None,
+
+    /// A single pole.
+    At(f64),
+
+    /// Poles at the multiples of a quarter turn in the given unit with the given parity ($0$ for
+    /// the even multiples, e.g. of `cot`, and $1$ for the odd multiples, e.g. of `tan`).
+    Periodic(Unit, u8),
+}
+
+impl Poles {
+    /// Finds a pole in an interval.
+    ///
+    /// # Arguments
+    ///
+    /// * `lo` - Lower end of the interval.
+    /// * `hi` - Upper end of the interval.
+    ///
+    /// # Returns
+    ///
+    /// A pole in $\[\text{lo},\text{hi}\]$ (the smallest one if `lo` is finite), or `None` if there
+    /// is none.
+    ///
+    /// # Note
+    ///
+    /// Whether a periodic pole lies in the interval is decided from the exact reductions of its
+    /// ends modulo a quarter turn (as for [`Interval`](crate::Interval)), so that the decision is
+    /// correct even for intervals that are a few ulps wide. The returned pole is the nearest
+    /// [`f64`] to it, moved into the interval if it is rounded out of it.
+    fn find(self, lo: f64, hi: f64) -> Option<f64> {
+        let (unit, parity) = match self {
+            Poles::None => return None,
+            Poles::At(c) => return (lo <= c && c <= hi).then_some(c),
+            Poles::Periodic(unit, parity) => (unit, parity as i64),
+        };
+        let quarter_turn = unit.quarter_turn();
+        if lo.is_finite() {
+            // Smallest pole at or above the lower end, k quarter turns above the multiple of a
+            // quarter turn nearest to it.
+            let (q_lo, r_lo) = unit.reduce(lo);
+            let mut k = if r_lo > 0.0 { 1 } else { 0 };
+            if (q_lo as i64 + k) % 2 != parity {
+                k += 1;
+            }
+
+            // An interval of two half-periods or more contains a pole. Otherwise, the number of
+            // quarter turns between the reductions of the ends is an integer that can be rounded
+            // from its approximation.
+            if hi.is_finite() && hi - lo <= 4.0 * quarter_turn {
+                let (_, r_hi) = unit.reduce(hi);
+                let turns = math::round(((hi - lo) + (r_lo - r_hi)) / quarter_turn) as i64;
+                let k_last = if r_hi < 0.0 { turns - 1 } else { turns };
+                if k > k_last {
+                    return None;
+                }
+            }
+            let n = math::round((lo - r_lo) / quarter_turn);
+            Some(((n + k as f64) * quarter_turn).clamp(lo, hi))
+        } else if hi.is_finite() {
+            // Largest pole at or below the upper end.
+            let (q_hi, r_hi) = unit.reduce(hi);
+            let mut k = if r_hi < 0.0 { -1 } else { 0 };
+            if (q_hi as i64 + k).rem_euclid(2) != parity {
+                k -= 1;
+            }
+            let n = math::round((hi - r_hi) / quarter_turn);
+            Some(((n + k as f64) * quarter_turn).min(hi))
+        } else {
+            Some(parity as f64 * quarter_turn)
+        }
+    }
+}
+
+/// Evaluates a definite integral from an antiderivative.
+///
+/// # Arguments
+///
+/// * `a` - Lower limit of integration.
+/// * `b` - Upper limit of integration.
+/// * `antiderivative` - Antiderivative $F$ of the integrand.
+/// * `domain` - Domain of the integrand.
+/// * `poles` - Poles of the integrand.
+///
+/// # Returns
+///
+/// $F(b)-F(a)$.
+///
+/// # Errors
+///
+/// See [`IntegralError`].
+fn integrate(
+    a: f64,
+    b: f64,
+    antiderivative: fn(&f64) -> f64,
+    domain: Domain,
+    poles: Poles,
+) -> Result<f64, IntegralError<f64>> {
+    if a.is_nan() || b.is_nan() {
+        return Err(IntegralError::Domain);
+    }
+    let (lo, hi) = if a <= b { (a, b) } else { (b, a) };
+    if !domain.contains(lo, hi) {
+        return Err(IntegralError::Domain);
+    }
+    if a == b {
+        return Ok(0.0);
+    }
+    if let Some(pole) = poles.find(lo, hi) {
+        return Err(IntegralError::Pole(pole));
+    }
+    let integral = antiderivative(&b) - antiderivative(&a);
+    if integral.is_finite() {
+        Ok(integral)
+    } else {
+        Err(IntegralError::NotFinite)
+    }
+}
+
+/// $\sqrt{1-x^2}$, evaluated as $\sqrt{(1-x)(1+x)}$ for accuracy near $|x|=1$.
+fn sqrt_one_minus_square(x: f64) -> f64 {
+    math::sqrt((1.0 - x) * (1.0 + x))
+}
+
+/// $\sqrt{x^2-1}$, evaluated as $\sqrt{|x|-1}\sqrt{|x|+1}$ to avoid cancellation and overflow.
+fn sqrt_square_minus_one(x: f64) -> f64 {
+    math::sqrt(x.abs() - 1.0) * math::sqrt(x.abs() + 1.0)
+}
+
+/// $\frac{1}{2}\ln\left(1+x^2\right)$, evaluated without overflow for large $|x|$.
+fn half_ln_1p_square(x: f64) -> f64 {
+    if x.abs() > 1.0 {
+        ln(x.abs()) + 0.5 * math::ln_1p(1.0 / (x * x))
+    } else {
+        0.5 * math::ln_1p(x * x)
+    }
+}
+
+/// $\ln(\cosh(x))$, evaluated without overflow for large $|x|$.
+fn ln_cosh(x: f64) -> f64 {
+    if x.abs() < 1.0 {
+        0.5 * math::ln_1p(x.sinh() * x.sinh())
+    } else {
+        x.abs() - LN_2 + math::ln_1p(math::exp(-2.0 * x.abs()))
+    }
+}
+
+/// $\ln|\sinh(x)|$, evaluated without overflow for large $|x|$.
+fn ln_abs_sinh(x: f64) -> f64 {
+    if x.abs() < 1.0 {
+        ln(x.sinh().abs())
+    } else {
+        x.abs() - LN_2 + math::ln_1p(-math::exp(-2.0 * x.abs()))
+    }
+}
+
+/// Implements the methods of [`TrigIntegral`] on [`f64`].
+macro_rules! f64_methods {
+    ($($antideriv:ident, $integrate:ident, $domain:expr, $poles:expr => |$x:ident| $body:expr;)*) => {
+        $(
+            fn $antideriv(&self) -> f64 {
+                let $x = *self;
+                $body
+            }
+            fn $integrate(&self, upper: &f64) -> Result<f64, IntegralError<f64>> {
+                integrate(*self, *upper, <f64 as TrigIntegral>::$antideriv, $domain, $poles)
+            }
+        )*
+    };
+}
+
+impl TrigIntegral for f64 {
+    f64_methods!(
+        sin_antideriv, sin_integrate, Domain::Real, Poles::None => |x| -x.cos();
+        cos_antideriv, cos_integrate, Domain::Real, Poles::None => |x| x.sin();
+        tan_antideriv, tan_integrate, Domain::Real, Poles::Periodic(Unit::Radians, 1) => |x| -ln(x.cos().abs());
+        csc_antideriv, csc_integrate, Domain::Real, Poles::Periodic(Unit::Radians, 0) => |x| ln((0.5 * x).tan().abs());
+        sec_antideriv, sec_integrate, Domain::Real, Poles::Periodic(Unit::Radians, 1) => |x| ln((x.sec() + x.tan()).abs());
+        cot_antideriv, cot_integrate, Domain::Real, Poles::Periodic(Unit::Radians, 0) => |x| ln(x.sin().abs());
+        asin_antideriv, asin_integrate, Domain::Unit, Poles::None => |x| x * x.asin() + sqrt_one_minus_square(x);
+        acos_antideriv, acos_integrate, Domain::Unit, Poles::None => |x| x * x.acos() - sqrt_one_minus_square(x);
+        atan_antideriv, atan_integrate, Domain::Real, Poles::None => |x| x * x.atan() - half_ln_1p_square(x);
+        acsc_antideriv, acsc_integrate, Domain::OutsideUnit, Poles::None => |x| x * x.acsc() + x.abs().acosh();
+        asec_antideriv, asec_integrate, Domain::OutsideUnit, Poles::None => |x| x * x.asec() - x.abs().acosh();
+        acot_antideriv, acot_integrate, Domain::Real, Poles::None => |x| x * x.acot() + half_ln_1p_square(x);
+        deg2rad_antideriv, deg2rad_integrate, Domain::Real, Poles::None => |x| 0.5 * x * x.deg2rad();
+        rad2deg_antideriv, rad2deg_integrate, Domain::Real, Poles::None => |x| 0.5 * x * x.rad2deg();
+        sind_antideriv, sind_integrate, Domain::Real, Poles::None => |x| -DEGREES * x.cosd();
+        cosd_antideriv, cosd_integrate, Domain::Real, Poles::None => |x| DEGREES * x.sind();
+        tand_antideriv, tand_integrate, Domain::Real, Poles::Periodic(Unit::Degrees, 1) => |x| -DEGREES * ln(x.cosd().abs());
+        cscd_antideriv, cscd_integrate, Domain::Real, Poles::Periodic(Unit::Degrees, 0) => |x| DEGREES * ln((0.5 * x).tand().abs());
+        secd_antideriv, secd_integrate, Domain::Real, Poles::Periodic(Unit::Degrees, 1) => |x| DEGREES * ln((x.secd() + x.tand()).abs());
+        cotd_antideriv, cotd_integrate, Domain::Real, Poles::Periodic(Unit::Degrees, 0) => |x| DEGREES * ln(x.sind().abs());
+        asind_antideriv, asind_integrate, Domain::Unit, Poles::None => |x| x * x.asind() + DEGREES * sqrt_one_minus_square(x);
+        acosd_antideriv, acosd_integrate, Domain::Unit, Poles::None => |x| x * x.acosd() - DEGREES * sqrt_one_minus_square(x);
+        atand_antideriv, atand_integrate, Domain::Real, Poles::None => |x| x * x.atand() - DEGREES * half_ln_1p_square(x);
+        acscd_antideriv, acscd_integrate, Domain::OutsideUnit, Poles::None => |x| x * x.acscd() + DEGREES * x.abs().acosh();
+        asecd_antideriv, asecd_integrate, Domain::OutsideUnit, Poles::None => |x| x * x.asecd() - DEGREES * x.abs().acosh();
+        acotd_antideriv, acotd_integrate, Domain::Real, Poles::None => |x| x * x.acotd() + DEGREES * half_ln_1p_square(x);
+        sinpi_antideriv, sinpi_integrate, Domain::Real, Poles::None => |x| -FRAC_1_PI * x.cospi();
+        cospi_antideriv, cospi_integrate, Domain::Real, Poles::None => |x| FRAC_1_PI * x.sinpi();
+        tanpi_antideriv, tanpi_integrate, Domain::Real, Poles::Periodic(Unit::HalfTurns, 1) => |x| -FRAC_1_PI * ln(x.cospi().abs());
+        asinpi_antideriv, asinpi_integrate, Domain::Unit, Poles::None => |x| x * x.asinpi() + FRAC_1_PI * sqrt_one_minus_square(x);
+        acospi_antideriv, acospi_integrate, Domain::Unit, Poles::None => |x| x * x.acospi() - FRAC_1_PI * sqrt_one_minus_square(x);
+        atanpi_antideriv, atanpi_integrate, Domain::Real, Poles::None => |x| x * x.atanpi() - FRAC_1_PI * half_ln_1p_square(x);
+        sinh_antideriv, sinh_integrate, Domain::Real, Poles::None => |x| x.cosh();
+        cosh_antideriv, cosh_integrate, Domain::Real, Poles::None => |x| x.sinh();
+        tanh_antideriv, tanh_integrate, Domain::Real, Poles::None => |x| ln_cosh(x);
+        csch_antideriv, csch_integrate, Domain::Real, Poles::At(0.0) => |x| ln((0.5 * x).tanh().abs());
+        sech_antideriv, sech_integrate, Domain::Real, Poles::None => |x| 2.0 * (0.5 * x).tanh().atan();
+        coth_antideriv, coth_integrate, Domain::Real, Poles::At(0.0) => |x| ln_abs_sinh(x);
+        asinh_antideriv, asinh_integrate, Domain::Real, Poles::None => |x| x * x.asinh() - math::hypot(x, 1.0);
+        acosh_antideriv, acosh_integrate, Domain::AtLeastOne, Poles::None => |x| x * x.acosh() - sqrt_square_minus_one(x);
+        atanh_antideriv, atanh_integrate, Domain::Unit, Poles::None => |x| if x.abs() == 1.0 {
+                LN_2
+            } else {
+                x * x.atanh() + 0.5 * (math::ln_1p(x) + math::ln_1p(-x))
+            };
+        acsch_antideriv, acsch_integrate, Domain::Real, Poles::None => |x| if x == 0.0 {
+                0.0
+            } else {
+                x * x.acsch() + x.abs().asinh()
+            };
+        asech_antideriv, asech_integrate, Domain::ZeroToOne, Poles::None => |x| if x == 0.0 {
+                0.0
+            } else {
+                x * x.asech() + x.asin()
+            };
+        acoth_antideriv, acoth_integrate, Domain::OutsideUnit, Poles::None => |x| if x.abs() == 1.0 {
+                LN_2
+            } else {
+                x * x.acoth() + 0.5 * (ln(x.abs() - 1.0) + ln(x.abs() + 1.0))
+            };
+    );
+}
+
+/// Implements the methods of [`TrigIntegral`] on [`f32`] by evaluating them on [`f64`].
+macro_rules! f32_methods {
+    ($($antideriv:ident, $integrate:ident),* $(,)?) => {
+        $(
+            fn $antideriv(&self) -> f32 {
+                (*self as f64).$antideriv() as f32
+            }
+            fn $integrate(&self, upper: &f32) -> Result<f32, IntegralError<f32>> {
+                match (*self as f64).$integrate(&(*upper as f64)) {
+                    Ok(integral) if (integral as f32).is_finite() => Ok(integral as f32),
+                    Ok(_) => Err(IntegralError::NotFinite),
+                    Err(IntegralError::Pole(pole)) => Err(IntegralError::Pole(pole as f32)),
+                    Err(IntegralError::Domain) => Err(IntegralError::Domain),
+                    Err(IntegralError::NotFinite) => Err(IntegralError::NotFinite),
+                }
+            }
+        )*
+    };
+}
+
+impl TrigIntegral for f32 {
+    f32_methods!(
+        sin_antideriv,
+        sin_integrate,
+        cos_antideriv,
+        cos_integrate,
+        tan_antideriv,
+        tan_integrate,
+        csc_antideriv,
+        csc_integrate,
+        sec_antideriv,
+        sec_integrate,
+        cot_antideriv,
+        cot_integrate,
+        asin_antideriv,
+        asin_integrate,
+        acos_antideriv,
+        acos_integrate,
+        atan_antideriv,
+        atan_integrate,
+        acsc_antideriv,
+        acsc_integrate,
+        asec_antideriv,
+        asec_integrate,
+        acot_antideriv,
+        acot_integrate,
+        deg2rad_antideriv,
+        deg2rad_integrate,
+        rad2deg_antideriv,
+        rad2deg_integrate,
+        sind_antideriv,
+        sind_integrate,
+        cosd_antideriv,
+        cosd_integrate,
+        tand_antideriv,
+        tand_integrate,
+        cscd_antideriv,
+        cscd_integrate,
+        secd_antideriv,
+        secd_integrate,
+        cotd_antideriv,
+        cotd_integrate,
+        asind_antideriv,
+        asind_integrate,
+        acosd_antideriv,
+        acosd_integrate,
+        atand_antideriv,
+        atand_integrate,
+        acscd_antideriv,
+        acscd_integrate,
+        asecd_antideriv,
+        asecd_integrate,
+        acotd_antideriv,
+        acotd_integrate,
+        sinpi_antideriv,
+        sinpi_integrate,
+        cospi_antideriv,
+        cospi_integrate,
+        tanpi_antideriv,
+        tanpi_integrate,
+        asinpi_antideriv,
+        asinpi_integrate,
+        acospi_antideriv,
+        acospi_integrate,
+        atanpi_antideriv,
+        atanpi_integrate,
+        sinh_antideriv,
+        sinh_integrate,
+        cosh_antideriv,
+        cosh_integrate,
+        tanh_antideriv,
+        tanh_integrate,
+        csch_antideriv,
+        csch_integrate,
+        sech_antideriv,
+        sech_integrate,
+        coth_antideriv,
+        coth_integrate,
+        asinh_antideriv,
+        asinh_integrate,
+        acosh_antideriv,
+        acosh_integrate,
+        atanh_antideriv,
+        atanh_integrate,
+        acsch_antideriv,
+        acsch_integrate,
+        asech_antideriv,
+        asech_integrate,
+        acoth_antideriv,
+        acoth_integrate,
+    );
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use core::f64::consts::{FRAC_PI_2, FRAC_PI_4};
+
+    /// Asserts that the antiderivatives differentiate (by central differences) to the integrands.
+    macro_rules! check_antideriv {
+        ($($antideriv:ident => $name:ident),* $(,)?) => {
+            $(
+                for x in [0.3_f64, -0.45, 1.7, -2.5, 30.0, 100.0] {
+                    let f = Trig::$name(&x);
+                    let h = 1e-6 * f64::max(1.0, x.abs());
+                    let (lo, hi) = ((x - h).$antideriv(), (x + h).$antideriv());
+                    if !(f.is_finite() && lo.is_finite() && hi.is_finite()) {
+                        continue;
+                    }
+                    let derivative = (hi - lo) / (2.0 * h);
+                    assert!(
+                        (derivative - f).abs() <= 1e-6 * f64::max(1.0, f.abs()),
+                        "{}'({x}) = {derivative}, expected {f}",
+                        stringify!($antideriv)
+                    );
+                }
+            )*
+        };
+    }
+
+    #[test]
+    fn test_antiderivatives() {
+        check_antideriv!(
+            sin_antideriv => sin, cos_antideriv => cos, tan_antideriv => tan,
+            csc_antideriv => csc, sec_antideriv => sec, cot_antideriv => cot,
+            asin_antideriv => asin, acos_antideriv => acos, atan_antideriv => atan,
+            acsc_antideriv => acsc, asec_antideriv => asec, acot_antideriv => acot,
+            deg2rad_antideriv => deg2rad, rad2deg_antideriv => rad2deg,
+            sind_antideriv => sind, cosd_antideriv => cosd, tand_antideriv => tand,
+            cscd_antideriv => cscd, secd_antideriv => secd, cotd_antideriv => cotd,
+            asind_antideriv => asind, acosd_antideriv => acosd, atand_antideriv => atand,
+            acscd_antideriv => acscd, asecd_antideriv => asecd, acotd_antideriv => acotd,
+            sinpi_antideriv => sinpi, cospi_antideriv => cospi, tanpi_antideriv => tanpi,
+            asinpi_antideriv => asinpi, acospi_antideriv => acospi, atanpi_antideriv => atanpi,
+            sinh_antideriv => sinh, cosh_antideriv => cosh, tanh_antideriv => tanh,
+            csch_antideriv => csch, sech_antideriv => sech, coth_antideriv => coth,
+            asinh_antideriv => asinh, acosh_antideriv => acosh, atanh_antideriv => atanh,
+            acsch_antideriv => acsch, asech_antideriv => asech, acoth_antideriv => acoth,
+        );
+    }
+
+    /// Composite Simpson's rule with 2000 subintervals.
+    fn simpson(f: fn(&f64) -> f64, a: f64, b: f64) -> f64 {
+        let n = 2000;
+        let h = (b - a) / n as f64;
+        let sum: f64 = (0..=n)
+            .map(|i| {
+                let weight = if i == 0 || i == n {
+                    1.0
+                } else {
+                    (2 + 2 * (i % 2)) as f64
+                };
+                weight * f(&(a + i as f64 * h))
+            })
+            .sum();
+        sum * h / 3.0
+    }
+
+    #[test]
+    fn test_integrals() {
+        type Case = (
+            fn(&f64, &f64) -> Result<f64, IntegralError<f64>>,
+            fn(&f64) -> f64,
+            f64,
+            f64,
+        );
+        let cases: [Case; 12] = [
+            (f64::sec_integrate, Trig::sec, -1.2, 1.5),
+            (f64::csc_integrate, Trig::csc, 3.5, 6.0),
+            (f64::cot_integrate, Trig::cot, 0.2, 3.0),
+            (f64::acsc_integrate, Trig::acsc, -5.0, -1.1),
+            (f64::tand_integrate, Trig::tand, 100.0, 250.0),
+            (f64::cscd_integrate, Trig::cscd, -170.0, -10.0),
+            (f64::tanpi_integrate, Trig::tanpi, 0.6, 1.4),
+            (f64::atanpi_integrate, Trig::atanpi, -3.0, 7.0),
+            (f64::coth_integrate, Trig::coth, -4.0, -0.1),
+            (f64::sech_integrate, Trig::sech, -3.0, 5.0),
+            (f64::acosh_integrate, Trig::acosh, 1.5, 9.0),
+            (f64::acoth_integrate, Trig::acoth, 1.1, 4.0),
+        ];
+        for (integrate, f, a, b) in cases {
+            let expected = simpson(f, a, b);
+            let integral = integrate(&a, &b).unwrap();
+            assert!((integral - expected).abs() < 1e-9 * f64::max(1.0, expected.abs()));
+            assert_eq!(integrate(&b, &a), Ok(-integral));
+        }
+
+        // Closed forms, including integrable singularities at the ends of the interval.
+        let close = |x: f64, y: f64| (x - y).abs() < 1e-15;
+        assert!(close(
+            0.0.sec_integrate(&FRAC_PI_4).unwrap(),
+            2.0_f64.sqrt().ln_1p()
+        ));
+        assert!(close(0.0.tan_integrate(&FRAC_PI_4).unwrap(), 0.5 * LN_2));
+        assert!(close(0.0.atanh_integrate(&1.0).unwrap(), LN_2));
+        assert!(close((-1.0).atanh_integrate(&1.0).unwrap(), 0.0));
+        assert!(close(0.0.asech_integrate(&1.0).unwrap(), FRAC_PI_2));
+        assert!(close(
+            0.0.acsch_integrate(&1.0).unwrap(),
+            2.0 * 1.0_f64.asinh()
+        ));
+        assert!(close(
+            1.0.acoth_integrate(&2.0).unwrap(),
+            1.5 * 3.0_f64.ln() - LN_2
+        ));
+        assert!(close(0.0.asin_integrate(&1.0).unwrap(), FRAC_PI_2 - 1.0));
+        assert!(close(0.0.sind_integrate(&180.0).unwrap(), 2.0 * DEGREES));
+        assert!(close(
+            0.0.sech_integrate(&f64::INFINITY).unwrap(),
+            FRAC_PI_2
+        ));
+        assert_eq!(0.0.cos_integrate(&0.0), Ok(0.0));
+
+        // Large arguments.
+        assert!(close(
+            0.0.tanh_integrate(&1000.0).unwrap() / 1000.0,
+            1.0 - LN_2 / 1000.0
+        ));
+        assert!(close(
+            1.0.coth_integrate(&1000.0).unwrap(),
+            1000.0 - 1.0_f64.sinh().ln() - LN_2
+        ));
+        let atan = 0.0.atan_integrate(&1e200).unwrap();
+        assert!(close(atan / 1e200, FRAC_PI_2));
+    }
+
+    #[test]
+    fn test_errors() {
+        // Poles.
+        assert_eq!(0.0.tan_integrate(&PI), Err(IntegralError::Pole(FRAC_PI_2)));
+        assert_eq!(
+            1.0.sec_integrate(&-10.0),
+            Err(IntegralError::Pole(-5.0 * FRAC_PI_2))
+        );
+        assert_eq!(0.1.csc_integrate(&7.0), Err(IntegralError::Pole(PI)));
+        assert_eq!((-10.0).cotd_integrate(&10.0), Err(IntegralError::Pole(0.0)));
+        assert_eq!(
+            450.0.secd_integrate(&500.0),
+            Err(IntegralError::Pole(450.0))
+        );
+        assert_eq!(0.0.tanpi_integrate(&1.0), Err(IntegralError::Pole(0.5)));
+        assert_eq!((-1.0).csch_integrate(&1.0), Err(IntegralError::Pole(0.0)));
+        assert_eq!(0.0.coth_integrate(&1.0), Err(IntegralError::Pole(0.0)));
+        assert_eq!(
+            f64::NEG_INFINITY.tan_integrate(&0.0),
+            Err(IntegralError::Pole(-FRAC_PI_2))
+        );
+        assert_eq!(
+            f64::NEG_INFINITY.cscd_integrate(&f64::INFINITY),
+            Err(IntegralError::Pole(0.0))
+        );
+        assert!(0.1.cotd_integrate(&179.9).is_ok());
+
+        // Poles in intervals a few ulps wide, and intervals ending just short of a pole.
+        for (a, b) in [
+            (34474.266984167596_f64, 34474.2669841676),
+            (1.914340479039228e6, 1.9143404790392283e6),
+            (23658126.331809428, 23658126.33180943),
+            (24974032.68024797, 24974032.680247974),
+        ] {
+            assert!(matches!(a.tan_integrate(&b), Err(IntegralError::Pole(p)) if a <= p && p <= b));
+            assert!(matches!(b.sec_integrate(&a), Err(IntegralError::Pole(p)) if a <= p && p <= b));
+            assert!(a.next_down().tan_integrate(&a).is_ok());
+            assert!(b.tan_integrate(&b.next_up()).is_ok());
+        }
+        assert!(0.0.tan_integrate(&FRAC_PI_2).is_ok_and(|x| x > 30.0));
+
+        // Empty intervals.
+        assert_eq!(1e300.tan_integrate(&1e300), Ok(0.0));
+        assert_eq!(90.0.tand_integrate(&90.0), Ok(0.0));
+        assert_eq!(0.0.coth_integrate(&0.0), Ok(0.0));
+
+        // Domains.
+        assert_eq!(0.0.asin_integrate(&1.5), Err(IntegralError::Domain));
+        assert_eq!((-2.0).asec_integrate(&2.0), Err(IntegralError::Domain));
+        assert_eq!(0.5.acosh_integrate(&2.0), Err(IntegralError::Domain));
+        assert_eq!((-0.5).asech_integrate(&0.5), Err(IntegralError::Domain));
+        assert_eq!(f64::NAN.sin_integrate(&1.0), Err(IntegralError::Domain));
+
+        // Divergent and overflowing integrals.
+        assert_eq!(
+            0.0.sin_integrate(&f64::INFINITY),
+            Err(IntegralError::NotFinite)
+        );
+        assert_eq!(0.0.cosh_integrate(&800.0), Err(IntegralError::NotFinite));
+        assert_eq!(
+            0.0_f32.cosh_integrate(&100.0),
+            Err(IntegralError::NotFinite)
+        );
+    }
+
+    #[test]
+    fn test_f32() {
+        let integral = 0.0_f32
+            .sec_integrate(&core::f32::consts::FRAC_PI_4)
+            .unwrap();
+        assert!((integral - 2.0_f32.sqrt().ln_1p()).abs() < 1e-6);
+        assert_eq!(0.5_f32.sind_antideriv(), 0.5_f64.sind_antideriv() as f32);
+        assert_eq!(
+            45.0_f32.tand_integrate(&135.0),
+            Err(IntegralError::Pole(90.0))
+        );
+        assert_eq!(
+            alloc::format!("{}", IntegralError::Pole(90.0_f32)),
+            "the integrand has a pole at 90"
+        );
+    }
+}
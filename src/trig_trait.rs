@@ -2,6 +2,10 @@
 pub trait Trig {
     /// Computes the sine of this value, where this value is in radians.
     ///
+    /// The argument is reduced modulo $\frac{\pi}{2}$ against a high-precision expansion of
+    /// $\frac{2}{\pi}$ (Payne–Hanek for large arguments), so the result stays accurate even for
+    /// arguments of very large magnitude.
+    ///
     /// # Returns
     ///
     /// Sine of this value.
@@ -32,6 +36,10 @@ pub trait Trig {
 
     /// Computes the cosine of this value, where this value is in radians.
     ///
+    /// The argument is reduced modulo $\frac{\pi}{2}$ against a high-precision expansion of
+    /// $\frac{2}{\pi}$ (Payne–Hanek for large arguments), so the result stays accurate even for
+    /// arguments of very large magnitude.
+    ///
     /// # Returns
     ///
     /// Cosine of this value.
@@ -62,6 +70,10 @@ pub trait Trig {
 
     /// Computes the tangent of this value, where this value is in radians.
     ///
+    /// The argument is reduced modulo $\frac{\pi}{2}$ against a high-precision expansion of
+    /// $\frac{2}{\pi}$ (Payne–Hanek for large arguments), so the result stays accurate even for
+    /// arguments of very large magnitude.
+    ///
     /// # Returns
     ///
     /// Tangent of this value.
@@ -197,6 +209,43 @@ pub trait Trig {
     /// * [https://mathworld.wolfram.com/Cotangent.html](https://mathworld.wolfram.com/Cotangent.html)
     fn cot(&self) -> Self;
 
+    /// Simultaneously computes the sine and cosine of this value, where this value is in radians.
+    ///
+    /// Both values are computed from a single argument reduction, which makes this method cheaper
+    /// than calling [`Trig::sin`] and [`Trig::cos`] separately.
+    ///
+    /// # Returns
+    ///
+    /// Sine and cosine of this value, as the tuple `(sin, cos)`.
+    ///
+    /// # Domain
+    ///
+    /// $(-\infty,\infty)$ _radians_
+    ///
+    /// # Range
+    ///
+    /// $[-1,1]$ (for both the sine and the cosine)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trig::Trig;
+    ///
+    /// let x = std::f64::consts::FRAC_PI_3;
+    /// let (s, c) = x.sincos();
+    ///
+    /// assert!((s - 3.0_f64.sqrt() / 2.0).abs() < 1e-15);
+    /// assert!((c - 0.5).abs() < 1e-15);
+    /// ```
+    ///
+    /// # References
+    ///
+    /// * [https://mathworld.wolfram.com/Sine.html](https://mathworld.wolfram.com/Sine.html)
+    /// * [https://mathworld.wolfram.com/Cosine.html](https://mathworld.wolfram.com/Cosine.html)
+    fn sincos(&self) -> (Self, Self)
+    where
+        Self: Sized;
+
     /// Computes the inverse sine of this value, returning the result in radians.
     ///
     /// # Returns
@@ -418,7 +467,7 @@ pub trait Trig {
     /// ```
     /// use trig::Trig;
     ///
-    /// let x = std::f64::consts::FRAC_PI_4;
+    /// let x = std::f64::consts::FRAC_PI_3;
     /// let f = x.sec().asec();
     /// let abs_difference = (f - x).abs();
     ///
@@ -499,6 +548,10 @@ pub trait Trig {
 
     /// Computes the sine of this value, where this value is in degrees.
     ///
+    /// The argument is first reduced exactly modulo $360$ degrees and only then converted to
+    /// radians, so that multiples of $90$ degrees (as well as $\pm30$ and $\pm45$ degrees, where
+    /// applicable) give exact results.
+    ///
     /// # Returns
     ///
     /// Sine of this value.
@@ -529,6 +582,10 @@ pub trait Trig {
 
     /// Computes the cosine of this value, where this value is in degrees.
     ///
+    /// The argument is first reduced exactly modulo $360$ degrees and only then converted to
+    /// radians, so that multiples of $90$ degrees (as well as $\pm30$ and $\pm45$ degrees, where
+    /// applicable) give exact results.
+    ///
     /// # Returns
     ///
     /// Cosine of this value.
@@ -559,6 +616,10 @@ pub trait Trig {
 
     /// Computes the tangent of this value, where this value is in degrees.
     ///
+    /// The argument is first reduced exactly modulo $360$ degrees and only then converted to
+    /// radians, so that multiples of $90$ degrees (as well as $\pm30$ and $\pm45$ degrees, where
+    /// applicable) give exact results.
+    ///
     /// # Returns
     ///
     /// Tangent of this value.
@@ -573,7 +634,8 @@ pub trait Trig {
     ///
     /// # Warning
     ///
-    /// The value of the tangent function at points outside its domain is `NaN`.
+    /// The tangent function has poles at the points outside its domain, where this method returns
+    /// $\pm\infty$.
     ///
     /// # Examples
     ///
@@ -608,7 +670,8 @@ pub trait Trig {
     ///
     /// # Warning
     ///
-    /// The value of the cosecant function at points outside its domain is `NaN`.
+    /// The cosecant function has poles at the points outside its domain, where this method returns
+    /// $\pm\infty$.
     ///
     /// # Examples
     ///
@@ -642,7 +705,8 @@ pub trait Trig {
     ///
     /// # Warning
     ///
-    /// The value of the secant function at points outside its domain is `NaN`.
+    /// The secant function has poles at the points outside its domain, where this method returns
+    /// $\pm\infty$.
     ///
     /// # Examples
     ///
@@ -676,7 +740,8 @@ pub trait Trig {
     ///
     /// # Warning
     ///
-    /// The value of the cotangent function at points outside its domain is `NaN`.
+    /// The cotangent function has poles at the points outside its domain, where this method returns
+    /// $\pm\infty$.
     ///
     /// # Examples
     ///
@@ -694,6 +759,42 @@ pub trait Trig {
     /// * [https://mathworld.wolfram.com/Cotangent.html](https://mathworld.wolfram.com/Cotangent.html)
     fn cotd(&self) -> Self;
 
+    /// Simultaneously computes the sine and cosine of this value, where this value is in degrees.
+    ///
+    /// Both values are computed from a single exact reduction of the argument modulo $360$
+    /// degrees, which makes this method cheaper than calling [`Trig::sind`] and [`Trig::cosd`]
+    /// separately while giving identical results.
+    ///
+    /// # Returns
+    ///
+    /// Sine and cosine of this value, as the tuple `(sind, cosd)`.
+    ///
+    /// # Domain
+    ///
+    /// $(-\infty,\infty)$ _degrees_
+    ///
+    /// # Range
+    ///
+    /// $[-1,1]$ (for both the sine and the cosine)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trig::Trig;
+    ///
+    /// let x = 90.0_f64;
+    ///
+    /// assert_eq!(x.sincosd(), (1.0, 0.0));
+    /// ```
+    ///
+    /// # References
+    ///
+    /// * [https://mathworld.wolfram.com/Sine.html](https://mathworld.wolfram.com/Sine.html)
+    /// * [https://mathworld.wolfram.com/Cosine.html](https://mathworld.wolfram.com/Cosine.html)
+    fn sincosd(&self) -> (Self, Self)
+    where
+        Self: Sized;
+
     /// Computes the inverse sine of this value, returning the result in degrees.
     ///
     /// # Returns
@@ -756,7 +857,7 @@ pub trait Trig {
     /// let f = x.cosd().acosd();
     /// let abs_difference = (f - x).abs();
     ///
-    /// assert!(abs_difference < 1e-16);
+    /// assert!(abs_difference < 1e-13);
     /// ```
     ///
     /// # References
@@ -909,7 +1010,7 @@ pub trait Trig {
     /// ```
     /// use trig::Trig;
     ///
-    /// let x = 45.0_f64;
+    /// let x = 60.0_f64;
     /// let f = x.secd().asecd();
     /// let abs_difference = (f - x).abs();
     ///
@@ -952,6 +1053,268 @@ pub trait Trig {
     /// * [https://mathworld.wolfram.com/InverseCotangent.html](https://mathworld.wolfram.com/InverseCotangent.html)
     fn acotd(&self) -> Self;
 
+    /// Computes the sine of $\pi$ times this value, where this value is in half-turns.
+    ///
+    /// The argument is first reduced exactly modulo $2$ half-turns and only then multiplied by
+    /// $\pi$, so that the result is exact at integer and half-integer arguments.
+    ///
+    /// # Returns
+    ///
+    /// Sine of $\pi$ times this value.
+    ///
+    /// # Domain
+    ///
+    /// $(-\infty,\infty)$ _half-turns_
+    ///
+    /// # Range
+    ///
+    /// $[-1,1]$
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trig::Trig;
+    ///
+    /// let x = 1.0_f64;
+    ///
+    /// assert_eq!(x.sinpi(), 0.0);
+    /// assert_eq!(0.5_f64.sinpi(), 1.0);
+    /// ```
+    ///
+    /// # References
+    ///
+    /// * IEEE Standard for Floating-Point Arithmetic (IEEE 754-2019), Section 9.2
+    /// * [https://mathworld.wolfram.com/Sine.html](https://mathworld.wolfram.com/Sine.html)
+    fn sinpi(&self) -> Self;
+
+    /// Computes the cosine of $\pi$ times this value, where this value is in half-turns.
+    ///
+    /// The argument is first reduced exactly modulo $2$ half-turns and only then multiplied by
+    /// $\pi$, so that the result is exact at integer and half-integer arguments.
+    ///
+    /// # Returns
+    ///
+    /// Cosine of $\pi$ times this value.
+    ///
+    /// # Domain
+    ///
+    /// $(-\infty,\infty)$ _half-turns_
+    ///
+    /// # Range
+    ///
+    /// $[-1,1]$
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trig::Trig;
+    ///
+    /// let x = 0.5_f64;
+    ///
+    /// assert_eq!(x.cospi(), 0.0);
+    /// assert_eq!(1.0_f64.cospi(), -1.0);
+    /// ```
+    ///
+    /// # References
+    ///
+    /// * IEEE Standard for Floating-Point Arithmetic (IEEE 754-2019), Section 9.2
+    /// * [https://mathworld.wolfram.com/Cosine.html](https://mathworld.wolfram.com/Cosine.html)
+    fn cospi(&self) -> Self;
+
+    /// Computes the tangent of $\pi$ times this value, where this value is in half-turns.
+    ///
+    /// The argument is first reduced exactly modulo $2$ half-turns and only then multiplied by
+    /// $\pi$, so that the result is exact at integer and quarter-integer arguments.
+    ///
+    /// # Returns
+    ///
+    /// Tangent of $\pi$ times this value.
+    ///
+    /// # Domain
+    ///
+    /// $\mathbb{R}\\;\setminus\\;\left\\{\frac{1}{2}+n\mid n\in\mathbb{Z}\right\\}$ _half-turns_
+    ///
+    /// # Range
+    ///
+    /// $(-\infty,\infty)$
+    ///
+    /// # Warning
+    ///
+    /// The tangent function has poles at the points outside its domain, where this method returns
+    /// $\pm\infty$.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trig::Trig;
+    ///
+    /// let x = 0.25_f64;
+    ///
+    /// assert_eq!(x.tanpi(), 1.0);
+    /// assert_eq!(1.0_f64.tanpi(), 0.0);
+    /// ```
+    ///
+    /// # References
+    ///
+    /// * IEEE Standard for Floating-Point Arithmetic (IEEE 754-2019), Section 9.2
+    /// * [https://mathworld.wolfram.com/Tangent.html](https://mathworld.wolfram.com/Tangent.html)
+    fn tanpi(&self) -> Self;
+
+    /// Computes the inverse sine of this value, returning the result in half-turns (i.e. as a
+    /// multiple of $\pi$).
+    ///
+    /// # Returns
+    ///
+    /// Inverse sine of this value divided by $\pi$ \[half-turns\].
+    ///
+    /// # Domain
+    ///
+    /// $[-1,1]$
+    ///
+    /// # Range
+    ///
+    /// $\left[-\frac{1}{2},\frac{1}{2}\right]$ _half-turns_
+    ///
+    /// # Warning
+    ///
+    /// The value of the inverse sine function at points outside its domain is `NaN`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trig::Trig;
+    ///
+    /// let x = 1.0_f64;
+    ///
+    /// assert_eq!(x.asinpi(), 0.5);
+    /// ```
+    ///
+    /// # References
+    ///
+    /// * IEEE Standard for Floating-Point Arithmetic (IEEE 754-2019), Section 9.2
+    /// * [https://mathworld.wolfram.com/InverseSine.html](https://mathworld.wolfram.com/InverseSine.html)
+    fn asinpi(&self) -> Self;
+
+    /// Computes the inverse cosine of this value, returning the result in half-turns (i.e. as a
+    /// multiple of $\pi$).
+    ///
+    /// # Returns
+    ///
+    /// Inverse cosine of this value divided by $\pi$ \[half-turns\].
+    ///
+    /// # Domain
+    ///
+    /// $[-1,1]$
+    ///
+    /// # Range
+    ///
+    /// $\[0,1\]$ _half-turns_
+    ///
+    /// # Warning
+    ///
+    /// The value of the inverse cosine function at points outside its domain is `NaN`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trig::Trig;
+    ///
+    /// let x = -1.0_f64;
+    ///
+    /// assert_eq!(x.acospi(), 1.0);
+    /// ```
+    ///
+    /// # References
+    ///
+    /// * IEEE Standard for Floating-Point Arithmetic (IEEE 754-2019), Section 9.2
+    /// * [https://mathworld.wolfram.com/InverseCosine.html](https://mathworld.wolfram.com/InverseCosine.html)
+    fn acospi(&self) -> Self;
+
+    /// Computes the inverse tangent of this value, returning the result in half-turns (i.e. as a
+    /// multiple of $\pi$).
+    ///
+    /// # Returns
+    ///
+    /// Inverse tangent of this value divided by $\pi$ \[half-turns\].
+    ///
+    /// # Domain
+    ///
+    /// $(-\infty,\infty)$
+    ///
+    /// # Range
+    ///
+    /// $\left[-\frac{1}{2},\frac{1}{2}\right]$ _half-turns_
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trig::Trig;
+    ///
+    /// let x = 1.0_f64;
+    ///
+    /// assert_eq!(x.atanpi(), 0.25);
+    /// ```
+    ///
+    /// # References
+    ///
+    /// * IEEE Standard for Floating-Point Arithmetic (IEEE 754-2019), Section 9.2
+    /// * [https://mathworld.wolfram.com/InverseTangent.html](https://mathworld.wolfram.com/InverseTangent.html)
+    fn atanpi(&self) -> Self;
+
+    /// Computes the four-quadrant inverse tangent of `self` (`y`) and `other` (`x`), returning the
+    /// result in half-turns (i.e. as a multiple of $\pi$).
+    ///
+    /// The four-quadrant inverse tangent computes the angle, measured counterclockwise, between the
+    /// $+x$-axis and the ray from the origin to the point $(x,y)$.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - Other value (`x`) for computing the four-quadrant inverse with `self` (`y`).
+    ///
+    /// # Returns
+    ///
+    /// Four-quadrant inverse tangent of `self` (`y`) and `other` (`x`) divided by $\pi$
+    /// \[half-turns\].
+    ///
+    /// # Domain
+    ///
+    /// * `self` (`y`): $(-\infty,\infty)$
+    /// * `other` (`x`): $(-\infty,\infty)$
+    ///
+    /// # Range
+    ///
+    /// $[-1,1]$ _half-turns_
+    ///
+    /// # Examples
+    ///
+    /// ## 45 degrees clockwise
+    ///
+    /// ```
+    /// use trig::Trig;
+    ///
+    /// let x = 3.0_f64;
+    /// let y = -3.0_f64;
+    ///
+    /// assert_eq!(y.atan2pi(&x), -0.25);
+    /// ```
+    ///
+    /// ## 135 degrees counterclockwise
+    ///
+    /// ```
+    /// use trig::Trig;
+    ///
+    /// let x = -3.0_f64;
+    /// let y = 3.0_f64;
+    ///
+    /// assert_eq!(y.atan2pi(&x), 0.75);
+    /// ```
+    ///
+    /// # References
+    ///
+    /// * IEEE Standard for Floating-Point Arithmetic (IEEE 754-2019), Section 9.2
+    /// * [https://en.wikipedia.org/wiki/Atan2](https://en.wikipedia.org/wiki/Atan2)
+    fn atan2pi(&self, other: &Self) -> Self;
+
     /// Computes the hyperbolic sine of this value.
     ///
     /// # Returns
@@ -1118,7 +1481,7 @@ pub trait Trig {
     /// let g = (2.0 * E) / ((E * E) + 1.0);
     /// let abs_difference = (f - g).abs();
     ///
-    /// assert!(abs_difference < 1e-16);
+    /// assert!(abs_difference < 1e-15);
     /// ```
     ///
     /// # References
@@ -1156,7 +1519,7 @@ pub trait Trig {
     /// let g = (1.0 + E.powi(-2)) / (1.0 - E.powi(-2));
     /// let abs_difference = (f - g).abs();
     ///
-    /// assert!(abs_difference < 1e-16);
+    /// assert!(abs_difference < 1e-15);
     /// ```
     ///
     /// # References
@@ -1164,6 +1527,45 @@ pub trait Trig {
     /// * [https://mathworld.wolfram.com/HyperbolicCotangent.html](https://mathworld.wolfram.com/HyperbolicCotangent.html)
     fn coth(&self) -> Self;
 
+    /// Simultaneously computes the hyperbolic sine and hyperbolic cosine of this value.
+    ///
+    /// Both values are computed from a single evaluation of the exponential function, which makes
+    /// this method cheaper than calling [`Trig::sinh`] and [`Trig::cosh`] separately.
+    ///
+    /// # Returns
+    ///
+    /// Hyperbolic sine and hyperbolic cosine of this value, as the tuple `(sinh, cosh)`.
+    ///
+    /// # Domain
+    ///
+    /// $(-\infty,\infty)$
+    ///
+    /// # Range
+    ///
+    /// * hyperbolic sine: $(-\infty,\infty)$
+    /// * hyperbolic cosine: $[1,\infty)$
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::f64::consts::E;
+    /// use trig::Trig;
+    ///
+    /// let x = 1.0_f64;
+    /// let (s, c) = x.sinhcosh();
+    ///
+    /// assert!((s - ((E * E) - 1.0) / (2.0 * E)).abs() < 1e-15);
+    /// assert!((c - ((E * E) + 1.0) / (2.0 * E)).abs() < 1e-15);
+    /// ```
+    ///
+    /// # References
+    ///
+    /// * [https://mathworld.wolfram.com/HyperbolicSine.html](https://mathworld.wolfram.com/HyperbolicSine.html)
+    /// * [https://mathworld.wolfram.com/HyperbolicCosine.html](https://mathworld.wolfram.com/HyperbolicCosine.html)
+    fn sinhcosh(&self) -> (Self, Self)
+    where
+        Self: Sized;
+
     /// Computes the inverse hyperbolic sine of this value.
     ///
     /// # Returns
@@ -0,0 +1,800 @@
+//! Audit of the accuracy of the [`Trig`] implementations.
+//!
+//! Every [`Trig`] method on [`f32`] and [`f64`] is evaluated over dense random samples of its
+//! domain together with a fixed set of edge cases, and each result is compared against the
+//! correctly rounded value computed by the multiprecision reference in
+//! [`correctly_rounded`](crate::correctly_rounded). The error of a result is measured as its
+//! distance, in units in the last place (ulps), from the correctly rounded value, so an error of $0$
+//! means that the result is correctly rounded and an error of $n$ means that the result is within
+//! $n+\frac{1}{2}$ ulps of the exact value.
+//!
+//! Results whose correctly rounded value is subnormal are excluded, since an ulp there no longer
+//! corresponds to a fixed relative error.
+//!
+//! The audit prints the maximum and mean error of every method (visible with
+//! `cargo test ulp_audit -- --nocapture`) and fails if any maximum exceeds the bound documented in
+//! the crate-level "Accuracy" section.
+
+use crate::correctly_rounded;
+use crate::trig_trait::Trig;
+
+/// Number of random samples per method.
+const SAMPLES: usize = 2000;
+
+/// Documented accuracy bounds (maximum error in ulps for [`f32`] and [`f64`]) of the `std`, `libm`,
+/// and `deterministic` backends.
+///
+/// These must match the table in the "Accuracy" section of the crate documentation. With the
+/// `correctly-rounded` feature, every bound is $0$.
+const BOUNDS: [(&str, u64, u64); 53] = [
+    ("sin", 1, 1),
+    ("cos", 1, 1),
+    ("tan", 1, 2),
+    ("csc", 1, 2),
+    ("sec", 1, 2),
+    ("cot", 1, 2),
+    ("sincos (sin)", 1, 1),
+    ("sincos (cos)", 1, 1),
+    ("asin", 1, 1),
+    ("acos", 1, 1),
+    ("atan", 1, 1),
+    ("atan2", 1, 1),
+    ("acsc", 2, 1),
+    ("asec", 2, 1),
+    ("acot", 1, 1),
+    ("deg2rad", 1, 1),
+    ("rad2deg", 1, 1),
+    ("sind", 1, 2),
+    ("cosd", 1, 1),
+    ("tand", 3, 2),
+    ("cscd", 4, 3),
+    ("secd", 2, 2),
+    ("cotd", 4, 3),
+    ("sincosd (sin)", 2, 1),
+    ("sincosd (cos)", 2, 1),
+    ("asind", 1, 2),
+    ("acosd", 1, 2),
+    ("atand", 1, 2),
+    ("atan2d", 1, 1),
+    ("acscd", 1, 1),
+    ("asecd", 1, 1),
+    ("acotd", 1, 1),
+    ("sinpi", 2, 2),
+    ("cospi", 2, 2),
+    ("tanpi", 3, 3),
+    ("asinpi", 1, 2),
+    ("acospi", 1, 2),
+    ("atanpi", 1, 1),
+    ("atan2pi", 1, 2),
+    ("sinh", 2, 2),
+    ("cosh", 2, 1),
+    ("tanh", 2, 2),
+    ("csch", 2, 2),
+    ("sech", 2, 2),
+    ("coth", 3, 2),
+    ("sinhcosh (sinh)", 2, 2),
+    ("sinhcosh (cosh)", 2, 1),
+    ("asinh", 1, 1),
+    ("acosh", 2, 2),
+    ("atanh", 2, 1),
+    ("acsch", 2, 2),
+    ("asech", 2, 2),
+    ("acoth", 1, 1),
+];
+
+/// Floating-point format under audit.
+trait Float: Trig + Copy {
+    /// Name of the format.
+    const NAME: &'static str;
+
+    /// Number of significand bits (including the implicit bit).
+    const PRECISION: u32;
+
+    /// Exponent of the smallest positive normal number.
+    const MIN_EXPONENT: i32;
+
+    /// Exponent of the largest finite number.
+    const MAX_EXPONENT: i32;
+
+    /// Rounds an [`f64`] to this format.
+    fn from_f64(x: f64) -> Self;
+
+    /// Converts to an [`f64`] (exactly).
+    fn to_f64(self) -> f64;
+
+    /// Maps the value to an integer such that adjacent values map to adjacent integers and both
+    /// zeros map to $0$.
+    fn ordered(self) -> i64;
+}
+
+impl Float for f32 {
+    const NAME: &'static str = "f32";
+    const PRECISION: u32 = f32::MANTISSA_DIGITS;
+    const MIN_EXPONENT: i32 = f32::MIN_EXP - 1;
+    const MAX_EXPONENT: i32 = f32::MAX_EXP - 1;
+    fn from_f64(x: f64) -> f32 {
+        x as f32
+    }
+    fn to_f64(self) -> f64 {
+        self as f64
+    }
+    fn ordered(self) -> i64 {
+        let magnitude = (self.to_bits() & 0x7fff_ffff) as i64;
+        if self.is_sign_negative() {
+            -magnitude
+        } else {
+            magnitude
+        }
+    }
+}
+
+impl Float for f64 {
+    const NAME: &'static str = "f64";
+    const PRECISION: u32 = f64::MANTISSA_DIGITS;
+    const MIN_EXPONENT: i32 = f64::MIN_EXP - 1;
+    const MAX_EXPONENT: i32 = f64::MAX_EXP - 1;
+    fn from_f64(x: f64) -> f64 {
+        x
+    }
+    fn to_f64(self) -> f64 {
+        self
+    }
+    fn ordered(self) -> i64 {
+        let magnitude = (self.to_bits() & 0x7fff_ffff_ffff_ffff) as i64;
+        if self.is_sign_negative() {
+            -magnitude
+        } else {
+            magnitude
+        }
+    }
+}
+
+/// Domain from which the arguments of a method are sampled.
+#[derive(Clone, Copy)]
+enum Domain {
+    /// Angles in radians.
+    Radians,
+
+    /// Angles in degrees.
+    Degrees,
+
+    /// Angles in half-turns.
+    HalfTurns,
+
+    /// $[-1,1]$.
+    UnitInterval,
+
+    /// $(0,1]$.
+    PositiveUnitInterval,
+
+    /// $|x|\geq1$.
+    OutsideUnitInterval,
+
+    /// $x\geq1$.
+    AtLeastOne,
+
+    /// Arguments of the hyperbolic functions.
+    Hyperbolic,
+
+    /// The whole real line.
+    Real,
+}
+
+/// Method under audit.
+struct Method<T> {
+    /// Name of the method (with the component for methods returning a pair).
+    name: &'static str,
+
+    /// Domain of the first argument (the second argument, if any, is sampled from the real line).
+    domain: Domain,
+
+    /// Evaluates the method.
+    evaluate: fn(T, T) -> T,
+
+    /// Evaluates the correctly rounded reference at the given precision.
+    reference: fn(f64, f64, u32) -> f64,
+}
+
+/// Accuracy statistics of a method.
+struct Report {
+    /// Name of the method.
+    name: &'static str,
+
+    /// Number of samples compared.
+    samples: usize,
+
+    /// Maximum error \[ulp\].
+    max: u64,
+
+    /// Mean error \[ulp\].
+    mean: f64,
+
+    /// Arguments at which the maximum error occurs.
+    worst: (f64, f64),
+}
+
+/// SplitMix64 pseudorandom number generator (so that the samples are reproducible).
+struct Rng(u64);
+
+impl Rng {
+    /// Generates the next 64 random bits.
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Generates a uniform random number in $[a,b)$.
+    fn uniform(&mut self, a: f64, b: f64) -> f64 {
+        a + (b - a) * ((self.next_u64() >> 11) as f64 / (1u64 << 53) as f64)
+    }
+
+    /// Generates a uniform random integer in $[a,b]$.
+    fn integer(&mut self, a: i32, b: i32) -> i32 {
+        a + (self.next_u64() % (b - a + 1) as u64) as i32
+    }
+
+    /// Generates a random sign.
+    fn sign(&mut self) -> f64 {
+        if self.next_u64() & 1 == 0 { 1.0 } else { -1.0 }
+    }
+
+    /// Generates a number whose binary exponent is uniform in $[e_{\min},e_{\max}]$.
+    fn log_uniform(&mut self, min_exponent: i32, max_exponent: i32) -> f64 {
+        self.uniform(1.0, 2.0) * 2.0_f64.powi(self.integer(min_exponent, max_exponent))
+    }
+}
+
+/// Lists the methods under audit (methods returning a pair appear once per component).
+fn methods<T: Float>() -> Vec<Method<T>> {
+    use Domain::*;
+    use correctly_rounded as cr;
+    vec![
+        Method {
+            name: "sin",
+            domain: Radians,
+            evaluate: |x, _| x.sin(),
+            reference: |x, _, p| cr::sin(x, p),
+        },
+        Method {
+            name: "cos",
+            domain: Radians,
+            evaluate: |x, _| x.cos(),
+            reference: |x, _, p| cr::cos(x, p),
+        },
+        Method {
+            name: "tan",
+            domain: Radians,
+            evaluate: |x, _| x.tan(),
+            reference: |x, _, p| cr::tan(x, p),
+        },
+        Method {
+            name: "csc",
+            domain: Radians,
+            evaluate: |x, _| x.csc(),
+            reference: |x, _, p| cr::csc(x, p),
+        },
+        Method {
+            name: "sec",
+            domain: Radians,
+            evaluate: |x, _| x.sec(),
+            reference: |x, _, p| cr::sec(x, p),
+        },
+        Method {
+            name: "cot",
+            domain: Radians,
+            evaluate: |x, _| x.cot(),
+            reference: |x, _, p| cr::cot(x, p),
+        },
+        Method {
+            name: "sincos (sin)",
+            domain: Radians,
+            evaluate: |x, _| x.sincos().0,
+            reference: |x, _, p| cr::sin(x, p),
+        },
+        Method {
+            name: "sincos (cos)",
+            domain: Radians,
+            evaluate: |x, _| x.sincos().1,
+            reference: |x, _, p| cr::cos(x, p),
+        },
+        Method {
+            name: "asin",
+            domain: UnitInterval,
+            evaluate: |x, _| x.asin(),
+            reference: |x, _, p| cr::asin(x, p),
+        },
+        Method {
+            name: "acos",
+            domain: UnitInterval,
+            evaluate: |x, _| x.acos(),
+            reference: |x, _, p| cr::acos(x, p),
+        },
+        Method {
+            name: "atan",
+            domain: Real,
+            evaluate: |x, _| x.atan(),
+            reference: |x, _, p| cr::atan(x, p),
+        },
+        Method {
+            name: "atan2",
+            domain: Real,
+            evaluate: |y, x| y.atan2(&x),
+            reference: cr::atan2,
+        },
+        Method {
+            name: "acsc",
+            domain: OutsideUnitInterval,
+            evaluate: |x, _| x.acsc(),
+            reference: |x, _, p| cr::acsc(x, p),
+        },
+        Method {
+            name: "asec",
+            domain: OutsideUnitInterval,
+            evaluate: |x, _| x.asec(),
+            reference: |x, _, p| cr::asec(x, p),
+        },
+        Method {
+            name: "acot",
+            domain: Real,
+            evaluate: |x, _| x.acot(),
+            reference: |x, _, p| cr::acot(x, p),
+        },
+        Method {
+            name: "deg2rad",
+            domain: Real,
+            evaluate: |x, _| x.deg2rad(),
+            reference: |x, _, p| cr::deg2rad(x, p),
+        },
+        Method {
+            name: "rad2deg",
+            domain: Real,
+            evaluate: |x, _| x.rad2deg(),
+            reference: |x, _, p| cr::rad2deg(x, p),
+        },
+        Method {
+            name: "sind",
+            domain: Degrees,
+            evaluate: |x, _| x.sind(),
+            reference: |x, _, p| cr::sind(x, p),
+        },
+        Method {
+            name: "cosd",
+            domain: Degrees,
+            evaluate: |x, _| x.cosd(),
+            reference: |x, _, p| cr::cosd(x, p),
+        },
+        Method {
+            name: "tand",
+            domain: Degrees,
+            evaluate: |x, _| x.tand(),
+            reference: |x, _, p| cr::tand(x, p),
+        },
+        Method {
+            name: "cscd",
+            domain: Degrees,
+            evaluate: |x, _| x.cscd(),
+            reference: |x, _, p| cr::cscd(x, p),
+        },
+        Method {
+            name: "secd",
+            domain: Degrees,
+            evaluate: |x, _| x.secd(),
+            reference: |x, _, p| cr::secd(x, p),
+        },
+        Method {
+            name: "cotd",
+            domain: Degrees,
+            evaluate: |x, _| x.cotd(),
+            reference: |x, _, p| cr::cotd(x, p),
+        },
+        Method {
+            name: "sincosd (sin)",
+            domain: Degrees,
+            evaluate: |x, _| x.sincosd().0,
+            reference: |x, _, p| cr::sind(x, p),
+        },
+        Method {
+            name: "sincosd (cos)",
+            domain: Degrees,
+            evaluate: |x, _| x.sincosd().1,
+            reference: |x, _, p| cr::cosd(x, p),
+        },
+        Method {
+            name: "asind",
+            domain: UnitInterval,
+            evaluate: |x, _| x.asind(),
+            reference: |x, _, p| cr::asind(x, p),
+        },
+        Method {
+            name: "acosd",
+            domain: UnitInterval,
+            evaluate: |x, _| x.acosd(),
+            reference: |x, _, p| cr::acosd(x, p),
+        },
+        Method {
+            name: "atand",
+            domain: Real,
+            evaluate: |x, _| x.atand(),
+            reference: |x, _, p| cr::atand(x, p),
+        },
+        Method {
+            name: "atan2d",
+            domain: Real,
+            evaluate: |y, x| y.atan2d(&x),
+            reference: cr::atan2d,
+        },
+        Method {
+            name: "acscd",
+            domain: OutsideUnitInterval,
+            evaluate: |x, _| x.acscd(),
+            reference: |x, _, p| cr::acscd(x, p),
+        },
+        Method {
+            name: "asecd",
+            domain: OutsideUnitInterval,
+            evaluate: |x, _| x.asecd(),
+            reference: |x, _, p| cr::asecd(x, p),
+        },
+        Method {
+            name: "acotd",
+            domain: Real,
+            evaluate: |x, _| x.acotd(),
+            reference: |x, _, p| cr::acotd(x, p),
+        },
+        Method {
+            name: "sinpi",
+            domain: HalfTurns,
+            evaluate: |x, _| x.sinpi(),
+            reference: |x, _, p| cr::sinpi(x, p),
+        },
+        Method {
+            name: "cospi",
+            domain: HalfTurns,
+            evaluate: |x, _| x.cospi(),
+            reference: |x, _, p| cr::cospi(x, p),
+        },
+        Method {
+            name: "tanpi",
+            domain: HalfTurns,
+            evaluate: |x, _| x.tanpi(),
+            reference: |x, _, p| cr::tanpi(x, p),
+        },
+        Method {
+            name: "asinpi",
+            domain: UnitInterval,
+            evaluate: |x, _| x.asinpi(),
+            reference: |x, _, p| cr::asinpi(x, p),
+        },
+        Method {
+            name: "acospi",
+            domain: UnitInterval,
+            evaluate: |x, _| x.acospi(),
+            reference: |x, _, p| cr::acospi(x, p),
+        },
+        Method {
+            name: "atanpi",
+            domain: Real,
+            evaluate: |x, _| x.atanpi(),
+            reference: |x, _, p| cr::atanpi(x, p),
+        },
+        Method {
+            name: "atan2pi",
+            domain: Real,
+            evaluate: |y, x| y.atan2pi(&x),
+            reference: cr::atan2pi,
+        },
+        Method {
+            name: "sinh",
+            domain: Hyperbolic,
+            evaluate: |x, _| x.sinh(),
+            reference: |x, _, p| cr::sinh(x, p),
+        },
+        Method {
+            name: "cosh",
+            domain: Hyperbolic,
+            evaluate: |x, _| x.cosh(),
+            reference: |x, _, p| cr::cosh(x, p),
+        },
+        Method {
+            name: "tanh",
+            domain: Hyperbolic,
+            evaluate: |x, _| x.tanh(),
+            reference: |x, _, p| cr::tanh(x, p),
+        },
+        Method {
+            name: "csch",
+            domain: Hyperbolic,
+            evaluate: |x, _| x.csch(),
+            reference: |x, _, p| cr::csch(x, p),
+        },
+        Method {
+            name: "sech",
+            domain: Hyperbolic,
+            evaluate: |x, _| x.sech(),
+            reference: |x, _, p| cr::sech(x, p),
+        },
+        Method {
+            name: "coth",
+            domain: Hyperbolic,
+            evaluate: |x, _| x.coth(),
+            reference: |x, _, p| cr::coth(x, p),
+        },
+        Method {
+            name: "sinhcosh (sinh)",
+            domain: Hyperbolic,
+            evaluate: |x, _| x.sinhcosh().0,
+            reference: |x, _, p| cr::sinh(x, p),
+        },
+        Method {
+            name: "sinhcosh (cosh)",
+            domain: Hyperbolic,
+            evaluate: |x, _| x.sinhcosh().1,
+            reference: |x, _, p| cr::cosh(x, p),
+        },
+        Method {
+            name: "asinh",
+            domain: Real,
+            evaluate: |x, _| x.asinh(),
+            reference: |x, _, p| cr::asinh(x, p),
+        },
+        Method {
+            name: "acosh",
+            domain: AtLeastOne,
+            evaluate: |x, _| x.acosh(),
+            reference: |x, _, p| cr::acosh(x, p),
+        },
+        Method {
+            name: "atanh",
+            domain: UnitInterval,
+            evaluate: |x, _| x.atanh(),
+            reference: |x, _, p| cr::atanh(x, p),
+        },
+        Method {
+            name: "acsch",
+            domain: Real,
+            evaluate: |x, _| x.acsch(),
+            reference: |x, _, p| cr::acsch(x, p),
+        },
+        Method {
+            name: "asech",
+            domain: PositiveUnitInterval,
+            evaluate: |x, _| x.asech(),
+            reference: |x, _, p| cr::asech(x, p),
+        },
+        Method {
+            name: "acoth",
+            domain: OutsideUnitInterval,
+            evaluate: |x, _| x.acoth(),
+            reference: |x, _, p| cr::acoth(x, p),
+        },
+    ]
+}
+
+/// Draws a random argument from a domain.
+///
+/// # Arguments
+///
+/// * `domain` - Domain.
+/// * `rng` - Random number generator.
+///
+/// # Returns
+///
+/// Random argument, representable in the format `T`.
+fn sample<T: Float>(domain: Domain, rng: &mut Rng) -> T {
+    let (emin, emax) = (T::MIN_EXPONENT, T::MAX_EXPONENT);
+    let ulp_exponent = 1 - T::PRECISION as i32;
+    let dense = !rng.next_u64().is_multiple_of(4);
+    let s = rng.sign();
+    let x = match domain {
+        Domain::Radians if dense => rng.uniform(-8.0, 8.0) * std::f64::consts::PI,
+        Domain::Degrees if dense => match rng.next_u64() % 2 {
+            0 => rng.uniform(-1440.0, 1440.0),
+            _ => s * 7.5 * rng.integer(0, 1000) as f64,
+        },
+        Domain::HalfTurns if dense => match rng.next_u64() % 2 {
+            0 => rng.uniform(-8.0, 8.0),
+            _ => s * rng.integer(0, 1000) as f64 / 24.0,
+        },
+        Domain::Radians | Domain::Degrees | Domain::HalfTurns | Domain::Real => {
+            s * rng.log_uniform(emin, emax)
+        }
+        Domain::UnitInterval | Domain::PositiveUnitInterval => {
+            let x = if dense {
+                rng.uniform(0.0, 1.0)
+            } else if rng.next_u64().is_multiple_of(2) {
+                1.0 - rng.log_uniform(ulp_exponent - 1, -2)
+            } else {
+                rng.log_uniform(emin, -1)
+            };
+            match domain {
+                Domain::UnitInterval => s * x,
+                _ => x,
+            }
+        }
+        Domain::OutsideUnitInterval | Domain::AtLeastOne => {
+            let x = if dense {
+                1.0 + rng.log_uniform(ulp_exponent, 1)
+            } else {
+                rng.log_uniform(0, emax)
+            };
+            match domain {
+                Domain::OutsideUnitInterval => s * x,
+                _ => x,
+            }
+        }
+        Domain::Hyperbolic if dense => {
+            // Beyond this, the results overflow or are subnormal.
+            let limit = if T::PRECISION == f64::MANTISSA_DIGITS {
+                710.0
+            } else {
+                89.0
+            };
+            rng.uniform(-limit, limit)
+        }
+        Domain::Hyperbolic => s * rng.log_uniform(emin, 0),
+    };
+    T::from_f64(x)
+}
+
+/// Fixed edge-case arguments shared by every method.
+///
+/// # Returns
+///
+/// Edge cases, representable in the format `T`.
+fn edge_cases<T: Float>() -> Vec<T> {
+    let (emin, emax) = (T::MIN_EXPONENT, T::MAX_EXPONENT);
+    let ulp = 2.0_f64.powi(1 - T::PRECISION as i32);
+    let mut cases = vec![
+        0.0,
+        2.0_f64.powi(emin),
+        2.0_f64.powi(emin + 1) * (1.0 - ulp / 2.0),
+        ulp,
+        0.5,
+        1.0 - ulp / 2.0,
+        1.0,
+        1.0 + ulp,
+        2.0,
+        std::f64::consts::FRAC_PI_4,
+        std::f64::consts::FRAC_PI_2,
+        std::f64::consts::PI,
+        1e6,
+        2.0_f64.powi(T::PRECISION as i32),
+        2.0_f64.powi(emax) * (2.0 - ulp),
+        f64::INFINITY,
+        f64::NAN,
+        // Arguments at which a method once exceeded its bound (`coth` by 3 ulps).
+        0.576_336_389_424_958_9,
+    ];
+    for k in 1..=8 {
+        cases.push(15.0 * k as f64);
+        cases.push(0.25 * k as f64);
+    }
+    let mut cases: Vec<T> = cases.into_iter().map(T::from_f64).collect();
+    cases.extend(cases.clone().into_iter().map(|x| T::from_f64(-x.to_f64())));
+    cases
+}
+
+/// Computes the error of a result relative to its correctly rounded value.
+///
+/// # Arguments
+///
+/// * `result` - Result of a method.
+/// * `reference` - Correctly rounded value.
+///
+/// # Returns
+///
+/// Error \[ulp\], or [`None`] if the correctly rounded value is subnormal.
+fn ulp_error<T: Float>(result: T, reference: T) -> Option<u64> {
+    let (r, c) = (result.to_f64(), reference.to_f64());
+    if c != 0.0 && c.abs() < 2.0_f64.powi(T::MIN_EXPONENT) {
+        None
+    } else if r.is_nan() || c.is_nan() {
+        Some(if r.is_nan() && c.is_nan() {
+            0
+        } else {
+            u64::MAX
+        })
+    } else {
+        Some(result.ordered().abs_diff(reference.ordered()))
+    }
+}
+
+/// Audits every method in a floating-point format.
+///
+/// # Returns
+///
+/// Accuracy report of every method.
+fn audit<T: Float>() -> Vec<Report> {
+    let mut rng = Rng(0x5eed_f00d_u64 + T::PRECISION as u64);
+    let edge_cases = edge_cases::<T>();
+    methods::<T>()
+        .into_iter()
+        .map(|method| {
+            let mut arguments: Vec<(T, T)> = edge_cases
+                .iter()
+                .flat_map(|&x| [(x, T::from_f64(1.0)), (x, T::from_f64(-2.0))])
+                .collect();
+            for _ in 0..SAMPLES {
+                let x = sample(method.domain, &mut rng);
+                arguments.push((x, sample(Domain::Real, &mut rng)));
+            }
+            let mut report = Report {
+                name: method.name,
+                samples: 0,
+                max: 0,
+                mean: 0.0,
+                worst: (f64::NAN, f64::NAN),
+            };
+            for (x, y) in arguments {
+                let result = (method.evaluate)(x, y);
+                let reference = (method.reference)(x.to_f64(), y.to_f64(), T::PRECISION);
+                if let Some(error) = ulp_error(result, T::from_f64(reference)) {
+                    report.samples += 1;
+                    report.mean += error as f64;
+                    if error > report.max || report.worst.0.is_nan() {
+                        report.max = report.max.max(error);
+                        report.worst = (x.to_f64(), y.to_f64());
+                    }
+                }
+            }
+            report.mean /= report.samples as f64;
+            report
+        })
+        .collect()
+}
+
+/// Prints the audit of a floating-point format and checks it against the documented bounds.
+///
+/// # Panics
+///
+/// If the maximum error of any method exceeds its documented bound.
+fn check<T: Float>() {
+    let mut failures = Vec::new();
+    println!(
+        "{:<16} {:>8} {:>10} {:>10} {:>6}  worst argument(s)",
+        T::NAME,
+        "samples",
+        "max [ulp]",
+        "mean [ulp]",
+        "bound"
+    );
+    for report in audit::<T>() {
+        let (_, f32_bound, f64_bound) = BOUNDS
+            .iter()
+            .find(|(name, _, _)| *name == report.name)
+            .unwrap();
+        let bound = if cfg!(feature = "correctly-rounded") {
+            0
+        } else if T::PRECISION == f32::MANTISSA_DIGITS {
+            *f32_bound
+        } else {
+            *f64_bound
+        };
+        println!(
+            "{:<16} {:>8} {:>10} {:>10.4} {:>6}  {:?}",
+            report.name, report.samples, report.max, report.mean, bound, report.worst
+        );
+        if report.max > bound {
+            failures.push(report.name);
+        }
+    }
+    assert!(
+        failures.is_empty(),
+        "{} methods exceed their documented accuracy bounds: {:?}",
+        T::NAME,
+        failures
+    );
+}
+
+#[test]
+fn test_f32_accuracy() {
+    check::<f32>();
+}
+
+#[test]
+fn test_f64_accuracy() {
+    check::<f64>();
+}